@@ -0,0 +1,78 @@
+//! Allocating Conveniences for the Executable and Linkable Format
+//!
+//! The `parse` module of `rt11-ffi-elf` exposes its helpers as iterators over
+//! caller provided buffers, and never allocates. This module collects the
+//! output of these iterators into vectors, for tools running with an
+//! allocator. All of its functions require `alloc`.
+//!
+//! This is a separate crate, rather than a feature of `rt11-ffi-elf`, so it
+//! never enters the dependency graph of the loader. Cargo unifies features
+//! across a workspace build (e.g., with `--all-features`), and would thus
+//! link `alloc` into the loader binaries, which provide no global allocator.
+//! Neither `rt11-ffi-elf` nor `rt11-linux` provide any allocating API.
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+use rt11_ffi_elf::{elfn, parse};
+
+/// Collect Symbols
+///
+/// Collect the output of `parse::symbols()` into a vector.
+pub fn symbols_vec<'a>(
+    symtab: &'a [elfn::Sym],
+    strtab: &'a [u8],
+) -> alloc::vec::Vec<(&'a [u8], &'a elfn::Sym)> {
+    parse::symbols(symtab, strtab).collect()
+}
+
+/// Collect Needed Libraries
+///
+/// Collect the output of `parse::needed_libraries()` into a vector,
+/// preserving the load order.
+pub fn needed_libraries_vec<'a>(
+    dynamic: &'a [elfn::Dyn],
+    strtab: &'a [u8],
+) -> alloc::vec::Vec<&'a [u8]> {
+    parse::needed_libraries(dynamic, strtab).collect()
+}
+
+/// Collect Program Headers
+///
+/// Collect the output of `parse::phdrs()` into a vector.
+pub fn phdrs_vec(table: &[u8]) -> Result<alloc::vec::Vec<elfn::Phdr>, parse::Error> {
+    Ok(parse::phdrs(table)?.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rt11_ffi_elf::elf;
+
+    #[test]
+    fn test_vec() {
+        let strtab = b"\0libc.so.6\0main\0";
+        let dynamic = [
+            elfn::Dyn {
+                d_tag: elf::Dyn::<(), ()>::DT_NEEDED as _,
+                d_val: 1,
+                ..Default::default()
+            },
+            elfn::Dyn::default(),
+        ];
+        assert_eq!(needed_libraries_vec(&dynamic, strtab), [&b"libc.so.6"[..]]);
+
+        let symtab = [elfn::Sym::default(), elfn::Sym { st_name: 11, ..Default::default() }];
+        assert_eq!(
+            symbols_vec(&symtab, strtab),
+            [(&b""[..], &symtab[0]), (&b"main"[..], &symtab[1])],
+        );
+
+        let table = [0u8; 2 * core::mem::size_of::<elfn::Phdr>()];
+        assert_eq!(phdrs_vec(&table), Ok(std::vec![elfn::Phdr::default(); 2]));
+        assert_eq!(phdrs_vec(&table[1..]), Err(parse::Error::Truncated));
+    }
+}
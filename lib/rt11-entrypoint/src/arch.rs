@@ -56,6 +56,18 @@ pub mod doc {
         ($id:literal) => { $id }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_branch_tracking {
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_branch_tracking_property {
+        () => { "" }
+    }
+
     #[doc(hidden)]
     #[macro_export]
     macro_rules! arch_doc_entry_align {
@@ -80,6 +92,30 @@ pub mod doc {
         ($_:expr) => { "" }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_gnu_property {
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_pointer_auth {
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_pointer_auth_property {
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_zero_frame_pointer {
+        () => { "" }
+    }
+
     /// Expand Identifier with Prefix
     ///
     /// Take a GNU-as identifier and prefix it with the
@@ -91,6 +127,24 @@ pub mod doc {
     /// is used for a specific platform.
     pub use arch_doc_asm_prefix as asm_prefix;
 
+    /// Indirect-branch-tracking Landing Pad
+    ///
+    /// This macro expands to the landing pad of indirect-branch-tracking
+    /// (e.g., `endbr64` on x86-64). It is expanded right after the custom
+    /// entry-point header, so it is the first instruction of the symbol. If
+    /// the architecture has no opt-in branch tracking, this should expand
+    /// to an empty string.
+    pub use arch_doc_branch_tracking as branch_tracking;
+
+    /// GNU Program Properties for Indirect-branch-tracking
+    ///
+    /// This macro expands to the assembly of a `.note.gnu.property`
+    /// section that announces the indirect-branch-tracking of the
+    /// entry-point (see `branch_tracking`). It is emitted in addition to
+    /// `gnu_property`. If the architecture has no opt-in branch tracking,
+    /// this should expand to an empty string.
+    pub use arch_doc_branch_tracking_property as branch_tracking_property;
+
     /// Alignment Requirements for Function Entry-points
     ///
     /// This macro expands to the alignment-requirements of function
@@ -124,6 +178,44 @@ pub mod doc {
     /// Note that this is expanded after the code of the entry-point but
     /// before the generic footer of a symbol.
     pub use arch_doc_entry_custom_end as entry_custom_end;
+
+    /// GNU Program Properties
+    ///
+    /// This macro expands to the assembly of a `.note.gnu.property`
+    /// section that announces the hardening features supported by the
+    /// entry-point (e.g., branch-target or shadow-stack protection). If
+    /// the architecture has no such features, this should expand to an
+    /// empty string.
+    ///
+    /// Note that the linker only retains a feature in the final binary if
+    /// all linked objects announce it.
+    pub use arch_doc_gnu_property as gnu_property;
+
+    /// Return-address Signing
+    ///
+    /// This macro expands to the inline-assembly that signs the return
+    /// address of the entry-point, including the matching DWARF
+    /// annotations. It is expanded right after the start of the function is
+    /// marked via DWARF. If the architecture has no pointer-authentication,
+    /// this should expand to an empty string.
+    pub use arch_doc_pointer_auth as pointer_auth;
+
+    /// GNU Program Properties for Return-address Signing
+    ///
+    /// This macro expands to the assembly of a `.note.gnu.property`
+    /// section that announces the pointer-authentication of the
+    /// entry-point (see `pointer_auth`). It is emitted in addition to
+    /// `gnu_property`, and linkers combine the features of all notes of an
+    /// object. If the architecture has no pointer-authentication, this
+    /// should expand to an empty string.
+    pub use arch_doc_pointer_auth_property as pointer_auth_property;
+
+    /// Frame-pointer Reset
+    ///
+    /// This macro expands to the inline-assembly that clears the
+    /// frame-pointer register of the architecture. It is used to mark the
+    /// root frame for frame-pointer based unwinders.
+    pub use arch_doc_zero_frame_pointer as zero_frame_pointer;
 }
 
 /// ARM 32-bit Architecture Support
@@ -138,6 +230,20 @@ pub mod arm {
         ($id:literal) => { concat!("%", $id) }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_branch_tracking {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_branch_tracking_property {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
     #[doc(hidden)]
     #[macro_export]
     macro_rules! arch_arm_entry_align {
@@ -181,11 +287,45 @@ pub mod arm {
         ($_:expr) => { ".fnend;\n" }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_gnu_property {
+        // No arch-specific program properties.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_pointer_auth {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_pointer_auth_property {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_zero_frame_pointer {
+        // Clear the frame-pointer (%r11) to mark the root frame.
+        () => { "mov r11, #0;\n" }
+    }
+
     pub use arch_arm_asm_prefix as asm_prefix;
+    pub use arch_arm_branch_tracking as branch_tracking;
+    pub use arch_arm_branch_tracking_property as branch_tracking_property;
     pub use arch_arm_entry_align as entry_align;
     pub use arch_arm_entry_code as entry_code;
     pub use arch_arm_entry_custom_begin as entry_custom_begin;
     pub use arch_arm_entry_custom_end as entry_custom_end;
+    pub use arch_arm_gnu_property as gnu_property;
+    pub use arch_arm_pointer_auth as pointer_auth;
+    pub use arch_arm_pointer_auth_property as pointer_auth_property;
+    pub use arch_arm_zero_frame_pointer as zero_frame_pointer;
 }
 
 /// ARM 64-bit Architecture Support
@@ -193,7 +333,10 @@ pub mod arm {
 /// This module implements the required macros and interfaces for the
 /// ARM architecture in 64-bit mode.
 ///
-/// ARMv8.5-A support is required at compile-time, but not runtime.
+/// ARMv8.5-A support is required at compile-time, but not runtime. The
+/// landing pads (i.e., `bti c`) and return-address signing (i.e.,
+/// `paciasp`) are encoded in the hint space, and thus execute as no-ops on
+/// older machines.
 pub mod arm64 {
     #[doc(hidden)]
     #[macro_export]
@@ -202,6 +345,20 @@ pub mod arm64 {
         ($id:literal) => { concat!("%", $id) }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_branch_tracking {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_branch_tracking_property {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
     #[doc(hidden)]
     #[macro_export]
     macro_rules! arch_arm64_entry_align {
@@ -249,11 +406,89 @@ pub mod arm64 {
         ($_:expr) => { "" }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_gnu_property {
+        // Announce BTI support, matching the `bti c` landing pad of the
+        // entry-point. The descriptor carries a single 4-byte property,
+        // padded to 8 bytes as required for 64-bit objects.
+        () => {
+            core::concat!(
+                ".pushsection .note.gnu.property, \"a\", %note;\n",
+                ".balign 8;\n",
+                ".long 4;\n", // n_namesz
+                ".long 16;\n", // n_descsz
+                ".long 5;\n", // n_type: NT_GNU_PROPERTY_TYPE_0
+                ".asciz \"GNU\";\n",
+                ".long 0xc0000000;\n", // pr_type: GNU_PROPERTY_AARCH64_FEATURE_1_AND
+                ".long 4;\n", // pr_datasz
+                ".long 0x1;\n", // pr_data: GNU_PROPERTY_AARCH64_FEATURE_1_BTI
+                ".long 0;\n", // padding
+                ".popsection;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_pointer_auth {
+        // Sign the return address (%x30) with key A and the stack-pointer as
+        // modifier, and tell DWARF unwinders that it is signed from here on.
+        // The instruction lives in the hint space, and thus is a no-op on
+        // machines without pointer-authentication (i.e., before ARMv8.3-A).
+        // It is never authenticated (i.e., `autiasp`), since the
+        // entry-point does not return.
+        () => {
+            core::concat!(
+                "paciasp;\n",
+                ".cfi_negate_ra_state;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_pointer_auth_property {
+        // Announce PAC support, matching the `paciasp` of the entry-point.
+        // This is a separate note next to the BTI note, and linkers merge
+        // the features of all notes of an object. The descriptor carries a
+        // single 4-byte property, padded to 8 bytes as required for 64-bit
+        // objects.
+        () => {
+            core::concat!(
+                ".pushsection .note.gnu.property, \"a\", %note;\n",
+                ".balign 8;\n",
+                ".long 4;\n", // n_namesz
+                ".long 16;\n", // n_descsz
+                ".long 5;\n", // n_type: NT_GNU_PROPERTY_TYPE_0
+                ".asciz \"GNU\";\n",
+                ".long 0xc0000000;\n", // pr_type: GNU_PROPERTY_AARCH64_FEATURE_1_AND
+                ".long 4;\n", // pr_datasz
+                ".long 0x2;\n", // pr_data: GNU_PROPERTY_AARCH64_FEATURE_1_PAC
+                ".long 0;\n", // padding
+                ".popsection;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_zero_frame_pointer {
+        // Clear the frame-pointer (%x29) to mark the root frame.
+        () => { "mov x29, xzr;\n" }
+    }
+
     pub use arch_arm64_asm_prefix as asm_prefix;
+    pub use arch_arm64_branch_tracking as branch_tracking;
+    pub use arch_arm64_branch_tracking_property as branch_tracking_property;
     pub use arch_arm64_entry_align as entry_align;
     pub use arch_arm64_entry_code as entry_code;
     pub use arch_arm64_entry_custom_begin as entry_custom_begin;
     pub use arch_arm64_entry_custom_end as entry_custom_end;
+    pub use arch_arm64_gnu_property as gnu_property;
+    pub use arch_arm64_pointer_auth as pointer_auth;
+    pub use arch_arm64_pointer_auth_property as pointer_auth_property;
+    pub use arch_arm64_zero_frame_pointer as zero_frame_pointer;
 }
 
 /// RISC-V 64-bit Architecture Support
@@ -268,6 +503,20 @@ pub mod riscv64 {
         ($id:literal) => { concat!("%", $id) }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_branch_tracking {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_branch_tracking_property {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
     #[doc(hidden)]
     #[macro_export]
     macro_rules! arch_riscv64_entry_align {
@@ -311,11 +560,45 @@ pub mod riscv64 {
         ($_:expr) => { "" }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_gnu_property {
+        // No arch-specific program properties.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_pointer_auth {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_pointer_auth_property {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_zero_frame_pointer {
+        // Clear the frame-pointer (%s0) to mark the root frame.
+        () => { "li s0, 0;\n" }
+    }
+
     pub use arch_riscv64_asm_prefix as asm_prefix;
+    pub use arch_riscv64_branch_tracking as branch_tracking;
+    pub use arch_riscv64_branch_tracking_property as branch_tracking_property;
     pub use arch_riscv64_entry_align as entry_align;
     pub use arch_riscv64_entry_code as entry_code;
     pub use arch_riscv64_entry_custom_begin as entry_custom_begin;
     pub use arch_riscv64_entry_custom_end as entry_custom_end;
+    pub use arch_riscv64_gnu_property as gnu_property;
+    pub use arch_riscv64_pointer_auth as pointer_auth;
+    pub use arch_riscv64_pointer_auth_property as pointer_auth_property;
+    pub use arch_riscv64_zero_frame_pointer as zero_frame_pointer;
 }
 
 /// Intel 32-bit (x86 / i686) Architecture Support
@@ -330,6 +613,20 @@ pub mod x86 {
         ($id:literal) => { concat!("@", $id) }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_branch_tracking {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_branch_tracking_property {
+        // No indirect-branch-tracking.
+        () => { "" }
+    }
+
     #[doc(hidden)]
     #[macro_export]
     macro_rules! arch_x86_entry_align {
@@ -380,11 +677,45 @@ pub mod x86 {
         ($_:expr) => { "" }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_gnu_property {
+        // No arch-specific program properties.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_pointer_auth {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_pointer_auth_property {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_zero_frame_pointer {
+        // Clear the frame-pointer (%ebp) to mark the root frame.
+        () => { "xor ebp, ebp;\n" }
+    }
+
     pub use arch_x86_asm_prefix as asm_prefix;
+    pub use arch_x86_branch_tracking as branch_tracking;
+    pub use arch_x86_branch_tracking_property as branch_tracking_property;
     pub use arch_x86_entry_align as entry_align;
     pub use arch_x86_entry_code as entry_code;
     pub use arch_x86_entry_custom_begin as entry_custom_begin;
     pub use arch_x86_entry_custom_end as entry_custom_end;
+    pub use arch_x86_gnu_property as gnu_property;
+    pub use arch_x86_pointer_auth as pointer_auth;
+    pub use arch_x86_pointer_auth_property as pointer_auth_property;
+    pub use arch_x86_zero_frame_pointer as zero_frame_pointer;
 }
 
 /// Intel 64-bit (x86-64 / amd64) Architecture Support
@@ -399,6 +730,37 @@ pub mod x86_64 {
         ($id:literal) => { concat!("@", $id) }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_branch_tracking {
+        // Denote start of function with an indirect-branch-tracking landing
+        // pad. The instruction decodes as no-op on machines without CET.
+        () => { "endbr64;\n" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_branch_tracking_property {
+        // Announce IBT support, matching the `endbr64` landing pad of the
+        // entry-point. The descriptor carries a single 4-byte property,
+        // padded to 8 bytes as required for 64-bit objects.
+        () => {
+            core::concat!(
+                ".pushsection .note.gnu.property, \"a\", @note;\n",
+                ".balign 8;\n",
+                ".long 4;\n", // n_namesz
+                ".long 16;\n", // n_descsz
+                ".long 5;\n", // n_type: NT_GNU_PROPERTY_TYPE_0
+                ".asciz \"GNU\";\n",
+                ".long 0xc0000002;\n", // pr_type: GNU_PROPERTY_X86_FEATURE_1_AND
+                ".long 4;\n", // pr_datasz
+                ".long 0x1;\n", // pr_data: GNU_PROPERTY_X86_FEATURE_1_IBT
+                ".long 0;\n", // padding
+                ".popsection;\n",
+            )
+        }
+    }
+
     #[doc(hidden)]
     #[macro_export]
     macro_rules! arch_x86_64_entry_align {
@@ -442,11 +804,45 @@ pub mod x86_64 {
         ($_:expr) => { "" }
     }
 
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_gnu_property {
+        // No arch-specific program properties.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_pointer_auth {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_pointer_auth_property {
+        // No pointer-authentication.
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_zero_frame_pointer {
+        // Clear the frame-pointer (%rbp) to mark the root frame.
+        () => { "xor ebp, ebp;\n" }
+    }
+
     pub use arch_x86_64_asm_prefix as asm_prefix;
+    pub use arch_x86_64_branch_tracking as branch_tracking;
+    pub use arch_x86_64_branch_tracking_property as branch_tracking_property;
     pub use arch_x86_64_entry_align as entry_align;
     pub use arch_x86_64_entry_code as entry_code;
     pub use arch_x86_64_entry_custom_begin as entry_custom_begin;
     pub use arch_x86_64_entry_custom_end as entry_custom_end;
+    pub use arch_x86_64_gnu_property as gnu_property;
+    pub use arch_x86_64_pointer_auth as pointer_auth;
+    pub use arch_x86_64_pointer_auth_property as pointer_auth_property;
+    pub use arch_x86_64_zero_frame_pointer as zero_frame_pointer;
 }
 
 /// Native Architecture
@@ -469,3 +865,54 @@ pub use riscv64 as native;
 pub use x86 as native;
 #[cfg(all(not(doc), target_arch = "x86_64"))]
 pub use x86_64 as native;
+
+#[cfg(test)]
+mod tests {
+    // Verify that no assembly fragment contains the line-comment marker of
+    // the assembler dialect of its architecture (outside of string
+    // literals). Such a marker would silently truncate the directive (e.g.,
+    // `@progbits` is a comment on ARM, and thus yields a section without
+    // type). All architecture modules are compiled on all targets, so this
+    // covers foreign architectures as well.
+    fn check_dialect(comment: &str, fragments: &[&str]) {
+        for fragment in fragments {
+            for line in fragment.lines() {
+                let code: std::string::String = line
+                    .split('"')
+                    .step_by(2)
+                    .collect();
+                assert!(!code.contains(comment), "Comment marker in: {}", line);
+            }
+        }
+    }
+
+    macro_rules! fragments {
+        ($arch:ident) => {
+            [
+                core::concat!(
+                    ".pushsection ..text, \"ax\", ",
+                    crate::arch::$arch::asm_prefix!("progbits"),
+                    ";\n",
+                ),
+                crate::arch::$arch::branch_tracking!(),
+                crate::arch::$arch::branch_tracking_property!(),
+                crate::arch::$arch::entry_custom_begin!("sym"),
+                crate::arch::$arch::entry_code!("sym"),
+                crate::arch::$arch::entry_custom_end!("sym"),
+                crate::arch::$arch::gnu_property!(),
+                crate::arch::$arch::pointer_auth!(),
+                crate::arch::$arch::pointer_auth_property!(),
+                crate::arch::$arch::zero_frame_pointer!(),
+            ]
+        };
+    }
+
+    #[test]
+    fn test_dialect() {
+        check_dialect("@", &fragments!(arm));
+        check_dialect("//", &fragments!(arm64));
+        check_dialect("#", &fragments!(riscv64));
+        check_dialect("#", &fragments!(x86));
+        check_dialect("#", &fragments!(x86_64));
+    }
+}
@@ -38,9 +38,56 @@ pub mod arch;
 ///
 /// `extern "C" fn loader(sp: *const core::ffi::c_void) -> rt11_ffi_efi::elfn::Size`
 /// `extern "C" fn main() -> !`
+///
+/// On ARM64, the symbol always starts with the landing pad of
+/// branch-target-identification (i.e., `bti c`), so it remains a valid
+/// target of indirect branches if BTI is enforced. The landing pad is a
+/// no-op on machines without BTI, and thus need not be requested
+/// explicitly.
+///
+/// Further options can be passed as trailing identifiers, in any order:
+///
+///  * `branch_tracking`: Start the symbol with the landing pad of
+///    indirect-branch-tracking (i.e., `endbr64` on x86-64), and emit a
+///    `.note.gnu.property` section announcing it (i.e.,
+///    `GNU_PROPERTY_X86_FEATURE_1_IBT`). This is required for CET builds,
+///    where the entry-point might be reached via an indirect branch with IBT
+///    enforced. The linker only marks the final binary as IBT-compatible if
+///    all linked objects announce it. Note that the kernel does not enable
+///    IBT for user space based on this note. It is up to the runtime to
+///    request it. The instruction is a no-op on machines without CET. On
+///    other platforms this option has no effect (ARM64 always starts with
+///    `bti c`, see above).
+///
+///  * `gnu_property`: Emit a `.note.gnu.property` section, announcing the
+///    hardening features supported by the entry-point of the platform (i.e.,
+///    BTI on ARM64). The kernel only enforces BTI on a static executable
+///    (or on the interpreter of a dynamic one) if it carries the respective
+///    property note. Since the linker only retains a feature if all linked
+///    objects announce it, the remaining code must be compiled with BTI
+///    enabled as well. On other platforms this option has no effect.
+///
+///  * `pointer_auth`: Sign the return address on entry with the
+///    pointer-authentication of the platform (i.e., `paciasp` on ARM64), and
+///    emit a `.note.gnu.property` section announcing it (i.e.,
+///    `GNU_PROPERTY_AARCH64_FEATURE_1_PAC`). The entry-point never returns,
+///    so the signature is never authenticated, but toolchains building with
+///    return-address signing expect all functions to be framed accordingly.
+///    The instruction is a no-op on machines without pointer-authentication
+///    (i.e., before ARMv8.3-A), so it is safe to emit unconditionally. On
+///    other platforms this option has no effect.
+///
+///  * `zero_frame_pointer`: Clear the frame-pointer register of the platform
+///    before calling into the loader. By default, the entry-point only marks
+///    the return address as undefined, which terminates DWARF-based
+///    unwinding, but leaves the frame-pointer as provided by the kernel.
+///    Frame-pointer based unwinders (e.g., used by profilers) rely on a NULL
+///    frame-pointer to detect the root frame, though. This costs a single
+///    instruction, but is only of use if the loader and application are
+///    compiled with frame-pointers.
 #[macro_export]
 macro_rules! assembly {
-    ($section:expr, $symbol:expr) => {
+    ($section:expr, $symbol:expr $(, $opt:ident)* $(,)?) => {
         core::concat!(
             // Create an ELF section with the given name. Flag 'a' marks the
             // resulting segment as runtime allocated, 'x' ensures the pages
@@ -58,8 +105,15 @@ macro_rules! assembly {
             $symbol, ":\n",
             // Custom jump-pad of the platform, or empty.
             $crate::arch::native::entry_custom_begin!($symbol),
+            // Landing pad of indirect-branch-tracking, if requested.
+            $($crate::assembly_option!($opt, branch_tracking),)*
             // Mark start of function via DWARF.
             ".cfi_startproc;\n",
+            // Sign the return address, if requested.
+            $($crate::assembly_option!($opt, pointer_auth),)*
+            // Establish a root frame for frame-pointer unwinders, if
+            // requested.
+            $($crate::assembly_option!($opt, zero_frame_pointer),)*
             // Fill in the actual assembly instructions of the platform.
             $crate::arch::native::entry_code!($symbol),
             // Mark end of function via DWARF.
@@ -70,9 +124,36 @@ macro_rules! assembly {
             // position).
             ".size ", $symbol, ", . - ", $symbol, ";\n",
             // Restore the section marker of the surrounding code.
-            ".popsection;\n"
+            ".popsection;\n",
+            // Announce the hardening features of the entry-point, if
+            // requested.
+            $($crate::assembly_option!($opt, gnu_property),)*
         )
-    }
+    };
+}
+
+/// Entry-point Assembly Option
+///
+/// This is a helper of `assembly!()`. It expands to the assembly of the
+/// option `$opt` if it matches the option `$want`, or to an empty string
+/// otherwise. Options that contribute to the property notes expand to them
+/// if `$want` is `gnu_property`. Unknown options raise a compile-time error.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assembly_option {
+    (branch_tracking, branch_tracking) => { $crate::arch::native::branch_tracking!() };
+    (branch_tracking, gnu_property) => { $crate::arch::native::branch_tracking_property!() };
+    (gnu_property, gnu_property) => { $crate::arch::native::gnu_property!() };
+    (pointer_auth, pointer_auth) => { $crate::arch::native::pointer_auth!() };
+    (pointer_auth, gnu_property) => { $crate::arch::native::pointer_auth_property!() };
+    (zero_frame_pointer, zero_frame_pointer) => { $crate::arch::native::zero_frame_pointer!() };
+    (branch_tracking, $want:ident) => { "" };
+    (gnu_property, $want:ident) => { "" };
+    (pointer_auth, $want:ident) => { "" };
+    (zero_frame_pointer, $want:ident) => { "" };
+    ($opt:ident, $want:ident) => {
+        core::compile_error!(core::concat!("Unknown entry-point option: ", core::stringify!($opt)))
+    };
 }
 
 #[cfg(test)]
@@ -90,12 +171,128 @@ mod tests {
         sym rt11_entrypoint_loader,
     );
     extern "C" fn rt11_entrypoint_loader(_sp: *const core::ffi::c_void) -> elfn::Size {
-        rt11_entrypoint_main as usize as elfn::Size
+        rt11_entrypoint_main as *const () as usize as elfn::Size
     }
     extern "C" fn rt11_entrypoint_main() -> ! {
         core::panic!("Dummy entry-point");
     }
 
+    // Dummy Entry-point with Properties
+    //
+    // Create another dummy entry-point called `rt11_entrypoint_test_prop`,
+    // which also emits the GNU property note of the platform.
+    core::arch::global_asm!(
+        assembly!(".text.rt11_entrypoint_test_prop", "rt11_entrypoint_test_prop", gnu_property),
+        sym rt11_entrypoint_loader,
+    );
+
+    // Dummy Entry-point with Root Frame
+    //
+    // Create another dummy entry-point called `rt11_entrypoint_test_fp`,
+    // which clears the frame-pointer and emits the GNU property note.
+    core::arch::global_asm!(
+        assembly!(
+            ".text.rt11_entrypoint_test_fp",
+            "rt11_entrypoint_test_fp",
+            zero_frame_pointer,
+            gnu_property,
+        ),
+        sym rt11_entrypoint_loader,
+    );
+
+    // Dummy Entry-point with Return-address Signing
+    //
+    // Create another dummy entry-point called `rt11_entrypoint_test_pac`,
+    // which signs the return address and emits the GNU property notes.
+    core::arch::global_asm!(
+        assembly!(
+            ".text.rt11_entrypoint_test_pac",
+            "rt11_entrypoint_test_pac",
+            pointer_auth,
+            gnu_property,
+        ),
+        sym rt11_entrypoint_loader,
+    );
+
+    // Dummy Entry-point with Branch Tracking
+    //
+    // Create another dummy entry-point called `rt11_entrypoint_test_ibt`,
+    // which starts with the indirect-branch-tracking landing pad and emits
+    // the GNU property notes.
+    core::arch::global_asm!(
+        assembly!(
+            ".text.rt11_entrypoint_test_ibt",
+            "rt11_entrypoint_test_ibt",
+            branch_tracking,
+            gnu_property,
+        ),
+        sym rt11_entrypoint_loader,
+    );
+
+    // Test entry-point landing pads
+    //
+    // Verify that the entry-points start with the branch-target landing pad
+    // of the platform, regardless of the options passed.
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn test_landing_pad() {
+        extern "C" {
+            fn rt11_entrypoint_test() -> !;
+            fn rt11_entrypoint_test_fp() -> !;
+            fn rt11_entrypoint_test_pac() -> !;
+        }
+
+        let pad: [u8; 4] = 0xd503245fu32.to_le_bytes(); // bti c
+
+        for f in [
+            rt11_entrypoint_test as *const () as usize,
+            rt11_entrypoint_test_fp as *const () as usize,
+            rt11_entrypoint_test_pac as *const () as usize,
+        ] {
+            let code = unsafe { core::slice::from_raw_parts(f as *const u8, 4) };
+            assert_eq!(code, pad);
+        }
+    }
+
+    // Test indirect-branch-tracking
+    //
+    // Verify that the entry-point starts with the landing pad of
+    // indirect-branch-tracking only if requested.
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_branch_tracking() {
+        extern "C" {
+            fn rt11_entrypoint_test() -> !;
+            fn rt11_entrypoint_test_ibt() -> !;
+        }
+
+        let pad: [u8; 4] = [0xf3, 0x0f, 0x1e, 0xfa]; // endbr64
+
+        let f = rt11_entrypoint_test_ibt as *const () as usize;
+        let code = unsafe { core::slice::from_raw_parts(f as *const u8, 4) };
+        assert_eq!(code, pad);
+
+        let f = rt11_entrypoint_test as *const () as usize;
+        let code = unsafe { core::slice::from_raw_parts(f as *const u8, 4) };
+        assert_ne!(code, pad);
+    }
+
+    // Test return-address signing
+    //
+    // Verify that the return address is signed right after the landing pad,
+    // if requested.
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn test_pointer_auth() {
+        extern "C" {
+            fn rt11_entrypoint_test_pac() -> !;
+        }
+
+        let f = rt11_entrypoint_test_pac as *const () as usize;
+        let code = unsafe { core::slice::from_raw_parts((f + 4) as *const u8, 4) };
+        assert_eq!(code, 0xd503233fu32.to_le_bytes()); // paciasp
+    }
+
     // Test entry-point generation
     //
     // Create a reference to the generated entrypoint and verify its address
@@ -105,8 +302,16 @@ mod tests {
     fn test_existence() {
         extern "C" {
             fn rt11_entrypoint_test() -> !;
+            fn rt11_entrypoint_test_prop() -> !;
+            fn rt11_entrypoint_test_fp() -> !;
+            fn rt11_entrypoint_test_pac() -> !;
+            fn rt11_entrypoint_test_ibt() -> !;
         }
 
-        assert!(rt11_entrypoint_test as usize != 0);
+        assert!(rt11_entrypoint_test as *const () as usize != 0);
+        assert!(rt11_entrypoint_test_prop as *const () as usize != 0);
+        assert!(rt11_entrypoint_test_fp as *const () as usize != 0);
+        assert!(rt11_entrypoint_test_pac as *const () as usize != 0);
+        assert!(rt11_entrypoint_test_ibt as *const () as usize != 0);
     }
 }
@@ -0,0 +1,338 @@
+//! Building Helpers
+//!
+//! This module provides helpers to synthesize ELF data structures. All
+//! helpers either return fixed-size structures by value, or write into
+//! caller provided buffers of fixed capacity. They never allocate.
+
+use crate::{elf, elfn};
+
+/// Builder Errors
+///
+/// This enum describes the reasons a builder rejected its input.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// The output buffer is too small.
+    Overflow,
+    /// A tag that must be unique was added more than once.
+    Duplicate(u32),
+    /// A terminator was added explicitly.
+    Terminator,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("output buffer too small"),
+            Self::Duplicate(v) => write!(f, "duplicate unique tag {}", v),
+            Self::Terminator => f.write_str("explicit terminator"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Dynamic Section Builder
+///
+/// Accumulate entries of a dynamic section in a caller provided buffer and
+/// terminate them with `DT_NULL`. One slot of the buffer is always reserved
+/// for the terminator.
+///
+/// Most tags must appear at most once in a dynamic section (e.g.,
+/// `DT_STRTAB`), and the builder rejects duplicates of those. Tags that can
+/// legitimately appear multiple times (e.g., `DT_NEEDED`) and unknown tags
+/// are accepted without checks. Use `push_duplicate()` to override the
+/// check explicitly.
+#[derive(Debug)]
+pub struct DynBuilder<'a, SIZE, ALIGN> {
+    buf: &'a mut [elf::Dyn<SIZE, ALIGN>],
+    len: usize,
+}
+
+type Dyn = elf::Dyn<(), ()>;
+
+// Check whether a tag must appear at most once.
+fn is_singleton(tag: u32) -> bool {
+    matches!(
+        tag,
+        Dyn::DT_PLTRELSZ
+        | Dyn::DT_PLTGOT
+        | Dyn::DT_HASH
+        | Dyn::DT_STRTAB
+        | Dyn::DT_SYMTAB
+        | Dyn::DT_RELA
+        | Dyn::DT_RELASZ
+        | Dyn::DT_RELAENT
+        | Dyn::DT_STRSZ
+        | Dyn::DT_SYMENT
+        | Dyn::DT_INIT
+        | Dyn::DT_FINI
+        | Dyn::DT_SONAME
+        | Dyn::DT_RPATH
+        | Dyn::DT_SYMBOLIC
+        | Dyn::DT_REL
+        | Dyn::DT_RELSZ
+        | Dyn::DT_RELENT
+        | Dyn::DT_PLTREL
+        | Dyn::DT_DEBUG
+        | Dyn::DT_TEXTREL
+        | Dyn::DT_JMPREL
+        | Dyn::DT_BIND_NOW
+        | Dyn::DT_INIT_ARRAY
+        | Dyn::DT_FINI_ARRAY
+        | Dyn::DT_INIT_ARRAYSZ
+        | Dyn::DT_FINI_ARRAYSZ
+        | Dyn::DT_RUNPATH
+        | Dyn::DT_FLAGS
+        | Dyn::DT_PREINIT_ARRAY
+        | Dyn::DT_PREINIT_ARRAYSZ
+        | Dyn::DT_SYMTAB_SHNDX
+        | Dyn::DT_RELRSZ
+        | Dyn::DT_RELR
+        | Dyn::DT_RELRENT
+        | Dyn::DT_GNU_HASH
+        | Dyn::DT_VERSYM
+        | Dyn::DT_RELACOUNT
+        | Dyn::DT_RELCOUNT
+        | Dyn::DT_FLAGS_1
+        | Dyn::DT_VERDEF
+        | Dyn::DT_VERDEFNUM
+        | Dyn::DT_VERNEED
+        | Dyn::DT_VERNEEDNUM
+    )
+}
+
+impl<'a, SIZE, ALIGN> DynBuilder<'a, SIZE, ALIGN>
+where
+    SIZE: Copy + Default + From<u32> + PartialEq,
+    ALIGN: Copy + Default,
+{
+    /// Create Dynamic Section Builder
+    ///
+    /// Create a new builder writing into `buf`. Any previous content of
+    /// `buf` is overwritten.
+    pub fn new(buf: &'a mut [elf::Dyn<SIZE, ALIGN>]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Return the entries added so far, without terminator.
+    pub fn entries(&self) -> &[elf::Dyn<SIZE, ALIGN>] {
+        &self.buf[..self.len]
+    }
+
+    /// Add Entry
+    ///
+    /// Append an entry with tag `tag` and value `val`. Fails with
+    /// `Error::Duplicate` if `tag` must be unique but is already present.
+    pub fn push(&mut self, tag: u32, val: SIZE) -> Result<(), Error> {
+        if is_singleton(tag) && self.entries().iter().any(|v| v.d_tag == tag.into()) {
+            return Err(Error::Duplicate(tag));
+        }
+
+        self.push_duplicate(tag, val)
+    }
+
+    /// Add Entry Unchecked
+    ///
+    /// Append an entry with tag `tag` and value `val`, without checking for
+    /// duplicates.
+    pub fn push_duplicate(&mut self, tag: u32, val: SIZE) -> Result<(), Error> {
+        if tag == Dyn::DT_NULL {
+            return Err(Error::Terminator);
+        }
+        if self.len + 1 >= self.buf.len() {
+            return Err(Error::Overflow);
+        }
+
+        self.buf[self.len] = elf::Dyn {
+            d_tag: tag.into(),
+            d_val: val,
+            ..Default::default()
+        };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Finish Dynamic Section
+    ///
+    /// Append the `DT_NULL` terminator and return the used part of the
+    /// buffer, including the terminator. Fails with `Error::Overflow` if
+    /// the buffer has no room for the terminator (i.e., if it is empty).
+    pub fn finish(self) -> Result<&'a mut [elf::Dyn<SIZE, ALIGN>], Error> {
+        let end = self.buf.get_mut(self.len).ok_or(Error::Overflow)?;
+        *end = elf::Dyn::default();
+        Ok(&mut self.buf[..self.len + 1])
+    }
+}
+
+// Machine of the compilation target (i.e., `EM_*`), or `EM_NONE` if it is
+// not known to this crate.
+const EM_NATIVE: u16 = {
+    type Ehdr = elf::Ehdr<(), ()>;
+
+    if cfg!(target_arch = "arm") {
+        Ehdr::EM_ARM
+    } else if cfg!(target_arch = "aarch64") {
+        Ehdr::EM_AARCH64
+    } else if cfg!(target_arch = "riscv32") || cfg!(target_arch = "riscv64") {
+        Ehdr::EM_RISCV
+    } else if cfg!(target_arch = "x86") {
+        Ehdr::EM_386
+    } else if cfg!(target_arch = "x86_64") {
+        Ehdr::EM_X86_64
+    } else {
+        Ehdr::EM_NONE
+    }
+};
+
+// Class (i.e., `ELFCLASS*`) and data encoding (i.e., `ELFDATA*`) of the
+// native format.
+#[cfg(target_pointer_width = "32")]
+const ELFCLASS_NATIVE: u8 = elf::Ident::ELFCLASS32;
+#[cfg(target_pointer_width = "64")]
+const ELFCLASS_NATIVE: u8 = elf::Ident::ELFCLASS64;
+#[cfg(target_endian = "little")]
+const ELFDATA_NATIVE: u8 = elf::Ident::ELFDATA2LSB;
+#[cfg(target_endian = "big")]
+const ELFDATA_NATIVE: u8 = elf::Ident::ELFDATA2MSB;
+
+/// ELF Header Builder
+///
+/// Synthesize a well-formed ELF header in the native format (see `elfn`),
+/// for instance to build test fixtures without a toolchain. The builder
+/// fills in all fields derived from the format itself:
+///
+///  * The identification table gets the ELF magic, the native class and
+///    data encoding, `EV_CURRENT`, and `ELFOSABI_NONE`.
+///  * `e_machine` is the machine of the compilation target, or `EM_NONE` if
+///    it is not known to this crate. Use `machine()` to override it.
+///  * `e_version` is `EV_CURRENT`, and `e_ehsize`, `e_phentsize`, and
+///    `e_shentsize` are the sizes of the native structures.
+///  * There is no section header table (i.e., `e_shoff`, `e_shnum`, and
+///    `e_shstrndx` are 0), since loaders do not need one.
+///
+/// The remaining fields default to 0 and are set via the respective
+/// methods.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EhdrBuilder {
+    ehdr: elfn::Ehdr,
+}
+
+impl EhdrBuilder {
+    /// Create ELF Header Builder
+    ///
+    /// Create a new builder for an ELF header of the object type `e_type`
+    /// (i.e., `ET_*`).
+    pub fn new(e_type: u16) -> Self {
+        Self {
+            ehdr: elfn::Ehdr {
+                e_ident: elf::Ident {
+                    i_magic: [
+                        elf::Ident::ELFMAG0,
+                        elf::Ident::ELFMAG1,
+                        elf::Ident::ELFMAG2,
+                        elf::Ident::ELFMAG3,
+                    ],
+                    i_class: ELFCLASS_NATIVE,
+                    i_data: ELFDATA_NATIVE,
+                    i_version: elf::Ehdr::<(), ()>::EV_CURRENT,
+                    i_osabi: elf::Ident::ELFOSABI_NONE,
+                    ..Default::default()
+                },
+                e_type,
+                e_machine: EM_NATIVE,
+                e_version: elf::Ehdr::<(), ()>::EV_CURRENT as u32,
+                e_ehsize: core::mem::size_of::<elfn::Ehdr>() as u16,
+                e_phentsize: core::mem::size_of::<elfn::Phdr>() as u16,
+                e_shentsize: core::mem::size_of::<elfn::Shdr>() as u16,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the entry-point address (i.e., `e_entry`).
+    pub fn entry(mut self, e_entry: elfn::Size) -> Self {
+        self.ehdr.e_entry = e_entry;
+        self
+    }
+
+    /// Set the file offset (i.e., `e_phoff`) and number of entries (i.e.,
+    /// `e_phnum`) of the program header table.
+    pub fn phdrs(mut self, e_phoff: elfn::Size, e_phnum: u16) -> Self {
+        self.ehdr.e_phoff = e_phoff;
+        self.ehdr.e_phnum = e_phnum;
+        self
+    }
+
+    /// Set the machine (i.e., `e_machine`), overriding the native machine.
+    pub fn machine(mut self, e_machine: u16) -> Self {
+        self.ehdr.e_machine = e_machine;
+        self
+    }
+
+    /// Return the ELF header.
+    pub fn build(self) -> elfn::Ehdr {
+        self.ehdr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elf64;
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(std::format!("{}", Error::Overflow), "output buffer too small");
+        assert_eq!(std::format!("{}", Error::Duplicate(5)), "duplicate unique tag 5");
+        assert_eq!(std::format!("{}", Error::Terminator), "explicit terminator");
+    }
+
+    #[test]
+    fn test_dyn_builder() {
+        let mut buf = [elf64::Dyn::default(); 5];
+        let mut b = DynBuilder::new(&mut buf);
+
+        b.push(Dyn::DT_NEEDED, 1).unwrap();
+        b.push(Dyn::DT_NEEDED, 7).unwrap();
+        b.push(Dyn::DT_STRTAB, 0x1000).unwrap();
+        assert_eq!(b.push(Dyn::DT_STRTAB, 0x2000), Err(Error::Duplicate(Dyn::DT_STRTAB)));
+        assert_eq!(b.push(Dyn::DT_NULL, 0), Err(Error::Terminator));
+        b.push_duplicate(Dyn::DT_STRTAB, 0x2000).unwrap();
+        assert_eq!(b.push(Dyn::DT_STRSZ, 16), Err(Error::Overflow));
+        assert_eq!(b.entries().len(), 4);
+
+        let r = b.finish().unwrap();
+        assert_eq!(r.len(), 5);
+        assert_eq!((r[0].d_tag, r[0].d_val), (Dyn::DT_NEEDED as u64, 1));
+        assert_eq!((r[1].d_tag, r[1].d_val), (Dyn::DT_NEEDED as u64, 7));
+        assert_eq!((r[2].d_tag, r[2].d_val), (Dyn::DT_STRTAB as u64, 0x1000));
+        assert_eq!((r[3].d_tag, r[3].d_val), (Dyn::DT_STRTAB as u64, 0x2000));
+        assert_eq!((r[4].d_tag, r[4].d_val), (Dyn::DT_NULL as u64, 0));
+
+        // An empty buffer cannot even hold the terminator.
+        let mut buf: [elf64::Dyn; 0] = [];
+        assert_eq!(DynBuilder::new(&mut buf).finish().unwrap_err(), Error::Overflow);
+    }
+
+    #[test]
+    fn test_ehdr_builder() {
+        type Ehdr = elf::Ehdr<(), ()>;
+
+        let ehdr = EhdrBuilder::new(Ehdr::ET_EXEC).entry(0x401000).phdrs(64, 3).build();
+        assert_eq!(ehdr.e_ident.i_magic, *b"\x7fELF");
+        assert_eq!(ehdr.e_ident.i_class, ELFCLASS_NATIVE);
+        assert_eq!(ehdr.e_ident.i_data, ELFDATA_NATIVE);
+        assert_eq!(ehdr.e_ident.i_version, Ehdr::EV_CURRENT);
+        assert_eq!(ehdr.e_type, Ehdr::ET_EXEC);
+        assert_eq!(ehdr.e_machine, EM_NATIVE);
+        assert_eq!((ehdr.e_entry, ehdr.e_phoff, ehdr.e_phnum), (0x401000, 64, 3));
+        assert_eq!(ehdr.e_ehsize as usize, core::mem::size_of::<elfn::Ehdr>());
+        assert_eq!(ehdr.e_phentsize as usize, core::mem::size_of::<elfn::Phdr>());
+        assert_eq!((ehdr.e_shoff, ehdr.e_shnum, ehdr.e_shstrndx), (0, 0, 0));
+        assert_eq!(ehdr.phdr_table_range(), Some((64, 3 * core::mem::size_of::<elfn::Phdr>())));
+
+        let ehdr = EhdrBuilder::new(Ehdr::ET_DYN).machine(Ehdr::EM_NONE).build();
+        assert_eq!((ehdr.e_type, ehdr.e_machine), (Ehdr::ET_DYN, Ehdr::EM_NONE));
+        assert_eq!(ehdr.phdr_table_range(), Some((0, 0)));
+    }
+}
@@ -18,12 +18,22 @@
 //!
 //! Unless explicitly noted, constants are the same on all architectures and
 //! can be used from either of the modules.
+//!
+//! As the only exception to the above, the `parse` and `build` modules
+//! provide minimal, safe helpers to read and write ELF data structures that
+//! are hard to get right (e.g., due to alignment or termination
+//! requirements). They operate on caller provided buffers and never access
+//! memory outside of them. Their error types implement `core::error::Error`,
+//! which requires Rust 1.81 or newer.
 
 #![no_std]
 
 #[cfg(test)]
 extern crate std;
 
+pub mod build;
+pub mod parse;
+
 /// Generic Utilities
 ///
 /// The utility module contains all generic utilities required by the main
@@ -260,6 +270,20 @@ pub mod elf {
         pub r_addend: ADDEND,
     }
 
+    /// Note Header
+    ///
+    /// Notes are used to attach auxiliary information to an ELF file. Each
+    /// note starts with this header, followed by the name and the descriptor
+    /// of the note, each padded to the alignment of the note section. The
+    /// header is the same for 32bit and 64bit files.
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Nhdr {
+        pub n_namesz: u32,
+        pub n_descsz: u32,
+        pub n_type: u32,
+    }
+
     impl Ident {
         pub const ELFMAG0: u8 = 0x7f;
         pub const ELFMAG1: u8 = b'E';
@@ -703,6 +727,8 @@ pub mod elf {
     }
 
     impl<SIZE, ALIGN> Sym<SIZE, ALIGN> {
+        pub const STN_UNDEF: u32 = 0;
+
         pub const STB_LOCAL: u8 = 0;
         pub const STB_GLOBAL: u8 = 1;
         pub const STB_WEAK: u8 = 2;
@@ -876,6 +902,76 @@ pub mod elf {
         pub const DF_P1_LAZYLOAD: u32 = 0x00000001;
         pub const DF_P1_GROUPPERM: u32 = 0x00000002;
     }
+
+    impl<SIZE, ALIGN> Rel<SIZE, ALIGN> {
+        pub const R_386_NONE: u32 = 0;
+        pub const R_386_32: u32 = 1;
+        pub const R_386_COPY: u32 = 5;
+        pub const R_386_GLOB_DAT: u32 = 6;
+        pub const R_386_JMP_SLOT: u32 = 7;
+        pub const R_386_RELATIVE: u32 = 8;
+        pub const R_386_IRELATIVE: u32 = 42;
+
+        pub const R_ARM_NONE: u32 = 0;
+        pub const R_ARM_ABS32: u32 = 2;
+        pub const R_ARM_COPY: u32 = 20;
+        pub const R_ARM_GLOB_DAT: u32 = 21;
+        pub const R_ARM_JUMP_SLOT: u32 = 22;
+        pub const R_ARM_RELATIVE: u32 = 23;
+        pub const R_ARM_IRELATIVE: u32 = 160;
+    }
+
+    impl<SIZE, ALIGN, ADDEND> Rela<SIZE, ALIGN, ADDEND> {
+        pub const R_AARCH64_NONE: u32 = 0;
+        pub const R_AARCH64_ABS64: u32 = 257;
+        pub const R_AARCH64_COPY: u32 = 1024;
+        pub const R_AARCH64_GLOB_DAT: u32 = 1025;
+        pub const R_AARCH64_JUMP_SLOT: u32 = 1026;
+        pub const R_AARCH64_RELATIVE: u32 = 1027;
+        pub const R_AARCH64_IRELATIVE: u32 = 1032;
+
+        pub const R_RISCV_NONE: u32 = 0;
+        pub const R_RISCV_32: u32 = 1;
+        pub const R_RISCV_64: u32 = 2;
+        pub const R_RISCV_RELATIVE: u32 = 3;
+        pub const R_RISCV_COPY: u32 = 4;
+        pub const R_RISCV_JUMP_SLOT: u32 = 5;
+        pub const R_RISCV_IRELATIVE: u32 = 58;
+
+        pub const R_X86_64_NONE: u32 = 0;
+        pub const R_X86_64_64: u32 = 1;
+        pub const R_X86_64_COPY: u32 = 5;
+        pub const R_X86_64_GLOB_DAT: u32 = 6;
+        pub const R_X86_64_JUMP_SLOT: u32 = 7;
+        pub const R_X86_64_RELATIVE: u32 = 8;
+        pub const R_X86_64_IRELATIVE: u32 = 37;
+    }
+
+    impl Nhdr {
+        pub const NT_GNU_ABI_TAG: u32 = 1; // from: glibc
+        pub const NT_GNU_HWCAP: u32 = 2; // from: glibc
+        pub const NT_GNU_BUILD_ID: u32 = 3; // from: glibc
+        pub const NT_GNU_GOLD_VERSION: u32 = 4; // from: glibc
+        pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5; // from: glibc
+
+        pub const GNU_PROPERTY_STACK_SIZE: u32 = 1; // from: glibc
+        pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2; // from: glibc
+        pub const GNU_PROPERTY_LOPROC: u32 = 0xc0000000; // from: glibc
+        pub const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff; // from: glibc
+        pub const GNU_PROPERTY_LOUSER: u32 = 0xe0000000; // from: glibc
+        pub const GNU_PROPERTY_HIUSER: u32 = 0xffffffff; // from: glibc
+
+        pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000; // from: glibc
+        pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 0x00000001; // from: glibc
+        pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 0x00000002; // from: glibc
+        pub const GNU_PROPERTY_AARCH64_FEATURE_1_GCS: u32 = 0x00000004; // from: glibc
+
+        pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002; // from: glibc
+        pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 0x00000001; // from: glibc
+        pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 0x00000002; // from: glibc
+        pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U48: u32 = 0x00000004; // from: binutils
+        pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U57: u32 = 0x00000008; // from: binutils
+    }
 }
 
 /// ELF for 32bit
@@ -891,6 +987,7 @@ pub mod elf32 {
     pub type Dyn = super::elf::Dyn<Size, Align>;
     pub type Ehdr = super::elf::Ehdr<Size, Align>;
     pub type Ident = super::elf::Ident;
+    pub type Nhdr = super::elf::Nhdr;
     pub type Phdr = super::elf::Phdr<Size, Align>;
     pub type Rel = super::elf::Rel<Size, Align>;
     pub type Rela = super::elf::Rela<Size, Align, Addend>;
@@ -915,6 +1012,7 @@ pub mod elf64 {
     pub type Dyn = super::elf::Dyn<Size, Align>;
     pub type Ehdr = super::elf::Ehdr<Size, Align>;
     pub type Ident = super::elf::Ident;
+    pub type Nhdr = super::elf::Nhdr;
     pub type Rel = super::elf::Rel<Size, Align>;
     pub type Rela = super::elf::Rela<Size, Align, Addend>;
     pub type Shdr = super::elf::Shdr<Size, Align>;
@@ -922,7 +1020,8 @@ pub mod elf64 {
     /// Program Header for 64bit
     ///
     /// This is the 64bit equivalent of `elf::Phdr`. It reorders the member
-    /// fields to avoid padding bytes.
+    /// fields to avoid padding bytes. Use `parse::PhdrLike` to access
+    /// either class uniformly.
     #[repr(C)]
     #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
     pub struct Phdr {
@@ -963,6 +1062,49 @@ pub use elf32 as elfn;
 #[cfg(target_pointer_width = "64")]
 pub use elf64 as elfn;
 
+// Layout self-check of all ELF types. Unlike the unit tests, this is
+// evaluated whenever the crate is compiled, hence for every target, including
+// foreign targets that are only built but never run. Any padding or
+// alignment change of the `#[repr(C)]` definitions thus fails the build.
+const _: () = {
+    use core::mem::{align_of, size_of};
+
+    assert!(align_of::<elf::Ident>() == 1);
+    assert!(size_of::<elf::Ident>() == 16);
+    assert!(align_of::<elf::Nhdr>() == 4);
+    assert!(size_of::<elf::Nhdr>() == 12);
+
+    assert!(align_of::<elf32::Ehdr>() == 4);
+    assert!(size_of::<elf32::Ehdr>() == 52);
+    assert!(align_of::<elf32::Shdr>() == 4);
+    assert!(size_of::<elf32::Shdr>() == 40);
+    assert!(align_of::<elf32::Phdr>() == 4);
+    assert!(size_of::<elf32::Phdr>() == 32);
+    assert!(align_of::<elf32::Sym>() == 4);
+    assert!(size_of::<elf32::Sym>() == 16);
+    assert!(align_of::<elf32::Dyn>() == 4);
+    assert!(size_of::<elf32::Dyn>() == 8);
+    assert!(align_of::<elf32::Rel>() == 4);
+    assert!(size_of::<elf32::Rel>() == 8);
+    assert!(align_of::<elf32::Rela>() == 4);
+    assert!(size_of::<elf32::Rela>() == 12);
+
+    assert!(align_of::<elf64::Ehdr>() == 8);
+    assert!(size_of::<elf64::Ehdr>() == 64);
+    assert!(align_of::<elf64::Shdr>() == 8);
+    assert!(size_of::<elf64::Shdr>() == 64);
+    assert!(align_of::<elf64::Phdr>() == 8);
+    assert!(size_of::<elf64::Phdr>() == 56);
+    assert!(align_of::<elf64::Sym>() == 8);
+    assert!(size_of::<elf64::Sym>() == 24);
+    assert!(align_of::<elf64::Dyn>() == 8);
+    assert!(size_of::<elf64::Dyn>() == 16);
+    assert!(align_of::<elf64::Rel>() == 8);
+    assert!(size_of::<elf64::Rel>() == 16);
+    assert!(align_of::<elf64::Rela>() == 8);
+    assert!(size_of::<elf64::Rela>() == 24);
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -984,6 +1126,8 @@ mod tests {
     fn test_elf_typeinfo() {
         assert_eq!(align_of::<elf::Ident>(), 1);
         assert_eq!(size_of::<elf::Ident>(), 16);
+        assert_eq!(align_of::<elf::Nhdr>(), 4);
+        assert_eq!(size_of::<elf::Nhdr>(), 12);
 
         assert_eq!(align_of::<elf32::Ident>(), 1);
         assert_eq!(size_of::<elf32::Ident>(), 16);
@@ -0,0 +1,902 @@
+//! Parsing Helpers
+//!
+//! This module provides helpers to walk ELF data structures that have no
+//! fixed layout, but need to be parsed incrementally. All helpers operate on
+//! byte slices and verify every access against the bounds of the slice. Data
+//! is always interpreted in native byte-order.
+
+/// Parser Errors
+///
+/// This enum describes the reasons a parser rejected its input.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// The data ended before the structure was complete.
+    Truncated,
+    /// The entries of an array violate the ordering of their format.
+    Unordered,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("truncated data"),
+            Self::Unordered => f.write_str("unordered entries"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// GNU Program Property
+///
+/// A single entry of the property array of a `NT_GNU_PROPERTY_TYPE_0` note.
+/// `pr_data` refers to the property data without trailing padding.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Property<'a> {
+    pub pr_type: u32,
+    pub pr_data: &'a [u8],
+}
+
+/// GNU Program Property Iterator
+///
+/// Iterate the property array in the descriptor of a
+/// `NT_GNU_PROPERTY_TYPE_0` note. Each entry consists of a 4-byte type, a
+/// 4-byte data size, and the data itself, padded to the property alignment.
+///
+/// Note that the property alignment differs from the alignment of the
+/// enclosing note: 64-bit objects pad each property to 8 bytes, while
+/// 32-bit objects pad to 4 bytes, even though notes are 4-byte aligned in
+/// both cases. The padding is part of the descriptor (and thus included in
+/// `n_descsz`).
+///
+/// The entries must be sorted by strictly ascending type, as required by
+/// the specification. This also catches most parses with the wrong
+/// property alignment, since the padding is then misinterpreted as the
+/// type of the next entry.
+///
+/// If the descriptor is malformed, the iterator yields a single error and
+/// then ends.
+#[derive(Clone, Debug)]
+pub struct PropertyIter<'a> {
+    data: &'a [u8],
+    align: usize,
+    last: Option<u32>,
+}
+
+impl<'a> PropertyIter<'a> {
+    /// Create Property Iterator
+    ///
+    /// Create a new iterator over the properties in `desc`, which must be
+    /// the descriptor of a `NT_GNU_PROPERTY_TYPE_0` note. `align` is the
+    /// property alignment of the object (i.e., 8 for `ELFCLASS64` and 4 for
+    /// `ELFCLASS32`) and must be a power of two.
+    pub fn new(desc: &'a [u8], align: usize) -> Self {
+        debug_assert!(align.is_power_of_two());
+        Self {
+            data: desc,
+            align,
+            last: None,
+        }
+    }
+
+    /// Create Native Property Iterator
+    ///
+    /// Create a new iterator over the properties in `desc` with the property
+    /// alignment of the native ELF class.
+    pub fn native(desc: &'a [u8]) -> Self {
+        Self::new(desc, core::mem::size_of::<crate::elfn::Size>())
+    }
+
+    fn next_property(&mut self) -> Result<Property<'a>, Error> {
+        if self.data.len() < 8 {
+            return Err(Error::Truncated);
+        }
+
+        let (hdr, rest) = self.data.split_at(8);
+        let pr_type = u32::from_ne_bytes(hdr[0..4].try_into().unwrap());
+        let pr_datasz = u32::from_ne_bytes(hdr[4..8].try_into().unwrap()) as usize;
+
+        if self.last.is_some_and(|v| pr_type <= v) {
+            return Err(Error::Unordered);
+        }
+
+        // Entries are padded to the property alignment, relative to the
+        // start of the descriptor. Since the header size is a multiple of
+        // the alignment, it is sufficient to pad the data.
+        let step = pr_datasz
+            .checked_add(self.align - 1)
+            .ok_or(Error::Truncated)?
+            & !(self.align - 1);
+        if step > rest.len() {
+            return Err(Error::Truncated);
+        }
+
+        self.data = &rest[step..];
+        self.last = Some(pr_type);
+        Ok(Property {
+            pr_type,
+            pr_data: &rest[..pr_datasz],
+        })
+    }
+}
+
+impl<'a> Iterator for PropertyIter<'a> {
+    type Item = Result<Property<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let r = self.next_property();
+        if r.is_err() {
+            self.data = &[];
+        }
+        Some(r)
+    }
+}
+
+/// Note
+///
+/// A single note of a note section or `PT_NOTE` segment. `name` refers to
+/// the owner name without its terminating NUL (e.g., `b"GNU"`), and `desc`
+/// to the descriptor without trailing padding.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Note<'a> {
+    pub n_type: u32,
+    pub name: &'a [u8],
+    pub desc: &'a [u8],
+}
+
+/// Note Iterator
+///
+/// Iterate the notes of a note section or `PT_NOTE` segment. Each note
+/// consists of a `Nhdr`, the name, and the descriptor, where the name and
+/// the descriptor are each padded to the alignment of the notes (i.e.,
+/// `sh_addralign` or `p_align`), relative to the start of the data.
+///
+/// Notes are 4-byte aligned in both 32-bit and 64-bit objects, except for
+/// `NT_GNU_PROPERTY_TYPE_0`, which is placed in a separate segment with
+/// 8-byte alignment in 64-bit objects. Hence, the alignment must be taken
+/// from the respective header, rather than from the ELF class.
+///
+/// If the data is malformed, the iterator yields a single error and then
+/// ends.
+#[derive(Clone, Debug)]
+pub struct NoteIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+    align: usize,
+}
+
+impl<'a> NoteIter<'a> {
+    /// Create Note Iterator
+    ///
+    /// Create a new iterator over the notes in `data`, which must be the
+    /// content of a note section or `PT_NOTE` segment. `align` is the
+    /// alignment of the notes, and must be a power of two. Alignments below
+    /// 4 are treated as 4, like the GNU tools do.
+    pub fn new(data: &'a [u8], align: usize) -> Self {
+        debug_assert!(align.is_power_of_two());
+        Self {
+            data,
+            pos: 0,
+            align: align.max(4),
+        }
+    }
+
+    fn pad(&self, off: usize) -> Result<usize, Error> {
+        Ok(off.checked_add(self.align - 1).ok_or(Error::Truncated)? & !(self.align - 1))
+    }
+
+    fn next_note(&mut self) -> Result<Note<'a>, Error> {
+        let hdr = self.data.get(self.pos..self.pos + 12).ok_or(Error::Truncated)?;
+        let n_namesz = u32::from_ne_bytes(hdr[0..4].try_into().unwrap()) as usize;
+        let n_descsz = u32::from_ne_bytes(hdr[4..8].try_into().unwrap()) as usize;
+        let n_type = u32::from_ne_bytes(hdr[8..12].try_into().unwrap());
+
+        let name_off = self.pos + 12;
+        let name_end = name_off.checked_add(n_namesz).ok_or(Error::Truncated)?;
+        let desc_off = self.pad(name_end)?;
+        let desc_end = desc_off.checked_add(n_descsz).ok_or(Error::Truncated)?;
+
+        let name = self.data.get(name_off..name_end).ok_or(Error::Truncated)?;
+        let desc = self.data.get(desc_off..desc_end).ok_or(Error::Truncated)?;
+        let name = name.strip_suffix(b"\0").unwrap_or(name);
+
+        // The padding of the last note is commonly omitted.
+        self.pos = self.pad(desc_end)?.min(self.data.len());
+        Ok(Note { n_type, name, desc })
+    }
+}
+
+impl<'a> Iterator for NoteIter<'a> {
+    type Item = Result<Note<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+
+        let r = self.next_note();
+        if r.is_err() {
+            self.pos = self.data.len();
+        }
+        Some(r)
+    }
+}
+
+/// Look up String in String Table
+///
+/// Return the string at offset `off` in the string table `strtab` (e.g.,
+/// `.strtab` or `.dynstr`), without its terminating NUL. If `off` is out of
+/// range, or if the string is not terminated before the end of the table,
+/// `None` is returned.
+pub fn strtab_get(strtab: &[u8], off: usize) -> Option<&[u8]> {
+    let tail = strtab.get(off..)?;
+    let len = tail.iter().position(|v| *v == 0)?;
+    Some(&tail[..len])
+}
+
+/// Resolve Symbol Section Index
+///
+/// Return the index of the section the symbol `sym` is defined in (or one
+/// of the reserved `SHN_*` indices). `index` is the index of `sym` in its
+/// symbol table, and `shndx_table` is the content of the
+/// `SHT_SYMTAB_SHNDX` section linked to that symbol table, if any.
+///
+/// Section indices that do not fit below `SHN_LORESERVE` are stored in
+/// the parallel `SHT_SYMTAB_SHNDX` table, in which case `st_shndx` is
+/// `SHN_XINDEX`. Only objects with a large number of sections (e.g., big
+/// statically linked programs, or objects compiled with one section per
+/// function) carry such a table. If `st_shndx` is `SHN_XINDEX`, but the
+/// table is missing or too short, `None` is returned.
+pub fn sym_shndx(
+    sym: &crate::elfn::Sym,
+    index: usize,
+    shndx_table: Option<&[u32]>,
+) -> Option<u32> {
+    if sym.st_shndx == crate::elf::Shdr::<(), ()>::SHN_XINDEX {
+        shndx_table?.get(index).copied()
+    } else {
+        Some(sym.st_shndx as u32)
+    }
+}
+
+/// Enumerate Symbols
+///
+/// Return an iterator over all symbols of the symbol table `symtab` (e.g.,
+/// `.symtab`, `.dynsym`, or `DT_SYMTAB`), paired with their names resolved
+/// in the string table `strtab` linked to it. The iterator preserves the
+/// order of the table, so the position of an entry is its symbol index.
+///
+/// Like `needed_libraries()`, symbols whose name cannot be resolved yield
+/// an empty name, rather than being skipped, so the indices are retained.
+/// Note that the first entry of a symbol table is reserved and always
+/// unnamed.
+pub fn symbols<'a>(
+    symtab: &'a [crate::elfn::Sym],
+    strtab: &'a [u8],
+) -> impl Iterator<Item = (&'a [u8], &'a crate::elfn::Sym)> + 'a {
+    symtab.iter().map(move |v| {
+        (strtab_get(strtab, v.st_name as usize).unwrap_or(b""), v)
+    })
+}
+
+/// Enumerate Needed Libraries
+///
+/// Return an iterator over the names of all `DT_NEEDED` entries of the
+/// dynamic section `dynamic`, resolved in the string table `strtab` (i.e.,
+/// `DT_STRTAB`). The iterator stops at the first `DT_NULL` entry, or at the
+/// end of `dynamic`, whichever comes first.
+///
+/// The iterator preserves the order of the dynamic section. This order is
+/// significant, since it defines the breadth-first load order of the
+/// dependencies, and thus the symbol lookup scope.
+///
+/// Entries whose name cannot be resolved (i.e., the offset is out of range,
+/// or the string is not terminated) yield an empty name, rather than being
+/// skipped, so a dependency is never silently dropped. Callers must reject
+/// empty names.
+pub fn needed_libraries<'a>(
+    dynamic: &'a [crate::elfn::Dyn],
+    strtab: &'a [u8],
+) -> impl Iterator<Item = &'a [u8]> + 'a {
+    let dt_null = crate::elf::Dyn::<(), ()>::DT_NULL as crate::elfn::Size;
+    let dt_needed = crate::elf::Dyn::<(), ()>::DT_NEEDED as crate::elfn::Size;
+
+    dynamic
+        .iter()
+        .take_while(move |v| v.d_tag != dt_null)
+        .filter(move |v| v.d_tag == dt_needed)
+        .map(move |v| {
+            usize::try_from(v.d_val)
+                .ok()
+                .and_then(|off| strtab_get(strtab, off))
+                .unwrap_or(b"")
+        })
+}
+
+/// Relocation View
+///
+/// This is a normalized view of a relocation entry, regardless of whether it
+/// was read from a table with implicit addends (i.e., `Rel`), or with
+/// explicit addends (i.e., `Rela`). `sym` and `typ` are decoded from
+/// `r_info` according to the class of the entry.
+///
+/// `addend` is `None` for entries with implicit addend. In that case, the
+/// addend is stored in place at the relocation target, and must be read from
+/// there before the target is overwritten. Architectures use one of both
+/// formats for dynamic relocations (e.g., `Rel` on x86 and ARM, `Rela` on
+/// x86-64, AArch64, and RISC-V).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RelocView<SIZE, ADDEND> {
+    pub offset: SIZE,
+    pub sym: u32,
+    pub typ: u32,
+    pub addend: Option<ADDEND>,
+}
+
+impl From<crate::elf32::Rel> for RelocView<crate::elf32::Size, crate::elf32::Addend> {
+    fn from(v: crate::elf32::Rel) -> Self {
+        Self {
+            offset: v.r_offset,
+            sym: v.r_info >> 8,
+            typ: v.r_info & 0xff,
+            addend: None,
+        }
+    }
+}
+
+impl From<crate::elf32::Rela> for RelocView<crate::elf32::Size, crate::elf32::Addend> {
+    fn from(v: crate::elf32::Rela) -> Self {
+        Self {
+            offset: v.r_offset,
+            sym: v.r_info >> 8,
+            typ: v.r_info & 0xff,
+            addend: Some(v.r_addend),
+        }
+    }
+}
+
+impl From<crate::elf64::Rel> for RelocView<crate::elf64::Size, crate::elf64::Addend> {
+    fn from(v: crate::elf64::Rel) -> Self {
+        Self {
+            offset: v.r_offset,
+            sym: (v.r_info >> 32) as u32,
+            typ: v.r_info as u32,
+            addend: None,
+        }
+    }
+}
+
+impl From<crate::elf64::Rela> for RelocView<crate::elf64::Size, crate::elf64::Addend> {
+    fn from(v: crate::elf64::Rela) -> Self {
+        Self {
+            offset: v.r_offset,
+            sym: (v.r_info >> 32) as u32,
+            typ: v.r_info as u32,
+            addend: Some(v.r_addend),
+        }
+    }
+}
+
+/// Program Header Accessors
+///
+/// `elf32::Phdr` and `elf64::Phdr` order their fields differently (the
+/// latter moves `p_flags` up to avoid padding). This trait provides uniform
+/// access to both, so code can be written once for either class. Address
+/// and size fields are widened to `u64`.
+pub trait PhdrLike {
+    fn p_type(&self) -> u32;
+    fn p_flags(&self) -> u32;
+    fn p_offset(&self) -> u64;
+    fn p_vaddr(&self) -> u64;
+    fn p_filesz(&self) -> u64;
+    fn p_memsz(&self) -> u64;
+    fn p_align(&self) -> u64;
+}
+
+// Implement `PhdrLike` for `elf32::Phdr` and `elf64::Phdr`, which have the
+// same field names.
+macro_rules! impl_phdr_like {
+    ($mod:ident) => {
+        impl PhdrLike for crate::$mod::Phdr {
+            fn p_type(&self) -> u32 {
+                self.p_type
+            }
+
+            fn p_flags(&self) -> u32 {
+                self.p_flags
+            }
+
+            fn p_offset(&self) -> u64 {
+                self.p_offset.into()
+            }
+
+            fn p_vaddr(&self) -> u64 {
+                self.p_vaddr.into()
+            }
+
+            fn p_filesz(&self) -> u64 {
+                self.p_filesz.into()
+            }
+
+            fn p_memsz(&self) -> u64 {
+                self.p_memsz.into()
+            }
+
+            fn p_align(&self) -> u64 {
+                self.p_align.into()
+            }
+        }
+    };
+}
+
+impl_phdr_like!(elf32);
+impl_phdr_like!(elf64);
+
+// Implement the header table accessors for `elf32::Ehdr` and `elf64::Ehdr`,
+// which differ only in their `Size` type.
+macro_rules! impl_ehdr_tables {
+    ($mod:ident) => {
+        impl crate::$mod::Ehdr {
+            /// Locate Program Header Table
+            ///
+            /// Return the file offset and total size in bytes (i.e.,
+            /// `e_phnum * e_phentsize`) of the program header table. If
+            /// the end of the table is not representable, `None` is
+            /// returned.
+            ///
+            /// The header is not validated otherwise. Callers must still
+            /// check `e_phentsize` before interpreting the entries, and
+            /// must bounds-check the range against the file or mapping.
+            pub fn phdr_table_range(&self) -> Option<(crate::$mod::Size, usize)> {
+                table_range(self.e_phoff, self.e_phnum, self.e_phentsize)
+            }
+
+            /// Locate Section Header Table
+            ///
+            /// Return the file offset and total size in bytes (i.e.,
+            /// `e_shnum * e_shentsize`) of the section header table. See
+            /// `phdr_table_range()` for details.
+            ///
+            /// Note that this uses `e_shnum` as is. If the object has
+            /// `SHN_LORESERVE` or more sections, `e_shnum` is 0 and the
+            /// actual count is stored in the first section header. This
+            /// must be handled by the caller.
+            pub fn shdr_table_range(&self) -> Option<(crate::$mod::Size, usize)> {
+                table_range(self.e_shoff, self.e_shnum, self.e_shentsize)
+            }
+        }
+    };
+}
+
+impl_ehdr_tables!(elf32);
+impl_ehdr_tables!(elf64);
+
+fn table_range<S>(off: S, num: u16, entsize: u16) -> Option<(S, usize)>
+where
+    S: Copy + Into<u64> + TryFrom<u64>,
+{
+    // The product of two `u16` always fits into a `u32`, and thus into
+    // the `usize` of all supported targets.
+    let size = usize::try_from(num as u32 * entsize as u32).ok()?;
+    let end = off.into().checked_add(size as u64)?;
+    S::try_from(end).ok()?;
+    Some((off, size))
+}
+
+/// Enumerate Program Headers
+///
+/// Return an iterator over the native program headers stored in `table`,
+/// which is the program header table of an object as located via
+/// `phdr_table_range()` (e.g., read from a file). The entries are copied
+/// out of `table`, so it need not be aligned. If the size of `table` is not
+/// a multiple of the size of a program header, `Error::Truncated` is
+/// returned.
+///
+/// `e_phentsize` of the object must be checked by the caller, since
+/// entries of foreign size cannot be interpreted.
+pub fn phdrs(table: &[u8]) -> Result<impl Iterator<Item = crate::elfn::Phdr> + '_, Error> {
+    let size = core::mem::size_of::<crate::elfn::Phdr>();
+
+    let entries = table.chunks_exact(size);
+
+    if !entries.remainder().is_empty() {
+        return Err(Error::Truncated);
+    }
+
+    Ok(entries.map(|v| {
+        // `chunks_exact()` yields entries of exactly the size of a program
+        // header, and any bit-pattern is a valid program header.
+        unsafe { (v.as_ptr() as *const crate::elfn::Phdr).read_unaligned() }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elf;
+
+    fn push(buf: &mut std::vec::Vec<u8>, v: u32) {
+        buf.extend_from_slice(&v.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(std::format!("{}", Error::Truncated), "truncated data");
+        assert_eq!(std::format!("{}", Error::Unordered), "unordered entries");
+    }
+
+    #[test]
+    fn test_property_iter() {
+        let mut desc = std::vec::Vec::new();
+
+        // An 8-byte stack-size property, followed by a feature-1 property,
+        // padded to 8 bytes.
+        push(&mut desc, elf::Nhdr::GNU_PROPERTY_STACK_SIZE);
+        push(&mut desc, 8);
+        push(&mut desc, 0x1000);
+        push(&mut desc, 0);
+        push(&mut desc, elf::Nhdr::GNU_PROPERTY_X86_FEATURE_1_AND);
+        push(&mut desc, 4);
+        push(&mut desc, elf::Nhdr::GNU_PROPERTY_X86_FEATURE_1_IBT);
+        push(&mut desc, 0);
+
+        // With 8-byte alignment, both properties are found.
+        let mut it = PropertyIter::new(&desc, 8);
+        let p = it.next().unwrap().unwrap();
+        assert_eq!(p.pr_type, elf::Nhdr::GNU_PROPERTY_STACK_SIZE);
+        assert_eq!(p.pr_data.len(), 8);
+        let p = it.next().unwrap().unwrap();
+        assert_eq!(p.pr_type, elf::Nhdr::GNU_PROPERTY_X86_FEATURE_1_AND);
+        assert_eq!(p.pr_data, &1u32.to_ne_bytes());
+        assert!(it.next().is_none());
+
+        // With 4-byte alignment, the trailing padding is misinterpreted as
+        // another property, which is rejected as truncated.
+        let mut it = PropertyIter::new(&desc, 4);
+        assert!(it.next().unwrap().is_ok());
+        assert!(it.next().unwrap().is_ok());
+        assert_eq!(it.next(), Some(Err(Error::Truncated)));
+        assert!(it.next().is_none());
+
+        // Unsorted and duplicate types are rejected.
+        for types in [[2, 1], [1, 1]] {
+            let mut desc = std::vec::Vec::new();
+            for t in types {
+                push(&mut desc, t);
+                push(&mut desc, 0);
+            }
+            let mut it = PropertyIter::new(&desc, 8);
+            assert!(it.next().unwrap().is_ok());
+            assert_eq!(it.next(), Some(Err(Error::Unordered)));
+            assert!(it.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_property_iter_truncated() {
+        let mut desc = std::vec::Vec::new();
+
+        // A feature-1 property without its trailing padding.
+        push(&mut desc, elf::Nhdr::GNU_PROPERTY_AARCH64_FEATURE_1_AND);
+        push(&mut desc, 4);
+        push(&mut desc, elf::Nhdr::GNU_PROPERTY_AARCH64_FEATURE_1_BTI);
+
+        let mut it = PropertyIter::new(&desc, 4);
+        assert!(it.next().unwrap().is_ok());
+        assert!(it.next().is_none());
+
+        let mut it = PropertyIter::new(&desc, 8);
+        assert_eq!(it.next(), Some(Err(Error::Truncated)));
+        assert!(it.next().is_none());
+
+        // An oversized data-size must not overflow.
+        let mut desc = std::vec::Vec::new();
+        push(&mut desc, 0);
+        push(&mut desc, u32::MAX);
+        let mut it = PropertyIter::new(&desc, 8);
+        assert_eq!(it.next(), Some(Err(Error::Truncated)));
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn test_note_iter() {
+        let mut data = std::vec::Vec::new();
+
+        // A build-ID note, followed by a note with an empty name and an
+        // unpadded descriptor.
+        push(&mut data, 4);
+        push(&mut data, 4);
+        push(&mut data, elf::Nhdr::NT_GNU_BUILD_ID);
+        data.extend_from_slice(b"GNU\0");
+        data.extend_from_slice(&[0x71; 4]);
+        push(&mut data, 0);
+        push(&mut data, 3);
+        push(&mut data, 7);
+        data.extend_from_slice(b"abc");
+
+        let mut it = NoteIter::new(&data, 4);
+        let n = it.next().unwrap().unwrap();
+        assert_eq!(n.n_type, elf::Nhdr::NT_GNU_BUILD_ID);
+        assert_eq!(n.name, b"GNU");
+        assert_eq!(n.desc, &[0x71; 4]);
+        let n = it.next().unwrap().unwrap();
+        assert_eq!(n, Note { n_type: 7, name: b"", desc: b"abc" });
+        assert!(it.next().is_none());
+
+        // With 8-byte alignment, the second note is expected at offset 24,
+        // where no complete header fits anymore.
+        let mut it = NoteIter::new(&data, 8);
+        assert_eq!(it.next().unwrap().unwrap().desc, &[0x71; 4]);
+        assert_eq!(it.next(), Some(Err(Error::Truncated)));
+        assert!(it.next().is_none());
+
+        // Truncated headers, names, and descriptors are rejected, as are
+        // oversized sizes.
+        for len in [1, 12, 15, 19] {
+            let mut it = NoteIter::new(&data[..len], 4);
+            assert_eq!(it.next(), Some(Err(Error::Truncated)));
+            assert!(it.next().is_none());
+        }
+        let mut data = std::vec::Vec::new();
+        push(&mut data, u32::MAX);
+        push(&mut data, u32::MAX);
+        push(&mut data, 0);
+        let mut it = NoteIter::new(&data, 8);
+        assert_eq!(it.next(), Some(Err(Error::Truncated)));
+        assert!(it.next().is_none());
+        assert!(NoteIter::new(&[], 4).next().is_none());
+    }
+
+    #[test]
+    fn test_sym_shndx() {
+        let xindex = elf::Shdr::<(), ()>::SHN_XINDEX;
+        let sym = |shndx: u16| crate::elfn::Sym { st_shndx: shndx, ..Default::default() };
+        let table: &[u32] = &[0, 0xff00, 0x12345];
+
+        // Regular and reserved indices are returned as is.
+        assert_eq!(sym_shndx(&sym(7), 2, None), Some(7));
+        assert_eq!(sym_shndx(&sym(7), 2, Some(table)), Some(7));
+        let abs = elf::Shdr::<(), ()>::SHN_ABS;
+        assert_eq!(sym_shndx(&sym(abs), 2, Some(table)), Some(abs as u32));
+
+        // Extended indices are looked up in the table.
+        assert_eq!(sym_shndx(&sym(xindex), 1, Some(table)), Some(0xff00));
+        assert_eq!(sym_shndx(&sym(xindex), 2, Some(table)), Some(0x12345));
+        assert_eq!(sym_shndx(&sym(xindex), 3, Some(table)), None);
+        assert_eq!(sym_shndx(&sym(xindex), 2, None), None);
+    }
+
+    #[test]
+    fn test_table_range() {
+        let mut ehdr32 = crate::elf32::Ehdr {
+            e_phoff: 0x34,
+            e_phentsize: 0x20,
+            e_phnum: 8,
+            e_shoff: 0x1000,
+            e_shentsize: 0x28,
+            e_shnum: 30,
+            ..Default::default()
+        };
+        let mut ehdr64 = crate::elf64::Ehdr {
+            e_phoff: 0x40,
+            e_phentsize: 0x38,
+            e_phnum: 13,
+            ..Default::default()
+        };
+
+        assert_eq!(ehdr32.phdr_table_range(), Some((0x34, 0x100)));
+        assert_eq!(ehdr32.shdr_table_range(), Some((0x1000, 0x4b0)));
+        assert_eq!(ehdr64.phdr_table_range(), Some((0x40, 0x2d8)));
+        assert_eq!(ehdr64.shdr_table_range(), Some((0, 0)));
+
+        // The product of hostile counts and sizes cannot overflow.
+        ehdr32.e_phoff = 0;
+        ehdr32.e_phnum = u16::MAX;
+        ehdr32.e_phentsize = u16::MAX;
+        assert_eq!(ehdr32.phdr_table_range(), Some((0, 0xfffe0001)));
+
+        // Tables ending beyond the address space are rejected.
+        ehdr32.e_phoff = u32::MAX;
+        assert_eq!(ehdr32.phdr_table_range(), None);
+        ehdr64.e_shoff = u64::MAX - 0x10;
+        ehdr64.e_shnum = 1;
+        ehdr64.e_shentsize = 0x40;
+        assert_eq!(ehdr64.shdr_table_range(), None);
+    }
+
+    #[test]
+    fn test_reloc_view() {
+        let rel32 = crate::elf32::Rel {
+            r_offset: 0x1000,
+            r_info: (7 << 8) | 6,
+            ..Default::default()
+        };
+        let rela32 = crate::elf32::Rela {
+            r_offset: 0x1004,
+            r_info: (0xffffff << 8) | 0xff,
+            r_addend: -4,
+            ..Default::default()
+        };
+        let rel64 = crate::elf64::Rel {
+            r_offset: 0x2000,
+            r_info: (7 << 32) | 1026,
+            ..Default::default()
+        };
+        let rela64 = crate::elf64::Rela {
+            r_offset: 0x2008,
+            r_info: (0xffffffff << 32) | 0xffffffff,
+            r_addend: i64::MIN,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            RelocView::from(rel32),
+            RelocView { offset: 0x1000, sym: 7, typ: 6, addend: None },
+        );
+        assert_eq!(
+            RelocView::from(rela32),
+            RelocView { offset: 0x1004, sym: 0xffffff, typ: 0xff, addend: Some(-4) },
+        );
+        assert_eq!(
+            RelocView::from(rel64),
+            RelocView { offset: 0x2000, sym: 7, typ: 1026, addend: None },
+        );
+        assert_eq!(
+            RelocView::from(rela64),
+            RelocView {
+                offset: 0x2008,
+                sym: 0xffffffff,
+                typ: 0xffffffff,
+                addend: Some(i64::MIN),
+            },
+        );
+    }
+
+    #[test]
+    fn test_strtab_get() {
+        let strtab = b"\0foo\0bar\0baz";
+
+        assert_eq!(strtab_get(strtab, 0), Some(&b""[..]));
+        assert_eq!(strtab_get(strtab, 1), Some(&b"foo"[..]));
+        assert_eq!(strtab_get(strtab, 2), Some(&b"oo"[..]));
+        assert_eq!(strtab_get(strtab, 5), Some(&b"bar"[..]));
+        assert_eq!(strtab_get(strtab, 8), Some(&b""[..]));
+
+        // Unterminated and out-of-range strings are rejected.
+        assert_eq!(strtab_get(strtab, 9), None);
+        assert_eq!(strtab_get(strtab, 12), None);
+        assert_eq!(strtab_get(strtab, 13), None);
+        assert_eq!(strtab_get(strtab, usize::MAX), None);
+        assert_eq!(strtab_get(b"", 0), None);
+    }
+
+    #[test]
+    fn test_needed_libraries() {
+        let dyn_ = |tag: u32, val: u32| crate::elfn::Dyn {
+            d_tag: tag as _,
+            d_val: val as _,
+            ..Default::default()
+        };
+        let strtab = b"\0libc.so.6\0libm.so.6\0libfoo.so";
+        let dynamic = [
+            dyn_(elf::Dyn::<(), ()>::DT_NEEDED, 11),
+            dyn_(elf::Dyn::<(), ()>::DT_STRTAB, 0x1000),
+            dyn_(elf::Dyn::<(), ()>::DT_NEEDED, 1),
+            dyn_(elf::Dyn::<(), ()>::DT_NEEDED, 21),
+            dyn_(elf::Dyn::<(), ()>::DT_NEEDED, 99),
+            dyn_(elf::Dyn::<(), ()>::DT_NULL, 0),
+            dyn_(elf::Dyn::<(), ()>::DT_NEEDED, 1),
+        ];
+
+        // Order is preserved, unresolvable names yield an empty name, and
+        // entries after `DT_NULL` are ignored.
+        let mut it = needed_libraries(&dynamic, strtab);
+        assert_eq!(it.next(), Some(&b"libm.so.6"[..]));
+        assert_eq!(it.next(), Some(&b"libc.so.6"[..]));
+        assert_eq!(it.next(), Some(&b""[..]));
+        assert_eq!(it.next(), Some(&b""[..]));
+        assert_eq!(it.next(), None);
+
+        // A missing `DT_NULL` terminator ends at the slice boundary.
+        assert_eq!(needed_libraries(&dynamic[..1], strtab).count(), 1);
+        assert_eq!(needed_libraries(&[], strtab).count(), 0);
+    }
+
+    #[test]
+    fn test_phdr_like() {
+        fn load_end<P: PhdrLike>(v: &P) -> Option<u64> {
+            if v.p_type() != crate::elf::Phdr::<(), ()>::PT_LOAD {
+                return None;
+            }
+            v.p_vaddr().checked_add(v.p_memsz())
+        }
+
+        let p32 = crate::elf32::Phdr {
+            p_type: crate::elf::Phdr::<(), ()>::PT_LOAD,
+            p_offset: 0x1000,
+            p_vaddr: 0x401000,
+            p_filesz: 0x200,
+            p_memsz: 0x300,
+            p_flags: crate::elf::Phdr::<(), ()>::PF_R | crate::elf::Phdr::<(), ()>::PF_X,
+            p_align: 0x1000,
+            ..Default::default()
+        };
+        let p64 = crate::elf64::Phdr {
+            p_type: crate::elf::Phdr::<(), ()>::PT_LOAD,
+            p_flags: crate::elf::Phdr::<(), ()>::PF_R | crate::elf::Phdr::<(), ()>::PF_W,
+            p_offset: 0x2000,
+            p_vaddr: 0xffff_ffff_0000,
+            p_filesz: 0x100,
+            p_memsz: 0x400,
+            p_align: 0x10000,
+            ..Default::default()
+        };
+
+        assert_eq!(p32.p_flags(), 0x5);
+        assert_eq!(p32.p_offset(), 0x1000);
+        assert_eq!(p32.p_filesz(), 0x200);
+        assert_eq!(p32.p_align(), 0x1000);
+        assert_eq!(load_end(&p32), Some(0x401300));
+
+        assert_eq!(p64.p_flags(), 0x6);
+        assert_eq!(p64.p_offset(), 0x2000);
+        assert_eq!(p64.p_filesz(), 0x100);
+        assert_eq!(p64.p_align(), 0x10000);
+        assert_eq!(load_end(&p64), Some(0xffff_ffff_0400));
+
+        let note = crate::elf64::Phdr { p_type: crate::elf::Phdr::<(), ()>::PT_NOTE, ..p64 };
+        assert_eq!(load_end(&note), None);
+    }
+
+    #[test]
+    fn test_symbols() {
+        let sym = |name: u32, value: usize| crate::elfn::Sym {
+            st_name: name,
+            st_value: value as _,
+            ..Default::default()
+        };
+        let strtab = b"\0foo\0bar\0";
+        let symtab = [sym(0, 0), sym(5, 0x2000), sym(1, 0x1000), sym(99, 0x3000)];
+
+        let mut it = symbols(&symtab, strtab);
+        assert_eq!(it.next(), Some((&b""[..], &symtab[0])));
+        assert_eq!(it.next(), Some((&b"bar"[..], &symtab[1])));
+        assert_eq!(it.next(), Some((&b"foo"[..], &symtab[2])));
+        assert_eq!(it.next(), Some((&b""[..], &symtab[3])));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_phdrs() {
+        let size = core::mem::size_of::<crate::elfn::Phdr>();
+        let phdr = |typ: u32, vaddr: usize| crate::elfn::Phdr {
+            p_type: typ,
+            p_vaddr: vaddr as _,
+            ..Default::default()
+        };
+        let list = [
+            phdr(crate::elf::Phdr::<(), ()>::PT_PHDR, 0x40),
+            phdr(crate::elf::Phdr::<(), ()>::PT_LOAD, 0x1000),
+        ];
+
+        // Place the table at an odd offset, to verify unaligned tables.
+        let mut buf = std::vec![0u8; 1 + 2 * size];
+        unsafe {
+            let p = buf.as_mut_ptr().add(1) as *mut crate::elfn::Phdr;
+            p.write_unaligned(list[0]);
+            p.add(1).write_unaligned(list[1]);
+        }
+
+        let mut it = phdrs(&buf[1..]).unwrap();
+        assert_eq!(it.next(), Some(list[0]));
+        assert_eq!(it.next(), Some(list[1]));
+        assert_eq!(it.next(), None);
+
+        assert_eq!(phdrs(&[]).unwrap().count(), 0);
+        assert!(matches!(phdrs(&buf[2..]), Err(Error::Truncated)));
+    }
+}
@@ -0,0 +1,130 @@
+//! Architecture Identification
+//!
+//! This module provides a runtime representation of the architectures
+//! supported by this crate. Each variant corresponds to one of the
+//! architecture modules. This allows selecting the definitions of a foreign
+//! architecture at runtime (e.g., when inspecting foreign binaries in debug
+//! utilities).
+
+use rt11_ffi_elf::elf;
+
+/// Kernel Architecture
+///
+/// This enum names all architectures supported by this crate. The variants
+/// match the architecture modules of the crate root.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Arch {
+    /// ARM 32-bit (see `crate::arm`)
+    Arm,
+    /// ARM 64-bit (see `crate::arm64`)
+    Arm64,
+    /// RISC-V 64-bit (see `crate::riscv64`)
+    RiscV64,
+    /// Intel 32-bit (see `crate::x86`)
+    X86,
+    /// Intel 64-bit (see `crate::x86_64`)
+    X86_64,
+}
+
+impl Arch {
+    /// Identify Architecture of ELF Machine
+    ///
+    /// Return the architecture matching the ELF machine identifier
+    /// `e_machine` (i.e., `EM_*`), or `None` if it is not supported.
+    ///
+    /// Note that the ELF machine does not fully identify an ABI. For
+    /// instance, `EM_RISCV` is used for 32-bit and 64-bit RISC-V, and
+    /// `EM_X86_64` is also used by the x32 ABI. Callers must verify the ELF
+    /// class separately (see `Arch::elf_class()`).
+    pub fn from_em(e_machine: u16) -> Option<Arch> {
+        match e_machine {
+            elf::Ehdr::<(), ()>::EM_ARM => Some(Arch::Arm),
+            elf::Ehdr::<(), ()>::EM_AARCH64 => Some(Arch::Arm64),
+            elf::Ehdr::<(), ()>::EM_RISCV => Some(Arch::RiscV64),
+            elf::Ehdr::<(), ()>::EM_386 => Some(Arch::X86),
+            elf::Ehdr::<(), ()>::EM_X86_64 => Some(Arch::X86_64),
+            _ => None,
+        }
+    }
+
+    /// Native Architecture
+    ///
+    /// Return the architecture native to the compilation target. This
+    /// matches the `native` alias of the crate root.
+    ///
+    /// If the native architecture is not a supported architecture, this
+    /// function will not be available.
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    pub const fn native() -> Arch {
+        #[cfg(target_arch = "arm")]
+        { Arch::Arm }
+        #[cfg(target_arch = "aarch64")]
+        { Arch::Arm64 }
+        #[cfg(target_arch = "riscv64")]
+        { Arch::RiscV64 }
+        #[cfg(target_arch = "x86")]
+        { Arch::X86 }
+        #[cfg(target_arch = "x86_64")]
+        { Arch::X86_64 }
+    }
+
+    /// ELF Machine of Architecture
+    ///
+    /// Return the ELF machine identifier (i.e., `EM_*`) used by binaries of
+    /// this architecture.
+    pub const fn em(self) -> u16 {
+        match self {
+            Arch::Arm => elf::Ehdr::<(), ()>::EM_ARM,
+            Arch::Arm64 => elf::Ehdr::<(), ()>::EM_AARCH64,
+            Arch::RiscV64 => elf::Ehdr::<(), ()>::EM_RISCV,
+            Arch::X86 => elf::Ehdr::<(), ()>::EM_386,
+            Arch::X86_64 => elf::Ehdr::<(), ()>::EM_X86_64,
+        }
+    }
+
+    /// ELF Class of Architecture
+    ///
+    /// Return the ELF class (i.e., `ELFCLASS*`) used by binaries of this
+    /// architecture.
+    pub const fn elf_class(self) -> u8 {
+        match self {
+            Arch::Arm | Arch::X86 => elf::Ident::ELFCLASS32,
+            Arch::Arm64 | Arch::RiscV64 | Arch::X86_64 => elf::Ident::ELFCLASS64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify that the ELF machine mapping round-trips for all architectures
+    // and rejects unknown machines.
+    #[test]
+    fn em_mapping() {
+        for a in [Arch::Arm, Arch::Arm64, Arch::RiscV64, Arch::X86, Arch::X86_64] {
+            assert_eq!(Arch::from_em(a.em()), Some(a));
+        }
+
+        assert_eq!(Arch::from_em(elf::Ehdr::<(), ()>::EM_NONE), None);
+        assert_eq!(Arch::from_em(elf::Ehdr::<(), ()>::EM_LOONGARCH), None);
+    }
+
+    // Verify the native architecture matches the pointer width of the
+    // compilation target.
+    #[test]
+    fn native_check() {
+        let class = match core::mem::size_of::<usize>() {
+            4 => elf::Ident::ELFCLASS32,
+            _ => elf::Ident::ELFCLASS64,
+        };
+
+        assert_eq!(Arch::native().elf_class(), class);
+    }
+}
@@ -14,6 +14,22 @@
 //! Always clobbered:
 //!     <none>
 
+/// System Call ABI
+///
+/// This uninhabited marker tags system call numbers of ARM (see
+/// `crate::common::SyscallNr`), so they are only accepted by dispatchers of
+/// ARM.
+pub enum Abi {}
+
+/// Tag System Call Number
+///
+/// Wrap a system call number of `crate::arm::nr` as typed number of the
+/// ARM ABI. This is a `const fn`, so typed numbers can be defined as
+/// constants.
+pub const fn nr(nr: u32) -> crate::common::SyscallNr<Abi> {
+    crate::common::SyscallNr::from_raw(nr as usize)
+}
+
 /// System Call Invocation
 ///
 /// This is a dummy type to invoke standard system calls on ARM via the
@@ -181,3 +197,8 @@ impl crate::common::Syscall for Syscall {
         r
     }
 }
+
+#[cfg(target_arch = "arm")]
+impl crate::common::SyscallAbi for Syscall {
+    type Abi = Abi;
+}
@@ -0,0 +1,40 @@
+//! Auxiliary Vector
+//!
+//! The kernel passes an auxiliary vector to new programs on their initial
+//! stack, following the environment block. It is an array of key-value
+//! pairs of native word size, terminated by `AT_NULL`. It carries
+//! information about the program and the system that is otherwise hard to
+//! obtain.
+//!
+//! Note that some architectures define additional keys (e.g., `AT_SYSINFO`
+//! on x86), which are not provided here.
+
+pub const AT_NULL: u32 = 0;
+pub const AT_IGNORE: u32 = 1;
+pub const AT_EXECFD: u32 = 2;
+pub const AT_PHDR: u32 = 3;
+pub const AT_PHENT: u32 = 4;
+pub const AT_PHNUM: u32 = 5;
+pub const AT_PAGESZ: u32 = 6;
+pub const AT_BASE: u32 = 7;
+pub const AT_FLAGS: u32 = 8;
+pub const AT_ENTRY: u32 = 9;
+pub const AT_NOTELF: u32 = 10;
+pub const AT_UID: u32 = 11;
+pub const AT_EUID: u32 = 12;
+pub const AT_GID: u32 = 13;
+pub const AT_EGID: u32 = 14;
+pub const AT_PLATFORM: u32 = 15;
+pub const AT_HWCAP: u32 = 16;
+pub const AT_CLKTCK: u32 = 17;
+pub const AT_SECURE: u32 = 23;
+pub const AT_BASE_PLATFORM: u32 = 24;
+pub const AT_RANDOM: u32 = 25;
+pub const AT_HWCAP2: u32 = 26;
+pub const AT_RSEQ_FEATURE_SIZE: u32 = 27;
+pub const AT_RSEQ_ALIGN: u32 = 28;
+pub const AT_HWCAP3: u32 = 29;
+pub const AT_HWCAP4: u32 = 30;
+pub const AT_EXECFN: u32 = 31;
+pub const AT_SYSINFO_EHDR: u32 = 33;
+pub const AT_MINSIGSTKSZ: u32 = 51;
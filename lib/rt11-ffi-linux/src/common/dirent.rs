@@ -0,0 +1,50 @@
+//! Directory Entries
+//!
+//! The `getdents64(2)` system call reads the entries of a directory into a
+//! caller provided buffer. Each record consists of a fixed header, followed
+//! by the NUL-terminated name of the entry, padded to an 8-byte boundary.
+
+/// Directory Entry Header
+///
+/// This is the fixed-size header of `struct linux_dirent64` of the kernel.
+/// The name of the entry follows the header at offset
+/// `DIRENT64_NAME_OFFSET`, which differs from the size of this structure
+/// due to its trailing padding. `d_reclen` is the total length of the
+/// record, including the name and its padding, and must be used to advance
+/// to the next record.
+///
+/// Records are 8-byte aligned relative to the start of the buffer. Hence,
+/// callers should use a suitably aligned buffer, or read the header with
+/// unaligned accesses.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Dirent64 {
+    pub d_ino: u64,
+    pub d_off: i64,
+    pub d_reclen: u16,
+    pub d_type: u8,
+}
+
+// Offset of the name in a record
+pub const DIRENT64_NAME_OFFSET: usize = 19;
+
+// Entry types
+pub const DT_UNKNOWN: u8 = 0;
+pub const DT_FIFO: u8 = 1;
+pub const DT_CHR: u8 = 2;
+pub const DT_DIR: u8 = 4;
+pub const DT_BLK: u8 = 6;
+pub const DT_REG: u8 = 8;
+pub const DT_LNK: u8 = 10;
+pub const DT_SOCK: u8 = 12;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<Dirent64>(), 24);
+        assert_eq!(core::mem::align_of::<Dirent64>(), 8);
+    }
+}
@@ -0,0 +1,65 @@
+//! Event Polling
+//!
+//! The `epoll(7)` interface monitors a set of file-descriptors for readiness.
+//! An epoll instance is created via `epoll_create1(2)`, file-descriptors are
+//! registered via `epoll_ctl(2)`, and ready events are retrieved via
+//! `epoll_pwait2(2)` (or one of its predecessors). Events are passed as
+//! `EpollEvent`.
+
+/// Epoll Event
+///
+/// This describes the events `events` (i.e., `EPOLL*`) of interest (or that
+/// occurred) on a registered file-descriptor. `data` is opaque to the
+/// kernel, and returned unchanged with every event of the file-descriptor.
+///
+/// The kernel declares this structure as packed on x86-64 (and only there),
+/// to keep it compatible with 32-bit x86. The x86-64 module thus provides a
+/// packed variant of this structure. On 32-bit x86 both layouts match,
+/// since 64-bit integers are only 4-byte aligned.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EpollEvent {
+    pub events: u32,
+    pub data: u64,
+}
+
+// Creation flags
+pub const EPOLL_CLOEXEC: u32 = 0o2000000;
+
+// Control operations
+pub const EPOLL_CTL_ADD: u32 = 1;
+pub const EPOLL_CTL_DEL: u32 = 2;
+pub const EPOLL_CTL_MOD: u32 = 3;
+
+// Events
+pub const EPOLLIN: u32 = 0x00000001;
+pub const EPOLLPRI: u32 = 0x00000002;
+pub const EPOLLOUT: u32 = 0x00000004;
+pub const EPOLLERR: u32 = 0x00000008;
+pub const EPOLLHUP: u32 = 0x00000010;
+pub const EPOLLNVAL: u32 = 0x00000020;
+pub const EPOLLRDNORM: u32 = 0x00000040;
+pub const EPOLLRDBAND: u32 = 0x00000080;
+pub const EPOLLWRNORM: u32 = 0x00000100;
+pub const EPOLLWRBAND: u32 = 0x00000200;
+pub const EPOLLMSG: u32 = 0x00000400;
+pub const EPOLLRDHUP: u32 = 0x00002000;
+
+// Input flags
+pub const EPOLLEXCLUSIVE: u32 = 1 << 28;
+pub const EPOLLWAKEUP: u32 = 1 << 29;
+pub const EPOLLONESHOT: u32 = 1 << 30;
+pub const EPOLLET: u32 = 1 << 31;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        #[cfg(target_arch = "x86")]
+        assert_eq!(core::mem::size_of::<EpollEvent>(), 12);
+        #[cfg(not(target_arch = "x86"))]
+        assert_eq!(core::mem::size_of::<EpollEvent>(), 16);
+    }
+}
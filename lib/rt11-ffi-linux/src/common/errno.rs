@@ -4,6 +4,9 @@
 //! its interfaces. These are untyped and can be returned by any code in the
 //! kernel. Some have special meaning you can rely upon, yet this is not
 //! enforced by the kernel in any way.
+//!
+//! Besides the constants, `errno_name()` and `is_valid_errno()` allow naming
+//! and validating codes obtained from foreign sources.
 
 // Base error codes
 pub const EPERM: u16 = 1;
@@ -158,3 +161,186 @@ pub const ENOGRACE: u16 = 531;
 // Aliases to the canonical linux codes.
 pub const EWOULDBLOCK: u16 = 11; // EAGAIN
 pub const EDEADLOCK: u16 = 35; // EDEADLCK
+
+/// Query Error Name
+///
+/// Return the symbolic name of the error code `code` (e.g., `"ENOENT"` for
+/// `ENOENT`), or `None` if it is not a known linux error code. Aliases
+/// (i.e., `EWOULDBLOCK` and `EDEADLOCK`) share the code of their canonical
+/// name, and thus yield the latter.
+pub fn errno_name(code: u16) -> Option<&'static str> {
+    Some(match code {
+        EPERM => "EPERM",
+        ENOENT => "ENOENT",
+        ESRCH => "ESRCH",
+        EINTR => "EINTR",
+        EIO => "EIO",
+        ENXIO => "ENXIO",
+        E2BIG => "E2BIG",
+        ENOEXEC => "ENOEXEC",
+        EBADF => "EBADF",
+        ECHILD => "ECHILD",
+        EAGAIN => "EAGAIN",
+        ENOMEM => "ENOMEM",
+        EACCES => "EACCES",
+        EFAULT => "EFAULT",
+        ENOTBLK => "ENOTBLK",
+        EBUSY => "EBUSY",
+        EEXIST => "EEXIST",
+        EXDEV => "EXDEV",
+        ENODEV => "ENODEV",
+        ENOTDIR => "ENOTDIR",
+        EISDIR => "EISDIR",
+        EINVAL => "EINVAL",
+        ENFILE => "ENFILE",
+        EMFILE => "EMFILE",
+        ENOTTY => "ENOTTY",
+        ETXTBSY => "ETXTBSY",
+        EFBIG => "EFBIG",
+        ENOSPC => "ENOSPC",
+        ESPIPE => "ESPIPE",
+        EROFS => "EROFS",
+        EMLINK => "EMLINK",
+        EPIPE => "EPIPE",
+        EDOM => "EDOM",
+        ERANGE => "ERANGE",
+        EDEADLK => "EDEADLK",
+        ENAMETOOLONG => "ENAMETOOLONG",
+        ENOLCK => "ENOLCK",
+        ENOSYS => "ENOSYS",
+        ENOTEMPTY => "ENOTEMPTY",
+        ELOOP => "ELOOP",
+        ENOMSG => "ENOMSG",
+        EIDRM => "EIDRM",
+        ECHRNG => "ECHRNG",
+        EL2NSYNC => "EL2NSYNC",
+        EL3HLT => "EL3HLT",
+        EL3RST => "EL3RST",
+        ELNRNG => "ELNRNG",
+        EUNATCH => "EUNATCH",
+        ENOCSI => "ENOCSI",
+        EL2HLT => "EL2HLT",
+        EBADE => "EBADE",
+        EBADR => "EBADR",
+        EXFULL => "EXFULL",
+        ENOANO => "ENOANO",
+        EBADRQC => "EBADRQC",
+        EBADSLT => "EBADSLT",
+        EBFONT => "EBFONT",
+        ENOSTR => "ENOSTR",
+        ENODATA => "ENODATA",
+        ETIME => "ETIME",
+        ENOSR => "ENOSR",
+        ENONET => "ENONET",
+        ENOPKG => "ENOPKG",
+        EREMOTE => "EREMOTE",
+        ENOLINK => "ENOLINK",
+        EADV => "EADV",
+        ESRMNT => "ESRMNT",
+        ECOMM => "ECOMM",
+        EPROTO => "EPROTO",
+        EMULTIHOP => "EMULTIHOP",
+        EDOTDOT => "EDOTDOT",
+        EBADMSG => "EBADMSG",
+        EOVERFLOW => "EOVERFLOW",
+        ENOTUNIQ => "ENOTUNIQ",
+        EBADFD => "EBADFD",
+        EREMCHG => "EREMCHG",
+        ELIBACC => "ELIBACC",
+        ELIBBAD => "ELIBBAD",
+        ELIBSCN => "ELIBSCN",
+        ELIBMAX => "ELIBMAX",
+        ELIBEXEC => "ELIBEXEC",
+        EILSEQ => "EILSEQ",
+        ERESTART => "ERESTART",
+        ESTRPIPE => "ESTRPIPE",
+        EUSERS => "EUSERS",
+        ENOTSOCK => "ENOTSOCK",
+        EDESTADDRREQ => "EDESTADDRREQ",
+        EMSGSIZE => "EMSGSIZE",
+        EPROTOTYPE => "EPROTOTYPE",
+        ENOPROTOOPT => "ENOPROTOOPT",
+        EPROTONOSUPPORT => "EPROTONOSUPPORT",
+        ESOCKTNOSUPPORT => "ESOCKTNOSUPPORT",
+        EOPNOTSUPP => "EOPNOTSUPP",
+        EPFNOSUPPORT => "EPFNOSUPPORT",
+        EAFNOSUPPORT => "EAFNOSUPPORT",
+        EADDRINUSE => "EADDRINUSE",
+        EADDRNOTAVAIL => "EADDRNOTAVAIL",
+        ENETDOWN => "ENETDOWN",
+        ENETUNREACH => "ENETUNREACH",
+        ENETRESET => "ENETRESET",
+        ECONNABORTED => "ECONNABORTED",
+        ECONNRESET => "ECONNRESET",
+        ENOBUFS => "ENOBUFS",
+        EISCONN => "EISCONN",
+        ENOTCONN => "ENOTCONN",
+        ESHUTDOWN => "ESHUTDOWN",
+        ETOOMANYREFS => "ETOOMANYREFS",
+        ETIMEDOUT => "ETIMEDOUT",
+        ECONNREFUSED => "ECONNREFUSED",
+        EHOSTDOWN => "EHOSTDOWN",
+        EHOSTUNREACH => "EHOSTUNREACH",
+        EALREADY => "EALREADY",
+        EINPROGRESS => "EINPROGRESS",
+        ESTALE => "ESTALE",
+        EUCLEAN => "EUCLEAN",
+        ENOTNAM => "ENOTNAM",
+        ENAVAIL => "ENAVAIL",
+        EISNAM => "EISNAM",
+        EREMOTEIO => "EREMOTEIO",
+        EDQUOT => "EDQUOT",
+        ENOMEDIUM => "ENOMEDIUM",
+        EMEDIUMTYPE => "EMEDIUMTYPE",
+        ECANCELED => "ECANCELED",
+        ENOKEY => "ENOKEY",
+        EKEYEXPIRED => "EKEYEXPIRED",
+        EKEYREVOKED => "EKEYREVOKED",
+        EKEYREJECTED => "EKEYREJECTED",
+        EOWNERDEAD => "EOWNERDEAD",
+        ENOTRECOVERABLE => "ENOTRECOVERABLE",
+        ERFKILL => "ERFKILL",
+        EHWPOISON => "EHWPOISON",
+        EBADHANDLE => "EBADHANDLE",
+        ENOTSYNC => "ENOTSYNC",
+        EBADCOOKIE => "EBADCOOKIE",
+        ENOTSUPP => "ENOTSUPP",
+        ETOOSMALL => "ETOOSMALL",
+        ESERVERFAULT => "ESERVERFAULT",
+        EBADTYPE => "EBADTYPE",
+        EJUKEBOX => "EJUKEBOX",
+        EIOCBQUEUED => "EIOCBQUEUED",
+        ERECALLCONFLICT => "ERECALLCONFLICT",
+        ENOGRACE => "ENOGRACE",
+        _ => return None,
+    })
+}
+
+/// Check Error Code
+///
+/// Return whether `code` is a known linux error code. These are 1 to 133,
+/// excluding the unused 41 and 58, and the NFS codes 521 to 531.
+pub fn is_valid_errno(code: u16) -> bool {
+    errno_name(code).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the valid ranges, the gaps, and that aliases resolve to their
+    // canonical names.
+    #[test]
+    fn name_lookup() {
+        assert_eq!(errno_name(ENOENT), Some("ENOENT"));
+        assert_eq!(errno_name(ENOGRACE), Some("ENOGRACE"));
+        assert_eq!(errno_name(EWOULDBLOCK), Some("EAGAIN"));
+        assert_eq!(errno_name(EDEADLOCK), Some("EDEADLK"));
+
+        for v in 0..1024 {
+            let valid = (1..=133).contains(&v) && v != 41 && v != 58
+                || (521..=531).contains(&v);
+            assert_eq!(is_valid_errno(v), valid);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! File Control
+//!
+//! The `fcntl(2)` system call performs a wide range of operations on
+//! file-descriptors. The operation is selected by a command code, and each
+//! command interprets its argument differently.
+//!
+//! The file-status flags (`O_*`) diverge across architectures (e.g., ARM
+//! uses different values for `O_DIRECTORY`, `O_NOFOLLOW`, `O_DIRECT`, and
+//! `O_LARGEFILE`). Only flags that match on all supported architectures are
+//! provided here.
+
+// Commands
+pub const F_DUPFD: i32 = 0;
+pub const F_GETFD: i32 = 1;
+pub const F_SETFD: i32 = 2;
+pub const F_GETFL: i32 = 3;
+pub const F_SETFL: i32 = 4;
+pub const F_DUPFD_CLOEXEC: i32 = 1030;
+
+// Special directory file-descriptor (for `*at(2)` system calls)
+pub const AT_FDCWD: i32 = -100;
+
+// File-status flags
+pub const O_RDONLY: u32 = 0o0;
+pub const O_WRONLY: u32 = 0o1;
+pub const O_RDWR: u32 = 0o2;
+pub const O_CREAT: u32 = 0o100;
+pub const O_EXCL: u32 = 0o200;
+pub const O_NOCTTY: u32 = 0o400;
+pub const O_TRUNC: u32 = 0o1000;
+pub const O_APPEND: u32 = 0o2000;
+pub const O_NONBLOCK: u32 = 0o4000;
+pub const O_CLOEXEC: u32 = 0o2000000;
+pub const O_PATH: u32 = 0o10000000;
+
+// Seek origins (for `lseek(2)`)
+pub const SEEK_SET: u32 = 0;
+pub const SEEK_CUR: u32 = 1;
+pub const SEEK_END: u32 = 2;
+pub const SEEK_DATA: u32 = 3;
+pub const SEEK_HOLE: u32 = 4;
+
+// Access modes (for `faccessat(2)`)
+pub const F_OK: u32 = 0;
+pub const X_OK: u32 = 1;
+pub const W_OK: u32 = 2;
+pub const R_OK: u32 = 4;
+
+// File-descriptor flags
+pub const FD_CLOEXEC: u32 = 1;
+
+// Range flags (for `close_range(2)`)
+pub const CLOSE_RANGE_UNSHARE: u32 = 1 << 1;
+pub const CLOSE_RANGE_CLOEXEC: u32 = 1 << 2;
+
+// File-access advice (for `fadvise64(2)`)
+pub const POSIX_FADV_NORMAL: u32 = 0;
+pub const POSIX_FADV_RANDOM: u32 = 1;
+pub const POSIX_FADV_SEQUENTIAL: u32 = 2;
+pub const POSIX_FADV_WILLNEED: u32 = 3;
+pub const POSIX_FADV_DONTNEED: u32 = 4;
+pub const POSIX_FADV_NOREUSE: u32 = 5;
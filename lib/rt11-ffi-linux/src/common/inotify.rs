@@ -0,0 +1,58 @@
+//! File-system Event Notification
+//!
+//! The inotify API reports file-system events on watched files and
+//! directories. Events are read from an inotify file-descriptor as a
+//! sequence of `InotifyEvent` headers, each followed by `len` bytes of the
+//! NUL-padded name of the affected directory entry.
+//!
+//! Note that `IN_CLOEXEC` and `IN_NONBLOCK` are aliases of `O_CLOEXEC` and
+//! `O_NONBLOCK`, which match on all supported architectures.
+
+/// Inotify Event Header
+///
+/// This is the fixed-size header of an event read from an inotify
+/// file-descriptor. In C, it ends in a flexible array member `name`, which
+/// is omitted here. `len` is the size of the name, including NUL-padding.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InotifyEvent {
+    pub wd: i32,
+    pub mask: u32,
+    pub cookie: u32,
+    pub len: u32,
+}
+
+// Flags of `inotify_init1()`
+pub const IN_CLOEXEC: u32 = 0o2000000;
+pub const IN_NONBLOCK: u32 = 0o4000;
+
+// Events
+pub const IN_ACCESS: u32 = 0x00000001;
+pub const IN_MODIFY: u32 = 0x00000002;
+pub const IN_ATTRIB: u32 = 0x00000004;
+pub const IN_CLOSE_WRITE: u32 = 0x00000008;
+pub const IN_CLOSE_NOWRITE: u32 = 0x00000010;
+pub const IN_OPEN: u32 = 0x00000020;
+pub const IN_MOVED_FROM: u32 = 0x00000040;
+pub const IN_MOVED_TO: u32 = 0x00000080;
+pub const IN_CREATE: u32 = 0x00000100;
+pub const IN_DELETE: u32 = 0x00000200;
+pub const IN_DELETE_SELF: u32 = 0x00000400;
+pub const IN_MOVE_SELF: u32 = 0x00000800;
+pub const IN_CLOSE: u32 = IN_CLOSE_WRITE | IN_CLOSE_NOWRITE;
+pub const IN_MOVE: u32 = IN_MOVED_FROM | IN_MOVED_TO;
+pub const IN_ALL_EVENTS: u32 = 0x00000fff;
+
+// Events sent by the kernel unconditionally
+pub const IN_UNMOUNT: u32 = 0x00002000;
+pub const IN_Q_OVERFLOW: u32 = 0x00004000;
+pub const IN_IGNORED: u32 = 0x00008000;
+
+// Flags of `inotify_add_watch()`
+pub const IN_ONLYDIR: u32 = 0x01000000;
+pub const IN_DONT_FOLLOW: u32 = 0x02000000;
+pub const IN_EXCL_UNLINK: u32 = 0x04000000;
+pub const IN_MASK_CREATE: u32 = 0x10000000;
+pub const IN_MASK_ADD: u32 = 0x20000000;
+pub const IN_ISDIR: u32 = 0x40000000;
+pub const IN_ONESHOT: u32 = 0x80000000;
@@ -0,0 +1,144 @@
+//! Asynchronous I/O Rings
+//!
+//! The `io_uring(7)` interface uses a pair of rings shared between the kernel
+//! and user-space: the submission queue (SQ) carries requests to the kernel,
+//! and the completion queue (CQ) carries their results back. The rings are
+//! created via `io_uring_setup(2)`, which fills in `IoUringParams` with the
+//! offsets of the individual ring fields. User-space then maps the rings via
+//! `mmap(2)` on the returned file-descriptor at the fixed offsets
+//! `IORING_OFF_SQ_RING`, `IORING_OFF_CQ_RING`, and `IORING_OFF_SQES`.
+//!
+//! Only the setup and control definitions are provided here, but not the
+//! submission and completion entries.
+
+/// Submission Queue Ring Offsets
+///
+/// This describes the offsets of the fields of the submission queue ring,
+/// relative to its mapping at `IORING_OFF_SQ_RING`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IoSqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub flags: u32,
+    pub dropped: u32,
+    pub array: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+/// Completion Queue Ring Offsets
+///
+/// This describes the offsets of the fields of the completion queue ring,
+/// relative to its mapping at `IORING_OFF_CQ_RING`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IoCqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub overflow: u32,
+    pub cqes: u32,
+    pub flags: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+/// Ring Parameters
+///
+/// This is passed to `io_uring_setup(2)`. The caller fills in `flags` and
+/// the fields selected by it, and the kernel fills in the remaining fields
+/// (in particular, the ring sizes, `features`, and the ring offsets).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IoUringParams {
+    pub sq_entries: u32,
+    pub cq_entries: u32,
+    pub flags: u32,
+    pub sq_thread_cpu: u32,
+    pub sq_thread_idle: u32,
+    pub features: u32,
+    pub wq_fd: u32,
+    pub resv: [u32; 3],
+    pub sq_off: IoSqringOffsets,
+    pub cq_off: IoCqringOffsets,
+}
+
+// Setup flags
+pub const IORING_SETUP_IOPOLL: u32 = 1 << 0;
+pub const IORING_SETUP_SQPOLL: u32 = 1 << 1;
+pub const IORING_SETUP_SQ_AFF: u32 = 1 << 2;
+pub const IORING_SETUP_CQSIZE: u32 = 1 << 3;
+pub const IORING_SETUP_CLAMP: u32 = 1 << 4;
+pub const IORING_SETUP_ATTACH_WQ: u32 = 1 << 5;
+pub const IORING_SETUP_R_DISABLED: u32 = 1 << 6;
+pub const IORING_SETUP_SUBMIT_ALL: u32 = 1 << 7;
+pub const IORING_SETUP_COOP_TASKRUN: u32 = 1 << 8;
+pub const IORING_SETUP_TASKRUN_FLAG: u32 = 1 << 9;
+pub const IORING_SETUP_SQE128: u32 = 1 << 10;
+pub const IORING_SETUP_CQE32: u32 = 1 << 11;
+pub const IORING_SETUP_SINGLE_ISSUER: u32 = 1 << 12;
+pub const IORING_SETUP_DEFER_TASKRUN: u32 = 1 << 13;
+pub const IORING_SETUP_NO_MMAP: u32 = 1 << 14;
+pub const IORING_SETUP_REGISTERED_FD_ONLY: u32 = 1 << 15;
+pub const IORING_SETUP_NO_SQARRAY: u32 = 1 << 16;
+
+// Feature flags
+pub const IORING_FEAT_SINGLE_MMAP: u32 = 1 << 0;
+pub const IORING_FEAT_NODROP: u32 = 1 << 1;
+pub const IORING_FEAT_SUBMIT_STABLE: u32 = 1 << 2;
+pub const IORING_FEAT_RW_CUR_POS: u32 = 1 << 3;
+pub const IORING_FEAT_CUR_PERSONALITY: u32 = 1 << 4;
+pub const IORING_FEAT_FAST_POLL: u32 = 1 << 5;
+pub const IORING_FEAT_POLL_32BITS: u32 = 1 << 6;
+pub const IORING_FEAT_SQPOLL_NONFIXED: u32 = 1 << 7;
+pub const IORING_FEAT_EXT_ARG: u32 = 1 << 8;
+pub const IORING_FEAT_NATIVE_WORKERS: u32 = 1 << 9;
+pub const IORING_FEAT_RSRC_TAGS: u32 = 1 << 10;
+pub const IORING_FEAT_CQE_SKIP: u32 = 1 << 11;
+pub const IORING_FEAT_LINKED_FILE: u32 = 1 << 12;
+pub const IORING_FEAT_REG_REG_RING: u32 = 1 << 13;
+
+// Enter flags
+pub const IORING_ENTER_GETEVENTS: u32 = 1 << 0;
+pub const IORING_ENTER_SQ_WAKEUP: u32 = 1 << 1;
+pub const IORING_ENTER_SQ_WAIT: u32 = 1 << 2;
+pub const IORING_ENTER_EXT_ARG: u32 = 1 << 3;
+pub const IORING_ENTER_REGISTERED_RING: u32 = 1 << 4;
+
+// Register opcodes
+pub const IORING_REGISTER_BUFFERS: u32 = 0;
+pub const IORING_UNREGISTER_BUFFERS: u32 = 1;
+pub const IORING_REGISTER_FILES: u32 = 2;
+pub const IORING_UNREGISTER_FILES: u32 = 3;
+pub const IORING_REGISTER_EVENTFD: u32 = 4;
+pub const IORING_UNREGISTER_EVENTFD: u32 = 5;
+pub const IORING_REGISTER_FILES_UPDATE: u32 = 6;
+pub const IORING_REGISTER_EVENTFD_ASYNC: u32 = 7;
+pub const IORING_REGISTER_PROBE: u32 = 8;
+pub const IORING_REGISTER_PERSONALITY: u32 = 9;
+pub const IORING_UNREGISTER_PERSONALITY: u32 = 10;
+pub const IORING_REGISTER_RESTRICTIONS: u32 = 11;
+pub const IORING_REGISTER_ENABLE_RINGS: u32 = 12;
+pub const IORING_REGISTER_USE_REGISTERED_RING: u32 = 1 << 31;
+
+// Mapping offsets
+pub const IORING_OFF_SQ_RING: u64 = 0;
+pub const IORING_OFF_CQ_RING: u64 = 0x8000000;
+pub const IORING_OFF_SQES: u64 = 0x10000000;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the layout of the ring parameters matches the kernel ABI.
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<IoSqringOffsets>(), 40);
+        assert_eq!(core::mem::size_of::<IoCqringOffsets>(), 40);
+        assert_eq!(core::mem::size_of::<IoUringParams>(), 120);
+    }
+}
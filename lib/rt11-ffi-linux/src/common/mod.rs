@@ -12,7 +12,30 @@
 //! Note that for documentational purposes, this module also exposes some
 //! definitions that are not used by any architecture.
 
+pub mod auxvec;
+pub mod dirent;
+pub mod epoll;
 pub mod errno;
+pub mod fcntl;
+pub mod inotify;
+pub mod io_uring;
+pub mod mman;
+pub mod mount;
+pub mod personality;
+pub mod pidfd;
+pub mod poll;
+pub mod prctl;
+pub mod reboot;
+pub mod resource;
+pub mod sched;
+pub mod signal;
+pub mod socket;
+pub mod sysinfo;
+pub mod termios;
+pub mod time;
+pub mod uio;
+pub mod wait;
+pub mod xattr;
 
 /// System Call Numbers
 ///
@@ -24,6 +47,108 @@ pub mod errno;
 /// upstream definitions for details.
 pub mod nr;
 
+/// Typed System Call Number
+///
+/// The `nr` modules expose system call numbers as plain integers, which
+/// makes it easy to pass a number of one architecture to the dispatcher of
+/// another (e.g., `x86::nr::GETPID` to the x86_64 `syscall` instruction),
+/// which silently invokes an unrelated system call. This type wraps a
+/// system call number together with a marker type `A` naming its ABI (see
+/// `Abi` in the `syscall` module of each architecture).
+/// `SyscallAbi::invoke()` only accepts numbers tagged with the ABI of the
+/// implementation, so such mix-ups fail to compile.
+///
+/// Each architecture provides the typed counterparts of its `nr` constants
+/// in its `nr_typed` module (e.g., `x86_64::nr_typed::GETPID`). Other
+/// numbers can be tagged via the `const` constructor of the respective
+/// architecture (e.g., `x86_64::syscall::nr()`), or via
+/// `SyscallNr::from_raw()`, which performs no checks. `raw()` (or a
+/// conversion into `usize`) yields the plain number for instruction-level
+/// dispatch via `syscall0()` to `syscall6()`.
+pub struct SyscallNr<A> {
+    nr: usize,
+    _abi: core::marker::PhantomData<fn() -> A>,
+}
+
+impl<A> SyscallNr<A> {
+    /// Tag Raw System Call Number
+    ///
+    /// Wrap the plain system call number `nr`, tagging it with the ABI `A`.
+    /// The caller must ensure the number belongs to this ABI.
+    pub const fn from_raw(nr: usize) -> Self {
+        Self { nr, _abi: core::marker::PhantomData }
+    }
+
+    /// Return the plain system call number.
+    pub const fn raw(self) -> usize {
+        self.nr
+    }
+}
+
+// Implemented manually, since derives would require `A` to implement the
+// traits as well, while the markers are uninhabited.
+impl<A> Clone for SyscallNr<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for SyscallNr<A> {
+}
+
+impl<A> core::fmt::Debug for SyscallNr<A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SyscallNr").field(&self.nr).finish()
+    }
+}
+
+impl<A> Eq for SyscallNr<A> {
+}
+
+impl<A> core::hash::Hash for SyscallNr<A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.nr.hash(state)
+    }
+}
+
+impl<A> Ord for SyscallNr<A> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.nr.cmp(&other.nr)
+    }
+}
+
+impl<A> PartialEq for SyscallNr<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nr == other.nr
+    }
+}
+
+impl<A> PartialOrd for SyscallNr<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A> From<SyscallNr<A>> for usize {
+    fn from(v: SyscallNr<A>) -> Self {
+        v.raw()
+    }
+}
+
+/// Decode System Call Return Value
+///
+/// Decode the raw return value `r` of a system call following the common
+/// error scheme: values between `-4095` and `-1` are returned as `Err` with
+/// the positive error number (i.e., `E*`), any other value is returned
+/// untouched as `Ok`. See `Syscall::syscall_checked()`.
+pub fn decode_retval(r: usize) -> Result<usize, u16> {
+    if r > usize::MAX - 4096 {
+        Err((!r + 1) as u16)
+    } else {
+        Ok(r)
+    }
+}
+
 /// Syscall Invocation Trait
 ///
 /// There are different ways to invoke system calls for different platforms.
@@ -41,6 +166,14 @@ pub mod nr;
 /// on `usize` to match the native platform. Fortunately, there is little
 /// purpose in using this trait for foreign access, anyway.
 pub trait Syscall {
+    /// Invoke System Call without Arguments
+    ///
+    /// This is the same as `syscall6()`, but passes 0 for all arguments.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `syscall6()`.
     unsafe fn syscall0(
         &self,
         nr: usize,
@@ -48,6 +181,15 @@ pub trait Syscall {
         self.syscall6(nr, 0, 0, 0, 0, 0, 0)
     }
 
+    /// Invoke System Call with 1 Argument
+    ///
+    /// This is the same as `syscall6()`, but passes 0 for all arguments
+    /// beyond the first 1.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `syscall6()`.
     unsafe fn syscall1(
         &self,
         nr: usize,
@@ -56,6 +198,15 @@ pub trait Syscall {
         self.syscall6(nr, arg0, 0, 0, 0, 0, 0)
     }
 
+    /// Invoke System Call with 2 Arguments
+    ///
+    /// This is the same as `syscall6()`, but passes 0 for all arguments
+    /// beyond the first 2.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `syscall6()`.
     unsafe fn syscall2(
         &self,
         nr: usize,
@@ -65,6 +216,15 @@ pub trait Syscall {
         self.syscall6(nr, arg0, arg1, 0, 0, 0, 0)
     }
 
+    /// Invoke System Call with 3 Arguments
+    ///
+    /// This is the same as `syscall6()`, but passes 0 for all arguments
+    /// beyond the first 3.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `syscall6()`.
     unsafe fn syscall3(
         &self,
         nr: usize,
@@ -75,6 +235,15 @@ pub trait Syscall {
         self.syscall6(nr, arg0, arg1, arg2, 0, 0, 0)
     }
 
+    /// Invoke System Call with 4 Arguments
+    ///
+    /// This is the same as `syscall6()`, but passes 0 for all arguments
+    /// beyond the first 4.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `syscall6()`.
     unsafe fn syscall4(
         &self,
         nr: usize,
@@ -86,6 +255,15 @@ pub trait Syscall {
         self.syscall6(nr, arg0, arg1, arg2, arg3, 0, 0)
     }
 
+    /// Invoke System Call with 5 Arguments
+    ///
+    /// This is the same as `syscall6()`, but passes 0 for all arguments
+    /// beyond the first 5.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `syscall6()`.
     unsafe fn syscall5(
         &self,
         nr: usize,
@@ -98,6 +276,24 @@ pub trait Syscall {
         self.syscall6(nr, arg0, arg1, arg2, arg3, arg4, 0)
     }
 
+    /// Invoke System Call
+    ///
+    /// Invoke the system call `nr` of the native ABI with the arguments
+    /// `arg0` to `arg5`, and return the raw return value of the kernel.
+    /// Errors are not decoded (see `syscall_checked()`). Arguments not used
+    /// by the system call are ignored by the kernel.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// System calls can have arbitrary effects on the calling task,
+    /// including its memory. The caller must ensure that `nr` is a system
+    /// call number of the native ABI, and that the arguments satisfy the
+    /// requirements of that system call (e.g., pointers must be valid for
+    /// the accesses performed by the kernel). Any effect of the system call
+    /// must be sound with respect to the rest of the program (e.g.,
+    /// unmapping memory that is still referenced is not).
+    #[allow(clippy::too_many_arguments)]
     unsafe fn syscall6(
         &self,
         nr: usize,
@@ -108,4 +304,72 @@ pub trait Syscall {
         arg4: usize,
         arg5: usize,
     ) -> usize;
+
+    /// Invoke System Call with Error Decoding
+    ///
+    /// Invoke the system call `nr` with the arguments `args` and decode the
+    /// return value. Unused trailing arguments are passed as 0, and at most
+    /// 6 arguments are supported. If more are passed, `EINVAL` is returned
+    /// without entering the kernel.
+    ///
+    /// Almost all system calls return values between `-4095` and `-1` to
+    /// signal an error. These are returned as `Err` with the positive error
+    /// number (i.e., `E*`). Any other value is returned untouched as `Ok`
+    /// (see `decode_retval()`).
+    ///
+    /// Some system calls do not follow this scheme (e.g., `brk(2)`, or
+    /// `getpriority(2)` on some architectures). These must use the raw
+    /// entry-points (e.g., `syscall0()` to `syscall6()`) instead.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `syscall6()`.
+    unsafe fn syscall_checked(
+        &self,
+        nr: usize,
+        args: &[usize],
+    ) -> Result<usize, u16> {
+        let mut a = [0usize; 6];
+
+        a.get_mut(..args.len()).ok_or(errno::EINVAL)?.copy_from_slice(args);
+
+        decode_retval(self.syscall6(nr, a[0], a[1], a[2], a[3], a[4], a[5]))
+    }
+}
+
+/// Typed Syscall Invocation Trait
+///
+/// This extends `Syscall` with the ABI of the system call numbers an
+/// implementation dispatches, and provides entry-points that only accept
+/// numbers tagged with this ABI (see `SyscallNr`). It is kept separate from
+/// `Syscall`, so implementations that are not tied to a specific ABI (e.g.,
+/// for syscall-redirection) can still implement the raw entry-points.
+pub trait SyscallAbi: Syscall {
+    /// ABI Marker
+    ///
+    /// The marker type of the system call numbers this implementation
+    /// dispatches (see `SyscallNr`).
+    type Abi;
+
+    /// Invoke Typed System Call
+    ///
+    /// Invoke the system call `nr` with the arguments `args` and decode the
+    /// return value, like `Syscall::syscall_checked()`. Unlike the raw
+    /// entry-points, this only accepts numbers tagged with the ABI of this
+    /// implementation. If more than 6 arguments are passed, `EINVAL` is
+    /// returned without entering the kernel.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The tag only guarantees the number belongs to the right ABI. The
+    /// arguments must still satisfy the requirements of the system call.
+    unsafe fn invoke(
+        &self,
+        nr: SyscallNr<Self::Abi>,
+        args: &[usize],
+    ) -> Result<usize, u16> {
+        self.syscall_checked(nr.raw(), args)
+    }
 }
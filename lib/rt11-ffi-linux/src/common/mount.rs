@@ -0,0 +1,40 @@
+//! Mount Operations
+//!
+//! The `mount(2)` system call takes a set of flags (`MS_*`) which select the
+//! operation to perform (e.g., a new mount, a bind mount, a remount, or a
+//! propagation change) and the per-mount options to apply. The
+//! `umount2(2)` system call takes its own set of flags (`MNT_*` and
+//! `UMOUNT_*`).
+
+// Mount flags
+pub const MS_RDONLY: u32 = 0x00000001;
+pub const MS_NOSUID: u32 = 0x00000002;
+pub const MS_NODEV: u32 = 0x00000004;
+pub const MS_NOEXEC: u32 = 0x00000008;
+pub const MS_SYNCHRONOUS: u32 = 0x00000010;
+pub const MS_REMOUNT: u32 = 0x00000020;
+pub const MS_MANDLOCK: u32 = 0x00000040;
+pub const MS_DIRSYNC: u32 = 0x00000080;
+pub const MS_NOSYMFOLLOW: u32 = 0x00000100;
+pub const MS_NOATIME: u32 = 0x00000400;
+pub const MS_NODIRATIME: u32 = 0x00000800;
+pub const MS_BIND: u32 = 0x00001000;
+pub const MS_MOVE: u32 = 0x00002000;
+pub const MS_REC: u32 = 0x00004000;
+pub const MS_SILENT: u32 = 0x00008000;
+pub const MS_POSIXACL: u32 = 0x00010000;
+pub const MS_UNBINDABLE: u32 = 0x00020000;
+pub const MS_PRIVATE: u32 = 0x00040000;
+pub const MS_SLAVE: u32 = 0x00080000;
+pub const MS_SHARED: u32 = 0x00100000;
+pub const MS_RELATIME: u32 = 0x00200000;
+pub const MS_KERNMOUNT: u32 = 0x00400000;
+pub const MS_I_VERSION: u32 = 0x00800000;
+pub const MS_STRICTATIME: u32 = 0x01000000;
+pub const MS_LAZYTIME: u32 = 0x02000000;
+
+// Unmount flags
+pub const MNT_FORCE: u32 = 0x00000001;
+pub const MNT_DETACH: u32 = 0x00000002;
+pub const MNT_EXPIRE: u32 = 0x00000004;
+pub const UMOUNT_NOFOLLOW: u32 = 0x00000008;
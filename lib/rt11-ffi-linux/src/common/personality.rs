@@ -0,0 +1,29 @@
+//! Execution Domains
+//!
+//! Linux supports running binaries of foreign UNIX flavors by adjusting
+//! kernel behavior to match the expectations of those binaries. This is
+//! called the personality (or execution domain) of a task. Nowadays, only
+//! `PER_LINUX` is of relevance as base personality, but the personality
+//! flags are still used to tweak the memory layout of a task.
+//!
+//! The lower byte of a persona selects the base personality, the remaining
+//! bits are flags that modify its behavior.
+
+// Personality flags
+pub const UNAME26: u32 = 0x0020000;
+pub const ADDR_NO_RANDOMIZE: u32 = 0x0040000;
+pub const FDPIC_FUNCPTRS: u32 = 0x0080000;
+pub const MMAP_PAGE_ZERO: u32 = 0x0100000;
+pub const ADDR_COMPAT_LAYOUT: u32 = 0x0200000;
+pub const READ_IMPLIES_EXEC: u32 = 0x0400000;
+pub const ADDR_LIMIT_32BIT: u32 = 0x0800000;
+pub const SHORT_INODE: u32 = 0x1000000;
+pub const WHOLE_SECONDS: u32 = 0x2000000;
+pub const STICKY_TIMEOUTS: u32 = 0x4000000;
+pub const ADDR_LIMIT_3GB: u32 = 0x8000000;
+
+// Base personalities
+pub const PER_LINUX: u32 = 0x0000;
+pub const PER_LINUX_32BIT: u32 = PER_LINUX | ADDR_LIMIT_32BIT;
+pub const PER_LINUX32: u32 = 0x0008;
+pub const PER_MASK: u32 = 0x00ff;
@@ -0,0 +1,9 @@
+//! Process File-descriptors
+//!
+//! A pidfd is a file-descriptor that refers to a process (or thread). Unlike
+//! a process ID, it cannot be recycled, and thus allows race-free process
+//! management.
+
+// Flags of `pidfd_open(2)`
+pub const PIDFD_NONBLOCK: u32 = 0o4000;
+pub const PIDFD_THREAD: u32 = 0o200;
@@ -0,0 +1,40 @@
+//! File-descriptor Polling
+//!
+//! The `poll(2)` family of system calls waits for any of a set of
+//! file-descriptors to become ready. Each file-descriptor is described by a
+//! `PollFd`, which carries the requested events and receives the returned
+//! events.
+
+/// Poll Descriptor
+///
+/// This is the transpose of `struct pollfd` of the kernel. `events` is a
+/// bitmask of the requested `POLL*` events, and `revents` is filled by the
+/// kernel with the events that occurred. `POLLERR`, `POLLHUP`, and
+/// `POLLNVAL` are always reported, even if not requested. Negative `fd`
+/// values are ignored.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PollFd {
+    pub fd: i32,
+    pub events: i16,
+    pub revents: i16,
+}
+
+// Events of `PollFd`
+pub const POLLIN: i16 = 0x0001;
+pub const POLLPRI: i16 = 0x0002;
+pub const POLLOUT: i16 = 0x0004;
+pub const POLLERR: i16 = 0x0008;
+pub const POLLHUP: i16 = 0x0010;
+pub const POLLNVAL: i16 = 0x0020;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<PollFd>(), 8);
+        assert_eq!(core::mem::align_of::<PollFd>(), 4);
+    }
+}
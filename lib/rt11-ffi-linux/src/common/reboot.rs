@@ -0,0 +1,24 @@
+//! System Reboot
+//!
+//! The `reboot(2)` system call requires two magic numbers as first
+//! arguments, to guard against accidental invocation. The first must be
+//! `LINUX_REBOOT_MAGIC1`, the second any of the `LINUX_REBOOT_MAGIC2*`
+//! values (which encode birth dates of Linus Torvalds and his daughters).
+//! The third argument selects the operation via `LINUX_REBOOT_CMD_*`.
+
+// Magic values
+pub const LINUX_REBOOT_MAGIC1: u32 = 0xfee1dead;
+pub const LINUX_REBOOT_MAGIC2: u32 = 672274793;
+pub const LINUX_REBOOT_MAGIC2A: u32 = 85072278;
+pub const LINUX_REBOOT_MAGIC2B: u32 = 369367448;
+pub const LINUX_REBOOT_MAGIC2C: u32 = 537993216;
+
+// Commands
+pub const LINUX_REBOOT_CMD_RESTART: u32 = 0x01234567;
+pub const LINUX_REBOOT_CMD_HALT: u32 = 0xcdef0123;
+pub const LINUX_REBOOT_CMD_CAD_ON: u32 = 0x89abcdef;
+pub const LINUX_REBOOT_CMD_CAD_OFF: u32 = 0x00000000;
+pub const LINUX_REBOOT_CMD_POWER_OFF: u32 = 0x4321fedc;
+pub const LINUX_REBOOT_CMD_RESTART2: u32 = 0xa1b2c3d4;
+pub const LINUX_REBOOT_CMD_SW_SUSPEND: u32 = 0xd000fce2;
+pub const LINUX_REBOOT_CMD_KEXEC: u32 = 0x45584543;
@@ -0,0 +1,97 @@
+//! Resource Limits
+//!
+//! Every task has a set of resource limits, each consisting of a soft limit
+//! enforced by the kernel and a hard limit acting as ceiling for the soft
+//! limit. Only privileged tasks can raise the hard limit.
+
+/// Resource Limit
+///
+/// This is the 64-bit representation of a resource limit, as used by
+/// `prlimit64(2)` on all architectures.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Rlimit64 {
+    pub rlim_cur: u64,
+    pub rlim_max: u64,
+}
+
+/// Resource Usage
+///
+/// This is the transpose of `struct rusage` of the kernel, as returned by
+/// `getrusage(2)`. `ru_utime` and `ru_stime` are the user and system CPU
+/// time consumed, `ru_maxrss` is the maximum resident set size in KiB, and
+/// the remaining fields count page faults, block I/O operations, and
+/// context switches.
+///
+/// Linux does not maintain `ru_ixrss`, `ru_idrss`, `ru_isrss`, `ru_nswap`,
+/// `ru_msgsnd`, `ru_msgrcv`, and `ru_nsignals`. They are always 0.
+///
+/// All fields use native word sizes, so the structure differs between
+/// 32-bit and 64-bit architectures.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rusage {
+    pub ru_utime: crate::common::time::Timeval,
+    pub ru_stime: crate::common::time::Timeval,
+    pub ru_maxrss: isize,
+    pub ru_ixrss: isize,
+    pub ru_idrss: isize,
+    pub ru_isrss: isize,
+    pub ru_minflt: isize,
+    pub ru_majflt: isize,
+    pub ru_nswap: isize,
+    pub ru_inblock: isize,
+    pub ru_oublock: isize,
+    pub ru_msgsnd: isize,
+    pub ru_msgrcv: isize,
+    pub ru_nsignals: isize,
+    pub ru_nvcsw: isize,
+    pub ru_nivcsw: isize,
+}
+
+// Resources
+pub const RLIMIT_CPU: u32 = 0;
+pub const RLIMIT_FSIZE: u32 = 1;
+pub const RLIMIT_DATA: u32 = 2;
+pub const RLIMIT_STACK: u32 = 3;
+pub const RLIMIT_CORE: u32 = 4;
+pub const RLIMIT_RSS: u32 = 5;
+pub const RLIMIT_NPROC: u32 = 6;
+pub const RLIMIT_NOFILE: u32 = 7;
+pub const RLIMIT_MEMLOCK: u32 = 8;
+pub const RLIMIT_AS: u32 = 9;
+pub const RLIMIT_LOCKS: u32 = 10;
+pub const RLIMIT_SIGPENDING: u32 = 11;
+pub const RLIMIT_MSGQUEUE: u32 = 12;
+pub const RLIMIT_NICE: u32 = 13;
+pub const RLIMIT_RTPRIO: u32 = 14;
+pub const RLIMIT_RTTIME: u32 = 15;
+pub const RLIM_NLIMITS: u32 = 16;
+
+// Unlimited resource (for `prlimit64(2)`)
+pub const RLIM64_INFINITY: u64 = !0;
+
+// Priority targets (for `getpriority(2)` and `setpriority(2)`)
+pub const PRIO_PROCESS: u32 = 0;
+pub const PRIO_PGRP: u32 = 1;
+pub const PRIO_USER: u32 = 2;
+
+// Usage targets (for `getrusage(2)`)
+pub const RUSAGE_SELF: i32 = 0;
+pub const RUSAGE_CHILDREN: i32 = -1;
+pub const RUSAGE_THREAD: i32 = 1;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(core::mem::size_of::<Rusage>(), 72);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(core::mem::size_of::<Rusage>(), 144);
+        assert_eq!(core::mem::align_of::<Rusage>(), core::mem::align_of::<usize>());
+        assert_eq!(core::mem::size_of::<Rlimit64>(), 16);
+    }
+}
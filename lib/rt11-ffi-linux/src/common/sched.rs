@@ -0,0 +1,46 @@
+//! Scheduling and Task Creation
+//!
+//! The flags of `clone(2)` select which resources a new task shares with its
+//! parent. A subset of these flags is also accepted by `unshare(2)` to
+//! detach the calling task from shared resources, and the namespace flags
+//! (`CLONE_NEW*`) are used by `setns(2)` to select a namespace type.
+//!
+//! Some flags depend on each other, and `clone(2)` fails with `EINVAL` if
+//! a dependency is missing: `CLONE_THREAD` requires `CLONE_SIGHAND`, which
+//! in turn requires `CLONE_VM`. Hence, a new thread needs at least
+//! `CLONE_VM | CLONE_SIGHAND | CLONE_THREAD`, while `CLONE_VM` alone is
+//! valid (e.g., with `CLONE_VFORK`, as used to spawn programs). Similarly,
+//! `CLONE_FS` cannot be combined with `CLONE_NEWNS` or `CLONE_NEWUSER`,
+//! and `CLONE_THREAD` cannot be combined with `CLONE_NEWUSER` or
+//! `CLONE_NEWPID`.
+
+// Clone flags
+pub const CSIGNAL: u32 = 0x000000ff;
+pub const CLONE_VM: u32 = 0x00000100;
+pub const CLONE_FS: u32 = 0x00000200;
+pub const CLONE_FILES: u32 = 0x00000400;
+pub const CLONE_SIGHAND: u32 = 0x00000800;
+pub const CLONE_PIDFD: u32 = 0x00001000;
+pub const CLONE_PTRACE: u32 = 0x00002000;
+pub const CLONE_VFORK: u32 = 0x00004000;
+pub const CLONE_PARENT: u32 = 0x00008000;
+pub const CLONE_THREAD: u32 = 0x00010000;
+pub const CLONE_NEWNS: u32 = 0x00020000;
+pub const CLONE_SYSVSEM: u32 = 0x00040000;
+pub const CLONE_SETTLS: u32 = 0x00080000;
+pub const CLONE_PARENT_SETTID: u32 = 0x00100000;
+pub const CLONE_CHILD_CLEARTID: u32 = 0x00200000;
+pub const CLONE_DETACHED: u32 = 0x00400000;
+pub const CLONE_UNTRACED: u32 = 0x00800000;
+pub const CLONE_CHILD_SETTID: u32 = 0x01000000;
+pub const CLONE_NEWCGROUP: u32 = 0x02000000;
+pub const CLONE_NEWUTS: u32 = 0x04000000;
+pub const CLONE_NEWIPC: u32 = 0x08000000;
+pub const CLONE_NEWUSER: u32 = 0x10000000;
+pub const CLONE_NEWPID: u32 = 0x20000000;
+pub const CLONE_NEWNET: u32 = 0x40000000;
+pub const CLONE_IO: u32 = 0x80000000;
+
+// Clone flags (overlapping `CSIGNAL`, only valid for `clone3(2)`, `unshare(2)`
+// and `setns(2)`)
+pub const CLONE_NEWTIME: u32 = 0x00000080;
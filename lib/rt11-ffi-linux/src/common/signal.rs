@@ -0,0 +1,121 @@
+//! Signals
+//!
+//! Signals are asynchronous notifications delivered to tasks. The standard
+//! signals occupy the numbers 1 to 31, and the real-time signals occupy the
+//! numbers from `SIGRTMIN` up to `SIGRTMAX`. Signal sets passed to the
+//! kernel (e.g., via `rt_sigprocmask(2)`) are 64-bit masks, with signal `n`
+//! at bit `n - 1`.
+//!
+//! The signal numbers listed here match all supported architectures, but
+//! diverge on some others (e.g., alpha, mips, parisc, or sparc).
+
+// Standard signals
+pub const SIGHUP: u32 = 1;
+pub const SIGINT: u32 = 2;
+pub const SIGQUIT: u32 = 3;
+pub const SIGILL: u32 = 4;
+pub const SIGTRAP: u32 = 5;
+pub const SIGABRT: u32 = 6;
+pub const SIGIOT: u32 = 6;
+pub const SIGBUS: u32 = 7;
+pub const SIGFPE: u32 = 8;
+pub const SIGKILL: u32 = 9;
+pub const SIGUSR1: u32 = 10;
+pub const SIGSEGV: u32 = 11;
+pub const SIGUSR2: u32 = 12;
+pub const SIGPIPE: u32 = 13;
+pub const SIGALRM: u32 = 14;
+pub const SIGTERM: u32 = 15;
+pub const SIGSTKFLT: u32 = 16;
+pub const SIGCHLD: u32 = 17;
+pub const SIGCONT: u32 = 18;
+pub const SIGSTOP: u32 = 19;
+pub const SIGTSTP: u32 = 20;
+pub const SIGTTIN: u32 = 21;
+pub const SIGTTOU: u32 = 22;
+pub const SIGURG: u32 = 23;
+pub const SIGXCPU: u32 = 24;
+pub const SIGXFSZ: u32 = 25;
+pub const SIGVTALRM: u32 = 26;
+pub const SIGPROF: u32 = 27;
+pub const SIGWINCH: u32 = 28;
+pub const SIGIO: u32 = 29;
+pub const SIGPOLL: u32 = SIGIO;
+pub const SIGPWR: u32 = 30;
+pub const SIGSYS: u32 = 31;
+
+// Real-time signals
+pub const SIGRTMIN: u32 = 32;
+pub const SIGRTMAX: u32 = 64;
+
+// Special handlers of `rt_sigaction(2)`
+pub const SIG_DFL: usize = 0;
+pub const SIG_IGN: usize = 1;
+
+// Operations of `rt_sigprocmask(2)`
+pub const SIG_BLOCK: u32 = 0;
+pub const SIG_UNBLOCK: u32 = 1;
+pub const SIG_SETMASK: u32 = 2;
+
+// Flags of `rt_sigaction(2)`
+pub const SA_NOCLDSTOP: u32 = 0x00000001;
+pub const SA_NOCLDWAIT: u32 = 0x00000002;
+pub const SA_SIGINFO: u32 = 0x00000004;
+pub const SA_RESTORER: u32 = 0x04000000;
+pub const SA_ONSTACK: u32 = 0x08000000;
+pub const SA_RESTART: u32 = 0x10000000;
+pub const SA_NODEFER: u32 = 0x40000000;
+pub const SA_RESETHAND: u32 = 0x80000000;
+
+// Flags of `sigaltstack(2)`
+pub const SS_ONSTACK: u32 = 1;
+pub const SS_DISABLE: u32 = 2;
+
+/// Signal Action
+///
+/// This is `struct sigaction` of the kernel (not the one of the C library),
+/// as used by `rt_sigaction(2)`. It carries the handler `sa_handler` (or
+/// `SIG_DFL` or `SIG_IGN`), the flags `sa_flags` (i.e., `SA_*`), the
+/// trampoline `sa_restorer` used with `SA_RESTORER`, and the signals
+/// `sa_mask` blocked while the handler runs. The mask is split into native
+/// words, so it is only aligned to those on 32-bit architectures.
+///
+/// This layout is used by all supported architectures with `SA_RESTORER`.
+/// RISC-V has no restorer, and provides its own definition. An all-zero
+/// action (i.e., `Default`) selects the default action of a signal.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Sigaction {
+    pub sa_handler: usize,
+    pub sa_flags: usize,
+    pub sa_restorer: usize,
+    pub sa_mask: [usize; 8 / core::mem::size_of::<usize>()],
+}
+
+/// Alternate Signal Stack
+///
+/// This is `stack_t` of the kernel, as used by `sigaltstack(2)`. It
+/// describes a stack of `ss_size` bytes starting at `ss_sp`, and its state
+/// via `ss_flags` (i.e., `SS_*`). The layout matches on all supported
+/// architectures, with `ss_flags` padded to the native word size on 64-bit
+/// architectures.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Stack {
+    pub ss_sp: usize,
+    pub ss_flags: i32,
+    pub ss_size: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<Sigaction>(), 3 * core::mem::size_of::<usize>() + 8);
+        assert_eq!(core::mem::align_of::<Sigaction>(), core::mem::align_of::<usize>());
+        assert_eq!(core::mem::size_of::<Stack>(), 3 * core::mem::size_of::<usize>());
+        assert_eq!(core::mem::align_of::<Stack>(), core::mem::align_of::<usize>());
+    }
+}
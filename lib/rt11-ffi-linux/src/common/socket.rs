@@ -0,0 +1,83 @@
+//! Sockets
+//!
+//! The socket interface of the kernel (`socket(2)`, `connect(2)`,
+//! `setsockopt(2)`, ...) is shared by all address families. Addresses are
+//! passed as family-specific structures, prefixed by the address family.
+//!
+//! The system calls diverge across architectures: x86 historically
+//! multiplexes all socket operations through the single `socketcall(2)`
+//! system call, which takes a call number (`SYS_*`) and a pointer to the
+//! array of arguments. Direct system calls were only added to x86 in
+//! linux-4.3. All other supported architectures (i.e., x86-64, ARM,
+//! AArch64, and RISC-V) have always provided direct system calls. The call
+//! numbers of `socketcall(2)` are provided here as well.
+
+/// UNIX Domain Socket Address
+///
+/// This is the transpose of `struct sockaddr_un` of the kernel. `sun_path`
+/// holds the path of the socket, which is NUL-terminated unless it fills
+/// the entire array. If its first byte is 0, the address refers to the
+/// abstract namespace instead, and the name is given by the remaining
+/// bytes up to the address length passed alongside the structure.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SockaddrUn {
+    pub sun_family: u16,
+    pub sun_path: [u8; 108],
+}
+
+impl Default for SockaddrUn {
+    fn default() -> Self {
+        Self {
+            sun_family: AF_UNIX as u16,
+            sun_path: [0; 108],
+        }
+    }
+}
+
+// Address families
+pub const AF_UNSPEC: u32 = 0;
+pub const AF_UNIX: u32 = 1;
+pub const AF_INET: u32 = 2;
+pub const AF_INET6: u32 = 10;
+
+// Socket types and creation flags
+pub const SOCK_STREAM: u32 = 1;
+pub const SOCK_DGRAM: u32 = 2;
+pub const SOCK_RAW: u32 = 3;
+pub const SOCK_SEQPACKET: u32 = 5;
+pub const SOCK_NONBLOCK: u32 = 0o4000;
+pub const SOCK_CLOEXEC: u32 = 0o2000000;
+
+// Option levels
+pub const SOL_SOCKET: u32 = 1;
+
+// Socket-level options
+pub const SO_REUSEADDR: u32 = 2;
+pub const SO_TYPE: u32 = 3;
+pub const SO_ERROR: u32 = 4;
+pub const SO_SNDBUF: u32 = 7;
+pub const SO_RCVBUF: u32 = 8;
+pub const SO_KEEPALIVE: u32 = 9;
+pub const SO_PASSCRED: u32 = 16;
+pub const SO_PEERCRED: u32 = 17;
+
+// Call numbers of `socketcall(2)`
+pub const SYS_SOCKET: u32 = 1;
+pub const SYS_BIND: u32 = 2;
+pub const SYS_CONNECT: u32 = 3;
+pub const SYS_LISTEN: u32 = 4;
+pub const SYS_ACCEPT: u32 = 5;
+pub const SYS_SETSOCKOPT: u32 = 14;
+pub const SYS_GETSOCKOPT: u32 = 15;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<SockaddrUn>(), 110);
+        assert_eq!(core::mem::align_of::<SockaddrUn>(), 2);
+    }
+}
@@ -0,0 +1,54 @@
+//! System Information
+//!
+//! The `sysinfo(2)` system call returns overall statistics of the system,
+//! including memory and swap usage, the number of tasks, and the load
+//! averages.
+
+/// System Information
+///
+/// This is the transpose of `struct sysinfo` of the kernel. The memory and
+/// swap sizes (i.e., `*ram`, `*swap`, and `*high`) are given in units of
+/// `mem_unit` bytes, and must be multiplied by it to get bytes. The load
+/// averages over 1, 5, and 15 minutes in `loads` are fixed-point numbers
+/// with 16 fractional bits (i.e., they must be divided by 65536). `procs`
+/// is the number of tasks (i.e., threads, not processes).
+///
+/// The structure uses native word sizes, and thus differs between 32-bit
+/// and 64-bit architectures. The trailing padding `_f` only exists on
+/// 32-bit architectures, where it fills the structure to the size used by
+/// old kernels, which lacked `mem_unit` and reported sizes in bytes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Sysinfo {
+    pub uptime: isize,
+    pub loads: [usize; 3],
+    pub totalram: usize,
+    pub freeram: usize,
+    pub sharedram: usize,
+    pub bufferram: usize,
+    pub totalswap: usize,
+    pub freeswap: usize,
+    pub procs: u16,
+    pub pad: u16,
+    pub totalhigh: usize,
+    pub freehigh: usize,
+    pub mem_unit: u32,
+    pub _f: [u8; 20 - 2 * core::mem::size_of::<usize>() - core::mem::size_of::<u32>()],
+}
+
+// Fixed-point shift of `Sysinfo::loads`
+pub const SI_LOAD_SHIFT: u32 = 16;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(core::mem::size_of::<Sysinfo>(), 64);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(core::mem::size_of::<Sysinfo>(), 112);
+        assert_eq!(core::mem::align_of::<Sysinfo>(), core::mem::align_of::<usize>());
+    }
+}
@@ -0,0 +1,32 @@
+//! Terminal Control
+//!
+//! Terminals are controlled via `ioctl(2)` on their file-descriptors. The
+//! request numbers and structures listed here match all supported
+//! architectures, but diverge on some others (e.g., alpha, mips, powerpc,
+//! or sparc). Only requests that match on all supported architectures are
+//! provided here.
+
+/// Window Size
+///
+/// This describes the size of a terminal window, as used by `TIOCGWINSZ`
+/// and `TIOCSWINSZ`. The pixel sizes are unused by the kernel, and often 0.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Winsize {
+    pub ws_row: u16,
+    pub ws_col: u16,
+    pub ws_xpixel: u16,
+    pub ws_ypixel: u16,
+}
+
+// Requests
+pub const TCGETS: u32 = 0x5401;
+pub const TCSETS: u32 = 0x5402;
+pub const TCSETSW: u32 = 0x5403;
+pub const TCSETSF: u32 = 0x5404;
+pub const TIOCSCTTY: u32 = 0x540e;
+pub const TIOCGPGRP: u32 = 0x540f;
+pub const TIOCSPGRP: u32 = 0x5410;
+pub const TIOCGWINSZ: u32 = 0x5413;
+pub const TIOCSWINSZ: u32 = 0x5414;
+pub const TIOCNOTTY: u32 = 0x5422;
@@ -0,0 +1,48 @@
+//! Clocks and Time
+//!
+//! The kernel exposes a set of clocks, identified by `CLOCK_*`, which can be
+//! queried via `clock_gettime(2)` and `clock_getres(2)`. Time values are
+//! passed as `Timespec`, or as `Timeval` by some legacy interfaces.
+
+/// Time Specification
+///
+/// This describes a point in time, or a duration, as seconds and
+/// nanoseconds. `tv_nsec` is in the range 0 to 999,999,999 for all values
+/// returned by the kernel.
+///
+/// This matches `struct __kernel_timespec`, which uses 64-bit fields on all
+/// architectures. On 32-bit architectures, it must only be used with the
+/// `*_time64` system calls.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+/// Time Value
+///
+/// This describes a point in time, or a duration, as seconds and
+/// microseconds. It matches `struct __kernel_old_timeval`, which uses
+/// native word sized fields. Hence, it is limited to 32-bit seconds on
+/// 32-bit architectures. It is only used by legacy interfaces that have no
+/// `Timespec` based replacement (e.g., `getrusage(2)`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timeval {
+    pub tv_sec: isize,
+    pub tv_usec: isize,
+}
+
+// Clock Identifiers
+pub const CLOCK_REALTIME: u32 = 0;
+pub const CLOCK_MONOTONIC: u32 = 1;
+pub const CLOCK_PROCESS_CPUTIME_ID: u32 = 2;
+pub const CLOCK_THREAD_CPUTIME_ID: u32 = 3;
+pub const CLOCK_MONOTONIC_RAW: u32 = 4;
+pub const CLOCK_REALTIME_COARSE: u32 = 5;
+pub const CLOCK_MONOTONIC_COARSE: u32 = 6;
+pub const CLOCK_BOOTTIME: u32 = 7;
+pub const CLOCK_REALTIME_ALARM: u32 = 8;
+pub const CLOCK_BOOTTIME_ALARM: u32 = 9;
+pub const CLOCK_TAI: u32 = 11;
@@ -0,0 +1,32 @@
+//! Vectored I/O
+//!
+//! Vectored system calls (e.g., `readv(2)`, `writev(2)`, or
+//! `process_madvise(2)`) take an array of `Iovec` entries, each describing
+//! a contiguous memory range.
+
+/// Maximum number of entries in a single vector.
+pub const UIO_MAXIOV: u32 = 1024;
+
+/// I/O Vector Entry
+///
+/// This describes the memory range of `iov_len` bytes starting at
+/// `iov_base`. It matches `struct iovec`, which uses native word sized
+/// fields. The base is a plain address, since the range might not be part
+/// of the calling process (e.g., for `process_madvise(2)`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Iovec {
+    pub iov_base: usize,
+    pub iov_len: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<Iovec>(), 2 * core::mem::size_of::<usize>());
+        assert_eq!(core::mem::align_of::<Iovec>(), core::mem::align_of::<usize>());
+    }
+}
@@ -0,0 +1,83 @@
+//! Child Reaping
+//!
+//! The `waitid(2)` system call waits for a state change of a child task,
+//! selected by `P_*` and an identifier, and reports it via a `Siginfo`
+//! structure, as if `SIGCHLD` was delivered.
+
+// Selectors of `waitid(2)`
+pub const P_ALL: u32 = 0;
+pub const P_PID: u32 = 1;
+pub const P_PGID: u32 = 2;
+pub const P_PIDFD: u32 = 3;
+
+// Options of `waitid(2)` and `wait4(2)`
+pub const WNOHANG: u32 = 0x00000001;
+pub const WUNTRACED: u32 = 0x00000002;
+pub const WSTOPPED: u32 = 0x00000002;
+pub const WEXITED: u32 = 0x00000004;
+pub const WCONTINUED: u32 = 0x00000008;
+pub const WNOWAIT: u32 = 0x01000000;
+
+// Codes of `Siginfo::si_code` for `SIGCHLD`
+pub const CLD_EXITED: i32 = 1;
+pub const CLD_KILLED: i32 = 2;
+pub const CLD_DUMPED: i32 = 3;
+pub const CLD_TRAPPED: i32 = 4;
+pub const CLD_STOPPED: i32 = 5;
+pub const CLD_CONTINUED: i32 = 6;
+
+/// Child Signal Information
+///
+/// This is the subset of `siginfo_t` of the kernel filled in for
+/// `SIGCHLD`, as reported by `waitid(2)`. `si_code` is one of `CLD_*`.
+/// `si_status` is the exit code if `si_code` is `CLD_EXITED`, and the
+/// signal number otherwise. The fields of other signals are not exposed,
+/// but the structure has the full size of 128 bytes, so the kernel can
+/// write it.
+///
+/// The union of the kernel structure is aligned to the native word size,
+/// so `_pad` only has a non-zero size on 64-bit architectures.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Siginfo {
+    pub si_signo: i32,
+    pub si_errno: i32,
+    pub si_code: i32,
+    pub _pad: [u8; core::mem::size_of::<usize>() - 4],
+    pub si_pid: i32,
+    pub si_uid: u32,
+    pub si_status: i32,
+    pub _f: [u8; 104 - (core::mem::size_of::<usize>() - 4)],
+}
+
+impl Default for Siginfo {
+    fn default() -> Self {
+        Self {
+            si_signo: 0,
+            si_errno: 0,
+            si_code: 0,
+            _pad: [0; core::mem::size_of::<usize>() - 4],
+            si_pid: 0,
+            si_uid: 0,
+            si_status: 0,
+            _f: [0; 104 - (core::mem::size_of::<usize>() - 4)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<Siginfo>(), 128);
+        assert_eq!(core::mem::align_of::<Siginfo>(), 4);
+
+        let v = Siginfo::default();
+        let base = &v as *const _ as usize;
+        let pad = core::mem::size_of::<usize>() - 4;
+        assert_eq!(&v.si_pid as *const _ as usize - base, 12 + pad);
+        assert_eq!(&v.si_status as *const _ as usize - base, 20 + pad);
+    }
+}
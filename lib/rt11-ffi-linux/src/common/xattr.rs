@@ -0,0 +1,16 @@
+//! Extended Attributes
+//!
+//! Extended attributes are name-value pairs attached to inodes, in addition
+//! to the regular attributes of `stat(2)`. Names are NUL-terminated strings
+//! with a namespace prefix (e.g., `user.`, `trusted.`, or `security.`),
+//! values are arbitrary binary data. They are accessed via the
+//! `*xattr(2)` system calls.
+
+// Flags of `setxattr(2)`
+pub const XATTR_CREATE: u32 = 0x1;
+pub const XATTR_REPLACE: u32 = 0x2;
+
+// Limits
+pub const XATTR_NAME_MAX: usize = 255;
+pub const XATTR_SIZE_MAX: usize = 65536;
+pub const XATTR_LIST_MAX: usize = 65536;
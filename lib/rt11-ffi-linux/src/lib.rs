@@ -69,6 +69,7 @@
 #[cfg(test)]
 extern crate std;
 
+pub mod arch;
 pub mod common;
 
 pub mod arm;
@@ -178,6 +179,65 @@ mod test {
         assert_eq!(r0, std::process::id() as usize);
     }
 
+    // Run `syscall_checked()` with a succeeding and a failing system call,
+    // and verify the error decoding and the rejection of too many arguments.
+    #[test]
+    fn syscall_checked_check() {
+        let sc = native::syscall::Syscall {};
+
+        let r0 = unsafe {
+            <_ as common::Syscall>::syscall_checked(
+                &sc,
+                native::nr::GETPID as usize,
+                &[],
+            )
+        };
+        assert_eq!(r0, Ok(std::process::id() as usize));
+
+        let r0 = unsafe {
+            <_ as common::Syscall>::syscall_checked(
+                &sc,
+                native::nr::CLOSE as usize,
+                &[u32::MAX as usize],
+            )
+        };
+        assert_eq!(r0, Err(native::errno::EBADF));
+
+        let r0 = unsafe {
+            <_ as common::Syscall>::syscall_checked(
+                &sc,
+                native::nr::GETPID as usize,
+                &[0; 7],
+            )
+        };
+        assert_eq!(r0, Err(native::errno::EINVAL));
+    }
+
+    // Invoke typed system call numbers, and verify excess arguments are
+    // rejected, and the numbers are ordered and convert back into their
+    // plain numbers.
+    #[test]
+    fn syscall_invoke_check() {
+        const GETPID: common::SyscallNr<native::syscall::Abi> = native::nr_typed::GETPID;
+        let sc = native::syscall::Syscall {};
+        let close = native::syscall::nr(native::nr::CLOSE);
+
+        let r0 = unsafe { <_ as common::SyscallAbi>::invoke(&sc, GETPID, &[]) };
+        assert_eq!(r0, Ok(std::process::id() as usize));
+
+        let r0 = unsafe { <_ as common::SyscallAbi>::invoke(&sc, close, &[u32::MAX as usize]) };
+        assert_eq!(r0, Err(native::errno::EBADF));
+
+        let r0 = unsafe { <_ as common::SyscallAbi>::invoke(&sc, GETPID, &[0; 7]) };
+        assert_eq!(r0, Err(native::errno::EINVAL));
+
+        assert_eq!(GETPID.raw(), native::nr::GETPID as usize);
+        assert_eq!(usize::from(close), native::nr::CLOSE as usize);
+        assert_eq!(close, native::nr_typed::CLOSE);
+        assert_eq!(GETPID.cmp(&close), (native::nr::GETPID).cmp(&native::nr::CLOSE));
+        assert_eq!(GETPID, common::SyscallNr::from_raw(native::nr::GETPID as usize));
+    }
+
     // Run a simple invocation of `syscall1()` and `syscall2()` and see
     // whether they behave plausibly.
     #[test]
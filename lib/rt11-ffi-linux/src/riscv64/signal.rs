@@ -0,0 +1,30 @@
+//! Signals for RISC-V 64
+//!
+//! This module provides the same definitions as `common::signal`, except for
+//! `Sigaction`, which has no restorer on RISC-V.
+
+pub use crate::common::signal::*;
+
+/// Signal Action for RISC-V 64
+///
+/// This is the RISC-V equivalent of `common::signal::Sigaction`. RISC-V
+/// does not support `SA_RESTORER`, but always returns from handlers via the
+/// trampoline of the vDSO, so the action lacks `sa_restorer`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Sigaction {
+    pub sa_handler: usize,
+    pub sa_flags: usize,
+    pub sa_mask: [usize; 8 / core::mem::size_of::<usize>()],
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<Sigaction>(), 2 * core::mem::size_of::<usize>() + 8);
+        assert_eq!(core::mem::align_of::<Sigaction>(), core::mem::align_of::<usize>());
+    }
+}
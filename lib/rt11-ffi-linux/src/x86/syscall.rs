@@ -3,9 +3,9 @@
 //! This implements the syscall entries for x86.
 //!
 //! The implementation uses the x86-`int$0x80` software interrupt to enter the
-//! kernel. It would be much faster to use the VDSO entry point, but it does
-//! require access to `%gs` and the TLS mappings, and thus is left to higher
-//! level crates to implement.
+//! kernel. The VDSO entry point is an alternative, but it requires access to
+//! `%gs` and the TLS mappings, and thus is left to higher level crates to
+//! implement.
 //!
 //! Arguments are passed as:
 //!     Nr: eax
@@ -13,6 +13,22 @@
 //! Return value is in:
 //!     Ret: eax
 
+/// System Call ABI
+///
+/// This uninhabited marker tags system call numbers of x86 (see
+/// `crate::common::SyscallNr`), so they are only accepted by dispatchers of
+/// x86.
+pub enum Abi {}
+
+/// Tag System Call Number
+///
+/// Wrap a system call number of `crate::x86::nr` as typed number of the
+/// x86 ABI. This is a `const fn`, so typed numbers can be defined as
+/// constants.
+pub const fn nr(nr: u32) -> crate::common::SyscallNr<Abi> {
+    crate::common::SyscallNr::from_raw(nr as usize)
+}
+
 /// System Call Invocation
 ///
 /// This is a dummy type to invoke standard system calls on x86 via the
@@ -223,3 +239,8 @@ impl crate::common::Syscall for Syscall {
         r
     }
 }
+
+#[cfg(target_arch = "x86")]
+impl crate::common::SyscallAbi for Syscall {
+    type Abi = Abi;
+}
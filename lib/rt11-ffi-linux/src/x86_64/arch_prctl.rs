@@ -0,0 +1,28 @@
+//! Architecture-specific Task Control
+//!
+//! The `arch_prctl(2)` system call controls architecture-specific state of a
+//! task. On x86_64 it is the gateway to the segment bases of `%fs` and `%gs`
+//! (used for thread-local storage) as well as to feature controls like the
+//! permission to use dynamically enabled XSAVE features (e.g., AMX).
+//!
+//! The 32-bit x86 architecture provides the same system call, but only
+//! supports the CPUID and XSAVE-feature controls. The segment-base controls
+//! are only available on x86_64.
+
+pub const ARCH_SET_GS: u32 = 0x1001;
+pub const ARCH_SET_FS: u32 = 0x1002;
+pub const ARCH_GET_FS: u32 = 0x1003;
+pub const ARCH_GET_GS: u32 = 0x1004;
+
+pub const ARCH_GET_CPUID: u32 = 0x1011;
+pub const ARCH_SET_CPUID: u32 = 0x1012;
+
+pub const ARCH_GET_XCOMP_SUPP: u32 = 0x1021;
+pub const ARCH_GET_XCOMP_PERM: u32 = 0x1022;
+pub const ARCH_REQ_XCOMP_PERM: u32 = 0x1023;
+pub const ARCH_GET_XCOMP_GUEST_PERM: u32 = 0x1024;
+pub const ARCH_REQ_XCOMP_GUEST_PERM: u32 = 0x1025;
+
+pub const ARCH_MAP_VDSO_X32: u32 = 0x2001;
+pub const ARCH_MAP_VDSO_32: u32 = 0x2002;
+pub const ARCH_MAP_VDSO_64: u32 = 0x2003;
@@ -0,0 +1,29 @@
+//! Event Polling for x86_64
+//!
+//! This module provides the same definitions as `common::epoll`, except for
+//! `EpollEvent`, which is packed on x86_64.
+
+pub use crate::common::epoll::*;
+
+/// Epoll Event for x86_64
+///
+/// This is the x86_64 equivalent of `common::epoll::EpollEvent`. It is
+/// packed to match the layout of 32-bit x86, so `data` is not naturally
+/// aligned. Its fields must be copied out rather than borrowed.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EpollEvent {
+    pub events: u32,
+    pub data: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typeinfo() {
+        assert_eq!(core::mem::size_of::<EpollEvent>(), 12);
+        assert_eq!(core::mem::align_of::<EpollEvent>(), 1);
+    }
+}
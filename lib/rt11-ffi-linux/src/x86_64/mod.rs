@@ -3,7 +3,32 @@
 //! This module provides the linux-kernel API definitions specific
 //! to x86_64.
 
+pub mod arch_prctl;
+pub mod epoll;
 pub mod nr;
+pub mod nr_typed;
 pub mod syscall;
 
+pub use crate::common::auxvec as auxvec;
+pub use crate::common::dirent as dirent;
 pub use crate::common::errno as errno;
+pub use crate::common::fcntl as fcntl;
+pub use crate::common::inotify as inotify;
+pub use crate::common::io_uring as io_uring;
+pub use crate::common::mman as mman;
+pub use crate::common::mount as mount;
+pub use crate::common::personality as personality;
+pub use crate::common::pidfd as pidfd;
+pub use crate::common::poll as poll;
+pub use crate::common::prctl as prctl;
+pub use crate::common::reboot as reboot;
+pub use crate::common::resource as resource;
+pub use crate::common::sched as sched;
+pub use crate::common::signal as signal;
+pub use crate::common::socket as socket;
+pub use crate::common::sysinfo as sysinfo;
+pub use crate::common::termios as termios;
+pub use crate::common::time as time;
+pub use crate::common::uio as uio;
+pub use crate::common::wait as wait;
+pub use crate::common::xattr as xattr;
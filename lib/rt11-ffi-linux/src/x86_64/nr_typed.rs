@@ -0,0 +1,366 @@
+// This code is generated.
+use super::syscall::{nr, Abi};
+use crate::common::SyscallNr;
+
+pub const READ: SyscallNr<Abi> = nr(super::nr::READ);
+pub const WRITE: SyscallNr<Abi> = nr(super::nr::WRITE);
+pub const OPEN: SyscallNr<Abi> = nr(super::nr::OPEN);
+pub const CLOSE: SyscallNr<Abi> = nr(super::nr::CLOSE);
+pub const STAT: SyscallNr<Abi> = nr(super::nr::STAT);
+pub const FSTAT: SyscallNr<Abi> = nr(super::nr::FSTAT);
+pub const LSTAT: SyscallNr<Abi> = nr(super::nr::LSTAT);
+pub const POLL: SyscallNr<Abi> = nr(super::nr::POLL);
+pub const LSEEK: SyscallNr<Abi> = nr(super::nr::LSEEK);
+pub const MMAP: SyscallNr<Abi> = nr(super::nr::MMAP);
+pub const MPROTECT: SyscallNr<Abi> = nr(super::nr::MPROTECT);
+pub const MUNMAP: SyscallNr<Abi> = nr(super::nr::MUNMAP);
+pub const BRK: SyscallNr<Abi> = nr(super::nr::BRK);
+pub const RT_SIGACTION: SyscallNr<Abi> = nr(super::nr::RT_SIGACTION);
+pub const RT_SIGPROCMASK: SyscallNr<Abi> = nr(super::nr::RT_SIGPROCMASK);
+pub const RT_SIGRETURN: SyscallNr<Abi> = nr(super::nr::RT_SIGRETURN);
+pub const IOCTL: SyscallNr<Abi> = nr(super::nr::IOCTL);
+pub const PREAD64: SyscallNr<Abi> = nr(super::nr::PREAD64);
+pub const PWRITE64: SyscallNr<Abi> = nr(super::nr::PWRITE64);
+pub const READV: SyscallNr<Abi> = nr(super::nr::READV);
+pub const WRITEV: SyscallNr<Abi> = nr(super::nr::WRITEV);
+pub const ACCESS: SyscallNr<Abi> = nr(super::nr::ACCESS);
+pub const PIPE: SyscallNr<Abi> = nr(super::nr::PIPE);
+pub const SELECT: SyscallNr<Abi> = nr(super::nr::SELECT);
+pub const SCHED_YIELD: SyscallNr<Abi> = nr(super::nr::SCHED_YIELD);
+pub const MREMAP: SyscallNr<Abi> = nr(super::nr::MREMAP);
+pub const MSYNC: SyscallNr<Abi> = nr(super::nr::MSYNC);
+pub const MINCORE: SyscallNr<Abi> = nr(super::nr::MINCORE);
+pub const MADVISE: SyscallNr<Abi> = nr(super::nr::MADVISE);
+pub const SHMGET: SyscallNr<Abi> = nr(super::nr::SHMGET);
+pub const SHMAT: SyscallNr<Abi> = nr(super::nr::SHMAT);
+pub const SHMCTL: SyscallNr<Abi> = nr(super::nr::SHMCTL);
+pub const DUP: SyscallNr<Abi> = nr(super::nr::DUP);
+pub const DUP2: SyscallNr<Abi> = nr(super::nr::DUP2);
+pub const PAUSE: SyscallNr<Abi> = nr(super::nr::PAUSE);
+pub const NANOSLEEP: SyscallNr<Abi> = nr(super::nr::NANOSLEEP);
+pub const GETITIMER: SyscallNr<Abi> = nr(super::nr::GETITIMER);
+pub const ALARM: SyscallNr<Abi> = nr(super::nr::ALARM);
+pub const SETITIMER: SyscallNr<Abi> = nr(super::nr::SETITIMER);
+pub const GETPID: SyscallNr<Abi> = nr(super::nr::GETPID);
+pub const SENDFILE: SyscallNr<Abi> = nr(super::nr::SENDFILE);
+pub const SOCKET: SyscallNr<Abi> = nr(super::nr::SOCKET);
+pub const CONNECT: SyscallNr<Abi> = nr(super::nr::CONNECT);
+pub const ACCEPT: SyscallNr<Abi> = nr(super::nr::ACCEPT);
+pub const SENDTO: SyscallNr<Abi> = nr(super::nr::SENDTO);
+pub const RECVFROM: SyscallNr<Abi> = nr(super::nr::RECVFROM);
+pub const SENDMSG: SyscallNr<Abi> = nr(super::nr::SENDMSG);
+pub const RECVMSG: SyscallNr<Abi> = nr(super::nr::RECVMSG);
+pub const SHUTDOWN: SyscallNr<Abi> = nr(super::nr::SHUTDOWN);
+pub const BIND: SyscallNr<Abi> = nr(super::nr::BIND);
+pub const LISTEN: SyscallNr<Abi> = nr(super::nr::LISTEN);
+pub const GETSOCKNAME: SyscallNr<Abi> = nr(super::nr::GETSOCKNAME);
+pub const GETPEERNAME: SyscallNr<Abi> = nr(super::nr::GETPEERNAME);
+pub const SOCKETPAIR: SyscallNr<Abi> = nr(super::nr::SOCKETPAIR);
+pub const SETSOCKOPT: SyscallNr<Abi> = nr(super::nr::SETSOCKOPT);
+pub const GETSOCKOPT: SyscallNr<Abi> = nr(super::nr::GETSOCKOPT);
+pub const CLONE: SyscallNr<Abi> = nr(super::nr::CLONE);
+pub const FORK: SyscallNr<Abi> = nr(super::nr::FORK);
+pub const VFORK: SyscallNr<Abi> = nr(super::nr::VFORK);
+pub const EXECVE: SyscallNr<Abi> = nr(super::nr::EXECVE);
+pub const EXIT: SyscallNr<Abi> = nr(super::nr::EXIT);
+pub const WAIT4: SyscallNr<Abi> = nr(super::nr::WAIT4);
+pub const KILL: SyscallNr<Abi> = nr(super::nr::KILL);
+pub const UNAME: SyscallNr<Abi> = nr(super::nr::UNAME);
+pub const SEMGET: SyscallNr<Abi> = nr(super::nr::SEMGET);
+pub const SEMOP: SyscallNr<Abi> = nr(super::nr::SEMOP);
+pub const SEMCTL: SyscallNr<Abi> = nr(super::nr::SEMCTL);
+pub const SHMDT: SyscallNr<Abi> = nr(super::nr::SHMDT);
+pub const MSGGET: SyscallNr<Abi> = nr(super::nr::MSGGET);
+pub const MSGSND: SyscallNr<Abi> = nr(super::nr::MSGSND);
+pub const MSGRCV: SyscallNr<Abi> = nr(super::nr::MSGRCV);
+pub const MSGCTL: SyscallNr<Abi> = nr(super::nr::MSGCTL);
+pub const FCNTL: SyscallNr<Abi> = nr(super::nr::FCNTL);
+pub const FLOCK: SyscallNr<Abi> = nr(super::nr::FLOCK);
+pub const FSYNC: SyscallNr<Abi> = nr(super::nr::FSYNC);
+pub const FDATASYNC: SyscallNr<Abi> = nr(super::nr::FDATASYNC);
+pub const TRUNCATE: SyscallNr<Abi> = nr(super::nr::TRUNCATE);
+pub const FTRUNCATE: SyscallNr<Abi> = nr(super::nr::FTRUNCATE);
+pub const GETDENTS: SyscallNr<Abi> = nr(super::nr::GETDENTS);
+pub const GETCWD: SyscallNr<Abi> = nr(super::nr::GETCWD);
+pub const CHDIR: SyscallNr<Abi> = nr(super::nr::CHDIR);
+pub const FCHDIR: SyscallNr<Abi> = nr(super::nr::FCHDIR);
+pub const RENAME: SyscallNr<Abi> = nr(super::nr::RENAME);
+pub const MKDIR: SyscallNr<Abi> = nr(super::nr::MKDIR);
+pub const RMDIR: SyscallNr<Abi> = nr(super::nr::RMDIR);
+pub const CREAT: SyscallNr<Abi> = nr(super::nr::CREAT);
+pub const LINK: SyscallNr<Abi> = nr(super::nr::LINK);
+pub const UNLINK: SyscallNr<Abi> = nr(super::nr::UNLINK);
+pub const SYMLINK: SyscallNr<Abi> = nr(super::nr::SYMLINK);
+pub const READLINK: SyscallNr<Abi> = nr(super::nr::READLINK);
+pub const CHMOD: SyscallNr<Abi> = nr(super::nr::CHMOD);
+pub const FCHMOD: SyscallNr<Abi> = nr(super::nr::FCHMOD);
+pub const CHOWN: SyscallNr<Abi> = nr(super::nr::CHOWN);
+pub const FCHOWN: SyscallNr<Abi> = nr(super::nr::FCHOWN);
+pub const LCHOWN: SyscallNr<Abi> = nr(super::nr::LCHOWN);
+pub const UMASK: SyscallNr<Abi> = nr(super::nr::UMASK);
+pub const GETTIMEOFDAY: SyscallNr<Abi> = nr(super::nr::GETTIMEOFDAY);
+pub const GETRLIMIT: SyscallNr<Abi> = nr(super::nr::GETRLIMIT);
+pub const GETRUSAGE: SyscallNr<Abi> = nr(super::nr::GETRUSAGE);
+pub const SYSINFO: SyscallNr<Abi> = nr(super::nr::SYSINFO);
+pub const TIMES: SyscallNr<Abi> = nr(super::nr::TIMES);
+pub const PTRACE: SyscallNr<Abi> = nr(super::nr::PTRACE);
+pub const GETUID: SyscallNr<Abi> = nr(super::nr::GETUID);
+pub const SYSLOG: SyscallNr<Abi> = nr(super::nr::SYSLOG);
+pub const GETGID: SyscallNr<Abi> = nr(super::nr::GETGID);
+pub const SETUID: SyscallNr<Abi> = nr(super::nr::SETUID);
+pub const SETGID: SyscallNr<Abi> = nr(super::nr::SETGID);
+pub const GETEUID: SyscallNr<Abi> = nr(super::nr::GETEUID);
+pub const GETEGID: SyscallNr<Abi> = nr(super::nr::GETEGID);
+pub const SETPGID: SyscallNr<Abi> = nr(super::nr::SETPGID);
+pub const GETPPID: SyscallNr<Abi> = nr(super::nr::GETPPID);
+pub const GETPGRP: SyscallNr<Abi> = nr(super::nr::GETPGRP);
+pub const SETSID: SyscallNr<Abi> = nr(super::nr::SETSID);
+pub const SETREUID: SyscallNr<Abi> = nr(super::nr::SETREUID);
+pub const SETREGID: SyscallNr<Abi> = nr(super::nr::SETREGID);
+pub const GETGROUPS: SyscallNr<Abi> = nr(super::nr::GETGROUPS);
+pub const SETGROUPS: SyscallNr<Abi> = nr(super::nr::SETGROUPS);
+pub const SETRESUID: SyscallNr<Abi> = nr(super::nr::SETRESUID);
+pub const GETRESUID: SyscallNr<Abi> = nr(super::nr::GETRESUID);
+pub const SETRESGID: SyscallNr<Abi> = nr(super::nr::SETRESGID);
+pub const GETRESGID: SyscallNr<Abi> = nr(super::nr::GETRESGID);
+pub const GETPGID: SyscallNr<Abi> = nr(super::nr::GETPGID);
+pub const SETFSUID: SyscallNr<Abi> = nr(super::nr::SETFSUID);
+pub const SETFSGID: SyscallNr<Abi> = nr(super::nr::SETFSGID);
+pub const GETSID: SyscallNr<Abi> = nr(super::nr::GETSID);
+pub const CAPGET: SyscallNr<Abi> = nr(super::nr::CAPGET);
+pub const CAPSET: SyscallNr<Abi> = nr(super::nr::CAPSET);
+pub const RT_SIGPENDING: SyscallNr<Abi> = nr(super::nr::RT_SIGPENDING);
+pub const RT_SIGTIMEDWAIT: SyscallNr<Abi> = nr(super::nr::RT_SIGTIMEDWAIT);
+pub const RT_SIGQUEUEINFO: SyscallNr<Abi> = nr(super::nr::RT_SIGQUEUEINFO);
+pub const RT_SIGSUSPEND: SyscallNr<Abi> = nr(super::nr::RT_SIGSUSPEND);
+pub const SIGALTSTACK: SyscallNr<Abi> = nr(super::nr::SIGALTSTACK);
+pub const UTIME: SyscallNr<Abi> = nr(super::nr::UTIME);
+pub const MKNOD: SyscallNr<Abi> = nr(super::nr::MKNOD);
+pub const USELIB: SyscallNr<Abi> = nr(super::nr::USELIB);
+pub const PERSONALITY: SyscallNr<Abi> = nr(super::nr::PERSONALITY);
+pub const USTAT: SyscallNr<Abi> = nr(super::nr::USTAT);
+pub const STATFS: SyscallNr<Abi> = nr(super::nr::STATFS);
+pub const FSTATFS: SyscallNr<Abi> = nr(super::nr::FSTATFS);
+pub const SYSFS: SyscallNr<Abi> = nr(super::nr::SYSFS);
+pub const GETPRIORITY: SyscallNr<Abi> = nr(super::nr::GETPRIORITY);
+pub const SETPRIORITY: SyscallNr<Abi> = nr(super::nr::SETPRIORITY);
+pub const SCHED_SETPARAM: SyscallNr<Abi> = nr(super::nr::SCHED_SETPARAM);
+pub const SCHED_GETPARAM: SyscallNr<Abi> = nr(super::nr::SCHED_GETPARAM);
+pub const SCHED_SETSCHEDULER: SyscallNr<Abi> = nr(super::nr::SCHED_SETSCHEDULER);
+pub const SCHED_GETSCHEDULER: SyscallNr<Abi> = nr(super::nr::SCHED_GETSCHEDULER);
+pub const SCHED_GET_PRIORITY_MAX: SyscallNr<Abi> = nr(super::nr::SCHED_GET_PRIORITY_MAX);
+pub const SCHED_GET_PRIORITY_MIN: SyscallNr<Abi> = nr(super::nr::SCHED_GET_PRIORITY_MIN);
+pub const SCHED_RR_GET_INTERVAL: SyscallNr<Abi> = nr(super::nr::SCHED_RR_GET_INTERVAL);
+pub const MLOCK: SyscallNr<Abi> = nr(super::nr::MLOCK);
+pub const MUNLOCK: SyscallNr<Abi> = nr(super::nr::MUNLOCK);
+pub const MLOCKALL: SyscallNr<Abi> = nr(super::nr::MLOCKALL);
+pub const MUNLOCKALL: SyscallNr<Abi> = nr(super::nr::MUNLOCKALL);
+pub const VHANGUP: SyscallNr<Abi> = nr(super::nr::VHANGUP);
+pub const MODIFY_LDT: SyscallNr<Abi> = nr(super::nr::MODIFY_LDT);
+pub const PIVOT_ROOT: SyscallNr<Abi> = nr(super::nr::PIVOT_ROOT);
+pub const _SYSCTL: SyscallNr<Abi> = nr(super::nr::_SYSCTL);
+pub const PRCTL: SyscallNr<Abi> = nr(super::nr::PRCTL);
+pub const ARCH_PRCTL: SyscallNr<Abi> = nr(super::nr::ARCH_PRCTL);
+pub const ADJTIMEX: SyscallNr<Abi> = nr(super::nr::ADJTIMEX);
+pub const SETRLIMIT: SyscallNr<Abi> = nr(super::nr::SETRLIMIT);
+pub const CHROOT: SyscallNr<Abi> = nr(super::nr::CHROOT);
+pub const SYNC: SyscallNr<Abi> = nr(super::nr::SYNC);
+pub const ACCT: SyscallNr<Abi> = nr(super::nr::ACCT);
+pub const SETTIMEOFDAY: SyscallNr<Abi> = nr(super::nr::SETTIMEOFDAY);
+pub const MOUNT: SyscallNr<Abi> = nr(super::nr::MOUNT);
+pub const UMOUNT2: SyscallNr<Abi> = nr(super::nr::UMOUNT2);
+pub const SWAPON: SyscallNr<Abi> = nr(super::nr::SWAPON);
+pub const SWAPOFF: SyscallNr<Abi> = nr(super::nr::SWAPOFF);
+pub const REBOOT: SyscallNr<Abi> = nr(super::nr::REBOOT);
+pub const SETHOSTNAME: SyscallNr<Abi> = nr(super::nr::SETHOSTNAME);
+pub const SETDOMAINNAME: SyscallNr<Abi> = nr(super::nr::SETDOMAINNAME);
+pub const IOPL: SyscallNr<Abi> = nr(super::nr::IOPL);
+pub const IOPERM: SyscallNr<Abi> = nr(super::nr::IOPERM);
+pub const CREATE_MODULE: SyscallNr<Abi> = nr(super::nr::CREATE_MODULE);
+pub const INIT_MODULE: SyscallNr<Abi> = nr(super::nr::INIT_MODULE);
+pub const DELETE_MODULE: SyscallNr<Abi> = nr(super::nr::DELETE_MODULE);
+pub const GET_KERNEL_SYMS: SyscallNr<Abi> = nr(super::nr::GET_KERNEL_SYMS);
+pub const QUERY_MODULE: SyscallNr<Abi> = nr(super::nr::QUERY_MODULE);
+pub const QUOTACTL: SyscallNr<Abi> = nr(super::nr::QUOTACTL);
+pub const NFSSERVCTL: SyscallNr<Abi> = nr(super::nr::NFSSERVCTL);
+pub const GETPMSG: SyscallNr<Abi> = nr(super::nr::GETPMSG);
+pub const PUTPMSG: SyscallNr<Abi> = nr(super::nr::PUTPMSG);
+pub const AFS_SYSCALL: SyscallNr<Abi> = nr(super::nr::AFS_SYSCALL);
+pub const TUXCALL: SyscallNr<Abi> = nr(super::nr::TUXCALL);
+pub const SECURITY: SyscallNr<Abi> = nr(super::nr::SECURITY);
+pub const GETTID: SyscallNr<Abi> = nr(super::nr::GETTID);
+pub const READAHEAD: SyscallNr<Abi> = nr(super::nr::READAHEAD);
+pub const SETXATTR: SyscallNr<Abi> = nr(super::nr::SETXATTR);
+pub const LSETXATTR: SyscallNr<Abi> = nr(super::nr::LSETXATTR);
+pub const FSETXATTR: SyscallNr<Abi> = nr(super::nr::FSETXATTR);
+pub const GETXATTR: SyscallNr<Abi> = nr(super::nr::GETXATTR);
+pub const LGETXATTR: SyscallNr<Abi> = nr(super::nr::LGETXATTR);
+pub const FGETXATTR: SyscallNr<Abi> = nr(super::nr::FGETXATTR);
+pub const LISTXATTR: SyscallNr<Abi> = nr(super::nr::LISTXATTR);
+pub const LLISTXATTR: SyscallNr<Abi> = nr(super::nr::LLISTXATTR);
+pub const FLISTXATTR: SyscallNr<Abi> = nr(super::nr::FLISTXATTR);
+pub const REMOVEXATTR: SyscallNr<Abi> = nr(super::nr::REMOVEXATTR);
+pub const LREMOVEXATTR: SyscallNr<Abi> = nr(super::nr::LREMOVEXATTR);
+pub const FREMOVEXATTR: SyscallNr<Abi> = nr(super::nr::FREMOVEXATTR);
+pub const TKILL: SyscallNr<Abi> = nr(super::nr::TKILL);
+pub const TIME: SyscallNr<Abi> = nr(super::nr::TIME);
+pub const FUTEX: SyscallNr<Abi> = nr(super::nr::FUTEX);
+pub const SCHED_SETAFFINITY: SyscallNr<Abi> = nr(super::nr::SCHED_SETAFFINITY);
+pub const SCHED_GETAFFINITY: SyscallNr<Abi> = nr(super::nr::SCHED_GETAFFINITY);
+pub const SET_THREAD_AREA: SyscallNr<Abi> = nr(super::nr::SET_THREAD_AREA);
+pub const IO_SETUP: SyscallNr<Abi> = nr(super::nr::IO_SETUP);
+pub const IO_DESTROY: SyscallNr<Abi> = nr(super::nr::IO_DESTROY);
+pub const IO_GETEVENTS: SyscallNr<Abi> = nr(super::nr::IO_GETEVENTS);
+pub const IO_SUBMIT: SyscallNr<Abi> = nr(super::nr::IO_SUBMIT);
+pub const IO_CANCEL: SyscallNr<Abi> = nr(super::nr::IO_CANCEL);
+pub const GET_THREAD_AREA: SyscallNr<Abi> = nr(super::nr::GET_THREAD_AREA);
+pub const LOOKUP_DCOOKIE: SyscallNr<Abi> = nr(super::nr::LOOKUP_DCOOKIE);
+pub const EPOLL_CREATE: SyscallNr<Abi> = nr(super::nr::EPOLL_CREATE);
+pub const EPOLL_CTL_OLD: SyscallNr<Abi> = nr(super::nr::EPOLL_CTL_OLD);
+pub const EPOLL_WAIT_OLD: SyscallNr<Abi> = nr(super::nr::EPOLL_WAIT_OLD);
+pub const REMAP_FILE_PAGES: SyscallNr<Abi> = nr(super::nr::REMAP_FILE_PAGES);
+pub const GETDENTS64: SyscallNr<Abi> = nr(super::nr::GETDENTS64);
+pub const SET_TID_ADDRESS: SyscallNr<Abi> = nr(super::nr::SET_TID_ADDRESS);
+pub const RESTART_SYSCALL: SyscallNr<Abi> = nr(super::nr::RESTART_SYSCALL);
+pub const SEMTIMEDOP: SyscallNr<Abi> = nr(super::nr::SEMTIMEDOP);
+pub const FADVISE64: SyscallNr<Abi> = nr(super::nr::FADVISE64);
+pub const TIMER_CREATE: SyscallNr<Abi> = nr(super::nr::TIMER_CREATE);
+pub const TIMER_SETTIME: SyscallNr<Abi> = nr(super::nr::TIMER_SETTIME);
+pub const TIMER_GETTIME: SyscallNr<Abi> = nr(super::nr::TIMER_GETTIME);
+pub const TIMER_GETOVERRUN: SyscallNr<Abi> = nr(super::nr::TIMER_GETOVERRUN);
+pub const TIMER_DELETE: SyscallNr<Abi> = nr(super::nr::TIMER_DELETE);
+pub const CLOCK_SETTIME: SyscallNr<Abi> = nr(super::nr::CLOCK_SETTIME);
+pub const CLOCK_GETTIME: SyscallNr<Abi> = nr(super::nr::CLOCK_GETTIME);
+pub const CLOCK_GETRES: SyscallNr<Abi> = nr(super::nr::CLOCK_GETRES);
+pub const CLOCK_NANOSLEEP: SyscallNr<Abi> = nr(super::nr::CLOCK_NANOSLEEP);
+pub const EXIT_GROUP: SyscallNr<Abi> = nr(super::nr::EXIT_GROUP);
+pub const EPOLL_WAIT: SyscallNr<Abi> = nr(super::nr::EPOLL_WAIT);
+pub const EPOLL_CTL: SyscallNr<Abi> = nr(super::nr::EPOLL_CTL);
+pub const TGKILL: SyscallNr<Abi> = nr(super::nr::TGKILL);
+pub const UTIMES: SyscallNr<Abi> = nr(super::nr::UTIMES);
+pub const VSERVER: SyscallNr<Abi> = nr(super::nr::VSERVER);
+pub const MBIND: SyscallNr<Abi> = nr(super::nr::MBIND);
+pub const SET_MEMPOLICY: SyscallNr<Abi> = nr(super::nr::SET_MEMPOLICY);
+pub const GET_MEMPOLICY: SyscallNr<Abi> = nr(super::nr::GET_MEMPOLICY);
+pub const MQ_OPEN: SyscallNr<Abi> = nr(super::nr::MQ_OPEN);
+pub const MQ_UNLINK: SyscallNr<Abi> = nr(super::nr::MQ_UNLINK);
+pub const MQ_TIMEDSEND: SyscallNr<Abi> = nr(super::nr::MQ_TIMEDSEND);
+pub const MQ_TIMEDRECEIVE: SyscallNr<Abi> = nr(super::nr::MQ_TIMEDRECEIVE);
+pub const MQ_NOTIFY: SyscallNr<Abi> = nr(super::nr::MQ_NOTIFY);
+pub const MQ_GETSETATTR: SyscallNr<Abi> = nr(super::nr::MQ_GETSETATTR);
+pub const KEXEC_LOAD: SyscallNr<Abi> = nr(super::nr::KEXEC_LOAD);
+pub const WAITID: SyscallNr<Abi> = nr(super::nr::WAITID);
+pub const ADD_KEY: SyscallNr<Abi> = nr(super::nr::ADD_KEY);
+pub const REQUEST_KEY: SyscallNr<Abi> = nr(super::nr::REQUEST_KEY);
+pub const KEYCTL: SyscallNr<Abi> = nr(super::nr::KEYCTL);
+pub const IOPRIO_SET: SyscallNr<Abi> = nr(super::nr::IOPRIO_SET);
+pub const IOPRIO_GET: SyscallNr<Abi> = nr(super::nr::IOPRIO_GET);
+pub const INOTIFY_INIT: SyscallNr<Abi> = nr(super::nr::INOTIFY_INIT);
+pub const INOTIFY_ADD_WATCH: SyscallNr<Abi> = nr(super::nr::INOTIFY_ADD_WATCH);
+pub const INOTIFY_RM_WATCH: SyscallNr<Abi> = nr(super::nr::INOTIFY_RM_WATCH);
+pub const MIGRATE_PAGES: SyscallNr<Abi> = nr(super::nr::MIGRATE_PAGES);
+pub const OPENAT: SyscallNr<Abi> = nr(super::nr::OPENAT);
+pub const MKDIRAT: SyscallNr<Abi> = nr(super::nr::MKDIRAT);
+pub const MKNODAT: SyscallNr<Abi> = nr(super::nr::MKNODAT);
+pub const FCHOWNAT: SyscallNr<Abi> = nr(super::nr::FCHOWNAT);
+pub const FUTIMESAT: SyscallNr<Abi> = nr(super::nr::FUTIMESAT);
+pub const NEWFSTATAT: SyscallNr<Abi> = nr(super::nr::NEWFSTATAT);
+pub const UNLINKAT: SyscallNr<Abi> = nr(super::nr::UNLINKAT);
+pub const RENAMEAT: SyscallNr<Abi> = nr(super::nr::RENAMEAT);
+pub const LINKAT: SyscallNr<Abi> = nr(super::nr::LINKAT);
+pub const SYMLINKAT: SyscallNr<Abi> = nr(super::nr::SYMLINKAT);
+pub const READLINKAT: SyscallNr<Abi> = nr(super::nr::READLINKAT);
+pub const FCHMODAT: SyscallNr<Abi> = nr(super::nr::FCHMODAT);
+pub const FACCESSAT: SyscallNr<Abi> = nr(super::nr::FACCESSAT);
+pub const PSELECT6: SyscallNr<Abi> = nr(super::nr::PSELECT6);
+pub const PPOLL: SyscallNr<Abi> = nr(super::nr::PPOLL);
+pub const UNSHARE: SyscallNr<Abi> = nr(super::nr::UNSHARE);
+pub const SET_ROBUST_LIST: SyscallNr<Abi> = nr(super::nr::SET_ROBUST_LIST);
+pub const GET_ROBUST_LIST: SyscallNr<Abi> = nr(super::nr::GET_ROBUST_LIST);
+pub const SPLICE: SyscallNr<Abi> = nr(super::nr::SPLICE);
+pub const TEE: SyscallNr<Abi> = nr(super::nr::TEE);
+pub const SYNC_FILE_RANGE: SyscallNr<Abi> = nr(super::nr::SYNC_FILE_RANGE);
+pub const VMSPLICE: SyscallNr<Abi> = nr(super::nr::VMSPLICE);
+pub const MOVE_PAGES: SyscallNr<Abi> = nr(super::nr::MOVE_PAGES);
+pub const UTIMENSAT: SyscallNr<Abi> = nr(super::nr::UTIMENSAT);
+pub const EPOLL_PWAIT: SyscallNr<Abi> = nr(super::nr::EPOLL_PWAIT);
+pub const SIGNALFD: SyscallNr<Abi> = nr(super::nr::SIGNALFD);
+pub const TIMERFD_CREATE: SyscallNr<Abi> = nr(super::nr::TIMERFD_CREATE);
+pub const EVENTFD: SyscallNr<Abi> = nr(super::nr::EVENTFD);
+pub const FALLOCATE: SyscallNr<Abi> = nr(super::nr::FALLOCATE);
+pub const TIMERFD_SETTIME: SyscallNr<Abi> = nr(super::nr::TIMERFD_SETTIME);
+pub const TIMERFD_GETTIME: SyscallNr<Abi> = nr(super::nr::TIMERFD_GETTIME);
+pub const ACCEPT4: SyscallNr<Abi> = nr(super::nr::ACCEPT4);
+pub const SIGNALFD4: SyscallNr<Abi> = nr(super::nr::SIGNALFD4);
+pub const EVENTFD2: SyscallNr<Abi> = nr(super::nr::EVENTFD2);
+pub const EPOLL_CREATE1: SyscallNr<Abi> = nr(super::nr::EPOLL_CREATE1);
+pub const DUP3: SyscallNr<Abi> = nr(super::nr::DUP3);
+pub const PIPE2: SyscallNr<Abi> = nr(super::nr::PIPE2);
+pub const INOTIFY_INIT1: SyscallNr<Abi> = nr(super::nr::INOTIFY_INIT1);
+pub const PREADV: SyscallNr<Abi> = nr(super::nr::PREADV);
+pub const PWRITEV: SyscallNr<Abi> = nr(super::nr::PWRITEV);
+pub const RT_TGSIGQUEUEINFO: SyscallNr<Abi> = nr(super::nr::RT_TGSIGQUEUEINFO);
+pub const PERF_EVENT_OPEN: SyscallNr<Abi> = nr(super::nr::PERF_EVENT_OPEN);
+pub const RECVMMSG: SyscallNr<Abi> = nr(super::nr::RECVMMSG);
+pub const FANOTIFY_INIT: SyscallNr<Abi> = nr(super::nr::FANOTIFY_INIT);
+pub const FANOTIFY_MARK: SyscallNr<Abi> = nr(super::nr::FANOTIFY_MARK);
+pub const PRLIMIT64: SyscallNr<Abi> = nr(super::nr::PRLIMIT64);
+pub const NAME_TO_HANDLE_AT: SyscallNr<Abi> = nr(super::nr::NAME_TO_HANDLE_AT);
+pub const OPEN_BY_HANDLE_AT: SyscallNr<Abi> = nr(super::nr::OPEN_BY_HANDLE_AT);
+pub const CLOCK_ADJTIME: SyscallNr<Abi> = nr(super::nr::CLOCK_ADJTIME);
+pub const SYNCFS: SyscallNr<Abi> = nr(super::nr::SYNCFS);
+pub const SENDMMSG: SyscallNr<Abi> = nr(super::nr::SENDMMSG);
+pub const SETNS: SyscallNr<Abi> = nr(super::nr::SETNS);
+pub const GETCPU: SyscallNr<Abi> = nr(super::nr::GETCPU);
+pub const PROCESS_VM_READV: SyscallNr<Abi> = nr(super::nr::PROCESS_VM_READV);
+pub const PROCESS_VM_WRITEV: SyscallNr<Abi> = nr(super::nr::PROCESS_VM_WRITEV);
+pub const KCMP: SyscallNr<Abi> = nr(super::nr::KCMP);
+pub const FINIT_MODULE: SyscallNr<Abi> = nr(super::nr::FINIT_MODULE);
+pub const SCHED_SETATTR: SyscallNr<Abi> = nr(super::nr::SCHED_SETATTR);
+pub const SCHED_GETATTR: SyscallNr<Abi> = nr(super::nr::SCHED_GETATTR);
+pub const RENAMEAT2: SyscallNr<Abi> = nr(super::nr::RENAMEAT2);
+pub const SECCOMP: SyscallNr<Abi> = nr(super::nr::SECCOMP);
+pub const GETRANDOM: SyscallNr<Abi> = nr(super::nr::GETRANDOM);
+pub const MEMFD_CREATE: SyscallNr<Abi> = nr(super::nr::MEMFD_CREATE);
+pub const KEXEC_FILE_LOAD: SyscallNr<Abi> = nr(super::nr::KEXEC_FILE_LOAD);
+pub const BPF: SyscallNr<Abi> = nr(super::nr::BPF);
+pub const EXECVEAT: SyscallNr<Abi> = nr(super::nr::EXECVEAT);
+pub const USERFAULTFD: SyscallNr<Abi> = nr(super::nr::USERFAULTFD);
+pub const MEMBARRIER: SyscallNr<Abi> = nr(super::nr::MEMBARRIER);
+pub const MLOCK2: SyscallNr<Abi> = nr(super::nr::MLOCK2);
+pub const COPY_FILE_RANGE: SyscallNr<Abi> = nr(super::nr::COPY_FILE_RANGE);
+pub const PREADV2: SyscallNr<Abi> = nr(super::nr::PREADV2);
+pub const PWRITEV2: SyscallNr<Abi> = nr(super::nr::PWRITEV2);
+pub const PKEY_MPROTECT: SyscallNr<Abi> = nr(super::nr::PKEY_MPROTECT);
+pub const PKEY_ALLOC: SyscallNr<Abi> = nr(super::nr::PKEY_ALLOC);
+pub const PKEY_FREE: SyscallNr<Abi> = nr(super::nr::PKEY_FREE);
+pub const STATX: SyscallNr<Abi> = nr(super::nr::STATX);
+pub const IO_PGETEVENTS: SyscallNr<Abi> = nr(super::nr::IO_PGETEVENTS);
+pub const RSEQ: SyscallNr<Abi> = nr(super::nr::RSEQ);
+pub const PIDFD_SEND_SIGNAL: SyscallNr<Abi> = nr(super::nr::PIDFD_SEND_SIGNAL);
+pub const IO_URING_SETUP: SyscallNr<Abi> = nr(super::nr::IO_URING_SETUP);
+pub const IO_URING_ENTER: SyscallNr<Abi> = nr(super::nr::IO_URING_ENTER);
+pub const IO_URING_REGISTER: SyscallNr<Abi> = nr(super::nr::IO_URING_REGISTER);
+pub const OPEN_TREE: SyscallNr<Abi> = nr(super::nr::OPEN_TREE);
+pub const MOVE_MOUNT: SyscallNr<Abi> = nr(super::nr::MOVE_MOUNT);
+pub const FSOPEN: SyscallNr<Abi> = nr(super::nr::FSOPEN);
+pub const FSCONFIG: SyscallNr<Abi> = nr(super::nr::FSCONFIG);
+pub const FSMOUNT: SyscallNr<Abi> = nr(super::nr::FSMOUNT);
+pub const FSPICK: SyscallNr<Abi> = nr(super::nr::FSPICK);
+pub const PIDFD_OPEN: SyscallNr<Abi> = nr(super::nr::PIDFD_OPEN);
+pub const CLONE3: SyscallNr<Abi> = nr(super::nr::CLONE3);
+pub const CLOSE_RANGE: SyscallNr<Abi> = nr(super::nr::CLOSE_RANGE);
+pub const OPENAT2: SyscallNr<Abi> = nr(super::nr::OPENAT2);
+pub const PIDFD_GETFD: SyscallNr<Abi> = nr(super::nr::PIDFD_GETFD);
+pub const FACCESSAT2: SyscallNr<Abi> = nr(super::nr::FACCESSAT2);
+pub const PROCESS_MADVISE: SyscallNr<Abi> = nr(super::nr::PROCESS_MADVISE);
+pub const EPOLL_PWAIT2: SyscallNr<Abi> = nr(super::nr::EPOLL_PWAIT2);
+pub const MOUNT_SETATTR: SyscallNr<Abi> = nr(super::nr::MOUNT_SETATTR);
+pub const QUOTACTL_FD: SyscallNr<Abi> = nr(super::nr::QUOTACTL_FD);
+pub const LANDLOCK_CREATE_RULESET: SyscallNr<Abi> = nr(super::nr::LANDLOCK_CREATE_RULESET);
+pub const LANDLOCK_ADD_RULE: SyscallNr<Abi> = nr(super::nr::LANDLOCK_ADD_RULE);
+pub const LANDLOCK_RESTRICT_SELF: SyscallNr<Abi> = nr(super::nr::LANDLOCK_RESTRICT_SELF);
+pub const MEMFD_SECRET: SyscallNr<Abi> = nr(super::nr::MEMFD_SECRET);
+pub const PROCESS_MRELEASE: SyscallNr<Abi> = nr(super::nr::PROCESS_MRELEASE);
+pub const FUTEX_WAITV: SyscallNr<Abi> = nr(super::nr::FUTEX_WAITV);
+pub const SET_MEMPOLICY_HOME_NODE: SyscallNr<Abi> = nr(super::nr::SET_MEMPOLICY_HOME_NODE);
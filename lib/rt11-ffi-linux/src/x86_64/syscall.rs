@@ -13,6 +13,22 @@
 //! Always clobbered:
 //!     rcx, r11
 
+/// System Call ABI
+///
+/// This uninhabited marker tags system call numbers of x86_64 (see
+/// `crate::common::SyscallNr`), so they are only accepted by dispatchers of
+/// x86_64.
+pub enum Abi {}
+
+/// Tag System Call Number
+///
+/// Wrap a system call number of `crate::x86_64::nr` as typed number of the
+/// x86_64 ABI. This is a `const fn`, so typed numbers can be defined as
+/// constants.
+pub const fn nr(nr: u64) -> crate::common::SyscallNr<Abi> {
+    crate::common::SyscallNr::from_raw(nr as usize)
+}
+
 /// System Call Invocation
 ///
 /// This is a dummy type to invoke standard system calls on x86 via the
@@ -193,3 +209,8 @@ impl crate::common::Syscall for Syscall {
         r
     }
 }
+
+#[cfg(target_arch = "x86_64")]
+impl crate::common::SyscallAbi for Syscall {
+    type Abi = Abi;
+}
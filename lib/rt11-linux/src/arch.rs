@@ -0,0 +1,274 @@
+//! Architecture Specifics
+//!
+//! This module encapsulates the architecture specifics of this crate that
+//! require assembly. Every architecture specific bit is extracted into a
+//! macro, which is then implemented for each supported architecture. This
+//! follows the structure of `rt11_entrypoint::arch`.
+//!
+//! Every supported architecture is represented by a sub-module, implementing
+//! the same API. The architecture native to the compilation target is also
+//! exposed via the `native` alias.
+//!
+//! Note that all architecture modules are compiled unconditionally, since
+//! they only provide macros expanding to assembly strings.
+
+/// Architecture Support
+///
+/// This module documents the macros required for each architecture support
+/// module. It is a dummy module not available at runtime.
+///
+/// Macros must be exported, hidden, and prefixed with `arch_<id>_`. Each
+/// macro is then aliased via `pub use arch_<id>_<macro> as <macro>` to
+/// export it with a correct path.
+#[cfg(doc)]
+pub mod doc {
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_sigreturn_code {
+        () => { "" }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_doc_sigreturn_nr {
+        () => { 0 }
+    }
+
+    /// Signal Return Code
+    ///
+    /// This macro expands to the inline-assembly that invokes the
+    /// `rt_sigreturn(2)` system call of the architecture. It must not
+    /// touch the stack, since the kernel expects the signal frame at the
+    /// stack-pointer when the signal handler returns.
+    pub use arch_doc_sigreturn_code as sigreturn_code;
+
+    /// Signal Return System Call Number
+    ///
+    /// This macro expands to the system call number of `rt_sigreturn(2)`
+    /// as integer literal. It must match `nr::RT_SIGRETURN` of the
+    /// architecture, but is needed as literal to embed it in assembly.
+    pub use arch_doc_sigreturn_nr as sigreturn_nr;
+}
+
+/// ARM 32-bit Architecture Support
+///
+/// This module implements the required macros for the ARM architecture in
+/// 32-bit mode.
+///
+/// The kernel uses a trampoline of its own signal page if `SA_RESTORER` is
+/// not set, so a restorer is optional.
+pub mod arm {
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_sigreturn_code {
+        () => {
+            core::concat!(
+                "mov r7, #", $crate::arch::arm::sigreturn_nr!(), ";\n",
+                "svc #0;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm_sigreturn_nr {
+        () => { 173 }
+    }
+
+    pub use arch_arm_sigreturn_code as sigreturn_code;
+    pub use arch_arm_sigreturn_nr as sigreturn_nr;
+}
+
+/// ARM 64-bit Architecture Support
+///
+/// This module implements the required macros for the ARM architecture in
+/// 64-bit mode.
+///
+/// The kernel uses the trampoline of the vDSO if `SA_RESTORER` is not set,
+/// so a restorer is not needed. It is supported nevertheless.
+pub mod arm64 {
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_sigreturn_code {
+        () => {
+            core::concat!(
+                "mov x8, #", $crate::arch::arm64::sigreturn_nr!(), ";\n",
+                "svc #0;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_arm64_sigreturn_nr {
+        () => { 139 }
+    }
+
+    pub use arch_arm64_sigreturn_code as sigreturn_code;
+    pub use arch_arm64_sigreturn_nr as sigreturn_nr;
+}
+
+/// RISC-V 64-bit Architecture Support
+///
+/// This module implements the required macros for the RISC-V architecture
+/// with 64-bit addresses.
+///
+/// The kernel always uses the trampoline of the vDSO, and does not support
+/// `SA_RESTORER` at all. The restorer is provided for completeness only,
+/// and cannot be installed.
+pub mod riscv64 {
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_sigreturn_code {
+        () => {
+            core::concat!(
+                "li a7, ", $crate::arch::riscv64::sigreturn_nr!(), ";\n",
+                "ecall;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_riscv64_sigreturn_nr {
+        () => { 139 }
+    }
+
+    pub use arch_riscv64_sigreturn_code as sigreturn_code;
+    pub use arch_riscv64_sigreturn_nr as sigreturn_nr;
+}
+
+/// Intel 32-bit (x86 / i686) Architecture Support
+///
+/// This module implements the required macros for the x86 architecture
+/// with i686 as baseline requirement.
+///
+/// The kernel uses the trampoline of the vDSO if `SA_RESTORER` is not set,
+/// so a restorer is optional. Note that handlers without `SA_SIGINFO` get a
+/// legacy signal frame on x86, which must be returned from via the legacy
+/// `sigreturn(2)` instead. Hence, the restorer must only be used with
+/// `SA_SIGINFO`.
+pub mod x86 {
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_sigreturn_code {
+        () => {
+            core::concat!(
+                "mov eax, ", $crate::arch::x86::sigreturn_nr!(), ";\n",
+                "int 0x80;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_sigreturn_nr {
+        () => { 173 }
+    }
+
+    pub use arch_x86_sigreturn_code as sigreturn_code;
+    pub use arch_x86_sigreturn_nr as sigreturn_nr;
+}
+
+/// Intel 64-bit (x86-64 / amd64) Architecture Support
+///
+/// This module implements the required macros for the x86-64 architecture.
+///
+/// The kernel requires `SA_RESTORER` for every signal handler, since it
+/// provides no trampoline of its own. Hence, a restorer is mandatory.
+pub mod x86_64 {
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_sigreturn_code {
+        () => {
+            core::concat!(
+                "mov eax, ", $crate::arch::x86_64::sigreturn_nr!(), ";\n",
+                "syscall;\n",
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! arch_x86_64_sigreturn_nr {
+        () => { 15 }
+    }
+
+    pub use arch_x86_64_sigreturn_code as sigreturn_code;
+    pub use arch_x86_64_sigreturn_nr as sigreturn_nr;
+}
+
+/// Native Architecture
+///
+/// The architecture native to the compilation target is aliased as
+/// `native`.
+#[cfg(doc)]
+pub use doc as native;
+
+#[cfg(all(not(doc), target_arch = "arm"))]
+pub use arm as native;
+#[cfg(all(not(doc), target_arch = "aarch64"))]
+pub use arm64 as native;
+#[cfg(all(not(doc), target_arch = "riscv64"))]
+pub use riscv64 as native;
+#[cfg(all(not(doc), target_arch = "x86"))]
+pub use x86 as native;
+#[cfg(all(not(doc), target_arch = "x86_64"))]
+pub use x86_64 as native;
+
+#[cfg(test)]
+mod test {
+    // Dummy Trampoline
+    //
+    // Create a signal-return trampoline called `rt11_linux_test_sigreturn`
+    // for use in the tests.
+    core::arch::global_asm!(
+        crate::sigreturn!(".text.rt11_linux_test_sigreturn", "rt11_linux_test_sigreturn"),
+    );
+    extern "C" {
+        fn rt11_linux_test_sigreturn() -> !;
+    }
+
+    // Verify the system call numbers embedded in the assembly match the
+    // generated system call tables.
+    #[test]
+    fn sigreturn_nr() {
+        assert_eq!(rt11_ffi_linux::arm::nr::RT_SIGRETURN, super::arm::sigreturn_nr!());
+        assert_eq!(rt11_ffi_linux::arm64::nr::RT_SIGRETURN, super::arm64::sigreturn_nr!());
+        assert_eq!(rt11_ffi_linux::riscv64::nr::RT_SIGRETURN, super::riscv64::sigreturn_nr!());
+        assert_eq!(rt11_ffi_linux::x86::nr::RT_SIGRETURN, super::x86::sigreturn_nr!());
+        assert_eq!(rt11_ffi_linux::x86_64::nr::RT_SIGRETURN, super::x86_64::sigreturn_nr!());
+    }
+
+    // Install a signal handler with the trampoline as restorer, raise the
+    // signal on the calling thread, and verify execution continues after
+    // the handler returned.
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn sigreturn_check() {
+        static HIT: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+
+        extern "C" fn handler(_sig: u32) {
+            HIT.store(true, core::sync::atomic::Ordering::SeqCst);
+        }
+
+        let sc = crate::syscall::Syscall::new();
+        let sig = rt11_ffi_linux::native::signal::SIGUSR2;
+        let act = rt11_ffi_linux::native::signal::Sigaction {
+            sa_handler: handler as *const () as usize,
+            sa_flags: rt11_ffi_linux::native::signal::SA_RESTORER as usize,
+            sa_restorer: rt11_linux_test_sigreturn as *const () as usize,
+            ..Default::default()
+        };
+        let dfl = rt11_ffi_linux::native::signal::Sigaction::default();
+
+        unsafe {
+            sc.rt_sigaction(sig, &act, core::ptr::null_mut()).unwrap();
+
+            sc.tgkill(std::process::id() as i32, sc.gettid() as i32, sig).unwrap();
+            assert!(HIT.load(core::sync::atomic::Ordering::SeqCst));
+
+            sc.rt_sigaction(sig, &dfl, core::ptr::null_mut()).unwrap();
+        }
+    }
+}
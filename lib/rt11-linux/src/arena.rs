@@ -0,0 +1,186 @@
+//! Memory Arena
+//!
+//! This module provides a bump allocator backed by a single anonymous memory
+//! mapping. It is meant for short-lived data structures of known upper
+//! bounds (e.g., the dependency list of a loader), where no general purpose
+//! allocator is available.
+
+use crate::syscall::{Errno, Syscall};
+use rt11_ffi_linux::native::mman;
+
+/// Memory Arena
+///
+/// An arena maps a fixed region of anonymous memory when created, and hands
+/// out aligned allocations from it in order. Individual allocations are
+/// never freed. Instead, all allocations are released together, either via
+/// `reset()`, which makes the entire region available again, or by dropping
+/// the arena, which unmaps the region.
+///
+/// The arena never grows. Once the region is exhausted, allocations return
+/// `None`.
+///
+/// Values placed in the arena are never dropped. Hence, types with drop
+/// glue leak their resources, unless they are dropped manually.
+pub struct Arena<'sc> {
+    syscall: &'sc Syscall,
+    base: usize,
+    len: usize,
+    pos: core::cell::Cell<usize>,
+}
+
+impl<'sc> Arena<'sc> {
+    /// Create Arena
+    ///
+    /// Map a new region of `len` bytes and create an arena on it. The region
+    /// is mapped lazily by the kernel, so unused parts do not consume
+    /// memory. Errors of `mmap()` are returned as is.
+    pub fn new(syscall: &'sc Syscall, len: usize) -> Result<Self, Errno> {
+        let base = unsafe {
+            syscall.mmap(
+                0,
+                len,
+                mman::PROT_READ | mman::PROT_WRITE,
+                mman::MAP_PRIVATE | mman::MAP_ANONYMOUS | mman::MAP_NORESERVE,
+                -1,
+                0,
+            )?
+        };
+
+        Ok(Self {
+            syscall,
+            base,
+            len,
+            pos: core::cell::Cell::new(0),
+        })
+    }
+
+    /// Query Capacity
+    ///
+    /// Return the size of the region backing the arena, in bytes.
+    pub fn capacity(&self) -> usize {
+        self.len
+    }
+
+    /// Query Usage
+    ///
+    /// Return the number of bytes allocated from the arena, including any
+    /// padding required for alignment.
+    pub fn used(&self) -> usize {
+        self.pos.get()
+    }
+
+    /// Allocate Raw Memory
+    ///
+    /// Allocate `size` bytes aligned to `align` from the arena, and return
+    /// a pointer to them. `align` must be a power of two. If the arena is
+    /// exhausted, `None` is returned.
+    ///
+    /// The memory is valid until the arena is reset or dropped. It is
+    /// zero-filled on first use, but retains its content across `reset()`.
+    pub fn alloc_raw(&self, size: usize, align: usize) -> Option<*mut u8> {
+        let addr = crate::util::align_up(self.base + self.pos.get(), align)?;
+        let end = addr.checked_add(size)?;
+
+        if end > self.base + self.len {
+            return None;
+        }
+
+        self.pos.set(end - self.base);
+        Some(addr as *mut u8)
+    }
+
+    /// Allocate Value
+    ///
+    /// Move `v` into the arena and return a reference to it. If the arena
+    /// is exhausted, `None` is returned (and `v` is dropped).
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, v: T) -> Option<&mut T> {
+        let p = self.alloc_raw(core::mem::size_of::<T>(), core::mem::align_of::<T>())?;
+        let p = p as *mut T;
+
+        // The memory is suitably sized and aligned, and is not handed out
+        // again before `reset()`. The latter requires a mutable borrow of
+        // the arena, and thus cannot be called while this reference lives.
+        unsafe {
+            p.write(v);
+            Some(&mut *p)
+        }
+    }
+
+    /// Allocate Slice
+    ///
+    /// Allocate a slice of `n` elements in the arena, each initialized to
+    /// `v`, and return a reference to it. If the arena is exhausted, or the
+    /// size of the slice is not representable, `None` is returned.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice<T: Copy>(&self, n: usize, v: T) -> Option<&mut [T]> {
+        let size = core::mem::size_of::<T>().checked_mul(n)?;
+        let p = self.alloc_raw(size, core::mem::align_of::<T>())?;
+        let p = p as *mut T;
+
+        // See `alloc()` for the lifetime of the reference.
+        unsafe {
+            for i in 0..n {
+                p.add(i).write(v);
+            }
+            Some(core::slice::from_raw_parts_mut(p, n))
+        }
+    }
+
+    /// Reset Arena
+    ///
+    /// Release all allocations of the arena, making the entire region
+    /// available again. The memory is not cleared, nor returned to the
+    /// kernel.
+    pub fn reset(&mut self) {
+        self.pos.set(0);
+    }
+}
+
+impl<'sc> Drop for Arena<'sc> {
+    fn drop(&mut self) {
+        // All allocations borrow the arena, so none can outlive it.
+        unsafe {
+            let _ = self.syscall.munmap(self.base, self.len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Allocate values and slices from an arena, verify their alignment and
+    // the exhaustion handling, and verify the arena can be reused after a
+    // reset.
+    #[test]
+    fn arena_alloc() {
+        let this = unsafe { crate::this::This::new() };
+        let mut arena = Arena::new(&this.syscall, 4096).unwrap();
+
+        assert_eq!(arena.capacity(), 4096);
+        assert_eq!(arena.used(), 0);
+
+        let a = arena.alloc(0x71u8).unwrap();
+        let b = arena.alloc(0x1234u64).unwrap();
+        assert_eq!(*a, 0x71);
+        assert_eq!(*b, 0x1234);
+        assert_eq!(b as *mut u64 as usize % core::mem::align_of::<u64>(), 0);
+        assert_eq!(arena.used(), core::mem::align_of::<u64>() + 8);
+
+        let s = arena.alloc_slice(500, 7u64).unwrap();
+        assert_eq!(s.len(), 500);
+        assert!(s.iter().all(|v| *v == 7));
+        s[499] = 0;
+
+        assert!(arena.alloc_slice(12, 0u64).is_none());
+        assert!(arena.alloc_slice(usize::MAX, 0u64).is_none());
+        assert!(arena.alloc_raw(1, 1).is_some());
+        assert!(arena.alloc_raw(4096, 1).is_none());
+
+        arena.reset();
+        assert_eq!(arena.used(), 0);
+        assert!(arena.alloc_slice(512, 0u64).is_some());
+        assert!(arena.alloc(0u8).is_none());
+    }
+}
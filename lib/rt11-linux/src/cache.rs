@@ -0,0 +1,178 @@
+//! Cache Maintenance
+//!
+//! Some architectures do not keep their instruction caches coherent with
+//! their data caches. Code that was written to memory (e.g., by applying
+//! relocations to it, or by copying it) is not guaranteed to be visible to
+//! instruction fetches on those architectures, unless the caches are
+//! synchronized explicitly. Omitting this usually works, but eventually
+//! executes stale instructions, which causes crashes that are almost
+//! impossible to reproduce.
+//!
+//! The required sequence depends on the architecture:
+//!
+//!  * **ARM 32-bit**: Cache maintenance is privileged, so the kernel
+//!    performs it via the ARM-private `cacheflush(2)` system call.
+//!
+//!  * **ARM 64-bit**: User-space performs the maintenance directly, like
+//!    `__clear_cache()` of the compiler runtime: Every data cache line of the
+//!    range is cleaned to the point of unification (`dc cvau`), and every
+//!    instruction cache line is invalidated (`ic ivau`), each followed by a
+//!    barrier (`dsb ish`). A final `isb` discards instructions fetched
+//!    before. The cache-line sizes are read from `ctr_el0`, which also tells
+//!    whether either step can be skipped on coherent implementations.
+//!
+//!  * **RISC-V 64-bit**: `fence.i` only synchronizes the executing hart, and
+//!    the thread might be migrated to another hart right after. Hence, the
+//!    kernel performs it on all harts via `riscv_flush_icache(2)`.
+//!
+//!  * **x86 / x86-64**: The caches are coherent, so nothing is required.
+
+/// Synchronize Instruction Cache
+///
+/// Make sure instructions written to the range of `len` bytes at `addr` are
+/// visible to instruction fetches of all threads of the calling process.
+/// This must be called after modifying code and before executing it. See
+/// the module documentation for the sequence used on each architecture.
+///
+/// Safety
+/// ------
+///
+/// The range must be mapped readable, since the maintenance operations
+/// fault on unmapped addresses on some architectures.
+pub unsafe fn sync_icache(addr: usize, len: usize) {
+    let end = addr.saturating_add(len);
+
+    if end == addr {
+        return;
+    }
+
+    #[cfg(target_arch = "arm")]
+    {
+        // `__ARM_NR_cacheflush` is in the ARM-private range, which is not
+        // part of the generated system call tables. The trailing flags
+        // argument must be 0. The call only fails for unmapped ranges.
+        const ARM_NR_CACHEFLUSH: usize = 0x0f0002;
+
+        let _ = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &rt11_ffi_linux::native::syscall::Syscall {},
+                ARM_NR_CACHEFLUSH,
+                addr,
+                end,
+                0,
+            )
+        };
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        let ctr: usize;
+
+        unsafe {
+            core::arch::asm!(
+                "mrs {}, ctr_el0",
+                out(reg) ctr,
+                options(nomem, nostack, preserves_flags),
+            );
+        }
+
+        // `CTR_EL0.IDC` (bit 28) tells that cleaning the data cache is not
+        // required, `CTR_EL0.DIC` (bit 29) that invalidating the
+        // instruction cache is not required. The line sizes are given as
+        // log2 of the number of 4-byte words.
+        let dline = 4usize << ((ctr >> 16) & 0xf);
+        let iline = 4usize << (ctr & 0xf);
+
+        if ctr & (1 << 28) == 0 {
+            let mut v = addr & !(dline - 1);
+            while v < end {
+                unsafe {
+                    core::arch::asm!("dc cvau, {}", in(reg) v, options(nostack, preserves_flags));
+                }
+                v += dline;
+            }
+        }
+        unsafe {
+            core::arch::asm!("dsb ish", options(nostack, preserves_flags));
+        }
+
+        if ctr & (1 << 29) == 0 {
+            let mut v = addr & !(iline - 1);
+            while v < end {
+                unsafe {
+                    core::arch::asm!("ic ivau, {}", in(reg) v, options(nostack, preserves_flags));
+                }
+                v += iline;
+            }
+            unsafe {
+                core::arch::asm!("dsb ish", options(nostack, preserves_flags));
+            }
+        }
+        unsafe {
+            core::arch::asm!("isb", options(nostack, preserves_flags));
+        }
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        // `riscv_flush_icache(2)` is the 16th architecture specific system
+        // call. Flags of 0 synchronize all threads of the process, rather
+        // than just the calling one. The range is currently ignored by the
+        // kernel, which synchronizes the entire cache.
+        let nr = rt11_ffi_linux::native::nr::ARCH_SPECIFIC_SYSCALL as usize + 15;
+
+        let _ = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &rt11_ffi_linux::native::syscall::Syscall {},
+                nr,
+                addr,
+                end,
+                0,
+            )
+        };
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        let _ = end;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Write a function returning a constant into a fresh mapping, and verify
+    // it executes correctly once the caches are synchronized. This is only
+    // implemented for architectures where a fixed instruction sequence is
+    // easy to embed.
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[test]
+    fn icache_sync() {
+        use rt11_ffi_linux::native::mman;
+
+        #[cfg(target_arch = "aarch64")]
+        let code: [u32; 2] = [0xd2800e20, 0xd65f03c0]; // mov x0, #0x71; ret
+        #[cfg(target_arch = "x86_64")]
+        let code: [u8; 6] = [0xb8, 0x71, 0x00, 0x00, 0x00, 0xc3]; // mov eax, 0x71; ret
+
+        let sc = crate::syscall::Syscall::new();
+        let len = core::mem::size_of_val(&code);
+
+        unsafe {
+            let prot = mman::PROT_READ | mman::PROT_WRITE;
+            let flags = mman::MAP_PRIVATE | mman::MAP_ANONYMOUS;
+            let addr = sc.mmap(0, 4096, prot, flags, -1, 0).unwrap();
+
+            core::ptr::copy_nonoverlapping(code.as_ptr().cast::<u8>(), addr as *mut u8, len);
+            sync_icache(addr, len);
+            sc.mprotect(addr, 4096, mman::PROT_READ | mman::PROT_EXEC).unwrap();
+
+            let f: extern "C" fn() -> u32 = core::mem::transmute(addr);
+            assert_eq!(f(), 0x71);
+
+            sync_icache(addr, 0);
+            sc.munmap(addr, 4096).unwrap();
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! CPU Identification
+//!
+//! On x86, the kernel reports CPU features via `AT_HWCAP` and `AT_HWCAP2`,
+//! but those only carry a small, legacy subset of the flags. The `cpuid`
+//! instruction, on the other hand, is available to user-space on every
+//! supported x86 CPU and reports all of them. This module provides access
+//! to it, and decodes the flags commonly used to select implementations at
+//! runtime (e.g., by IFUNC resolvers).
+//!
+//! `cpuid` writes `ebx` (or `rbx`), which needs special treatment: LLVM
+//! reserves `rbx` on x86-64 for its own use (e.g., as base pointer of
+//! functions with dynamic stack realignment), so inline-asm cannot name it
+//! as operand. Hence, it is saved to a scratch register picked by the
+//! compiler before `cpuid`, and swapped back afterwards, similar to how the
+//! x86 system call code deals with `esi`. On x86, `ebx` is not reserved,
+//! and is used as operand directly.
+//!
+//! Note that CPU support of a feature is not sufficient for features that
+//! extend the register file (e.g., AVX or AVX-512). The kernel must also
+//! save and restore the new registers on context switches, which it
+//! announces via `XCR0` (read via `xgetbv`). `CpuFeatures` takes this into
+//! account.
+
+/// Query CPU Identification
+///
+/// Execute `cpuid` with `leaf` in `eax` and `subleaf` in `ecx`, and return
+/// the resulting `(eax, ebx, ecx, edx)`. Leaves beyond the maximum leaf of
+/// the CPU (i.e., `eax` of leaf 0 for basic leaves) return unspecified
+/// data, usually that of the highest basic leaf. `subleaf` is ignored by
+/// leaves without sub-leaves.
+pub fn cpuid(leaf: u32, subleaf: u32) -> (u32, u32, u32, u32) {
+    let (eax, ebx, ecx, edx): (u32, u32, u32, u32);
+
+    // `cpuid` is available on all supported CPUs, and only writes the four
+    // registers.
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        let rbx: u64;
+
+        core::arch::asm!(
+            "mov {rbx}, rbx",
+            "cpuid",
+            "xchg {rbx}, rbx",
+            rbx = out(reg) rbx,
+            inlateout("eax") leaf => eax,
+            inlateout("ecx") subleaf => ecx,
+            out("edx") edx,
+            options(nomem, nostack, preserves_flags),
+        );
+
+        ebx = rbx as u32;
+    }
+
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        core::arch::asm!(
+            "cpuid",
+            inlateout("eax") leaf => eax,
+            out("ebx") ebx,
+            inlateout("ecx") subleaf => ecx,
+            out("edx") edx,
+            options(nomem, nostack, preserves_flags),
+        );
+    }
+
+    (eax, ebx, ecx, edx)
+}
+
+// Read the extended control register 0 via `xgetbv`. This must only be
+// used if `OSXSAVE` is reported by `cpuid`, since it faults otherwise.
+fn xgetbv0() -> u64 {
+    let (eax, edx): (u32, u32);
+
+    unsafe {
+        core::arch::asm!(
+            "xgetbv",
+            in("ecx") 0u32,
+            out("eax") eax,
+            out("edx") edx,
+            options(nomem, nostack, preserves_flags),
+        );
+    }
+
+    ((edx as u64) << 32) | eax as u64
+}
+
+/// CPU Features
+///
+/// This describes the availability of commonly used CPU features, as
+/// decoded from `cpuid` leaf 1 and leaf 7 (sub-leaf 0). Features extending
+/// the register file are only reported if the kernel enabled their state
+/// in `XCR0`. See the module documentation for details.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CpuFeatures {
+    /// SSE4.2 (including `crc32` and `pcmpistri`).
+    pub sse4_2: bool,
+    /// AVX (256-bit floating-point vectors).
+    pub avx: bool,
+    /// AVX2 (256-bit integer vectors).
+    pub avx2: bool,
+    /// AVX-512 Foundation (512-bit vectors and mask registers).
+    pub avx512f: bool,
+    /// Bit Manipulation Instruction Set 1 (e.g., `andn`, `tzcnt`).
+    pub bmi1: bool,
+    /// Bit Manipulation Instruction Set 2 (e.g., `pdep`, `shlx`).
+    pub bmi2: bool,
+    /// Hardware random number generator (i.e., `rdrand`).
+    pub rdrand: bool,
+}
+
+impl CpuFeatures {
+    // Leaf 1, `ecx`
+    const L1_ECX_SSE4_2: u32 = 1 << 20;
+    const L1_ECX_OSXSAVE: u32 = 1 << 27;
+    const L1_ECX_AVX: u32 = 1 << 28;
+    const L1_ECX_RDRAND: u32 = 1 << 30;
+
+    // Leaf 7, sub-leaf 0, `ebx`
+    const L7_EBX_BMI1: u32 = 1 << 3;
+    const L7_EBX_AVX2: u32 = 1 << 5;
+    const L7_EBX_BMI2: u32 = 1 << 8;
+    const L7_EBX_AVX512F: u32 = 1 << 16;
+
+    // `XCR0`: SSE and AVX state, and opmask, upper ZMM, and high ZMM state
+    const XCR0_AVX: u64 = 0x6;
+    const XCR0_AVX512: u64 = 0xe0;
+
+    /// Decode CPU Features
+    ///
+    /// Decode the features from the results of `cpuid` leaf 1 (`leaf1`)
+    /// and leaf 7, sub-leaf 0 (`leaf7`), each given as `(eax, ebx, ecx,
+    /// edx)`, and the value of `XCR0` (`xcr0`). `leaf7` must be zeroed if
+    /// the CPU does not support leaf 7, and `xcr0` must be 0 if `OSXSAVE` is
+    /// not reported.
+    pub fn decode(leaf1: (u32, u32, u32, u32), leaf7: (u32, u32, u32, u32), xcr0: u64) -> Self {
+        let (_, _, ecx1, _) = leaf1;
+        let (_, ebx7, _, _) = leaf7;
+        let avx_os = ecx1 & Self::L1_ECX_OSXSAVE != 0 && xcr0 & Self::XCR0_AVX == Self::XCR0_AVX;
+        let avx512_os = avx_os && xcr0 & Self::XCR0_AVX512 == Self::XCR0_AVX512;
+
+        Self {
+            sse4_2: ecx1 & Self::L1_ECX_SSE4_2 != 0,
+            avx: avx_os && ecx1 & Self::L1_ECX_AVX != 0,
+            avx2: avx_os && ebx7 & Self::L7_EBX_AVX2 != 0,
+            avx512f: avx512_os && ebx7 & Self::L7_EBX_AVX512F != 0,
+            bmi1: ebx7 & Self::L7_EBX_BMI1 != 0,
+            bmi2: ebx7 & Self::L7_EBX_BMI2 != 0,
+            rdrand: ecx1 & Self::L1_ECX_RDRAND != 0,
+        }
+    }
+
+    /// Detect CPU Features
+    ///
+    /// Query the features of the executing CPU via `cpuid` and `xgetbv`,
+    /// and decode them via `decode()`. All CPUs of a system are assumed to
+    /// report the same features.
+    pub fn detect() -> Self {
+        let (max, _, _, _) = cpuid(0, 0);
+        let leaf1 = cpuid(1, 0);
+        let leaf7 = if max >= 7 { cpuid(7, 0) } else { (0, 0, 0, 0) };
+        let xcr0 = if leaf1.2 & Self::L1_ECX_OSXSAVE != 0 { xgetbv0() } else { 0 };
+
+        Self::decode(leaf1, leaf7, xcr0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the vendor string is sane, and the detected features match the
+    // detection of the standard library.
+    #[test]
+    fn cpuid_check() {
+        let (_, ebx, ecx, edx) = cpuid(0, 0);
+        let mut vendor = [0u8; 12];
+        vendor[0..4].copy_from_slice(&ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&edx.to_le_bytes());
+        vendor[8..12].copy_from_slice(&ecx.to_le_bytes());
+        assert!(vendor.iter().all(|v| v.is_ascii_graphic() || *v == b' '));
+
+        let v = CpuFeatures::detect();
+        assert_eq!(v.sse4_2, std::is_x86_feature_detected!("sse4.2"));
+        assert_eq!(v.avx, std::is_x86_feature_detected!("avx"));
+        assert_eq!(v.avx2, std::is_x86_feature_detected!("avx2"));
+        assert_eq!(v.avx512f, std::is_x86_feature_detected!("avx512f"));
+        assert_eq!(v.bmi1, std::is_x86_feature_detected!("bmi1"));
+        assert_eq!(v.bmi2, std::is_x86_feature_detected!("bmi2"));
+        assert_eq!(v.rdrand, std::is_x86_feature_detected!("rdrand"));
+    }
+
+    // Verify register-file extensions are only reported with kernel
+    // support.
+    #[test]
+    fn feature_decoding() {
+        let leaf1 = (0, 0, u32::MAX, 0);
+        let leaf7 = (0, u32::MAX, 0, 0);
+
+        let v = CpuFeatures::decode(leaf1, leaf7, 0xe7);
+        assert!(v.sse4_2 && v.avx && v.avx2 && v.avx512f && v.bmi1 && v.bmi2 && v.rdrand);
+
+        let v = CpuFeatures::decode(leaf1, leaf7, 0x7);
+        assert!(v.avx && v.avx2 && !v.avx512f);
+
+        let v = CpuFeatures::decode(leaf1, leaf7, 0x3);
+        assert!(v.sse4_2 && !v.avx && !v.avx2 && !v.avx512f && v.bmi1);
+
+        let v = CpuFeatures::decode((0, 0, !CpuFeatures::L1_ECX_OSXSAVE, 0), leaf7, 0xe7);
+        assert!(!v.avx && !v.avx2 && !v.avx512f);
+
+        assert_eq!(CpuFeatures::decode((0, 0, 0, 0), (0, 0, 0, 0), 0), CpuFeatures::default());
+    }
+}
@@ -0,0 +1,122 @@
+//! Program Execution
+//!
+//! `execve(2)` takes the argument and environment vectors as NULL-terminated
+//! arrays of pointers to NUL-terminated strings. This module provides a
+//! builder for such arrays that does not allocate, so a launcher can
+//! assemble a modified environment right before executing a program.
+
+use crate::syscall::Errno;
+use rt11_ffi_linux::native::errno;
+
+/// Execution Argument Vector
+///
+/// A fixed-capacity vector of up to `N` string pointers, which is always
+/// followed by a NULL pointer. `as_ptr()` yields the array as expected by
+/// `execve()` for both `argv` and `envp`.
+///
+/// The strings are borrowed for `'a`, so they outlive the vector. Only the
+/// pointers are stored, the strings are never copied.
+#[repr(C)]
+pub struct ExecArgs<'a, const N: usize> {
+    // The terminator directly follows the entries, since both have the
+    // same type and `repr(C)` inserts no padding between them. All unused
+    // entries are kept NULL, so the array is terminated at any length.
+    ptrs: [*const u8; N],
+    end: *const u8,
+    len: usize,
+    _strings: core::marker::PhantomData<&'a [u8]>,
+}
+
+impl<'a, const N: usize> ExecArgs<'a, N> {
+    /// Create Vector
+    ///
+    /// Create an empty vector, which consists of just the terminator.
+    pub fn new() -> Self {
+        Self {
+            ptrs: [core::ptr::null(); N],
+            end: core::ptr::null(),
+            len: 0,
+            _strings: core::marker::PhantomData,
+        }
+    }
+
+    /// Query Length
+    ///
+    /// Return the number of strings in the vector, excluding the
+    /// terminator.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check for Emptiness
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append String
+    ///
+    /// Append the string `s` to the vector. `s` must include its NUL
+    /// terminator, otherwise `EINVAL` is returned. If the vector is full,
+    /// `E2BIG` is returned (matching the error of the kernel for overlong
+    /// vectors). The vector is left unchanged on error.
+    pub fn push(&mut self, s: &'a [u8]) -> Result<(), Errno> {
+        if s.last() != Some(&0) {
+            return Err(errno::EINVAL);
+        }
+
+        let slot = self.ptrs.get_mut(self.len).ok_or(errno::E2BIG)?;
+        *slot = s.as_ptr();
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Access Pointer Array
+    ///
+    /// Return a pointer to the NULL-terminated array of string pointers,
+    /// suitable for `argv` or `envp` of `execve()`. It is valid as long as
+    /// the vector is neither moved nor modified.
+    pub fn as_ptr(&self) -> *const *const u8 {
+        // Derive the pointer from the entire structure, so it covers the
+        // terminator as well.
+        self as *const Self as *const *const u8
+    }
+}
+
+impl<'a, const N: usize> Default for ExecArgs<'a, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Fill a vector to its capacity, and verify the array is terminated at
+    // any length and invalid strings are rejected.
+    #[test]
+    fn exec_args() {
+        let mut v = ExecArgs::<2>::new();
+        let array = |v: &ExecArgs<2>| {
+            unsafe { core::slice::from_raw_parts(v.as_ptr(), 3) }.to_vec()
+        };
+
+        assert!(v.is_empty());
+        assert!(array(&v).iter().all(|p| p.is_null()));
+
+        let foo = b"foo\0";
+        let bar = b"bar\0";
+        v.push(foo).unwrap();
+        assert_eq!(array(&v), [foo.as_ptr(), core::ptr::null(), core::ptr::null()]);
+        assert_eq!(v.push(b"bar"), Err(errno::EINVAL));
+        assert_eq!(v.push(b""), Err(errno::EINVAL));
+        v.push(bar).unwrap();
+        assert_eq!(v.push(foo), Err(errno::E2BIG));
+
+        assert_eq!(v.len(), 2);
+        assert_eq!(array(&v), [foo.as_ptr(), bar.as_ptr(), core::ptr::null()]);
+
+        let empty = ExecArgs::<0>::default();
+        assert!(unsafe { *empty.as_ptr() }.is_null());
+    }
+}
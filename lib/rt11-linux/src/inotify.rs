@@ -0,0 +1,112 @@
+//! File-system Event Notification
+//!
+//! This module provides helpers to interpret the data read from an inotify
+//! file-descriptor. See `Syscall::inotify_init1()` to create such a
+//! file-descriptor.
+
+use rt11_ffi_linux::native::inotify::InotifyEvent;
+
+/// Inotify Event
+///
+/// A single event read from an inotify file-descriptor. `name` is the name
+/// of the affected directory entry without any NUL-padding, or empty if the
+/// event refers to the watched object itself.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Event<'a> {
+    pub wd: i32,
+    pub mask: u32,
+    pub cookie: u32,
+    pub name: &'a [u8],
+}
+
+/// Inotify Event Iterator
+///
+/// Iterate the events in a buffer filled by a `read()` on an inotify
+/// file-descriptor. Each event consists of an `InotifyEvent` header, followed
+/// by `len` bytes of NUL-padded name. The kernel only ever returns complete
+/// events, so the iterator simply ends if the buffer is truncated.
+#[derive(Clone, Debug)]
+pub struct EventIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> EventIter<'a> {
+    /// Create Event Iterator
+    ///
+    /// Create a new iterator over the events in `buf`, which must be the
+    /// data returned by a `read()` on an inotify file-descriptor.
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for EventIter<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let hlen = core::mem::size_of::<InotifyEvent>();
+        if self.buf.len() < hlen {
+            return None;
+        }
+
+        // The buffer carries no alignment guarantees, so read the header
+        // unaligned.
+        let hdr = unsafe {
+            core::ptr::read_unaligned(self.buf.as_ptr() as *const InotifyEvent)
+        };
+
+        let Some(name) = self.buf[hlen..].get(..hdr.len as usize) else {
+            self.buf = &[];
+            return None;
+        };
+        let nlen = name.iter().position(|v| *v == 0).unwrap_or(name.len());
+
+        self.buf = &self.buf[hlen + hdr.len as usize..];
+        Some(Event {
+            wd: hdr.wd,
+            mask: hdr.mask,
+            cookie: hdr.cookie,
+            name: &name[..nlen],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn push(buf: &mut std::vec::Vec<u8>, wd: i32, mask: u32, name: &[u8], len: u32) {
+        buf.extend_from_slice(&wd.to_ne_bytes());
+        buf.extend_from_slice(&mask.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&len.to_ne_bytes());
+        buf.extend_from_slice(name);
+        buf.resize(buf.len() + len as usize - name.len(), 0);
+    }
+
+    // Verify that events with and without names are parsed, and that the
+    // NUL-padding of the names is stripped.
+    #[test]
+    fn event_iter() {
+        let mut buf = std::vec::Vec::new();
+
+        push(&mut buf, 1, 2, b"", 0);
+        push(&mut buf, 3, 4, b"foobar", 16);
+        push(&mut buf, 5, 6, b"", 0);
+
+        let mut it = EventIter::new(&buf);
+        let e = it.next().unwrap();
+        assert_eq!((e.wd, e.mask, e.name), (1, 2, &b""[..]));
+        let e = it.next().unwrap();
+        assert_eq!((e.wd, e.mask, e.name), (3, 4, &b"foobar"[..]));
+        let e = it.next().unwrap();
+        assert_eq!((e.wd, e.mask, e.name), (5, 6, &b""[..]));
+        assert!(it.next().is_none());
+
+        // A truncated name ends the iteration.
+        let mut it = EventIter::new(&buf[..40]);
+        assert!(it.next().is_some());
+        assert!(it.next().is_none());
+        assert!(it.next().is_none());
+    }
+}
@@ -8,5 +8,81 @@
 #[cfg(test)]
 extern crate std;
 
+pub mod arch;
+pub mod arena;
+pub mod cache;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod cpuid;
+pub mod exec;
+pub mod inotify;
+pub mod mapping;
+pub mod sched;
 pub mod syscall;
 pub mod this;
+pub mod time;
+pub mod trace;
+pub mod util;
+pub mod wait;
+
+/// Signal-return Trampoline
+///
+/// This macro expands to the assembly of a signal-return trampoline. It
+/// creates a single global symbol with the given name in the specified
+/// section, which invokes `rt_sigreturn(2)`. When a signal handler returns,
+/// it returns into this trampoline, which then asks the kernel to restore
+/// the context of the interrupted code from the signal frame on the stack.
+///
+/// The address of the symbol is meant to be passed as `sa_restorer` to
+/// `rt_sigaction(2)`, together with `SA_RESTORER`. Whether a restorer is
+/// required differs across architectures: x86-64 requires it for every
+/// handler, x86 and ARM fall back to a trampoline of the kernel, and
+/// RISC-V does not support it at all. See the architecture modules in
+/// `arch` for details.
+///
+/// Note that the trampoline carries no unwind information. Unwinders thus
+/// cannot unwind across signal frames.
+///
+/// In rust, the signature of the trampoline is:
+///
+/// `extern "C" fn restorer() -> !`
+///
+/// However, it must never be called directly.
+#[macro_export]
+macro_rules! sigreturn {
+    ($section:expr, $symbol:expr $(,)?) => {
+        core::concat!(
+            ".pushsection .", $section, ", \"ax\";\n",
+            ".balign 4;\n",
+            ".globl ", $symbol, ";\n",
+            ".type ", $symbol, ", STT_FUNC;\n",
+            $symbol, ":\n",
+            $crate::arch::native::sigreturn_code!(),
+            ".size ", $symbol, ", . - ", $symbol, ";\n",
+            ".popsection;\n",
+        )
+    };
+}
+
+// ABI Consistency
+//
+// `rt11_ffi_elf::elfn` is selected based on the pointer width of the target,
+// while `rt11_ffi_linux::native` is selected based on the target
+// architecture. On exotic targets (e.g., the x32 ABI) these can disagree,
+// and any code combining both would silently use mismatching definitions.
+// Hence, verify at compile-time that both agree.
+const _: () = {
+    let class = match core::mem::size_of::<rt11_ffi_elf::elfn::Size>() {
+        4 => rt11_ffi_elf::elf::Ident::ELFCLASS32,
+        8 => rt11_ffi_elf::elf::Ident::ELFCLASS64,
+        _ => panic!("Unsupported ELF class."),
+    };
+
+    assert!(
+        core::mem::size_of::<rt11_ffi_elf::elfn::Size>() == core::mem::size_of::<usize>(),
+        "Native ELF class does not match the pointer width.",
+    );
+    assert!(
+        class == rt11_ffi_linux::arch::Arch::native().elf_class(),
+        "Native ELF class does not match the native kernel architecture.",
+    );
+};
@@ -0,0 +1,183 @@
+//! Owned Memory Mappings
+//!
+//! This module provides an owner for memory mappings, which unmaps them
+//! when dropped. It is meant for mappings with a clear owner (e.g., files
+//! mapped by a loader to inspect them), so error paths cannot leak them.
+
+use crate::syscall::{Errno, Syscall};
+use rt11_ffi_linux::native::mman;
+
+/// Memory Mapping
+///
+/// This owns a memory mapping of `len` bytes at `ptr`, and unmaps it when
+/// dropped. Unlike `arena::Arena`, it does not borrow a system call
+/// context, but uses the context-free dispatcher (i.e., the fallback
+/// mechanism of the platform) to unmap, so it is not bound to the lifetime
+/// of a context.
+///
+/// Mappings that must outlive their owner are released via `leak()`. In
+/// particular, the final image of a program loaded for execution must be
+/// leaked before jumping to its entry-point, since that jump never returns,
+/// and any owner dropped before it would unmap the program.
+#[derive(Debug)]
+pub struct Mapping {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl Mapping {
+    /// Map Anonymous Memory
+    ///
+    /// Create a new private anonymous mapping of `len` bytes with the
+    /// protection `prot` (i.e., `PROT_*`). The memory is zero-filled.
+    /// Errors of `mmap()` are returned as is.
+    pub fn anonymous(syscall: &Syscall, len: usize, prot: u32) -> Result<Self, Errno> {
+        let ptr = unsafe {
+            syscall.mmap(0, len, prot, mman::MAP_PRIVATE | mman::MAP_ANONYMOUS, -1, 0)?
+        };
+
+        Ok(Self { ptr: ptr as *mut u8, len })
+    }
+
+    /// Adopt Mapping
+    ///
+    /// Take ownership of the existing mapping of `len` bytes at `ptr`, for
+    /// instance as returned by `Syscall::mmap()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The range must be mapped, and must not be owned by anything else,
+    /// since it is unmapped once the returned object is dropped.
+    pub unsafe fn from_raw(ptr: *mut u8, len: usize) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Query Length
+    ///
+    /// Return the length of the mapping in bytes, as requested when it was
+    /// created (i.e., not rounded up to the page size).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check for Empty Mapping
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Access Start Address
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr
+    }
+
+    /// Access as Slice
+    ///
+    /// Return the content of the mapping as byte slice.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The mapping must be readable, and must not be modified while the
+    /// slice is borrowed (e.g., via a shared mapping of the same file, or
+    /// by another process).
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /// Access as Mutable Slice
+    ///
+    /// Return the content of the mapping as mutable byte slice.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The mapping must be readable and writable, and must not be accessed
+    /// by other means while the slice is borrowed. See `as_slice()`.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    /// Leak Mapping
+    ///
+    /// Release ownership of the mapping without unmapping it, and return
+    /// its start address and length. The mapping then stays in place until
+    /// it is unmapped manually, or the process exits or executes another
+    /// program.
+    pub fn leak(self) -> (*mut u8, usize) {
+        let v = (self.ptr, self.len);
+        core::mem::forget(self);
+        v
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // The mapping is owned exclusively, and all borrows of its content
+        // are bound to the owner.
+        unsafe {
+            let _ = Syscall::new().munmap(self.ptr as usize, self.len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Create mappings, access their content, and verify they are unmapped
+    // when dropped, but retained when leaked. Other tests might map memory
+    // concurrently and reuse the address of a dropped mapping, so the drop
+    // is verified in a forked child, which runs no other threads.
+    #[test]
+    fn mapping_ownership() {
+        let this = unsafe { crate::this::This::new() };
+        let prot = mman::PROT_READ | mman::PROT_WRITE;
+        let mut vec = [0u8; 1];
+
+        let mut m = Mapping::anonymous(&this.syscall, 4096, prot).unwrap();
+        assert_eq!(m.len(), 4096);
+        assert!(!m.is_empty());
+        unsafe {
+            assert!(m.as_slice().iter().all(|v| *v == 0));
+            m.as_mut_slice()[4095] = 0x71;
+            assert_eq!(m.as_ptr().add(4095).read(), 0x71);
+        }
+
+        drop(m);
+
+        match unsafe { this.syscall.fork() }.unwrap() {
+            crate::syscall::ForkResult::Child => {
+                let Ok(m) = Mapping::anonymous(&this.syscall, 4096, prot) else {
+                    this.syscall.exit_group(1);
+                };
+                let addr = m.as_ptr() as usize;
+                drop(m);
+
+                let r = unsafe { this.syscall.mincore(addr, 1, vec.as_mut_ptr()) };
+                if r != Err(rt11_ffi_linux::native::errno::ENOMEM) {
+                    this.syscall.exit_group(2);
+                }
+                this.syscall.exit_group(0);
+            },
+            crate::syscall::ForkResult::Parent(pid) => {
+                assert_eq!(crate::syscall::test::reap_exit(&this.syscall, pid), 0);
+            },
+        }
+
+        let m = Mapping::anonymous(&this.syscall, 4096, prot).unwrap();
+        let (ptr, len) = m.leak();
+        unsafe {
+            ptr.write(0x71);
+            this.syscall.mincore(ptr as usize, len, vec.as_mut_ptr()).unwrap();
+
+            let m = Mapping::from_raw(ptr, len);
+            assert_eq!(m.as_slice()[0], 0x71);
+        }
+
+        assert_eq!(
+            Mapping::anonymous(&this.syscall, 0, prot).unwrap_err(),
+            rt11_ffi_linux::native::errno::EINVAL,
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Scheduling Helpers
+//!
+//! This module provides the CPU sets used to query and modify the CPU
+//! affinity of tasks. See `Syscall::sched_getaffinity()` and
+//! `Syscall::sched_setaffinity()`.
+
+/// Maximum Number of CPUs
+///
+/// This is the number of CPUs a `CpuSet` can describe. It matches
+/// `CPU_SETSIZE` of the C library.
+pub const CPU_SETSIZE: usize = 1024;
+
+const BITS: usize = usize::BITS as usize;
+
+/// CPU Set
+///
+/// A fixed-size bitmask of CPUs, where bit `n` describes the CPU with
+/// number `n`. It matches the layout of the kernel CPU mask (an array of
+/// native words), and can describe up to `CPU_SETSIZE` CPUs. Accesses to
+/// CPUs beyond that are ignored.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CpuSet {
+    bits: [usize; CPU_SETSIZE / BITS],
+}
+
+impl CpuSet {
+    /// Create Empty Set
+    pub const fn new() -> Self {
+        Self { bits: [0; CPU_SETSIZE / BITS] }
+    }
+
+    /// Add CPU
+    ///
+    /// Add the CPU `cpu` to the set. CPUs beyond `CPU_SETSIZE` are ignored.
+    pub fn set(&mut self, cpu: usize) {
+        if let Some(v) = self.bits.get_mut(cpu / BITS) {
+            *v |= 1 << (cpu % BITS);
+        }
+    }
+
+    /// Remove CPU
+    ///
+    /// Remove the CPU `cpu` from the set. CPUs beyond `CPU_SETSIZE` are
+    /// ignored.
+    pub fn clear(&mut self, cpu: usize) {
+        if let Some(v) = self.bits.get_mut(cpu / BITS) {
+            *v &= !(1 << (cpu % BITS));
+        }
+    }
+
+    /// Check for CPU
+    ///
+    /// Return whether the CPU `cpu` is part of the set. CPUs beyond
+    /// `CPU_SETSIZE` are never part of it.
+    pub fn is_set(&self, cpu: usize) -> bool {
+        self.bits
+            .get(cpu / BITS)
+            .is_some_and(|v| v & (1 << (cpu % BITS)) != 0)
+    }
+
+    /// Count CPUs
+    ///
+    /// Return the number of CPUs in the set.
+    pub fn count(&self) -> usize {
+        self.bits.iter().map(|v| v.count_ones() as usize).sum()
+    }
+
+    /// Access Raw Mask
+    ///
+    /// Return a pointer to the mask as passed to the kernel. It is valid for
+    /// `size_of::<CpuSet>()` bytes.
+    pub fn as_ptr(&self) -> *const usize {
+        self.bits.as_ptr()
+    }
+
+    /// Access Raw Mask Mutably
+    ///
+    /// Return a pointer to the mask as written by the kernel. See `as_ptr()`
+    /// for details.
+    pub fn as_mut_ptr(&mut self) -> *mut usize {
+        self.bits.as_mut_ptr()
+    }
+}
+
+impl Default for CpuSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Add and remove CPUs across word boundaries, and verify CPUs beyond
+    // the set size are ignored.
+    #[test]
+    fn cpu_set() {
+        let mut set = CpuSet::new();
+        assert_eq!(set, CpuSet::default());
+        assert_eq!(core::mem::size_of::<CpuSet>(), CPU_SETSIZE / 8);
+
+        set.set(0);
+        set.set(BITS - 1);
+        set.set(BITS);
+        set.set(CPU_SETSIZE - 1);
+        set.set(CPU_SETSIZE);
+        assert_eq!(set.count(), 4);
+        assert!(set.is_set(0));
+        assert!(!set.is_set(1));
+        assert!(set.is_set(BITS - 1));
+        assert!(set.is_set(BITS));
+        assert!(set.is_set(CPU_SETSIZE - 1));
+        assert!(!set.is_set(CPU_SETSIZE));
+
+        set.clear(BITS);
+        set.clear(CPU_SETSIZE);
+        assert_eq!(set.count(), 3);
+        assert!(!set.is_set(BITS));
+        assert!(set.is_set(BITS - 1));
+    }
+}
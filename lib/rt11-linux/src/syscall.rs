@@ -28,6 +28,14 @@ use rt11_ffi_linux;
 /// 4096. It depends on the context how these invalid values are treated.
 pub type Errno = u16;
 
+/// Unexpected End of File
+///
+/// This is returned by `Syscall::read_exact()` if the end of the file is
+/// reached before the buffer was filled. It is not an error number of the
+/// kernel, but lies outside of their range (see `Errno`), so it cannot be
+/// confused with errors of the kernel.
+pub const UNEXPECTED_EOF: Errno = u16::MAX;
+
 /// Return syscall result to rust
 ///
 /// Take a `usize` return value of a linux system call and convert it to the
@@ -41,15 +49,74 @@ pub type Errno = u16;
 /// calls need to use other methods to return the information.
 ///
 /// This function turns any valid error code into `Err<Errno>`, but leaves
-/// everything else untouched as `Ok<usize>`.
+/// everything else untouched as `Ok<usize>`. It is the same decoding as
+/// applied by `Syscall::syscall_checked()` of `rt11-ffi-linux`.
 pub fn result_from_retval(r: usize) -> Result<usize, Errno> {
-    if r > core::usize::MAX - 4096 {
-        Err((!r + 1) as u16)
+    rt11_ffi_linux::common::decode_retval(r)
+}
+
+/// Split 64-bit System Call Argument
+///
+/// Split the 64-bit system call argument `v` into its low and high 32-bit
+/// halves, as required to pass it in two registers on 32-bit architectures.
+/// The halves are returned in the order the kernel expects them in
+/// consecutive arguments on all supported 32-bit architectures (which are
+/// all little-endian). 64-bit architectures pass such arguments unsplit.
+///
+/// While the order of the halves is the same, the placement of the pair
+/// depends on the architecture:
+///
+///  * x86 passes the halves in any two consecutive arguments.
+///
+///  * ARM (EABI) passes 64-bit values in register pairs starting on an even
+///    register (i.e., `r0:r1`, `r2:r3`, or `r4:r5`). If an odd number of
+///    arguments precedes the pair, an unused padding argument must be
+///    inserted, which shifts all following arguments by one. The kernel
+///    avoids this for some system calls by reordering their arguments
+///    instead (e.g., `arm_fadvise64_64()`), so each system call must be
+///    checked individually.
+///
+/// Both halves are returned as `usize`, so this is valid on all
+/// architectures, but has no use on 64-bit architectures.
+pub fn split64(v: u64) -> (usize, usize) {
+    (v as u32 as usize, (v >> 32) as u32 as usize)
+}
+
+// Return the time remaining from `now` until `deadline`, or 0 if the
+// deadline has passed. Both values must be normalized (i.e., `tv_nsec` in
+// the range 0 to 999,999,999).
+pub(crate) fn timespec_until(
+    deadline: &rt11_ffi_linux::native::time::Timespec,
+    now: &rt11_ffi_linux::native::time::Timespec,
+) -> rt11_ffi_linux::native::time::Timespec {
+    let mut sec = deadline.tv_sec.saturating_sub(now.tv_sec);
+    let mut nsec = deadline.tv_nsec - now.tv_nsec;
+
+    if nsec < 0 {
+        nsec += 1_000_000_000;
+        sec = sec.saturating_sub(1);
+    }
+
+    if sec < 0 {
+        Default::default()
     } else {
-        Ok(r)
+        rt11_ffi_linux::native::time::Timespec { tv_sec: sec, tv_nsec: nsec }
     }
 }
 
+/// Result of a Fork
+///
+/// This is returned by `Syscall::fork()` to tell the parent and child task
+/// apart. The parent gets the task ID of the new child, the child does not
+/// get any extra information.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ForkResult {
+    /// Returned to the parent, carrying the task ID of the child.
+    Parent(u32),
+    /// Returned to the child.
+    Child,
+}
+
 /// Syscall Invocation
 ///
 /// This type represents necessary context to invoke system calls. Since most
@@ -74,6 +141,431 @@ impl Syscall {
         }
     }
 
+    /// Abort Process
+    ///
+    /// Terminate the process abnormally via `SIGABRT`, so a core dump is
+    /// produced (if enabled) and the parent observes the signal. This
+    /// mirrors `abort()` of the C library, including its guarantee to
+    /// terminate even if `SIGABRT` is caught and the handler returns:
+    ///
+    ///  1. `SIGABRT` is unblocked and raised on the calling thread via
+    ///     `tgkill()`, so any installed handler runs.
+    ///  2. If that returns (i.e., the handler returned, or the signal was
+    ///     ignored), the action of `SIGABRT` is reset to the default, it is
+    ///     unblocked again (in case the handler blocked it), and raised
+    ///     again.
+    ///  3. If that returns as well (which the kernel never allows),
+    ///     the thread group is terminated via `exit_group()` with 134 (i.e.,
+    ///     `128 + SIGABRT`, as reported by shells) as exit-code.
+    ///
+    /// Signals sent to the calling thread are delivered before `tgkill()`
+    /// returns, so no step can be skipped by a delayed delivery. Errors of
+    /// the individual steps are ignored, since the fallback guarantees
+    /// termination.
+    pub fn abort(&self) -> ! {
+        let sig = rt11_ffi_linux::native::signal::SIGABRT;
+        let unblock = rt11_ffi_linux::native::signal::SIG_UNBLOCK;
+        let mask = 1u64 << (sig - 1);
+        let tgid = self.getpid() as i32;
+        let tid = self.gettid() as i32;
+
+        // Unblocking runs pending handlers, which is exactly what is
+        // wanted here.
+        unsafe {
+            let _ = self.rt_sigprocmask(unblock, Some(&mask), None);
+        }
+        let _ = self.tgkill(tgid, tid, sig);
+
+        // An all-zero signal action (i.e., `Sigaction::default()`) selects
+        // the default action on all architectures.
+        unsafe {
+            let dfl = rt11_ffi_linux::native::signal::Sigaction::default();
+            let _ = self.rt_sigaction(sig, &dfl, core::ptr::null_mut());
+            let _ = self.rt_sigprocmask(unblock, Some(&mask), None);
+        }
+        let _ = self.tgkill(tgid, tid, sig);
+
+        self.exit_group(128 + sig);
+    }
+
+    /// Query FS Segment Base
+    ///
+    /// Return the base address of the `%fs` segment of the calling task. On
+    /// x86_64 this is the thread pointer used to address thread-local
+    /// storage.
+    ///
+    /// This is a convenience around `arch_prctl(ARCH_GET_FS)`, and only
+    /// available on x86_64.
+    #[cfg(target_arch = "x86_64")]
+    pub fn arch_get_fs(&self) -> Result<usize, Errno> {
+        let mut v: usize = 0;
+
+        unsafe {
+            self.arch_prctl(
+                rt11_ffi_linux::native::arch_prctl::ARCH_GET_FS,
+                &mut v as *mut usize as usize,
+            )?;
+        }
+
+        Ok(v)
+    }
+
+    /// Architecture-specific Task Control
+    ///
+    /// `fn sys_arch_prctl(code: i32, addr: usize) -> i32`
+    ///
+    /// Query or modify architecture-specific state of the calling task. The
+    /// operation is selected by `code` (see the `arch_prctl` module of the
+    /// architecture). Depending on the operation, `addr` is either a value or
+    /// a pointer to a value that is read or written by the kernel. On x86_64
+    /// this controls the segment bases of `%fs` and `%gs`, as well as the
+    /// permissions for dynamically enabled XSAVE features like AMX (e.g.,
+    /// `ARCH_REQ_XCOMP_PERM`).
+    ///
+    /// This system call exists only on x86 and x86_64, and the segment-base
+    /// operations are only supported on x86_64. Hence, this is only available
+    /// on x86_64.
+    ///
+    /// Returns 0 on success, or `EINVAL` if `code` is not supported.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// If `addr` is used as pointer by the selected operation, it must point
+    /// to memory valid for the access performed by the kernel. Operations
+    /// that modify task state (e.g., `ARCH_SET_FS`) affect all code of the
+    /// calling task, so the caller must uphold the requirements of the
+    /// respective operation (see `arch_set_fs()`).
+    #[cfg(target_arch = "x86_64")]
+    pub unsafe fn arch_prctl(&self, code: u32, addr: usize) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::ARCH_PRCTL as usize,
+                    code as usize,
+                    addr,
+                )
+            }
+        )
+    }
+
+    /// Set FS Segment Base
+    ///
+    /// Set the base address of the `%fs` segment of the calling task to
+    /// `addr`. On x86_64 this is the thread pointer used to address
+    /// thread-local storage.
+    ///
+    /// This is a convenience around `arch_prctl(ARCH_SET_FS)`, and only
+    /// available on x86_64.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Any thread-local storage access of the calling task uses the new
+    /// segment base. The caller must ensure that `addr` points to a valid
+    /// thread control block, and that no code relies on the previous
+    /// thread-local storage afterwards.
+    #[cfg(target_arch = "x86_64")]
+    pub unsafe fn arch_set_fs(&self, addr: usize) -> Result<(), Errno> {
+        unsafe {
+            self.arch_prctl(
+                rt11_ffi_linux::native::arch_prctl::ARCH_SET_FS,
+                addr,
+            ).map(|_| ())
+        }
+    }
+
+    /// Change Working Directory
+    ///
+    /// `fn sys_chdir(path: *const u8) -> i32`
+    ///
+    /// Change the current working directory of the calling task to the
+    /// directory at `path`. Relative paths are resolved against the previous
+    /// working directory. The calling task needs search permission on every
+    /// component of `path`.
+    ///
+    /// Note that the working directory is shared with all tasks that share
+    /// the file-system information (i.e., `CLONE_FS`), which includes all
+    /// threads of a process.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string.
+    pub unsafe fn chdir(&self, path: *const u8) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::CHDIR as usize,
+                    path as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Change Root Directory
+    ///
+    /// `fn sys_chroot(path: *const u8) -> i32`
+    ///
+    /// Change the root directory of the calling task to the directory at
+    /// `path`. Absolute paths are resolved against the new root from then
+    /// on. This requires `CAP_SYS_CHROOT`.
+    ///
+    /// This does not change the current working directory. If it is outside
+    /// of the new root, relative paths still resolve outside of it, and the
+    /// task can escape the new root trivially (e.g., via `chdir("..")`).
+    /// Hence, this must be followed by `chdir("/")` to actually confine the
+    /// task. See `enter_chroot()` for a helper that does both.
+    ///
+    /// Furthermore, open file-descriptors referring to directories outside
+    /// of the new root can be used to escape it (e.g., via `fchdir()`), and
+    /// privileged tasks can always escape via another `chroot()`. This is
+    /// thus no security boundary on its own.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string. Furthermore, the
+    /// root directory is shared with all tasks that share the file-system
+    /// information (i.e., `CLONE_FS`). Any code of the process resolving
+    /// absolute paths is affected by this change.
+    pub unsafe fn chroot(&self, path: *const u8) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::CHROOT as usize,
+                    path as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Query Clock Resolution
+    ///
+    /// `fn sys_clock_getres(clockid: u32, res: *mut Timespec) -> i32`
+    ///
+    /// Return the resolution of the clock `clockid` (i.e., `CLOCK_*`). This
+    /// is the granularity of the values returned by `clock_gettime()` for
+    /// the same clock, not its accuracy. High-resolution clocks usually
+    /// report 1ns. `EINVAL` is returned for unknown clocks.
+    ///
+    /// On 32-bit architectures, this uses `clock_getres_time64()` to
+    /// retrieve the 64-bit representation of `Timespec`.
+    pub fn clock_getres(
+        &self,
+        clockid: u32,
+    ) -> Result<rt11_ffi_linux::native::time::Timespec, Errno> {
+        let mut res = rt11_ffi_linux::native::time::Timespec::default();
+
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::CLOCK_GETRES_TIME64;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::CLOCK_GETRES;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    nr as usize,
+                    clockid as usize,
+                    &mut res as *mut _ as usize,
+                )
+            }
+        ).map(|_| res)
+    }
+
+    /// Read Clock
+    ///
+    /// `fn sys_clock_gettime(clockid: u32, tp: *mut Timespec) -> i32`
+    ///
+    /// Return the current value of the clock `clockid` (i.e., `CLOCK_*`).
+    /// `EINVAL` is returned for unknown clocks.
+    ///
+    /// This always enters the kernel, rather than using the vDSO. On 32-bit
+    /// architectures, this uses `clock_gettime64()` to retrieve the 64-bit
+    /// representation of `Timespec`.
+    pub fn clock_gettime(
+        &self,
+        clockid: u32,
+    ) -> Result<rt11_ffi_linux::native::time::Timespec, Errno> {
+        let mut tp = rt11_ffi_linux::native::time::Timespec::default();
+
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::CLOCK_GETTIME64;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::CLOCK_GETTIME;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    nr as usize,
+                    clockid as usize,
+                    &mut tp as *mut _ as usize,
+                )
+            }
+        ).map(|_| tp)
+    }
+
+    /// Clone Task onto Separate Stack
+    ///
+    /// `fn sys_clone(flags: usize, stack: usize, ptid: *mut i32, tls: usize, ctid: *mut i32) -> isize`
+    ///
+    /// Create a new task via `clone(2)` with `flags` (i.e., `CLONE_*`, with
+    /// the exit signal in the low byte), and return its task ID. The child
+    /// starts executing `entry(arg)` on the stack ending at `stack`. It never
+    /// returns from this function, and `entry` must never return either, but
+    /// end the task via `exit()`, `exit_group()`, or a successful
+    /// `execve()`.
+    ///
+    /// Unlike with `fork()`, the child does not continue on the stack of the
+    /// caller, so this is suitable for `CLONE_VM`. The flags that make the
+    /// kernel access the pointer arguments (i.e., `CLONE_PARENT_SETTID`,
+    /// `CLONE_CHILD_SETTID`, `CLONE_CHILD_CLEARTID`, `CLONE_PIDFD`, and
+    /// `CLONE_SETTLS`) are not supported, and yield `EINVAL`. So does a
+    /// `stack` that is not aligned to 16 bytes. The argument order of
+    /// `clone(2)` diverges across architectures, but only affects these
+    /// pointer arguments.
+    ///
+    /// This enters the kernel via inline assembly, rather than the `Syscall`
+    /// trait, since the child must switch to `entry` without returning into
+    /// compiled code. Hence, it is not visible to wrappers like
+    /// `TracingSyscall`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `stack` must be the end of a writable memory region large enough for
+    /// `entry`. Without `CLONE_VM`, the child gets a copy of it, otherwise it
+    /// must stay valid until the child exits or executes a new program
+    /// (e.g., via `CLONE_VFORK`, which suspends the caller until then).
+    ///
+    /// With `CLONE_VM`, the child shares the address space of the caller,
+    /// including the state of its execution context (`This`) and of any
+    /// lock. The child must restrict itself to async-signal-safe operations
+    /// and must not modify state it shares with the caller, unless
+    /// synchronized explicitly. See `fork()` for the other caveats.
+    pub unsafe fn clone_entry(
+        &self,
+        flags: u32,
+        stack: *mut u8,
+        entry: extern "C" fn(usize) -> !,
+        arg: usize,
+    ) -> Result<u32, Errno> {
+        let pointers = rt11_ffi_linux::native::sched::CLONE_PARENT_SETTID
+            | rt11_ffi_linux::native::sched::CLONE_CHILD_SETTID
+            | rt11_ffi_linux::native::sched::CLONE_CHILD_CLEARTID
+            | rt11_ffi_linux::native::sched::CLONE_PIDFD
+            | rt11_ffi_linux::native::sched::CLONE_SETTLS;
+
+        if flags & pointers != 0 || stack as usize % 16 != 0 {
+            return Err(rt11_ffi_linux::native::errno::EINVAL);
+        }
+
+        let nr = rt11_ffi_linux::native::nr::CLONE as usize;
+        let r: usize;
+
+        // The pointer arguments are ignored by the kernel without the flags
+        // rejected above, so their registers carry `entry` and `arg` into
+        // the child where needed. The kernel preserves all registers but
+        // the return value, and the child starts with the stack-pointer set
+        // to `stack`.
+        #[cfg(target_arch = "arm")]
+        unsafe {
+            core::arch::asm!(
+                "svc 0",
+                "cmp r0, #0",
+                "bne 2f",
+                "mov r0, r8",
+                "blx r5",
+                "udf #0",
+                "2:",
+                in("r7") nr,
+                inlateout("r0") flags as usize => r,
+                in("r1") stack,
+                in("r5") entry,
+                in("r8") arg,
+                out("lr") _,
+            );
+        }
+        #[cfg(target_arch = "aarch64")]
+        unsafe {
+            core::arch::asm!(
+                "svc #0",
+                "cbnz x0, 2f",
+                "mov x0, x10",
+                "blr x9",
+                "brk #0",
+                "2:",
+                in("x8") nr,
+                inlateout("x0") flags as usize => r,
+                in("x1") stack,
+                in("x9") entry,
+                in("x10") arg,
+                out("x30") _,
+            );
+        }
+        #[cfg(target_arch = "riscv64")]
+        unsafe {
+            core::arch::asm!(
+                "ecall",
+                "bnez a0, 2f",
+                "mv a0, t1",
+                "jalr t0",
+                "unimp",
+                "2:",
+                in("a7") nr,
+                inlateout("a0") flags as usize => r,
+                in("a1") stack,
+                in("t0") entry,
+                in("t1") arg,
+                out("ra") _,
+            );
+        }
+        #[cfg(target_arch = "x86")]
+        unsafe {
+            core::arch::asm!(
+                "int $0x80",
+                "test eax, eax",
+                "jnz 2f",
+                "sub esp, 12",
+                "push edi",
+                "call edx",
+                "ud2",
+                "2:",
+                inlateout("eax") nr => r,
+                in("ebx") flags as usize,
+                in("ecx") stack,
+                in("edx") entry,
+                in("edi") arg,
+            );
+        }
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            core::arch::asm!(
+                "syscall",
+                "test rax, rax",
+                "jnz 2f",
+                "mov rdi, r13",
+                "call r12",
+                "ud2",
+                "2:",
+                inlateout("rax") nr => r,
+                in("rdi") flags as usize,
+                in("rsi") stack,
+                in("r12") entry,
+                in("r13") arg,
+                out("rcx") _,
+                out("r11") _,
+            );
+        }
+
+        result_from_retval(r).map(|v| v as u32)
+    }
+
     /// Close File Descriptor
     ///
     /// `fn sys_close(fd: u32) -> i32`
@@ -128,6 +620,368 @@ impl Syscall {
         )
     }
 
+    /// Close File-descriptor Range
+    ///
+    /// `fn sys_close_range(first: u32, last: u32, flags: u32) -> i32`
+    ///
+    /// Close all file-descriptors from `first` to `last` (inclusive). Gaps
+    /// in the range are skipped, and `last` may exceed the highest open
+    /// file-descriptor (e.g., `u32::MAX`). `flags` (i.e., `CLOSE_RANGE_*`)
+    /// modifies the behavior: with `CLOSE_RANGE_CLOEXEC`, the
+    /// file-descriptors are not closed, but marked close-on-exec, and with
+    /// `CLOSE_RANGE_UNSHARE`, the file-descriptor table is unshared first.
+    ///
+    /// This system call was introduced in linux-5.9, and
+    /// `CLOSE_RANGE_CLOEXEC` in linux-5.11. Older kernels return `ENOSYS`
+    /// and `EINVAL`, respectively.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Unless `CLOSE_RANGE_CLOEXEC` is passed, this closes file-descriptors
+    /// that might still be owned by other parts of the program. See
+    /// `close()` for details.
+    pub unsafe fn close_range(&self, first: u32, last: u32, flags: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::CLOSE_RANGE as usize,
+                    first as usize,
+                    last as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Connect Socket
+    ///
+    /// `fn sys_connect(fd: u32, addr: *const u8, addrlen: u32) -> i32`
+    ///
+    /// Connect the socket `fd` to the address `addr` of `addrlen` bytes,
+    /// which must match the address family of the socket (e.g.,
+    /// `SockaddrUn` for `AF_UNIX`). Stream sockets establish a connection,
+    /// while datagram sockets merely set their default destination. If no
+    /// socket listens on the address, `ECONNREFUSED` is returned (or
+    /// `ENOENT` for non-existent paths of `AF_UNIX`). Non-blocking sockets
+    /// return `EINPROGRESS` if the connection cannot be completed
+    /// immediately.
+    ///
+    /// On x86, this is multiplexed through `socketcall()` as
+    /// `SYS_CONNECT`, since the direct system call was only added in
+    /// linux-4.3. All other architectures use the direct system call.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `addr` must be valid for reads of `addrlen` bytes.
+    pub unsafe fn connect(&self, fd: u32, addr: *const u8, addrlen: u32) -> Result<(), Errno> {
+        #[cfg(target_arch = "x86")]
+        let r = {
+            let args: [usize; 3] = [fd as usize, addr as usize, addrlen as usize];
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SOCKETCALL as usize,
+                    rt11_ffi_linux::native::socket::SYS_CONNECT as usize,
+                    args.as_ptr() as usize,
+                )
+            }
+        };
+
+        #[cfg(not(target_arch = "x86"))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::CONNECT as usize,
+                fd as usize,
+                addr as usize,
+                addrlen as usize,
+            )
+        };
+
+        result_from_retval(r).map(|_| ())
+    }
+
+    /// Daemonize Process
+    ///
+    /// Detach the calling process from its controlling terminal and session
+    /// via the canonical double-fork sequence, and continue execution in
+    /// the resulting daemon.
+    ///
+    /// First, this forks and exits the original process with status 0. The
+    /// child is not a process-group leader, so `setsid()` cannot fail with
+    /// `EPERM`, and it creates a new session without controlling terminal.
+    /// Then, this forks again and the session leader exits as well. The
+    /// final process is not a session leader, so it can never acquire a
+    /// controlling terminal by opening one. This function only returns in
+    /// that process, or in the original process if the first fork fails.
+    /// If a later step fails, the intermediate process exits with status 1.
+    ///
+    /// The working directory, the file-creation mask, and all
+    /// file-descriptors (including the standard streams) are left
+    /// unchanged. Changing them is up to the caller.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The same requirements as for `fork()` apply, since the returning
+    /// process is a new task. Furthermore, the original process exits
+    /// without running any cleanup (e.g., flushing buffers).
+    pub unsafe fn daemonize(&self) -> Result<(), Errno> {
+        if let ForkResult::Parent(_) = unsafe { self.fork()? } {
+            self.exit_group(0);
+        }
+
+        if self.setsid().is_err() {
+            self.exit_group(1);
+        }
+
+        match unsafe { self.fork() } {
+            Ok(ForkResult::Child) => Ok(()),
+            Ok(ForkResult::Parent(_)) => self.exit_group(0),
+            Err(_) => self.exit_group(1),
+        }
+    }
+
+    /// Drop Privileges
+    ///
+    /// Irrevocably switch the calling task to the user `uid`, the group
+    /// `gid`, and the supplementary groups `groups`. The real, effective, and
+    /// saved IDs are all set, so the previous privileges cannot be regained.
+    ///
+    /// The order of operations is essential: changing the supplementary
+    /// groups and the group ID requires privileges (`CAP_SETGID`), which are
+    /// lost once the user ID is changed. Hence, this calls `setgroups()`,
+    /// then `setresgid()`, and finally `setresuid()`. Reversing the order
+    /// leaves the task with the groups of the privileged user, which is a
+    /// well-known security bug.
+    ///
+    /// If any step fails, the error is returned and the remaining steps are
+    /// skipped. The credentials of the task might be partially changed in
+    /// that case, so the caller must treat errors as fatal. Callers can use
+    /// `geteuid()` and `getgroups()` beforehand to check whether the task is
+    /// privileged at all, and whether a drop is required.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Credentials are a property of each thread on linux, and this only
+    /// changes the credentials of the calling thread. See `setgroups()` for
+    /// details.
+    pub unsafe fn drop_privileges(
+        &self,
+        uid: u32,
+        gid: u32,
+        groups: &[u32],
+    ) -> Result<(), Errno> {
+        unsafe { self.setgroups(groups)? };
+        self.setresgid(gid, gid, gid)?;
+        self.setresuid(uid, uid, uid)
+    }
+
+    /// Duplicate File-descriptor
+    ///
+    /// `fn sys_dup3(oldfd: u32, newfd: u32, flags: u32) -> i32`
+    ///
+    /// Make `newfd` refer to the same open file-description as `oldfd`, and
+    /// return `newfd`. If `newfd` is open, it is closed silently first (and
+    /// atomically). `flags` can only be `O_CLOEXEC`, which sets
+    /// `FD_CLOEXEC` on `newfd`, otherwise it is cleared.
+    ///
+    /// Unlike the legacy `dup2()` (which is not available on all
+    /// architectures), this returns `EINVAL` if `oldfd` equals `newfd`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Any file-descriptor at `newfd` is closed, regardless of who owns it.
+    pub unsafe fn dup3(&self, oldfd: u32, newfd: u32, flags: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::DUP3 as usize,
+                    oldfd as usize,
+                    newfd as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Enter Root Directory
+    ///
+    /// Change the root directory of the calling task to `path` via
+    /// `chroot()`, and then change the working directory to the new root
+    /// via `chdir("/")`. Unlike a plain `chroot()`, this leaves no working
+    /// directory outside of the new root behind.
+    ///
+    /// If `chdir()` fails, the root directory is still changed, and the
+    /// error is returned. Callers must treat errors as fatal.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `chroot()` for the requirements of the caller.
+    pub unsafe fn enter_chroot(&self, path: *const u8) -> Result<(), Errno> {
+        unsafe {
+            self.chroot(path)?;
+            self.chdir(c"/".as_ptr().cast())
+        }
+    }
+
+    /// Create Epoll Instance
+    ///
+    /// `fn sys_epoll_create1(flags: u32) -> i32`
+    ///
+    /// Create a new epoll instance and return a file-descriptor referring
+    /// to it. `flags` can be 0 or `EPOLL_CLOEXEC`.
+    pub fn epoll_create1(&self, flags: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::EPOLL_CREATE1 as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Control Epoll Instance
+    ///
+    /// `fn sys_epoll_ctl(epfd: u32, op: u32, fd: u32, event: *mut EpollEvent) -> i32`
+    ///
+    /// Perform the operation `op` (i.e., `EPOLL_CTL_*`) on the epoll
+    /// instance `epfd` for the file-descriptor `fd`:
+    ///
+    ///  * `EPOLL_CTL_ADD`: Register `fd` with the events and data given in
+    ///    `event`. `EEXIST` is returned if `fd` is already registered.
+    ///
+    ///  * `EPOLL_CTL_MOD`: Replace the events and data of the registered
+    ///    `fd` with `event`.
+    ///
+    ///  * `EPOLL_CTL_DEL`: Deregister `fd`. `event` is ignored and can be
+    ///    NULL.
+    ///
+    /// Note that registrations refer to the open file-description, not the
+    /// file-descriptor. A file-descriptor that is closed is only removed
+    /// from the instance once all its duplicates were closed as well.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `event` must point to a valid `EpollEvent`, unless `op` is
+    /// `EPOLL_CTL_DEL`.
+    pub unsafe fn epoll_ctl(
+        &self,
+        epfd: u32,
+        op: u32,
+        fd: u32,
+        event: *mut rt11_ffi_linux::native::epoll::EpollEvent,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::EPOLL_CTL as usize,
+                    epfd as usize,
+                    op as usize,
+                    fd as usize,
+                    event as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Wait for Epoll Events
+    ///
+    /// `fn sys_epoll_pwait2(epfd: u32, events: *mut EpollEvent, maxevents: i32, timeout: *const Timespec, sigmask: *const u64, sigsetsize: usize) -> i32`
+    ///
+    /// Wait for events on the epoll instance `epfd`, store up to `maxevents`
+    /// of them in `events`, and return their number. `timeout` is the
+    /// maximum duration to wait, or NULL to wait indefinitely. A zero
+    /// timeout returns immediately.
+    ///
+    /// If `sigmask` is non-NULL, the signal mask of the calling thread is
+    /// replaced by `sigmask` for the duration of the wait. This closes the
+    /// race between unblocking signals and starting to wait.
+    ///
+    /// This system call is available since linux-5.11, and returns `ENOSYS`
+    /// on older kernels.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `events` must point to an array of at least `maxevents` elements.
+    /// `timeout` and `sigmask` must be NULL or point to a valid `Timespec`
+    /// and signal set, respectively.
+    pub unsafe fn epoll_pwait2(
+        &self,
+        epfd: u32,
+        events: *mut rt11_ffi_linux::native::epoll::EpollEvent,
+        maxevents: u32,
+        timeout: *const rt11_ffi_linux::native::time::Timespec,
+        sigmask: *const u64,
+    ) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall6(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::EPOLL_PWAIT2 as usize,
+                    epfd as usize,
+                    events as usize,
+                    maxevents as usize,
+                    timeout as usize,
+                    sigmask as usize,
+                    core::mem::size_of::<u64>(),
+                )
+            }
+        )
+    }
+
+    /// Execute Program
+    ///
+    /// `fn sys_execve(filename: *const u8, argv: *const *const u8, envp: *const *const u8) -> i32`
+    ///
+    /// Replace the program of the calling process with the program at
+    /// `path`, passing it the argument vector `argv` and the environment
+    /// `envp`. Both are NULL-terminated arrays of pointers to NUL-terminated
+    /// strings (see `exec::ExecArgs` to build them without allocation).
+    ///
+    /// On success, this does not return, since the calling program no
+    /// longer exists. All other threads are terminated, and file-descriptors
+    /// marked close-on-exec are closed. On failure, the calling program is
+    /// left intact and the error is returned.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string, and `argv` and
+    /// `envp` must point to valid NULL-terminated arrays as described above.
+    pub unsafe fn execve(
+        &self,
+        path: *const u8,
+        argv: *const *const u8,
+        envp: *const *const u8,
+    ) -> Result<core::convert::Infallible, Errno> {
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::EXECVE as usize,
+                path as usize,
+                argv as usize,
+                envp as usize,
+            )
+        };
+        match result_from_retval(r) {
+            Ok(v) => core::unreachable!("`syscall(EXECVE)` returned unexpectedly: {}", v),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Exit Task
     ///
     /// Stop the current execution and tear down this task. Other tasks of a
@@ -153,90 +1007,5184 @@ impl Syscall {
         core::unreachable!("`syscall(EXIT)` returned unexpectedly: {}", r);
     }
 
-    /// Restart System Call
+    /// Exit Thread Group
     ///
-    /// This system call continues an interrupted system call with the same
-    /// parameters it was initially called, adjusted only for the time
-    /// difference between the original syscall and now.
+    /// Stop the current execution and tear down all tasks of the thread
+    /// group of the calling task (i.e., the entire process).
     ///
-    /// This system call is used by the kernel itself to resume system calls of
-    /// frozen processes. Whenever a system call is interrupted, the kernel
-    /// saves the system call parameters and restarts the system call with the
-    /// same parameters once the task is resumed again. However, for system
-    /// calls that take relative time-frames as arguments, the kernel usually
-    /// needs to adjust these relative time-frames for the elapsed time. For
-    /// those system calls, the kernel refrains from restarting the system call
-    /// directly and instead changes the system call number of the
-    /// to-be-restarted call to this system call. When this system call is then
-    /// invoked, the kernel fetches the original system call and its parameters
-    /// from its internal state, adjusts the relative timeout, and then
-    /// restarts the original system call.
+    /// Takes a single argument `code` which specifies the exit condition of
+    /// the thread group.
     ///
-    /// There is usually no reason why you would ever invoke this system call
-    /// from user-space. Moreover, even when the kernel triggers a syscall
-    /// restart with this system call, it never leaves kernel space, and thus
-    /// user-space should never see this system call at all. Tracing debuggers
-    /// might see it, though. And they are the only ones that might reasonable
-    /// interfere with it.
+    /// This system call never returns, under no circumstances. The kernel
+    /// uses the lower byte of `code` as exit-code, same as `exit()`.
+    pub fn exit_group(&self, code: u32) -> ! {
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::EXIT_GROUP as usize,
+                code as usize,
+            )
+        };
+        core::unreachable!("`syscall(EXIT_GROUP)` returned unexpectedly: {}", r);
+    }
+
+    /// Check File Accessibility
     ///
-    /// If no system call is to be resumed, this system call returns `EINTR`.
-    /// Otherwise, it resumes the original system call with adjusted relative
-    /// time parameters and returns the result of the resumed system call.
-    pub unsafe fn restart_syscall(&self) -> Result<usize, Errno> {
+    /// `fn sys_faccessat(dirfd: i32, path: *const u8, mode: u32) -> i32`
+    ///
+    /// Check whether the calling task may access the file at `path` with
+    /// the access mode `mode` (i.e., `F_OK` for existence, or a combination
+    /// of `R_OK`, `W_OK`, and `X_OK`). A relative `path` is resolved as
+    /// described for `openat()`. Symbolic links are followed.
+    ///
+    /// The check uses the real user and group IDs of the caller, rather
+    /// than the effective ones. Note that the result is only a snapshot. The
+    /// file can change before it is used, so this must not be used as a
+    /// security check for a later `open()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string.
+    pub unsafe fn faccessat(&self, dirfd: i32, path: *const u8, mode: u32) -> Result<(), Errno> {
         result_from_retval(
             unsafe {
-                <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
                     &self.ffi,
-                    rt11_ffi_linux::native::nr::RESTART_SYSCALL as usize,
+                    rt11_ffi_linux::native::nr::FACCESSAT as usize,
+                    dirfd as isize as usize,
+                    path as usize,
+                    mode as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Advise on File Access
+    ///
+    /// `fn sys_fadvise64_64(fd: u32, offset: u64, len: u64, advice: u32) -> i32`
+    ///
+    /// Announce the intended access pattern `advice` (i.e., `POSIX_FADV_*`)
+    /// for the range `offset` to `offset + len` of the file referred to by
+    /// `fd`. A `len` of 0 extends the range to the end of the file. The
+    /// kernel uses this to tune its caching (e.g., `POSIX_FADV_WILLNEED`
+    /// starts reading the range into the page cache without blocking, and
+    /// `POSIX_FADV_DONTNEED` drops clean cached pages of the range).
+    ///
+    /// This is advisory only and has no effect on the semantics of file
+    /// access. `ESPIPE` is returned for pipes and FIFOs.
+    ///
+    /// The 64-bit arguments are split into two registers each on 32-bit
+    /// architectures, which requires a different system call entry:
+    ///
+    ///  * x86 uses `fadvise64_64()` with the arguments in order, and
+    ///    each 64-bit argument split into its low and high half.
+    ///
+    ///  * ARM uses `arm_fadvise64_64()`, which moves `advice` to the second
+    ///    argument, so the 64-bit arguments start on an even register
+    ///    (as required for register pairs by the EABI).
+    ///
+    ///  * 64-bit architectures use `fadvise64()` with unsplit arguments.
+    pub fn fadvise(&self, fd: u32, offset: u64, len: u64, advice: u32) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let (offset_lo, offset_hi) = split64(offset);
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let (len_lo, len_hi) = split64(len);
+
+        #[cfg(target_arch = "arm")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall6(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::ARM_FADVISE64_64 as usize,
+                fd as usize,
+                advice as usize,
+                offset_lo,
+                offset_hi,
+                len_lo,
+                len_hi,
+            )
+        };
+
+        #[cfg(target_arch = "x86")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall6(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::FADVISE64_64 as usize,
+                fd as usize,
+                offset_lo,
+                offset_hi,
+                len_lo,
+                len_hi,
+                advice as usize,
+            )
+        };
+
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::FADVISE64 as usize,
+                fd as usize,
+                offset as usize,
+                len as usize,
+                advice as usize,
+            )
+        };
+
+        result_from_retval(r).map(|_| ())
+    }
+
+    /// Change Working Directory via File-descriptor
+    ///
+    /// `fn sys_fchdir(fd: u32) -> i32`
+    ///
+    /// This is the same as `chdir()`, but changes the current working
+    /// directory to the directory referred to by the file-descriptor `fd`.
+    /// This returns `ENOTDIR` if `fd` does not refer to a directory.
+    pub fn fchdir(&self, fd: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::FCHDIR as usize,
+                    fd as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// File Control
+    ///
+    /// `fn sys_fcntl(fd: u32, cmd: u32, arg: usize) -> i32`
+    ///
+    /// Perform the operation `cmd` on the file-descriptor `fd`. The meaning
+    /// of `arg` and of the return value depend on the command:
+    ///
+    ///  * `F_DUPFD` / `F_DUPFD_CLOEXEC`: Duplicate `fd` onto the lowest
+    ///    available file-descriptor greater than or equal to `arg`. The new
+    ///    file-descriptor is returned. With `F_DUPFD_CLOEXEC` the new
+    ///    file-descriptor has `FD_CLOEXEC` set.
+    ///
+    ///  * `F_GETFD`: Return the file-descriptor flags of `fd` (i.e.,
+    ///    `FD_CLOEXEC`). `arg` is ignored.
+    ///
+    ///  * `F_SETFD`: Set the file-descriptor flags of `fd` to `arg`. Returns 0.
+    ///
+    ///  * `F_GETFL`: Return the file-status flags and access mode of the open
+    ///    file-description of `fd` (i.e., `O_*` flags). `arg` is ignored.
+    ///
+    ///  * `F_SETFL`: Set the file-status flags of the open file-description
+    ///    of `fd` to `arg`. Only a subset of the flags can be changed, others
+    ///    are silently ignored. Returns 0.
+    ///
+    /// Note that file-descriptor flags are local to the file-descriptor,
+    /// while file-status flags are shared by all file-descriptors that refer
+    /// to the same open file-description.
+    ///
+    /// Other commands take pointers as `arg`, and thus this function is
+    /// unsafe. See `set_cloexec()` and `get_flags()` for safe helpers.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// If `cmd` interprets `arg` as pointer, it must point to memory valid
+    /// for the access performed by the command. Furthermore, the caller must
+    /// ensure that changes to `fd` (e.g., clearing `FD_CLOEXEC` or setting
+    /// `O_NONBLOCK`) do not violate assumptions of other owners of `fd` or
+    /// its open file-description.
+    pub unsafe fn fcntl(&self, fd: u32, cmd: i32, arg: usize) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::FCNTL as usize,
+                    fd as usize,
+                    cmd as isize as usize,
+                    arg,
                 )
             }
         )
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// Synchronize File Data
+    ///
+    /// `fn sys_fdatasync(fd: u32) -> i32`
+    ///
+    /// This is the same as `fsync()`, but does not flush metadata of the
+    /// file unless it is required to retrieve the file data (e.g., the file
+    /// size). Modification timestamps are thus not necessarily flushed.
+    ///
+    /// See `fsync()` for details on error reporting.
+    pub fn fdatasync(&self, fd: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::FDATASYNC as usize,
+                    fd as usize,
+                )
+            }
+        ).map(|_| ())
+    }
 
-    // Verify `result_from_retval()`. Check that error codes are correctly
-    // detected as such.
-    #[test]
-    fn retval_check() {
-        let success_values = [
-            0, 1, 2, 3,
-            254, 255, 256, 257,
-            65534, 65535, 65536, 65537,
-            core::usize::MAX / 2,
-            core::usize::MAX / 2 + 1,
-            core::usize::MAX - 4097,
-            core::usize::MAX - 4096,
-        ];
+    /// Get Extended Attribute of File-descriptor
+    ///
+    /// `fn sys_fgetxattr(fd: u32, name: *const u8, value: *mut u8, size: usize) -> isize`
+    ///
+    /// This is the same as `getxattr()`, but operates on the file referred
+    /// to by the file-descriptor `fd`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `name` must point to a valid NUL-terminated string.
+    pub unsafe fn fgetxattr(
+        &self,
+        fd: u32,
+        name: *const u8,
+        value: &mut [u8],
+    ) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::FGETXATTR as usize,
+                    fd as usize,
+                    name as usize,
+                    value.as_mut_ptr() as usize,
+                    value.len(),
+                )
+            }
+        )
+    }
 
-        for v in &success_values {
-            let r = result_from_retval(*v);
-            assert_eq!(r, Ok(*v));
+    /// List Extended Attributes of File-descriptor
+    ///
+    /// `fn sys_flistxattr(fd: u32, list: *mut u8, size: usize) -> isize`
+    ///
+    /// This is the same as `listxattr()`, but operates on the file referred
+    /// to by the file-descriptor `fd`.
+    pub fn flistxattr(&self, fd: u32, list: &mut [u8]) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::FLISTXATTR as usize,
+                    fd as usize,
+                    list.as_mut_ptr() as usize,
+                    list.len(),
+                )
+            }
+        )
+    }
+
+    /// Fork Task
+    ///
+    /// Create a new task as a copy of the calling task. The new task is a
+    /// child of the calling task and gets a copy of its address space, its
+    /// file-descriptor table, and most other resources. Only the calling
+    /// thread is duplicated, any other threads of the caller do not exist in
+    /// the child.
+    ///
+    /// This uses `clone(2)` with only `SIGCHLD` as exit signal and no other
+    /// flags, which is what the kernel uses to implement the legacy
+    /// `fork(2)` system call. Since no stack, TLS, or TID pointers are
+    /// passed, the diverging argument order of `clone(2)` across
+    /// architectures does not matter here.
+    ///
+    /// On success, this returns twice: `ForkResult::Parent` with the task ID
+    /// of the child is returned in the parent, and `ForkResult::Child` is
+    /// returned in the child. On failure, no child is created and the error
+    /// is returned to the caller.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The execution context (`This`) of the caller is duplicated along with
+    /// the address space. In the child, the duplicate is the only context
+    /// of the new task and thus conceptually a new context created for it.
+    /// The parent retains its original context. The caller must ensure that
+    /// no state cached by the context or its owner refers to the parent task
+    /// (e.g., cached task IDs) when used in the child. If in doubt, the child
+    /// should drop the inherited context and create a new one via
+    /// `This::new()` before performing any operation.
+    ///
+    /// Furthermore, if the caller is multi-threaded, any lock held by another
+    /// thread at the time of the fork stays locked forever in the child.
+    /// Hence, the child must restrict itself to async-signal-safe operations
+    /// until it calls `execve()` or exits.
+    pub unsafe fn fork(&self) -> Result<ForkResult, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::CLONE as usize,
+                    rt11_ffi_linux::native::signal::SIGCHLD as usize,
+                    0,
+                    0,
+                    0,
+                    0,
+                )
+            }
+        ).map(|v| {
+            match v {
+                0 => ForkResult::Child,
+                pid => ForkResult::Parent(pid as u32),
+            }
+        })
+    }
+
+    /// Set Extended Attribute of File-descriptor
+    ///
+    /// `fn sys_fsetxattr(fd: u32, name: *const u8, value: *const u8, size: usize, flags: u32) -> i32`
+    ///
+    /// This is the same as `setxattr()`, but operates on the file referred
+    /// to by the file-descriptor `fd`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `name` must point to a valid NUL-terminated string.
+    pub unsafe fn fsetxattr(
+        &self,
+        fd: u32,
+        name: *const u8,
+        value: &[u8],
+        flags: u32,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::FSETXATTR as usize,
+                    fd as usize,
+                    name as usize,
+                    value.as_ptr() as usize,
+                    value.len(),
+                    flags as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Synchronize File
+    ///
+    /// `fn sys_fsync(fd: u32) -> i32`
+    ///
+    /// Flush all modified data and metadata of the file referred to by `fd`
+    /// to the underlying storage device, and wait for the device to report
+    /// completion.
+    ///
+    /// This system call returns `EIO` if writeback failed. Note that writeback
+    /// is deferred, so this error might stem from a previous `write()` of
+    /// any task. Furthermore, on many file systems the error is reported only
+    /// once and the failed pages are marked clean regardless. Hence, a
+    /// subsequent call can succeed even though the data was never written.
+    /// Callers must treat `EIO` as data loss, rather than retry.
+    pub fn fsync(&self, fd: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::FSYNC as usize,
+                    fd as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Truncate File via File-descriptor
+    ///
+    /// `fn sys_ftruncate(fd: u32, length: u64) -> i32`
+    ///
+    /// Set the size of the regular file referred to by `fd` to `length`
+    /// bytes. The file must be open for writing. This can both shrink and
+    /// grow the file: data beyond `length` is discarded, while growing
+    /// appends a hole that reads as zeros, but does not allocate disk
+    /// space (i.e., the file becomes sparse). Growing an anonymous memory
+    /// file (e.g., `memfd_create()`) is the common way to give it a size
+    /// before mapping it.
+    ///
+    /// Lengths beyond `i64::MAX`, or beyond the limits of the file system,
+    /// are rejected with `EINVAL` and `EFBIG`, respectively.
+    ///
+    /// The 64-bit `length` is split into two registers on 32-bit
+    /// architectures, which use `ftruncate64()`. On ARM, an unused padding
+    /// argument precedes it, so it starts on an even register (as required
+    /// for register pairs by the EABI).
+    pub fn ftruncate(&self, fd: u32, length: u64) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let (length_lo, length_hi) = split64(length);
+
+        #[cfg(target_arch = "arm")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::FTRUNCATE64 as usize,
+                fd as usize,
+                0,
+                length_lo,
+                length_hi,
+            )
+        };
+
+        #[cfg(target_arch = "x86")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::FTRUNCATE64 as usize,
+                fd as usize,
+                length_lo,
+                length_hi,
+            )
+        };
+
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::FTRUNCATE as usize,
+                fd as usize,
+                length as usize,
+            )
+        };
+
+        result_from_retval(r).map(|_| ())
+    }
+
+    /// Query File-status Flags
+    ///
+    /// Return the file-status flags and access mode of the open
+    /// file-description referred to by `fd`. This is a convenience around
+    /// `fcntl(F_GETFL)`.
+    pub fn get_flags(&self, fd: u32) -> Result<u32, Errno> {
+        unsafe {
+            self.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFL, 0)
+                .map(|v| v as u32)
         }
+    }
 
-        let error_values = [
-            (4096, core::usize::MAX - 4095),
-            (4095, core::usize::MAX - 4094),
-            (4094, core::usize::MAX - 4093),
-            (4093, core::usize::MAX - 4092),
-            (4, core::usize::MAX - 3),
-            (3, core::usize::MAX - 2),
-            (2, core::usize::MAX - 1),
-            (1, core::usize::MAX),
-        ];
+    /// Read Directory Entries
+    ///
+    /// `fn sys_getdents64(fd: u32, dirp: *mut u8, count: u32) -> isize`
+    ///
+    /// Read as many entries of the directory `fd` as fit into the buffer of
+    /// `len` bytes at `buf`, and return the number of bytes written. Each
+    /// record starts with a `Dirent64` header, and the records are packed
+    /// back to back (see `dirent` for the layout). The position of the
+    /// open file-description is advanced accordingly. 0 is returned at the
+    /// end of the directory. If the buffer is too small to hold a single
+    /// record, `EINVAL` is returned.
+    ///
+    /// Lengths exceeding `u32::MAX` are clamped, since the kernel takes a
+    /// 32-bit count.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `buf` must be valid for writes of `len` bytes.
+    pub unsafe fn getdents64(&self, fd: u32, buf: *mut u8, len: usize) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::GETDENTS64 as usize,
+                    fd as usize,
+                    buf as usize,
+                    len.min(u32::MAX as usize),
+                )
+            }
+        )
+    }
 
-        for (c, v) in &error_values {
-            let r = result_from_retval(*v);
-            assert_eq!(r, Err(*c));
+    /// Query Effective Group ID
+    ///
+    /// `fn sys_getegid() -> u32`
+    ///
+    /// Return the effective group ID of the calling thread. It is used for
+    /// permission checks, together with the supplementary groups.
+    ///
+    /// On 32-bit ARM and x86, this uses `getegid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    pub fn getegid(&self) -> u32 {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::GETEGID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::GETEGID;
+
+        unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                &self.ffi,
+                nr as usize,
+            ) as u32
         }
     }
 
-    // Verify that `Syscall` instances can be created without context.
-    #[test]
-    fn syscall_creation() {
-        let _: Syscall = Syscall::new();
+    /// Query Effective User ID
+    ///
+    /// `fn sys_geteuid() -> u32`
+    ///
+    /// Return the effective user ID of the calling thread. It is used for
+    /// permission checks, and is 0 if the thread runs as root.
+    ///
+    /// On 32-bit ARM and x86, this uses `geteuid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    pub fn geteuid(&self) -> u32 {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::GETEUID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::GETEUID;
+
+        unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                &self.ffi,
+                nr as usize,
+            ) as u32
+        }
+    }
+
+    /// Query Group ID
+    ///
+    /// `fn sys_getgid() -> u32`
+    ///
+    /// Return the real group ID of the calling thread.
+    ///
+    /// On 32-bit ARM and x86, this uses `getgid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    pub fn getgid(&self) -> u32 {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::GETGID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::GETGID;
+
+        unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                &self.ffi,
+                nr as usize,
+            ) as u32
+        }
+    }
+
+    /// Query Supplementary Groups
+    ///
+    /// `fn sys_getgroups(size: i32, list: *mut u32) -> i32`
+    ///
+    /// Write the supplementary group IDs of the calling thread to `buf` and
+    /// return their number. If `buf` is empty, nothing is written and only
+    /// the number is returned (i.e., `getgroups(0, NULL)`), which can be
+    /// used to size the buffer. If `buf` is non-empty but too small to hold
+    /// all groups, `EINVAL` is returned. The effective group ID might or
+    /// might not be included in the list.
+    ///
+    /// On 32-bit ARM and x86, this uses `getgroups32()`, since the original
+    /// system call only supports 16-bit IDs.
+    pub fn getgroups(&self, buf: &mut [u32]) -> Result<usize, Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::GETGROUPS32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::GETGROUPS;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    nr as usize,
+                    buf.len().min(i32::MAX as usize),
+                    buf.as_mut_ptr() as usize,
+                )
+            }
+        )
+    }
+
+    /// Query Process Group
+    ///
+    /// `fn sys_getpgid(pid: i32) -> i32`
+    ///
+    /// Return the process-group ID of the process `pid`, or of the calling
+    /// process if `pid` is 0. Returns `ESRCH` if `pid` does not exist.
+    pub fn getpgid(&self, pid: i32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::GETPGID as usize,
+                    pid as isize as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Query Process ID
+    ///
+    /// `fn sys_getpid() -> i32`
+    ///
+    /// Return the ID of the thread group of the calling thread (i.e., the
+    /// process ID). This system call cannot fail.
+    pub fn getpid(&self) -> u32 {
+        unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::GETPID as usize,
+            ) as u32
+        }
+    }
+
+    /// Query Scheduling Priority
+    ///
+    /// `fn sys_getpriority(which: u32, who: u32) -> i32`
+    ///
+    /// Return the nice value of the target selected by `which` (i.e.,
+    /// `PRIO_*`) and `who`. For `PRIO_PROCESS`, `who` is a task ID, for
+    /// `PRIO_PGRP` a process group ID, and for `PRIO_USER` a user ID. A
+    /// `who` of 0 selects the calling task, its process group, or its real
+    /// user ID, respectively. If multiple tasks match, the highest priority
+    /// (i.e., lowest nice value) is returned.
+    ///
+    /// Unlike most system calls, the kernel does not return the nice value
+    /// (-20 to 19) directly, since it would collide with the error encoding
+    /// of negative return values. Instead, it returns `20 - nice` (i.e., 1
+    /// to 40), which this function translates back. Hence, the raw return
+    /// value must never be used as nice value. Note that the C library
+    /// function of the same name performs this translation as well, but
+    /// then cannot tell a nice value of -1 from an error, and callers must
+    /// clear and check `errno` separately. This function has no such
+    /// ambiguity.
+    ///
+    /// Note that the nice value is a property of each thread on linux, so
+    /// `PRIO_PROCESS` with a thread ID selects only that thread.
+    pub fn getpriority(&self, which: u32, who: u32) -> Result<i32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::GETPRIORITY as usize,
+                    which as usize,
+                    who as usize,
+                )
+            }
+        ).map(|v| 20 - v as i32)
+    }
+
+    /// Query Resource Usage
+    ///
+    /// `fn sys_getrusage(who: i32, ru: *mut Rusage) -> i32`
+    ///
+    /// Return the resource usage of the target selected by `who` (i.e.,
+    /// `RUSAGE_*`): the calling process (`RUSAGE_SELF`), the calling thread
+    /// (`RUSAGE_THREAD`), or all children of the caller that terminated and
+    /// were reaped (`RUSAGE_CHILDREN`). Any other target returns `EINVAL`.
+    ///
+    /// Linux leaves many fields of `Rusage` unmaintained, which always read
+    /// as 0. See its documentation for details.
+    pub fn getrusage(
+        &self,
+        who: i32,
+    ) -> Result<rt11_ffi_linux::native::resource::Rusage, Errno> {
+        let mut ru = rt11_ffi_linux::native::resource::Rusage::default();
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::GETRUSAGE as usize,
+                    who as isize as usize,
+                    &mut ru as *mut _ as usize,
+                )
+            }
+        ).map(|_| ru)
+    }
+
+    /// Query Session
+    ///
+    /// `fn sys_getsid(pid: i32) -> i32`
+    ///
+    /// Return the session ID (i.e., the process ID of the session leader) of
+    /// the process `pid`, or of the calling process if `pid` is 0. Returns
+    /// `ESRCH` if `pid` does not exist.
+    pub fn getsid(&self, pid: i32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::GETSID as usize,
+                    pid as isize as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Query Socket Option
+    ///
+    /// `fn sys_getsockopt(fd: u32, level: u32, optname: u32, optval: *mut u8, optlen: *mut u32) -> i32`
+    ///
+    /// Read the option `optname` of the protocol level `level` (e.g.,
+    /// `SO_*` of `SOL_SOCKET`) of the socket `fd` into the buffer at
+    /// `optval`. `optlen` must hold the size of the buffer on entry, and
+    /// holds the size of the option value on return. Unknown options are
+    /// rejected with `ENOPROTOOPT`.
+    ///
+    /// On x86, this is multiplexed through `socketcall()` as
+    /// `SYS_GETSOCKOPT`, since the direct system call was only added in
+    /// linux-4.3. All other architectures use the direct system call.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `optlen` must be valid for reads and writes, and `optval` must be
+    /// valid for writes of the number of bytes it holds.
+    pub unsafe fn getsockopt(
+        &self,
+        fd: u32,
+        level: u32,
+        optname: u32,
+        optval: *mut u8,
+        optlen: *mut u32,
+    ) -> Result<(), Errno> {
+        #[cfg(target_arch = "x86")]
+        let r = {
+            let args: [usize; 5] = [
+                fd as usize,
+                level as usize,
+                optname as usize,
+                optval as usize,
+                optlen as usize,
+            ];
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SOCKETCALL as usize,
+                    rt11_ffi_linux::native::socket::SYS_GETSOCKOPT as usize,
+                    args.as_ptr() as usize,
+                )
+            }
+        };
+
+        #[cfg(not(target_arch = "x86"))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::GETSOCKOPT as usize,
+                fd as usize,
+                level as usize,
+                optname as usize,
+                optval as usize,
+                optlen as usize,
+            )
+        };
+
+        result_from_retval(r).map(|_| ())
+    }
+
+    /// Query Thread ID
+    ///
+    /// `fn sys_gettid() -> i32`
+    ///
+    /// Return the ID of the calling thread. It equals the process ID for
+    /// the initial thread of a process. This system call cannot fail.
+    pub fn gettid(&self) -> u32 {
+        unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::GETTID as usize,
+            ) as u32
+        }
+    }
+
+    /// Query User ID
+    ///
+    /// `fn sys_getuid() -> u32`
+    ///
+    /// Return the real user ID of the calling thread.
+    ///
+    /// On 32-bit ARM and x86, this uses `getuid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    pub fn getuid(&self) -> u32 {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::GETUID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::GETUID;
+
+        unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                &self.ffi,
+                nr as usize,
+            ) as u32
+        }
+    }
+
+    /// Get Extended Attribute
+    ///
+    /// `fn sys_getxattr(path: *const u8, name: *const u8, value: *mut u8, size: usize) -> isize`
+    ///
+    /// Read the value of the extended attribute `name` (including its
+    /// namespace prefix, e.g., `user.`) of the file at `path` into `value`,
+    /// and return its size. Symbolic links are followed.
+    ///
+    /// If `value` is empty, nothing is read, but the current size of the
+    /// value is returned, so callers can size their buffer. The value can
+    /// change between both calls, though. If `value` is too small for the
+    /// value, `ERANGE` is returned. If the file has no such attribute,
+    /// `ENODATA` is returned. If the file system does not support extended
+    /// attributes (or the namespace), `EOPNOTSUPP` is returned.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` and `name` must point to valid NUL-terminated strings.
+    pub unsafe fn getxattr(
+        &self,
+        path: *const u8,
+        name: *const u8,
+        value: &mut [u8],
+    ) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::GETXATTR as usize,
+                    path as usize,
+                    name as usize,
+                    value.as_mut_ptr() as usize,
+                    value.len(),
+                )
+            }
+        )
+    }
+
+    /// Add Inotify Watch
+    ///
+    /// `fn sys_inotify_add_watch(fd: u32, path: *const u8, mask: u32) -> i32`
+    ///
+    /// Add a watch for the file-system object at `path` to the inotify
+    /// instance `fd`, or modify the existing watch of the object. `mask`
+    /// selects the events to report (i.e., `IN_*`). Symbolic links are
+    /// followed, unless `IN_DONT_FOLLOW` is given.
+    ///
+    /// On success, the watch descriptor is returned, which identifies the
+    /// watch in events read from `fd`. Watch descriptors are only unique
+    /// within a single inotify instance.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string.
+    pub unsafe fn inotify_add_watch(
+        &self,
+        fd: u32,
+        path: *const u8,
+        mask: u32,
+    ) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::INOTIFY_ADD_WATCH as usize,
+                    fd as usize,
+                    path as usize,
+                    mask as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Create Inotify Instance
+    ///
+    /// `fn sys_inotify_init1(flags: u32) -> i32`
+    ///
+    /// Create a new inotify instance and return a file-descriptor referring
+    /// to it. `flags` can contain `IN_CLOEXEC` and `IN_NONBLOCK`.
+    ///
+    /// Events are retrieved by calling `read()` on the file-descriptor. The
+    /// data can be interpreted via `inotify::EventIter`. Note that a read
+    /// fails with `EINVAL` if the buffer is too small for the next event.
+    pub fn inotify_init1(&self, flags: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::INOTIFY_INIT1 as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Remove Inotify Watch
+    ///
+    /// `fn sys_inotify_rm_watch(fd: u32, wd: i32) -> i32`
+    ///
+    /// Remove the watch `wd` from the inotify instance `fd`. An `IN_IGNORED`
+    /// event is generated for the watch.
+    pub fn inotify_rm_watch(&self, fd: u32, wd: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::INOTIFY_RM_WATCH as usize,
+                    fd as usize,
+                    wd as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Enter I/O Ring
+    ///
+    /// `fn sys_io_uring_enter(fd: u32, to_submit: u32, min_complete: u32, flags: u32, arg: *const c_void, argsz: usize) -> i32`
+    ///
+    /// Submit up to `to_submit` entries of the submission queue of the ring
+    /// `fd`, and return the number of entries consumed. With
+    /// `IORING_ENTER_GETEVENTS`, this then waits until at least
+    /// `min_complete` entries are available in the completion queue.
+    ///
+    /// `arg` and `argsz` are only used with `IORING_ENTER_EXT_ARG` (where
+    /// `arg` points to the extended arguments, including a timeout) and
+    /// must be NULL and 0 otherwise. Note that submission and completion
+    /// entries are not passed via this system call, but via the shared
+    /// rings.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The kernel reads the submission entries from the shared rings, and
+    /// performs the requested operations on the memory referenced by them.
+    /// The caller must ensure all submitted entries reference valid memory
+    /// until their completion is reaped. Furthermore, `arg` must be valid
+    /// for `argsz` bytes, if used.
+    pub unsafe fn io_uring_enter(
+        &self,
+        fd: u32,
+        to_submit: u32,
+        min_complete: u32,
+        flags: u32,
+        arg: *const core::ffi::c_void,
+        argsz: usize,
+    ) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall6(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::IO_URING_ENTER as usize,
+                    fd as usize,
+                    to_submit as usize,
+                    min_complete as usize,
+                    flags as usize,
+                    arg as usize,
+                    argsz,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Register I/O Ring Resources
+    ///
+    /// `fn sys_io_uring_register(fd: u32, opcode: u32, arg: *const c_void, nr_args: u32) -> i32`
+    ///
+    /// Register or unregister resources with the ring `fd`, as selected by
+    /// `opcode` (i.e., `IORING_REGISTER_*` and `IORING_UNREGISTER_*`). The
+    /// meaning of `arg` and `nr_args` depends on the operation (e.g., an
+    /// array of `nr_args` file-descriptors for `IORING_REGISTER_FILES`).
+    /// The return value depends on the operation as well.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `arg` must be valid as required by `opcode`. Registered buffers are
+    /// accessed by the kernel until they are unregistered, so the caller
+    /// must keep them valid until then.
+    pub unsafe fn io_uring_register(
+        &self,
+        fd: u32,
+        opcode: u32,
+        arg: *const core::ffi::c_void,
+        nr_args: u32,
+    ) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::IO_URING_REGISTER as usize,
+                    fd as usize,
+                    opcode as usize,
+                    arg as usize,
+                    nr_args as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Set Up I/O Ring
+    ///
+    /// `fn sys_io_uring_setup(entries: u32, params: *mut IoUringParams) -> i32`
+    ///
+    /// Create a new I/O ring with at least `entries` submission queue
+    /// entries and return a file-descriptor referring to it. The caller
+    /// selects options via `params` (in particular `flags`, i.e.,
+    /// `IORING_SETUP_*`), and the kernel fills in the remaining fields.
+    ///
+    /// The rings are not mapped by this call. Instead, the caller maps them
+    /// via `mmap()` with `MAP_SHARED` on the returned file-descriptor, at the
+    /// fixed offsets `IORING_OFF_SQ_RING`, `IORING_OFF_CQ_RING`, and
+    /// `IORING_OFF_SQES`. The layout of the rings within those mappings is
+    /// described by `params.sq_off` and `params.cq_off`. If
+    /// `IORING_FEAT_SINGLE_MMAP` is reported, both rings share a single
+    /// mapping at `IORING_OFF_SQ_RING`.
+    ///
+    /// This returns `EPERM` or `ENOSYS` if `io_uring` is disabled on the
+    /// system (e.g., via the `kernel.io_uring_disabled` sysctl).
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// With `IORING_SETUP_NO_MMAP`, `params.sq_off.user_addr` and
+    /// `params.cq_off.user_addr` point to caller provided memory which is
+    /// used for the rings. The caller must keep it valid for the lifetime of
+    /// the ring.
+    pub unsafe fn io_uring_setup(
+        &self,
+        entries: u32,
+        params: &mut rt11_ffi_linux::native::io_uring::IoUringParams,
+    ) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::IO_URING_SETUP as usize,
+                    entries as usize,
+                    params as *mut _ as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Device Control
+    ///
+    /// `fn sys_ioctl(fd: u32, request: usize, arg: usize) -> i32`
+    ///
+    /// Perform the device-specific operation `request` on the file
+    /// referred to by `fd`. Depending on `request`, `arg` is either a value
+    /// or a pointer to a request-specific structure, which is read or
+    /// written by the kernel. `ENOTTY` is returned if the file does not
+    /// support `request`.
+    ///
+    /// Request numbers are assigned per driver, and some of them differ
+    /// across architectures (e.g., the terminal requests on alpha, mips,
+    /// powerpc, or sparc). Only use request numbers of the native
+    /// architecture (e.g., `termios` of `rt11_ffi_linux::native`).
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Depending on `request`, `arg` must point to a valid structure of the
+    /// size expected by the kernel. Since request numbers are not globally
+    /// unique, the caller must also ensure `fd` refers to the expected type
+    /// of file.
+    pub unsafe fn ioctl(&self, fd: u32, request: usize, arg: usize) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::IOCTL as usize,
+                    fd as usize,
+                    request,
+                    arg,
+                )
+            }
+        )
+    }
+
+    /// Check for Terminal
+    ///
+    /// Return whether `fd` refers to a terminal. This queries the terminal
+    /// attributes via `ioctl(TCGETS)`, which only succeeds on terminals.
+    /// Any error (including `EBADF`) is treated as no terminal.
+    pub fn isatty(&self, fd: u32) -> bool {
+        // The kernel `struct termios` is 36 bytes on all supported
+        // architectures. Use a larger buffer to be safe.
+        let mut termios = [0u32; 16];
+
+        unsafe {
+            self.ioctl(
+                fd,
+                rt11_ffi_linux::native::termios::TCGETS as usize,
+                termios.as_mut_ptr() as usize,
+            ).is_ok()
+        }
+    }
+
+    /// Send Signal
+    ///
+    /// `fn sys_kill(pid: i32, sig: u32) -> i32`
+    ///
+    /// Send the signal `sig` (i.e., `SIG*`) to the target selected by `pid`:
+    ///
+    ///  * `pid > 0`: The process with the ID `pid`.
+    ///  * `pid == 0`: All processes in the process group of the caller.
+    ///  * `pid == -1`: All processes the caller may signal, except for the
+    ///    init task and the caller itself.
+    ///  * `pid < -1`: All processes in the process group `-pid`.
+    ///
+    /// If `sig` is 0, no signal is sent, but the permission and existence
+    /// checks are still performed. This returns `Ok` if the target exists
+    /// and the caller may signal it, `EPERM` if it exists but the caller
+    /// lacks permission, and `ESRCH` if it does not exist.
+    ///
+    /// Note that process IDs are recycled. Unless the target is a child of
+    /// the caller that was not yet reaped, the ID might refer to an
+    /// unrelated process by the time the signal is sent. Use pidfds for
+    /// race-free signaling.
+    pub fn kill(&self, pid: i32, sig: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::KILL as usize,
+                    pid as isize as usize,
+                    sig as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Get Extended Attribute without Following Links
+    ///
+    /// `fn sys_lgetxattr(path: *const u8, name: *const u8, value: *mut u8, size: usize) -> isize`
+    ///
+    /// This is the same as `getxattr()`, but operates on a symbolic link at
+    /// `path` itself, rather than on its target.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` and `name` must point to valid NUL-terminated strings.
+    pub unsafe fn lgetxattr(
+        &self,
+        path: *const u8,
+        name: *const u8,
+        value: &mut [u8],
+    ) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::LGETXATTR as usize,
+                    path as usize,
+                    name as usize,
+                    value.as_mut_ptr() as usize,
+                    value.len(),
+                )
+            }
+        )
+    }
+
+    /// List Extended Attributes
+    ///
+    /// `fn sys_listxattr(path: *const u8, list: *mut u8, size: usize) -> isize`
+    ///
+    /// Write the names of all extended attributes of the file at `path`
+    /// into `list`, and return the number of bytes written. Symbolic links
+    /// are followed. The names are stored back to back, each terminated by
+    /// NUL. Names of attributes the caller has no access to (e.g., in the
+    /// `trusted.` namespace) are omitted.
+    ///
+    /// If `list` is empty, nothing is written, but the required size is
+    /// returned, like for `getxattr()`. If `list` is too small, `ERANGE` is
+    /// returned.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string.
+    pub unsafe fn listxattr(&self, path: *const u8, list: &mut [u8]) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::LISTXATTR as usize,
+                    path as usize,
+                    list.as_mut_ptr() as usize,
+                    list.len(),
+                )
+            }
+        )
+    }
+
+    /// List Extended Attributes without Following Links
+    ///
+    /// `fn sys_llistxattr(path: *const u8, list: *mut u8, size: usize) -> isize`
+    ///
+    /// This is the same as `listxattr()`, but operates on a symbolic link at
+    /// `path` itself, rather than on its target.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string.
+    pub unsafe fn llistxattr(&self, path: *const u8, list: &mut [u8]) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::LLISTXATTR as usize,
+                    path as usize,
+                    list.as_mut_ptr() as usize,
+                    list.len(),
+                )
+            }
+        )
+    }
+
+    /// Reposition File Offset
+    ///
+    /// `fn sys_lseek(fd: u32, offset: isize, whence: u32) -> isize`
+    ///
+    /// Move the file offset of `fd` to `offset`, relative to the position
+    /// selected by `whence` (e.g., `SEEK_SET` or `SEEK_END`), and return the
+    /// resulting offset from the start of the file. Pipes and sockets
+    /// cannot be repositioned and yield `ESPIPE`.
+    ///
+    /// Offsets are native words, so on 32-bit platforms files beyond 2 GiB
+    /// cannot be addressed via this call.
+    pub fn lseek(&self, fd: u32, offset: isize, whence: u32) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::LSEEK as usize,
+                    fd as usize,
+                    offset as usize,
+                    whence as usize,
+                )
+            }
+        )
+    }
+
+    /// Set Extended Attribute without Following Links
+    ///
+    /// `fn sys_lsetxattr(path: *const u8, name: *const u8, value: *const u8, size: usize, flags: u32) -> i32`
+    ///
+    /// This is the same as `setxattr()`, but operates on a symbolic link at
+    /// `path` itself, rather than on its target. Note that the `user.`
+    /// namespace is not supported on symbolic links.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` and `name` must point to valid NUL-terminated strings.
+    pub unsafe fn lsetxattr(
+        &self,
+        path: *const u8,
+        name: *const u8,
+        value: &[u8],
+        flags: u32,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::LSETXATTR as usize,
+                    path as usize,
+                    name as usize,
+                    value.as_ptr() as usize,
+                    value.len(),
+                    flags as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Advise on Memory Usage
+    ///
+    /// `fn sys_madvise(addr: usize, len: usize, advice: i32) -> i32`
+    ///
+    /// Advise the kernel about the expected use of the range `addr` to
+    /// `addr + len` (i.e., `MADV_*`). `addr` must be aligned to the page
+    /// size, and `len` is rounded up to it. `ENOMEM` is returned if the
+    /// range is not fully mapped, but the advice is still applied to the
+    /// mapped parts.
+    ///
+    /// Most advice is a hint only. `MADV_COLD` deactivates the range, and
+    /// `MADV_PAGEOUT` reclaims it right away, both preserving the contents.
+    /// `MADV_FREE` lets the kernel free the range lazily, and the contents
+    /// are lost if it does so before the range is written again.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Some advice modifies the contents of the range (e.g., `MADV_DONTNEED`
+    /// or `MADV_FREE` on private mappings, or `MADV_REMOVE`), or its
+    /// behavior across `fork()` (e.g., `MADV_DONTFORK`). The caller must
+    /// ensure the range is not in use by anything relying on it.
+    pub unsafe fn madvise(&self, addr: usize, len: usize, advice: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::MADVISE as usize,
+                    addr,
+                    len,
+                    advice as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Create Anonymous Memory File
+    ///
+    /// `fn sys_memfd_create(name: *const u8, flags: u32) -> i32`
+    ///
+    /// Create an anonymous file that lives in memory, and return a
+    /// file-descriptor to it. The file is empty, and behaves like a regular
+    /// file otherwise (i.e., it can be sized, read, written, and mapped).
+    /// `name` is only used for display purposes (e.g., in `/proc/self/fd/`),
+    /// and need not be unique. `flags` is a combination of `MFD_*`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `name` must point to a valid NUL-terminated string.
+    pub unsafe fn memfd_create(&self, name: *const u8, flags: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::MEMFD_CREATE as usize,
+                    name as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Create Secret Memory File
+    ///
+    /// `fn sys_memfd_secret(flags: u32) -> i32`
+    ///
+    /// Create an anonymous memory file whose pages are only accessible to
+    /// the mappings of the calling process, and return a file-descriptor to
+    /// it. `flags` can be `O_CLOEXEC`, anything else is rejected with
+    /// `EINVAL`. The file is empty, and must be sized via `ftruncate()`
+    /// before it can be mapped via `mmap()` with `MAP_SHARED`. It is meant
+    /// to hold sensitive data like key material.
+    ///
+    /// The pages of the file are removed from the direct map of the kernel,
+    /// so they cannot be read via kernel memory (e.g., by a compromised
+    /// driver). They are also locked in memory (and thus charged against
+    /// `RLIMIT_MEMLOCK`), excluded from core dumps, and cannot be accessed
+    /// via `read()`, `write()`, or `process_vm_readv()`.
+    ///
+    /// This system call was introduced in linux-5.14, but is only available
+    /// if enabled at boot (i.e., `secretmem.enable=1`, the default since
+    /// linux-6.5). Otherwise, `ENOSYS` is returned. ARM does not provide
+    /// this system call, so `ENOSYS` is always returned there.
+    pub fn memfd_secret(&self, flags: u32) -> Result<u32, Errno> {
+        #[cfg(target_arch = "arm")]
+        let r: Result<usize, Errno> = {
+            let _ = flags;
+            Err(rt11_ffi_linux::native::errno::ENOSYS)
+        };
+
+        #[cfg(not(target_arch = "arm"))]
+        let r = result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::MEMFD_SECRET as usize,
+                    flags as usize,
+                )
+            }
+        );
+
+        r.map(|v| v as u32)
+    }
+
+    /// Query Page Residency
+    ///
+    /// `fn sys_mincore(addr: usize, len: usize, vec: *mut u8) -> i32`
+    ///
+    /// Query which pages of the range `addr` to `addr + len` are resident
+    /// in memory (i.e., would not fault on access). `vec` receives one byte
+    /// per page, of which the lowest bit is set if the page is resident.
+    /// The remaining bits are reserved and must be ignored.
+    ///
+    /// `addr` must be aligned to the page size, or `EINVAL` is returned.
+    /// `len` is rounded up to the page size. `ENOMEM` is returned if the
+    /// range is not fully mapped.
+    ///
+    /// Note that the result is only a snapshot, and can be outdated by the
+    /// time it is returned. For file mappings, residency refers to the page
+    /// cache, and does not imply the page was faulted into the mapping.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `vec` must point to at least `ceil(len / page_size)` writable bytes.
+    pub unsafe fn mincore(&self, addr: usize, len: usize, vec: *mut u8) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::MINCORE as usize,
+                    addr,
+                    len,
+                    vec as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Map Memory
+    ///
+    /// `fn sys_mmap(addr: usize, len: usize, prot: u32, flags: u32, fd: i32, offset: usize) -> usize`
+    ///
+    /// Create a new memory mapping of `len` bytes with the protection `prot`
+    /// (i.e., `PROT_*`) and return its address. `flags` (i.e., `MAP_*`)
+    /// must include exactly one of `MAP_PRIVATE`, `MAP_SHARED`, or
+    /// `MAP_SHARED_VALIDATE`. Unless `MAP_ANONYMOUS` is passed, the mapping
+    /// is backed by the file `fd` starting at `offset`, which must be
+    /// aligned to the page size. Otherwise, `fd` should be -1 and the
+    /// mapping is zero-filled.
+    ///
+    /// Unless `MAP_FIXED` or `MAP_FIXED_NOREPLACE` is passed, `addr` is only
+    /// a hint, and the kernel picks (and randomizes) the address. `len` is
+    /// rounded up to the page size.
+    ///
+    /// WARNING: The raw system call differs between architectures! On 32-bit
+    /// architectures (i.e., x86 and ARM), this uses `mmap2()`, which takes
+    /// the offset in units of 4096 bytes (regardless of the page size),
+    /// rather than in bytes. 64-bit architectures have no `mmap2()` (it is
+    /// not part of their syscall tables), and `mmap()` takes the offset in
+    /// bytes. This wrapper always takes a byte offset and converts it as
+    /// required. Passing a byte offset to `mmap2()` (or vice versa) would
+    /// silently map the wrong part of the file.
+    ///
+    /// Offsets that are not a multiple of 4096, or exceed the range of
+    /// `mmap2()`, are rejected with `EINVAL`, rather than being truncated.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// With `MAP_FIXED`, any existing mapping in the range is silently
+    /// replaced. The caller must ensure the range is not in use.
+    pub unsafe fn mmap(
+        &self,
+        addr: usize,
+        len: usize,
+        prot: u32,
+        flags: u32,
+        fd: i32,
+        offset: u64,
+    ) -> Result<usize, Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let (nr, off) = {
+            if offset & 4095 != 0 || offset / 4096 > u32::MAX as u64 {
+                return Err(rt11_ffi_linux::native::errno::EINVAL);
+            }
+            (rt11_ffi_linux::native::nr::MMAP2, (offset / 4096) as usize)
+        };
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let (nr, off) = (rt11_ffi_linux::native::nr::MMAP, offset as usize);
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall6(
+                    &self.ffi,
+                    nr as usize,
+                    addr,
+                    len,
+                    prot as usize,
+                    flags as usize,
+                    fd as isize as usize,
+                    off,
+                )
+            }
+        )
+    }
+
+    /// Read Monotonic Clock in Nanoseconds
+    ///
+    /// Read `CLOCK_MONOTONIC` via `clock_gettime()` and return its value in
+    /// nanoseconds. The clock starts at an unspecified point (usually boot)
+    /// and is not affected by changes of the system time, but it does not
+    /// advance while the system is suspended. It is thus suitable to
+    /// measure durations, by subtracting two values.
+    ///
+    /// A `u64` covers more than 584 years of nanoseconds. Nevertheless, the
+    /// conversion is checked, and `EOVERFLOW` is returned if the value
+    /// cannot be represented (or is negative).
+    pub fn monotonic_nanos(&self) -> Result<u64, Errno> {
+        let tp = self.clock_gettime(rt11_ffi_linux::native::time::CLOCK_MONOTONIC)?;
+
+        u64::try_from(tp.tv_sec)
+            .ok()
+            .and_then(|v| v.checked_mul(1_000_000_000))
+            .and_then(|v| v.checked_add(u64::try_from(tp.tv_nsec).ok()?))
+            .ok_or(rt11_ffi_linux::native::errno::EOVERFLOW)
+    }
+
+    /// Mount File System
+    ///
+    /// `fn sys_mount(source: *const u8, target: *const u8, fstype: *const u8, flags: usize, data: *const u8) -> i32`
+    ///
+    /// Attach a file system to the directory `target`. `flags` (i.e.,
+    /// `MS_*`) select the operation to perform:
+    ///
+    ///  * By default, a new instance of the file system type `fstype` is
+    ///    mounted. `source` is interpreted by the file system (e.g., a
+    ///    block device), and `data` carries file-system specific options as
+    ///    a NUL-terminated string (e.g., `"mode=0755"`).
+    ///
+    ///  * `MS_BIND`: The tree at `source` is mounted at `target`. `MS_REC`
+    ///    includes all mounts below `source`. `fstype` and `data` are
+    ///    ignored, and so are most per-mount options in `flags`. Hence, a
+    ///    bind mount cannot be made read-only directly. It must be remounted
+    ///    afterwards with `MS_REMOUNT | MS_BIND | MS_RDONLY`.
+    ///
+    ///  * `MS_REMOUNT`: The per-mount options of the existing mount at
+    ///    `target` are replaced. Combined with `MS_BIND`, only the per-mount
+    ///    options are changed, but not the options of the file system (which
+    ///    is shared by all its mounts).
+    ///
+    ///  * `MS_SHARED`, `MS_PRIVATE`, `MS_SLAVE`, `MS_UNBINDABLE`: The
+    ///    propagation type of the mount at `target` is changed. `MS_REC`
+    ///    applies it to all mounts below `target`. `source`, `fstype`, and
+    ///    `data` are ignored.
+    ///
+    ///  * `MS_MOVE`: The mount at `source` is moved to `target`.
+    ///
+    /// Any pointer that is ignored by the operation can be NULL. This
+    /// requires `CAP_SYS_ADMIN` in the user namespace owning the mount
+    /// namespace of the caller. Note that new mounts propagate to peer mount
+    /// namespaces, unless the target is private (see `unshare()`).
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `source`, `target`, `fstype`, and `data` must be NULL or point to
+    /// valid NUL-terminated strings, if required by the operation.
+    /// Furthermore, mounts change how all tasks of the mount namespace
+    /// resolve paths. The caller must ensure this is intended.
+    pub unsafe fn mount(
+        &self,
+        source: *const u8,
+        target: *const u8,
+        fstype: *const u8,
+        flags: u32,
+        data: *const u8,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::MOUNT as usize,
+                    source as usize,
+                    target as usize,
+                    fstype as usize,
+                    flags as usize,
+                    data as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Memory Protection
+    ///
+    /// `fn sys_mprotect(addr: usize, len: usize, prot: u32) -> i32`
+    ///
+    /// Change the access protection of the pages in the range `addr` to
+    /// `addr + len` to `prot` (i.e., `PROT_*`). `addr` must be aligned to the
+    /// page size, while `len` is rounded up to it. `ENOMEM` is returned if
+    /// the range is not fully mapped, and `EACCES` if the protection is not
+    /// permitted by the mapping (e.g., `PROT_WRITE` on a read-only shared
+    /// file mapping).
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The caller must ensure no code accesses the range in a way that is no
+    /// longer permitted, including code and data of the caller itself.
+    pub unsafe fn mprotect(&self, addr: usize, len: usize, prot: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::MPROTECT as usize,
+                    addr,
+                    len,
+                    prot as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Unmap Memory
+    ///
+    /// `fn sys_munmap(addr: usize, len: usize) -> i32`
+    ///
+    /// Remove all mappings in the range `addr` to `addr + len`. `addr` must
+    /// be aligned to the page size, while `len` is rounded up to it. Parts
+    /// of the range that are not mapped are ignored.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Any later access to the range faults (or hits an unrelated mapping
+    /// created later). The caller must ensure the range is no longer used,
+    /// including by references held elsewhere.
+    pub unsafe fn munmap(&self, addr: usize, len: usize) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::MUNMAP as usize,
+                    addr,
+                    len,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Open File
+    ///
+    /// Open the file at `path` and return a new file-descriptor for it. This
+    /// is the same as `openat()` with `AT_FDCWD`, so a relative `path` is
+    /// resolved relative to the current working directory.
+    ///
+    /// The legacy `open(2)` system call is only available on some
+    /// architectures (e.g., x86, x86-64, and ARM), while `openat(2)` is
+    /// available on all supported architectures (and all kernels since
+    /// 2.6.16). Hence, this always uses `openat(2)`, which provides the
+    /// same semantics with `AT_FDCWD`, and there is no need to select the
+    /// system call per architecture.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `openat()`.
+    pub unsafe fn open(&self, path: *const u8, flags: u32, mode: u32) -> Result<u32, Errno> {
+        unsafe { self.openat(rt11_ffi_linux::native::fcntl::AT_FDCWD, path, flags, mode) }
+    }
+
+    /// Open File Relative to Directory
+    ///
+    /// `fn sys_openat(dirfd: i32, path: *const u8, flags: u32, mode: u32) -> i32`
+    ///
+    /// Open the file at `path` and return a new file-descriptor for it. A
+    /// relative `path` is resolved relative to the directory referred to by
+    /// `dirfd`, or relative to the current working directory if `dirfd` is
+    /// `AT_FDCWD`. An absolute `path` ignores `dirfd`.
+    ///
+    /// `flags` is a combination of the file-status flags (i.e., `O_*`),
+    /// whose values diverge across architectures. `mode` is the permission
+    /// set of a new file and is ignored unless a file is created.
+    ///
+    /// Note that on 32-bit architectures `O_LARGEFILE` must be passed
+    /// explicitly, or the kernel refuses to open files larger than 2GiB
+    /// with `EOVERFLOW`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string. Furthermore, the
+    /// returned file-descriptor is owned by the caller and must be closed.
+    pub unsafe fn openat(
+        &self,
+        dirfd: i32,
+        path: *const u8,
+        flags: u32,
+        mode: u32,
+    ) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::OPENAT as usize,
+                    dirfd as isize as usize,
+                    path as usize,
+                    flags as usize,
+                    mode as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Set Process Execution Domain
+    ///
+    /// `fn sys_personality(persona: u32) -> i32`
+    ///
+    /// Change the execution domain of the calling task to `persona`. The
+    /// lower byte selects the base personality (usually `PER_LINUX`), the
+    /// remaining bits are flags that tweak the behavior of the kernel. Most
+    /// notably, `ADDR_NO_RANDOMIZE` disables address-space randomization,
+    /// `ADDR_COMPAT_LAYOUT` selects the legacy memory layout, and
+    /// `READ_IMPLIES_EXEC` makes all readable mappings executable. See the
+    /// `personality` module of the architecture for all flags.
+    ///
+    /// Note that the memory layout of a task is determined when an executable
+    /// is loaded. Hence, most flags only take effect on the next `execve()`.
+    /// That is, to load a child without ASLR, the flag must be set in the
+    /// child before it executes the target binary.
+    ///
+    /// If `0xffffffff` is passed as `persona`, the personality is queried but
+    /// left unchanged.
+    ///
+    /// On success, this returns the _previous_ persona of the task, not the
+    /// newly set persona. If the persona is not supported by the kernel,
+    /// `EINVAL` is returned and the personality is left unchanged.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Some flags change the behavior of the running task immediately. Most
+    /// notably, `READ_IMPLIES_EXEC` affects all following memory mappings,
+    /// and `STICKY_TIMEOUTS` or `SHORT_INODE` change the semantics of other
+    /// system calls. The caller must ensure that the calling task (and all
+    /// code running in it) can cope with the new persona.
+    pub unsafe fn personality(&self, persona: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PERSONALITY as usize,
+                    persona as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Duplicate File-descriptor of Another Process
+    ///
+    /// `fn sys_pidfd_getfd(pidfd: u32, targetfd: u32, flags: u32) -> i32`
+    ///
+    /// Duplicate the file-descriptor `targetfd` of the process referred to
+    /// by `pidfd` into the calling process, and return the new
+    /// file-descriptor. It refers to the same open file-description as the
+    /// original, and has `FD_CLOEXEC` set. `flags` is reserved and must be 0.
+    ///
+    /// This allows stealing file-descriptors from other processes, and thus
+    /// requires ptrace-access to the target (i.e., `PTRACE_MODE_ATTACH`),
+    /// or `EPERM` is returned.
+    pub fn pidfd_getfd(&self, pidfd: u32, targetfd: u32, flags: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PIDFD_GETFD as usize,
+                    pidfd as usize,
+                    targetfd as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Open Process File-descriptor
+    ///
+    /// `fn sys_pidfd_open(pid: i32, flags: u32) -> i32`
+    ///
+    /// Create a pidfd referring to the process `pid` and return it. `flags`
+    /// can be a combination of `PIDFD_NONBLOCK` and `PIDFD_THREAD`. The
+    /// latter allows `pid` to be a thread rather than a process. The new
+    /// file-descriptor has `FD_CLOEXEC` set.
+    ///
+    /// Unlike a process ID, a pidfd always refers to the same process, even
+    /// after it exited and its ID was recycled. It becomes readable once the
+    /// process exits, so it can be polled for process exit. It can be used
+    /// with `pidfd_send_signal()`, `pidfd_getfd()`, `setns()`, and
+    /// `waitid(P_PIDFD)`.
+    ///
+    /// Note that `pid` is still subject to recycling until this call
+    /// returns. Hence, this is only race-free if `pid` is a child of the
+    /// caller that was not yet reaped, or if the caller verifies the identity
+    /// of the process afterwards. Returns `ESRCH` if `pid` does not exist.
+    pub fn pidfd_open(&self, pid: i32, flags: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PIDFD_OPEN as usize,
+                    pid as isize as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Send Signal via Process File-descriptor
+    ///
+    /// `fn sys_pidfd_send_signal(pidfd: u32, sig: u32, info: *const SigInfo, flags: u32) -> i32`
+    ///
+    /// Send the signal `sig` (i.e., `SIG*`) to the process referred to by
+    /// `pidfd`. Unlike `kill()`, this cannot hit an unrelated process that
+    /// reused the process ID. Returns `ESRCH` if the process already exited.
+    ///
+    /// If `info` is NULL, the signal information is filled in as if sent via
+    /// `kill()`. Otherwise, it points to the signal information to be
+    /// delivered. `flags` is reserved and must be 0, unless the pidfd was
+    /// opened with `PIDFD_THREAD` (in which case it selects the scope of the
+    /// signal).
+    ///
+    /// As with `kill()`, a `sig` of 0 only performs the existence and
+    /// permission checks.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `info` must be NULL or point to a valid signal information structure
+    /// of the platform (i.e., `siginfo_t`).
+    pub unsafe fn pidfd_send_signal(
+        &self,
+        pidfd: u32,
+        sig: u32,
+        info: *const core::ffi::c_void,
+        flags: u32,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PIDFD_SEND_SIGNAL as usize,
+                    pidfd as usize,
+                    sig as usize,
+                    info as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Create Pipe
+    ///
+    /// `fn sys_pipe2(fds: *mut [u32; 2], flags: u32) -> i32`
+    ///
+    /// Create a unidirectional data channel, and return a file-descriptor
+    /// to its read end and one to its write end, in that order. `flags` is
+    /// a combination of `O_CLOEXEC`, `O_DIRECT`, and `O_NONBLOCK`, which
+    /// apply to both file-descriptors.
+    pub fn pipe2(&self, flags: u32) -> Result<[u32; 2], Errno> {
+        let mut fds: [u32; 2] = [0, 0];
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PIPE2 as usize,
+                    fds.as_mut_ptr() as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|_| fds)
+    }
+
+    /// Poll File-descriptors
+    ///
+    /// `fn sys_ppoll(fds: *mut PollFd, nfds: u32, tsp: *const Timespec, sigmask: *const SigSet, sigsetsize: usize) -> i32`
+    ///
+    /// Wait for any of the `nfds` file-descriptors in `fds` to become ready
+    /// for the requested events, and return the number of entries with
+    /// non-zero `revents`. The kernel fills in `revents` of all entries.
+    ///
+    /// If `timeout` is NULL, this waits indefinitely. Otherwise, it waits at
+    /// most for the given relative time, and returns 0 if it elapsed. If
+    /// `sigmask` is non-NULL, the signal mask of the calling thread is
+    /// replaced by `sigmask` for the duration of the wait.
+    ///
+    /// On 32-bit architectures, this uses `ppoll_time64(2)`, which is
+    /// available since linux-5.1.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `fds` must point to an array of at least `nfds` elements. `timeout`
+    /// and `sigmask` must be NULL or point to a valid `Timespec` and signal
+    /// set, respectively.
+    pub unsafe fn ppoll(
+        &self,
+        fds: *mut rt11_ffi_linux::native::poll::PollFd,
+        nfds: usize,
+        timeout: *const rt11_ffi_linux::native::time::Timespec,
+        sigmask: *const u64,
+    ) -> Result<usize, Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::PPOLL_TIME64;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::PPOLL;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    nr as usize,
+                    fds as usize,
+                    nfds,
+                    timeout as usize,
+                    sigmask as usize,
+                    core::mem::size_of::<u64>(),
+                )
+            }
+        )
+    }
+
+    /// Process Control
+    ///
+    /// `fn sys_prctl(option: i32, arg2: usize, arg3: usize, arg4: usize, arg5: usize) -> i32`
+    ///
+    /// Query or modify attributes of the calling task. The operation is
+    /// selected by `option` (i.e., `PR_*`), and each operation interprets the
+    /// remaining arguments differently. Unused arguments should be 0, since
+    /// some operations return `EINVAL` otherwise.
+    ///
+    /// Most attributes are a property of the calling thread, rather than its
+    /// process. See the individual operations for details.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Depending on `option`, the arguments are pointers which are read or
+    /// written by the kernel. Furthermore, some operations change the
+    /// behavior of the task in ways the caller must be prepared for. See
+    /// `set_pdeathsig()` for a safe helper.
+    pub unsafe fn prctl(
+        &self,
+        option: u32,
+        arg2: usize,
+        arg3: usize,
+        arg4: usize,
+        arg5: usize,
+    ) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PRCTL as usize,
+                    option as usize,
+                    arg2,
+                    arg3,
+                    arg4,
+                    arg5,
+                )
+            }
+        )
+    }
+
+    /// Get and Set Resource Limits
+    ///
+    /// `fn sys_prlimit64(pid: u32, resource: u32, new: *const Rlimit64, old: *mut Rlimit64) -> i32`
+    ///
+    /// Query and modify the resource limit `resource` (i.e., `RLIMIT_*`) of
+    /// the task `pid`, or of the calling task if `pid` is 0. If `old` is
+    /// given, the previous limit is stored in it. If `new` is given, the
+    /// limit is set to it.
+    ///
+    /// Raising the hard limit requires `CAP_SYS_RESOURCE`, and modifying the
+    /// limits of other tasks requires the same credentials as the target.
+    pub fn prlimit64(
+        &self,
+        pid: u32,
+        resource: u32,
+        new: Option<&rt11_ffi_linux::native::resource::Rlimit64>,
+        old: Option<&mut rt11_ffi_linux::native::resource::Rlimit64>,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PRLIMIT64 as usize,
+                    pid as usize,
+                    resource as usize,
+                    new.map_or(0, |v| v as *const _ as usize),
+                    old.map_or(0, |v| v as *mut _ as usize),
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Advise on Memory Usage of Process
+    ///
+    /// `fn sys_process_madvise(pidfd: i32, iov: *const Iovec, vlen: usize, advice: i32, flags: u32) -> isize`
+    ///
+    /// Apply `advice` (i.e., `MADV_*`) to the memory ranges of the process
+    /// referred to by `pidfd`, as described by the `iovcnt` entries at
+    /// `iov`, and return the number of bytes advised. Processing stops at
+    /// the first failing range, so the result might be short. `flags` is
+    /// reserved and must be 0.
+    ///
+    /// For other processes, only non-destructive advice is supported (e.g.,
+    /// `MADV_COLD`, `MADV_PAGEOUT`, `MADV_WILLNEED`, or `MADV_COLLAPSE`),
+    /// `EINVAL` is returned otherwise. The caller needs `PTRACE_MODE_READ`
+    /// access to the target, as well as `CAP_SYS_NICE`, or `EPERM` is
+    /// returned (newer kernels waive the latter for the calling process
+    /// itself). This is primarily meant for userspace memory managers that
+    /// steer reclaim of other processes.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `iov` must point to `iovcnt` valid entries. The ranges are addresses
+    /// in the target process and are not accessed by the caller.
+    pub unsafe fn process_madvise(
+        &self,
+        pidfd: u32,
+        iov: *const rt11_ffi_linux::native::uio::Iovec,
+        iovcnt: usize,
+        advice: u32,
+        flags: u32,
+    ) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::PROCESS_MADVISE as usize,
+                    pidfd as usize,
+                    iov as usize,
+                    iovcnt,
+                    advice as usize,
+                    flags as usize,
+                )
+            }
+        )
+    }
+
+    /// Read from File-descriptor
+    ///
+    /// `fn sys_read(fd: u32, buf: *mut u8, count: usize) -> isize`
+    ///
+    /// Read up to `len` bytes from `fd` into the buffer at `buf`, and
+    /// return the number of bytes read. Short reads are not an error, and
+    /// 0 signals the end of the file (or a closed pipe). Non-blocking
+    /// file-descriptors return `EAGAIN` if no data is available.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `buf` must be valid for writes of `len` bytes.
+    pub unsafe fn read(&self, fd: u32, buf: *mut u8, len: usize) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::READ as usize,
+                    fd as usize,
+                    buf as usize,
+                    len,
+                )
+            }
+        )
+    }
+
+    /// Read Exact Amount
+    ///
+    /// Read from `fd` until `buf` is filled, calling `read()` as often as
+    /// required. Interrupted reads (i.e., `EINTR`) are retried, since no
+    /// data was transferred by them. If the end of the file is reached
+    /// before `buf` is filled, `UNEXPECTED_EOF` is returned. Any other error
+    /// is returned as is.
+    ///
+    /// On error, an unknown amount of data was consumed from `fd`, and the
+    /// content of `buf` is unspecified.
+    ///
+    /// Note that `EINTR` must only be retried for system calls that did not
+    /// take effect. Never use this pattern for `close()` (see its
+    /// documentation).
+    pub fn read_exact(&self, fd: u32, buf: &mut [u8]) -> Result<(), Errno> {
+        let mut len = 0;
+
+        while len < buf.len() {
+            let rest = &mut buf[len..];
+            match unsafe { self.read(fd, rest.as_mut_ptr(), rest.len()) } {
+                Ok(0) => return Err(UNEXPECTED_EOF),
+                Ok(n) => len += n,
+                Err(rt11_ffi_linux::native::errno::EINTR) => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read Small Pseudo-file
+    ///
+    /// Open the file at `path`, read it into `buf` until the end of the file
+    /// (or until `buf` is full), close it again, and return the number of
+    /// bytes read. `path` must include its NUL terminator, otherwise
+    /// `EINVAL` is returned.
+    ///
+    /// This is meant for the small text files of `/proc` (e.g.,
+    /// `/proc/self/statm`). These report a size of 0 (e.g., via `statx()`),
+    /// and are generated on each read, which might return less than
+    /// requested without reaching the end. Hence, this never trusts the
+    /// file size, but reads until `read()` returns 0. Interrupted reads are
+    /// retried.
+    ///
+    /// A return value equal to the size of `buf` means the file might have
+    /// been truncated. Since the content of a `/proc` file can change
+    /// between reads, callers should pass a buffer that fits the entire
+    /// file, rather than reading it in multiple calls.
+    pub fn read_proc(&self, path: &[u8], buf: &mut [u8]) -> Result<usize, Errno> {
+        if path.last() != Some(&0) {
+            return Err(rt11_ffi_linux::native::errno::EINVAL);
+        }
+
+        let fd = unsafe {
+            self.openat(
+                rt11_ffi_linux::native::fcntl::AT_FDCWD,
+                path.as_ptr(),
+                rt11_ffi_linux::native::fcntl::O_RDONLY | rt11_ffi_linux::native::fcntl::O_CLOEXEC,
+                0,
+            )?
+        };
+
+        let mut len = 0;
+        let r = loop {
+            if len == buf.len() {
+                break Ok(len);
+            }
+
+            let rest = &mut buf[len..];
+            match unsafe { self.read(fd, rest.as_mut_ptr(), rest.len()) } {
+                Ok(0) => break Ok(len),
+                Ok(n) => len += n,
+                Err(rt11_ffi_linux::native::errno::EINTR) => {},
+                Err(e) => break Err(e),
+            }
+        };
+
+        // The file was opened above and is not shared.
+        unsafe {
+            let _ = self.close(fd);
+        }
+
+        r
+    }
+
+    /// Read Ahead File Data
+    ///
+    /// `fn sys_readahead(fd: u32, offset: u64, count: usize) -> isize`
+    ///
+    /// Read the range `offset` to `offset + count` of the file referred to
+    /// by `fd` into the page cache, so later reads (or page-faults of file
+    /// mappings) do not block on I/O. This blocks until the data was read,
+    /// unlike `fadvise(POSIX_FADV_WILLNEED)`. `EINVAL` is returned if the
+    /// file does not support read-ahead (e.g., pipes, sockets, or some
+    /// pseudo file systems).
+    ///
+    /// The 64-bit `offset` is split into two registers on 32-bit
+    /// architectures. On ARM, an unused padding argument precedes it, so it
+    /// starts on an even register (as required for register pairs by the
+    /// EABI).
+    pub fn readahead(&self, fd: u32, offset: u64, count: usize) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let (offset_lo, offset_hi) = split64(offset);
+
+        #[cfg(target_arch = "arm")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::READAHEAD as usize,
+                fd as usize,
+                0,
+                offset_lo,
+                offset_hi,
+                count,
+            )
+        };
+
+        #[cfg(target_arch = "x86")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::READAHEAD as usize,
+                fd as usize,
+                offset_lo,
+                offset_hi,
+                count,
+            )
+        };
+
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::READAHEAD as usize,
+                fd as usize,
+                offset as usize,
+                count,
+            )
+        };
+
+        result_from_retval(r).map(|_| ())
+    }
+
+    /// Reboot System
+    ///
+    /// `fn sys_reboot(magic1: u32, magic2: u32, cmd: u32, arg: usize) -> i32`
+    ///
+    /// Perform the reboot operation `cmd` (i.e., `LINUX_REBOOT_CMD_*`). The
+    /// magic numbers required by the kernel (`LINUX_REBOOT_MAGIC1` and
+    /// `LINUX_REBOOT_MAGIC2`) are supplied implicitly. `arg` is only used by
+    /// `LINUX_REBOOT_CMD_RESTART2`, where it points to a NUL-terminated
+    /// command string.
+    ///
+    /// This requires `CAP_SYS_BOOT`. Within a PID namespace other than the
+    /// initial one, restart and power-off commands terminate the init task
+    /// of the namespace rather than the system.
+    ///
+    /// Data is not flushed to disk by this system call. Use `sync()`
+    /// beforehand, or data will be lost.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Depending on `cmd`, this immediately halts, restarts, or powers off
+    /// the entire machine, without any cleanup of user-space. The caller
+    /// must ensure this is intended. Furthermore, `arg` must point to a
+    /// valid NUL-terminated string, if required by `cmd`.
+    pub unsafe fn reboot(&self, cmd: u32, arg: usize) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::REBOOT as usize,
+                    rt11_ffi_linux::native::reboot::LINUX_REBOOT_MAGIC1 as usize,
+                    rt11_ffi_linux::native::reboot::LINUX_REBOOT_MAGIC2 as usize,
+                    cmd as usize,
+                    arg,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Restart System Call
+    ///
+    /// This system call continues an interrupted system call with the same
+    /// parameters it was initially called, adjusted only for the time
+    /// difference between the original syscall and now.
+    ///
+    /// This system call is used by the kernel itself to resume system calls of
+    /// frozen processes. Whenever a system call is interrupted, the kernel
+    /// saves the system call parameters and restarts the system call with the
+    /// same parameters once the task is resumed again. However, for system
+    /// calls that take relative time-frames as arguments, the kernel usually
+    /// needs to adjust these relative time-frames for the elapsed time. For
+    /// those system calls, the kernel refrains from restarting the system call
+    /// directly and instead changes the system call number of the
+    /// to-be-restarted call to this system call. When this system call is then
+    /// invoked, the kernel fetches the original system call and its parameters
+    /// from its internal state, adjusts the relative timeout, and then
+    /// restarts the original system call.
+    ///
+    /// There is usually no reason why you would ever invoke this system call
+    /// from user-space. Moreover, even when the kernel triggers a syscall
+    /// restart with this system call, it never leaves kernel space, and thus
+    /// user-space should never see this system call at all. Tracing debuggers
+    /// might see it, though. And they are the only ones that might reasonable
+    /// interfere with it.
+    ///
+    /// If no system call is to be resumed, this system call returns `EINTR`.
+    /// Otherwise, it resumes the original system call with adjusted relative
+    /// time parameters and returns the result of the resumed system call.
+    pub unsafe fn restart_syscall(&self) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::RESTART_SYSCALL as usize,
+                )
+            }
+        )
+    }
+
+    /// Retry Timed Call until Deadline
+    ///
+    /// Invoke `f` with the time remaining until the absolute `deadline` of
+    /// `CLOCK_MONOTONIC`, and retry it as long as it fails with `EINTR`.
+    /// The remaining time is recomputed before every invocation, so the
+    /// total time spent never exceeds the deadline, regardless of how often
+    /// the call is interrupted. Once the deadline has passed, `f` is
+    /// invoked with a timeout of 0. Any other result of `f` is returned as
+    /// is, including its own way of reporting a timeout (e.g., `ETIMEDOUT`,
+    /// or 0 ready events).
+    ///
+    /// Most blocking system calls take a relative timeout (e.g.,
+    /// `epoll_pwait2()`), and are interrupted with `EINTR` when a signal
+    /// handler runs. Restarting them with the same relative timeout would
+    /// extend the total wait by the time already spent, unboundedly so with
+    /// recurring signals. Hence, callers that have to honor a deadline must
+    /// track it as absolute time and recompute the relative timeout on each
+    /// restart. The kernel does the same internally via
+    /// `restart_syscall()`, but only for calls restarted transparently
+    /// (i.e., with `SA_RESTART`), which never reach user-space.
+    ///
+    /// Errors of `clock_gettime()` are returned right away. A call that
+    /// keeps failing with `EINTR`, even with a timeout of 0, is retried
+    /// indefinitely.
+    ///
+    /// A `deadline` relative to the current time is best created via
+    /// `time::Deadline::after()` and its `as_timespec()`.
+    pub fn retry_timed<T, F>(
+        &self,
+        deadline: rt11_ffi_linux::native::time::Timespec,
+        mut f: F,
+    ) -> Result<T, Errno>
+    where
+        F: FnMut(&rt11_ffi_linux::native::time::Timespec) -> Result<T, Errno>,
+    {
+        loop {
+            let now = self.clock_gettime(rt11_ffi_linux::native::time::CLOCK_MONOTONIC)?;
+
+            match f(&timespec_until(&deadline, &now)) {
+                Err(rt11_ffi_linux::native::errno::EINTR) => continue,
+                r => return r,
+            }
+        }
+    }
+
+    /// Examine and Change Signal Action
+    ///
+    /// `fn sys_rt_sigaction(sig: u32, act: *const Sigaction, oact: *mut Sigaction, sigsetsize: usize) -> i32`
+    ///
+    /// Install the action at `act` for the signal `sig`, and store the
+    /// previous action at `oact`. Either can be NULL, to only query or only
+    /// install an action. `EINVAL` is returned for `SIGKILL` and `SIGSTOP`,
+    /// as well as for invalid signals.
+    ///
+    /// The layout of the kernel signal action diverges across architectures
+    /// (see `Sigaction`). Only architectures with `SA_RESTORER` (i.e., all
+    /// supported ones but RISC-V) carry a restorer. A default `Sigaction` is
+    /// the default action on all of them.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `act` and `oact` must be NULL or point to a valid signal action. The
+    /// handler of `act` is invoked asynchronously in any thread of the
+    /// process.
+    pub unsafe fn rt_sigaction(
+        &self,
+        sig: u32,
+        act: *const rt11_ffi_linux::native::signal::Sigaction,
+        oact: *mut rt11_ffi_linux::native::signal::Sigaction,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::RT_SIGACTION as usize,
+                    sig as usize,
+                    act as usize,
+                    oact as usize,
+                    core::mem::size_of::<u64>(),
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Examine and Change Blocked Signals
+    ///
+    /// `fn sys_rt_sigprocmask(how: i32, set: *const u64, oset: *mut u64, sigsetsize: usize) -> i32`
+    ///
+    /// Modify the signal mask of the calling thread according to `how`
+    /// (i.e., `SIG_BLOCK`, `SIG_UNBLOCK`, or `SIG_SETMASK`) with `set`, and
+    /// return the previous mask via `old`. If `set` is `None`, the mask is
+    /// only queried. `SIGKILL` and `SIGSTOP` cannot be blocked, and are
+    /// silently removed from the mask.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Blocking signals affects any code of the thread relying on their
+    /// delivery, and unblocking them runs pending handlers right away.
+    pub unsafe fn rt_sigprocmask(
+        &self,
+        how: u32,
+        set: Option<&u64>,
+        old: Option<&mut u64>,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::RT_SIGPROCMASK as usize,
+                    how as usize,
+                    set.map_or(0, |v| v as *const _ as usize),
+                    old.map_or(0, |v| v as *mut _ as usize),
+                    core::mem::size_of::<u64>(),
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Query CPU Affinity
+    ///
+    /// `fn sys_sched_getaffinity(pid: i32, len: u32, mask: *mut usize) -> i32`
+    ///
+    /// Write the CPU affinity of the thread `pid` (or of the calling thread
+    /// if 0) to `set`, and return the number of bytes written by the kernel.
+    /// `set` is cleared first, so CPUs beyond those bytes are not part of
+    /// it.
+    ///
+    /// The kernel copies its entire CPU mask, whose size is the number of
+    /// possible CPUs of the system (i.e., `nr_cpu_ids`, which is usually
+    /// the number of CPUs present at boot, rather than those online),
+    /// rounded up to a multiple of the word size. If `CpuSet` is too small to
+    /// hold it (i.e., more than `CPU_SETSIZE` CPUs are possible), `EINVAL`
+    /// is returned.
+    pub fn sched_getaffinity(
+        &self,
+        pid: i32,
+        set: &mut crate::sched::CpuSet,
+    ) -> Result<usize, Errno> {
+        *set = crate::sched::CpuSet::new();
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SCHED_GETAFFINITY as usize,
+                    pid as isize as usize,
+                    core::mem::size_of::<crate::sched::CpuSet>(),
+                    set.as_mut_ptr() as usize,
+                )
+            }
+        )
+    }
+
+    /// Set CPU Affinity
+    ///
+    /// `fn sys_sched_setaffinity(pid: i32, len: u32, mask: *const usize) -> i32`
+    ///
+    /// Restrict the thread `pid` (or the calling thread if 0) to run only on
+    /// the CPUs in `set`. CPUs that are not online, or not allowed by the
+    /// cpuset cgroup of the thread, are silently dropped. If no CPU remains,
+    /// `EINVAL` is returned. Modifying other threads requires them to run
+    /// as the same user, or `CAP_SYS_NICE`.
+    ///
+    /// Note that the affinity is a property of each thread, and is
+    /// inherited by new threads and child processes.
+    pub fn sched_setaffinity(&self, pid: i32, set: &crate::sched::CpuSet) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SCHED_SETAFFINITY as usize,
+                    pid as isize as usize,
+                    core::mem::size_of::<crate::sched::CpuSet>(),
+                    set.as_ptr() as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Close-on-exec Flag
+    ///
+    /// Set `FD_CLOEXEC` on the file-descriptor `fd`, so it is closed
+    /// automatically on `execve()`. Any other file-descriptor flag is
+    /// retained. This is a convenience around `fcntl(F_GETFD)` and
+    /// `fcntl(F_SETFD)`.
+    ///
+    /// Note that this is inherently racy in multi-threaded programs, since
+    /// other threads might call `execve()` before the flag is set. Prefer
+    /// creating file-descriptors with their respective `*_CLOEXEC` flag.
+    pub fn set_cloexec(&self, fd: u32) -> Result<(), Errno> {
+        unsafe {
+            let flags = self.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFD, 0)?;
+            self.fcntl(
+                fd,
+                rt11_ffi_linux::native::fcntl::F_SETFD,
+                flags | rt11_ffi_linux::native::fcntl::FD_CLOEXEC as usize,
+            ).map(|_| ())
+        }
+    }
+
+    /// Set Parent-death Signal
+    ///
+    /// Request the signal `sig` (i.e., `SIG*`) to be delivered to the calling
+    /// task when its parent dies. A value of 0 clears the request. This is a
+    /// convenience around `prctl(PR_SET_PDEATHSIG)`.
+    ///
+    /// Note that the parent is the thread that created the caller, not its
+    /// process. The signal is delivered when that thread exits, even if
+    /// other threads of its process keep running. Furthermore, the request
+    /// is cleared when the caller executes a set-user-ID or set-group-ID
+    /// binary, or one with file capabilities, and when its credentials
+    /// change. It is retained across other `execve()` calls, though, so the
+    /// program to be executed must be prepared to receive the signal.
+    ///
+    /// If the parent already died before this call, no signal is delivered.
+    /// Callers that must not outlive their parent should compare their
+    /// parent task ID with the expected one after this call.
+    pub fn set_pdeathsig(&self, sig: u32) -> Result<(), Errno> {
+        unsafe {
+            self.prctl(
+                rt11_ffi_linux::native::prctl::PR_SET_PDEATHSIG,
+                sig as usize,
+                0,
+                0,
+                0,
+            ).map(|_| ())
+        }
+    }
+
+    /// Set Group ID
+    ///
+    /// `fn sys_setgid(gid: u32) -> i32`
+    ///
+    /// Set the effective group ID of the calling thread to `gid`. With
+    /// `CAP_SETGID`, the real and saved group IDs are set as well. Otherwise,
+    /// `gid` must match the real or saved group ID, or `EPERM` is returned.
+    ///
+    /// On 32-bit ARM and x86, this uses `setgid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    ///
+    /// See `drop_privileges()` for the correct order of credential changes.
+    pub fn setgid(&self, gid: u32) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::SETGID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::SETGID;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    nr as usize,
+                    gid as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Supplementary Groups
+    ///
+    /// `fn sys_setgroups(size: i32, list: *const u32) -> i32`
+    ///
+    /// Replace the supplementary group IDs of the calling thread with
+    /// `list`. An empty list clears all supplementary groups. This requires
+    /// `CAP_SETGID`, and `EINVAL` is returned if `list` exceeds
+    /// `NGROUPS_MAX`.
+    ///
+    /// On 32-bit ARM and x86, this uses `setgroups32()`, since the original
+    /// system call only supports 16-bit IDs.
+    ///
+    /// See `drop_privileges()` for the correct order of credential changes.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Credentials are a property of each thread on linux, and this only
+    /// changes the supplementary groups of the calling thread. Other threads
+    /// retain their previous groups, and thus their privileges. The caller
+    /// must ensure the process is single-threaded, or apply the same change
+    /// on all threads.
+    pub unsafe fn setgroups(&self, list: &[u32]) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::SETGROUPS32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::SETGROUPS;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    nr as usize,
+                    list.len(),
+                    list.as_ptr() as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Join Namespace
+    ///
+    /// `fn sys_setns(fd: u32, nstype: u32) -> i32`
+    ///
+    /// Move the calling thread into the namespace referred to by `fd`. This
+    /// is either a namespace file-descriptor (e.g., opened from
+    /// `/proc/<pid>/ns/*`), in which case `nstype` is either 0 or the
+    /// `CLONE_NEW*` flag of the type of the namespace, or it is a pidfd, in
+    /// which case `nstype` is a combination of `CLONE_NEW*` flags selecting
+    /// the namespaces of that task to join.
+    ///
+    /// Joining a namespace requires `CAP_SYS_ADMIN` in the owning user
+    /// namespace of the target. Like `unshare()`, joining a PID namespace
+    /// only affects children of the caller, and joining a user namespace
+    /// fails with `EINVAL` for multi-threaded callers.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Namespaces are a property of each thread on linux. Joining a
+    /// namespace changes how the calling thread resolves paths, task IDs,
+    /// and network resources, while other threads are unaffected. The
+    /// caller must ensure no state derived from the previous namespaces
+    /// (e.g., cached task IDs) is used afterwards.
+    pub unsafe fn setns(&self, fd: u32, nstype: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SETNS as usize,
+                    fd as usize,
+                    nstype as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Process Group
+    ///
+    /// `fn sys_setpgid(pid: i32, pgid: i32) -> i32`
+    ///
+    /// Move the process `pid` (or the calling process if 0) into the process
+    /// group `pgid` (or into a new group with the ID of `pid`, if 0). The
+    /// target must be the caller or one of its children that did not call
+    /// `execve()` yet, and the group must be in the same session. Session
+    /// leaders cannot change their group (`EPERM`).
+    pub fn setpgid(&self, pid: i32, pgid: i32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SETPGID as usize,
+                    pid as isize as usize,
+                    pgid as isize as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Scheduling Priority
+    ///
+    /// `fn sys_setpriority(which: u32, who: u32, nice: i32) -> i32`
+    ///
+    /// Set the nice value of all tasks selected by `which` and `who` to
+    /// `nice`. See `getpriority()` for the selection of tasks. Values
+    /// outside of -20 to 19 are clamped.
+    ///
+    /// Lowering the nice value (i.e., raising the priority) below the
+    /// limit of `RLIMIT_NICE` requires `CAP_SYS_NICE`, and `EACCES` is
+    /// returned otherwise. Unlike `getpriority()`, the return value of this
+    /// system call follows the standard error encoding.
+    pub fn setpriority(&self, which: u32, who: u32, nice: i32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SETPRIORITY as usize,
+                    which as usize,
+                    who as usize,
+                    nice as isize as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Real, Effective, and Saved Group IDs
+    ///
+    /// `fn sys_setresgid(rgid: u32, egid: u32, sgid: u32) -> i32`
+    ///
+    /// Set the real, effective, and saved group IDs of the calling thread.
+    /// A value of `u32::MAX` (i.e., `-1`) leaves the respective ID unchanged.
+    /// Without `CAP_SETGID`, each ID must match one of the current real,
+    /// effective, or saved group IDs, or `EPERM` is returned.
+    ///
+    /// On 32-bit ARM and x86, this uses `setresgid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    ///
+    /// See `drop_privileges()` for the correct order of credential changes.
+    pub fn setresgid(&self, rgid: u32, egid: u32, sgid: u32) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::SETRESGID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::SETRESGID;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    nr as usize,
+                    rgid as usize,
+                    egid as usize,
+                    sgid as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Real, Effective, and Saved User IDs
+    ///
+    /// `fn sys_setresuid(ruid: u32, euid: u32, suid: u32) -> i32`
+    ///
+    /// Set the real, effective, and saved user IDs of the calling thread.
+    /// A value of `u32::MAX` (i.e., `-1`) leaves the respective ID unchanged.
+    /// Without `CAP_SETUID`, each ID must match one of the current real,
+    /// effective, or saved user IDs, or `EPERM` is returned.
+    ///
+    /// On 32-bit ARM and x86, this uses `setresuid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    ///
+    /// See `drop_privileges()` for the correct order of credential changes.
+    pub fn setresuid(&self, ruid: u32, euid: u32, suid: u32) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::SETRESUID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::SETRESUID;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    nr as usize,
+                    ruid as usize,
+                    euid as usize,
+                    suid as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Create Session
+    ///
+    /// `fn sys_setsid() -> i32`
+    ///
+    /// Create a new session with the calling process as its leader, and
+    /// return its ID. The process also becomes the leader of a new process
+    /// group, and has no controlling terminal.
+    ///
+    /// This fails with `EPERM` if the calling process is already a
+    /// process-group leader (e.g., a shell job started in the foreground).
+    /// Hence, the canonical daemonize sequence forks first and calls this in
+    /// the child, which is never a group leader. See `daemonize()`.
+    pub fn setsid(&self) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SETSID as usize,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Set Socket Option
+    ///
+    /// `fn sys_setsockopt(fd: u32, level: u32, optname: u32, optval: *const u8, optlen: u32) -> i32`
+    ///
+    /// Set the option `optname` of the protocol level `level` (e.g.,
+    /// `SO_*` of `SOL_SOCKET`) of the socket `fd` to the value of `optlen`
+    /// bytes at `optval`. Most options take an `i32`. Unknown options are
+    /// rejected with `ENOPROTOOPT`.
+    ///
+    /// On x86, this is multiplexed through `socketcall()` as
+    /// `SYS_SETSOCKOPT`, since the direct system call was only added in
+    /// linux-4.3. All other architectures use the direct system call.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `optval` must be valid for reads of `optlen` bytes.
+    pub unsafe fn setsockopt(
+        &self,
+        fd: u32,
+        level: u32,
+        optname: u32,
+        optval: *const u8,
+        optlen: u32,
+    ) -> Result<(), Errno> {
+        #[cfg(target_arch = "x86")]
+        let r = {
+            let args: [usize; 5] = [
+                fd as usize,
+                level as usize,
+                optname as usize,
+                optval as usize,
+                optlen as usize,
+            ];
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SOCKETCALL as usize,
+                    rt11_ffi_linux::native::socket::SYS_SETSOCKOPT as usize,
+                    args.as_ptr() as usize,
+                )
+            }
+        };
+
+        #[cfg(not(target_arch = "x86"))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::SETSOCKOPT as usize,
+                fd as usize,
+                level as usize,
+                optname as usize,
+                optval as usize,
+                optlen as usize,
+            )
+        };
+
+        result_from_retval(r).map(|_| ())
+    }
+
+    /// Set User ID
+    ///
+    /// `fn sys_setuid(uid: u32) -> i32`
+    ///
+    /// Set the effective user ID of the calling thread to `uid`. With
+    /// `CAP_SETUID`, the real and saved user IDs are set as well. Otherwise,
+    /// `uid` must match the real or saved user ID, or `EPERM` is returned.
+    ///
+    /// Once a privileged task changed its user IDs, it loses the privileges
+    /// to change its group IDs. Hence, group IDs must be changed first. See
+    /// `drop_privileges()` for the correct order of credential changes.
+    ///
+    /// On 32-bit ARM and x86, this uses `setuid32()`, since the original
+    /// system call only supports 16-bit IDs.
+    pub fn setuid(&self, uid: u32) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let nr = rt11_ffi_linux::native::nr::SETUID32;
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let nr = rt11_ffi_linux::native::nr::SETUID;
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    nr as usize,
+                    uid as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Set Extended Attribute
+    ///
+    /// `fn sys_setxattr(path: *const u8, name: *const u8, value: *const u8, size: usize, flags: u32) -> i32`
+    ///
+    /// Set the value of the extended attribute `name` (including its
+    /// namespace prefix, e.g., `user.`) of the file at `path` to `value`.
+    /// Symbolic links are followed. By default, the attribute is created or
+    /// replaced as needed. With `XATTR_CREATE` in `flags`, `EEXIST` is
+    /// returned if it exists already. With `XATTR_REPLACE`, `ENODATA` is
+    /// returned if it does not exist.
+    ///
+    /// Values larger than `XATTR_SIZE_MAX` are rejected with `E2BIG`, but
+    /// most file systems impose lower limits (reported as `ENOSPC` or
+    /// `E2BIG`). If the file system does not support extended attributes
+    /// (or the namespace), `EOPNOTSUPP` is returned.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` and `name` must point to valid NUL-terminated strings.
+    pub unsafe fn setxattr(
+        &self,
+        path: *const u8,
+        name: *const u8,
+        value: &[u8],
+        flags: u32,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SETXATTR as usize,
+                    path as usize,
+                    name as usize,
+                    value.as_ptr() as usize,
+                    value.len(),
+                    flags as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Shut Down Process
+    ///
+    /// Flush all file systems via `sync()` and then terminate the thread
+    /// group via `exit_group()` with `code` as exit-code. This is meant for
+    /// an orderly exit of a process that acts as init task (PID 1), where
+    /// the kernel will not flush data on its behalf.
+    ///
+    /// Note that this does not reboot or power off the machine. See
+    /// `reboot()` for that.
+    pub fn shutdown(&self, code: u32) -> ! {
+        self.sync();
+        self.exit_group(code);
+    }
+
+    /// Examine and Change Alternate Signal Stack
+    ///
+    /// `fn sys_sigaltstack(ss: *const Stack, old: *mut Stack) -> i32`
+    ///
+    /// Install `ss` as alternate signal stack of the calling thread, and
+    /// return the previous one via `old`. If `ss` is `None`, the stack is
+    /// only queried. Handlers installed with `SA_ONSTACK` run on this stack
+    /// (rather than on the stack of the interrupted code), which allows
+    /// handling faults caused by stack overflows. `SS_DISABLE` in `ss_flags`
+    /// disables the alternate stack. Stacks smaller than `MINSIGSTKSZ` of
+    /// the architecture are rejected with `ENOMEM`, and changing the stack
+    /// while running on it fails with `EPERM`.
+    ///
+    /// The alternate stack is a property of the thread, and is not
+    /// inherited by new threads (but it is by `fork()`).
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The stack described by `ss` must be mapped and writable, and must
+    /// remain so until it is replaced or disabled, since the kernel writes
+    /// signal frames to it.
+    pub unsafe fn sigaltstack(
+        &self,
+        ss: Option<&rt11_ffi_linux::native::signal::Stack>,
+        old: Option<&mut rt11_ffi_linux::native::signal::Stack>,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SIGALTSTACK as usize,
+                    ss.map_or(0, |v| v as *const _ as usize),
+                    old.map_or(0, |v| v as *mut _ as usize),
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Create Socket
+    ///
+    /// `fn sys_socket(domain: u32, typ: u32, protocol: u32) -> i32`
+    ///
+    /// Create a new socket of the address family `domain` (i.e., `AF_*`)
+    /// and the type `typ` (i.e., `SOCK_*`), and return its
+    /// file-descriptor. `typ` can be combined with `SOCK_CLOEXEC` and
+    /// `SOCK_NONBLOCK`. `protocol` selects the protocol within the family,
+    /// where 0 selects the default protocol of the type. Unsupported
+    /// families and types are rejected with `EAFNOSUPPORT` and `EINVAL`,
+    /// respectively.
+    ///
+    /// On x86, this is multiplexed through `socketcall()` as
+    /// `SYS_SOCKET`, since the direct system call was only added in
+    /// linux-4.3. All other architectures use the direct system call.
+    pub fn socket(&self, domain: u32, typ: u32, protocol: u32) -> Result<u32, Errno> {
+        #[cfg(target_arch = "x86")]
+        let r = {
+            let args: [usize; 3] = [domain as usize, typ as usize, protocol as usize];
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SOCKETCALL as usize,
+                    rt11_ffi_linux::native::socket::SYS_SOCKET as usize,
+                    args.as_ptr() as usize,
+                )
+            }
+        };
+
+        #[cfg(not(target_arch = "x86"))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::SOCKET as usize,
+                domain as usize,
+                typ as usize,
+                protocol as usize,
+            )
+        };
+
+        result_from_retval(r).map(|v| v as u32)
+    }
+
+    /// Synchronize File Systems
+    ///
+    /// `fn sys_sync()`
+    ///
+    /// Schedule writeback of all modified data and metadata of all file
+    /// systems. The kernel waits for writeback to complete before returning,
+    /// but errors are not reported. Use `fsync()` to synchronize a specific
+    /// file with error reporting.
+    pub fn sync(&self) {
+        unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall0(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::SYNC as usize,
+            );
+        }
+    }
+
+    /// Synchronize File System
+    ///
+    /// `fn sys_syncfs(fd: u32) -> i32`
+    ///
+    /// This is the same as `sync()`, but only flushes the file system
+    /// containing the file referred to by `fd`. Unlike `sync()`, writeback
+    /// errors of the file system are reported.
+    pub fn syncfs(&self, fd: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SYNCFS as usize,
+                    fd as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Query System Information
+    ///
+    /// `fn sys_sysinfo(info: *mut Sysinfo) -> i32`
+    ///
+    /// Return overall statistics of the system, including memory usage and
+    /// load averages. Memory sizes must be multiplied by `mem_unit` to get
+    /// bytes, and load averages are fixed-point numbers with
+    /// `SI_LOAD_SHIFT` fractional bits. See `Sysinfo` for details.
+    ///
+    /// Note that the values are global to the system, and do not reflect
+    /// the limits of the calling task (e.g., imposed by cgroups).
+    pub fn sysinfo(&self) -> Result<rt11_ffi_linux::native::sysinfo::Sysinfo, Errno> {
+        let mut info = rt11_ffi_linux::native::sysinfo::Sysinfo::default();
+
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::SYSINFO as usize,
+                    &mut info as *mut _ as usize,
+                )
+            }
+        ).map(|_| info)
+    }
+
+    /// Send Signal to Thread
+    ///
+    /// `fn sys_tgkill(tgid: i32, tid: i32, sig: u32) -> i32`
+    ///
+    /// Send the signal `sig` (i.e., `SIG*`) to the thread `tid` of the
+    /// thread group `tgid`. Unlike `kill()`, the signal is delivered to that
+    /// specific thread, rather than any thread of the process.
+    ///
+    /// Thread IDs are recycled just like process IDs, but a thread ID is
+    /// only reused for the same thread group if the entire thread group
+    /// ID is recycled as well. Hence, passing the thread group ID protects
+    /// against signaling an unrelated thread that reused the thread ID,
+    /// which makes this the race-free way to signal threads of the own
+    /// process. `ESRCH` is returned if `tid` is not part of `tgid`.
+    ///
+    /// As with `kill()`, a `sig` of 0 only performs the existence and
+    /// permission checks.
+    pub fn tgkill(&self, tgid: i32, tid: i32, sig: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::TGKILL as usize,
+                    tgid as isize as usize,
+                    tid as isize as usize,
+                    sig as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Truncate File
+    ///
+    /// `fn sys_truncate(path: *const u8, length: u64) -> i32`
+    ///
+    /// This is the same as `ftruncate()`, but operates on the file at
+    /// `path`, which must be writable by the caller. Symbolic links are
+    /// followed.
+    ///
+    /// The 64-bit `length` is split as for `ftruncate()`, with
+    /// `truncate64()` used on 32-bit architectures.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string.
+    pub unsafe fn truncate(&self, path: *const u8, length: u64) -> Result<(), Errno> {
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let (length_lo, length_hi) = split64(length);
+
+        #[cfg(target_arch = "arm")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::TRUNCATE64 as usize,
+                path as usize,
+                0,
+                length_lo,
+                length_hi,
+            )
+        };
+
+        #[cfg(target_arch = "x86")]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::TRUNCATE64 as usize,
+                path as usize,
+                length_lo,
+                length_hi,
+            )
+        };
+
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                &self.ffi,
+                rt11_ffi_linux::native::nr::TRUNCATE as usize,
+                path as usize,
+                length as usize,
+            )
+        };
+
+        result_from_retval(r).map(|_| ())
+    }
+
+    /// Unmount File System
+    ///
+    /// `fn sys_umount2(target: *const u8, flags: u32) -> i32`
+    ///
+    /// Detach the mount at `target`. `flags` (i.e., `MNT_*` and
+    /// `UMOUNT_NOFOLLOW`) modify the operation:
+    ///
+    ///  * `MNT_DETACH`: Perform a lazy unmount. The mount is detached from
+    ///    the tree immediately, but released only once it is no longer
+    ///    busy. Otherwise, `EBUSY` is returned for busy mounts.
+    ///
+    ///  * `MNT_FORCE`: Ask the file system to abort pending requests. Only
+    ///    some network file systems support this, and data might be lost.
+    ///
+    ///  * `UMOUNT_NOFOLLOW`: Do not follow `target` if it is a symbolic
+    ///    link.
+    ///
+    /// This requires `CAP_SYS_ADMIN` in the user namespace owning the mount
+    /// namespace of the caller, and returns `EINVAL` if `target` is not a
+    /// mount point.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `target` must point to a valid NUL-terminated string. Furthermore,
+    /// unmounting changes how all tasks of the mount namespace resolve
+    /// paths. The caller must ensure this is intended.
+    pub unsafe fn umount2(&self, target: *const u8, flags: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall2(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::UMOUNT2 as usize,
+                    target as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Disassociate Execution Context
+    ///
+    /// `fn sys_unshare(flags: u32) -> i32`
+    ///
+    /// Detach the calling thread from resources it shares with other tasks.
+    /// `flags` is a combination of `CLONE_*` flags, each selecting the
+    /// resource to unshare. The namespace flags (`CLONE_NEW*`) create new
+    /// namespaces of the respective type and move the caller into them.
+    /// Creating namespaces requires `CAP_SYS_ADMIN`, except for
+    /// `CLONE_NEWUSER`.
+    ///
+    /// `CLONE_NEWPID` and `CLONE_NEWTIME` do not move the caller. Its own PID
+    /// namespace is unchanged, and only children created afterwards become
+    /// members of the new namespace. The first such child becomes PID 1 of
+    /// the namespace.
+    ///
+    /// `CLONE_NEWUSER` makes the caller the owner of a new user namespace,
+    /// with a full set of capabilities in it. If combined with other
+    /// namespace flags, the user namespace is created first, and the other
+    /// namespaces are owned by it. Hence, an unprivileged caller can create
+    /// any namespace, as long as `CLONE_NEWUSER` is part of the same call or
+    /// a previous one. However, `CLONE_NEWUSER` fails with `EINVAL` for
+    /// multi-threaded callers, and the caller must write its ID mappings
+    /// (`/proc/self/uid_map` and `/proc/self/gid_map`) before it can act as
+    /// a specific user in the new namespace.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// Namespaces are a property of each thread on linux. Unsharing
+    /// namespaces changes how the calling thread resolves paths, task IDs,
+    /// and network resources, while other threads are unaffected. Similarly,
+    /// `CLONE_FS` and `CLONE_FILES` detach the caller from state shared with
+    /// other threads. The caller must ensure no state derived from the
+    /// previous context is used afterwards.
+    pub unsafe fn unshare(&self, flags: u32) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::UNSHARE as usize,
+                    flags as usize,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Wait for Child Process
+    ///
+    /// `fn sys_wait4(upid: i32, stat_addr: *mut i32, options: i32, ru: *mut Rusage) -> i32`
+    ///
+    /// Wait for a state change of the child selected by `pid`, store its
+    /// status word in `status` (see the `wait` module for decoding helpers),
+    /// and return its process ID. A positive `pid` selects that child, -1
+    /// selects any child, 0 any child in the process group of the caller,
+    /// and any other negative value any child in the process group `-pid`.
+    /// `options` is a combination of `WNOHANG`, `WUNTRACED` and
+    /// `WCONTINUED`. Returns `ECHILD` if no matching child exists.
+    ///
+    /// With `WNOHANG`, if no child changed state yet, this returns 0 and
+    /// leaves `status` untouched. No resource usage is requested.
+    ///
+    /// Unlike `waitid()`, this cannot leave the child in a waitable state,
+    /// and cannot select children via pidfds.
+    pub fn wait4(&self, pid: i32, status: &mut i32, options: u32) -> Result<u32, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall4(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::WAIT4 as usize,
+                    pid as isize as usize,
+                    status as *mut i32 as usize,
+                    options as usize,
+                    0,
+                )
+            }
+        ).map(|v| v as u32)
+    }
+
+    /// Wait for Child State Change
+    ///
+    /// `fn sys_waitid(which: i32, upid: i32, infop: *mut Siginfo, options: i32, ru: *mut Rusage) -> i32`
+    ///
+    /// Wait for a state change of a child selected by `which` (i.e., `P_*`)
+    /// and `id`, and report it in `info`. `options` selects the state
+    /// changes to wait for (i.e., `WEXITED`, `WSTOPPED`, `WCONTINUED`), and
+    /// can request a non-blocking check (`WNOHANG`) or leave the child in a
+    /// waitable state (`WNOWAIT`). Returns `ECHILD` if no matching child
+    /// exists.
+    ///
+    /// With `WNOHANG`, if no child changed state yet, this succeeds and
+    /// `si_pid` of `info` is left 0. Hence, callers should clear it before.
+    /// No resource usage is requested.
+    ///
+    /// `P_PIDFD` selects the child referred to by the pidfd `id`. It is
+    /// available since linux-5.4, and returns `EINVAL` on older kernels.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `info` must be valid for writes of a `Siginfo`.
+    pub unsafe fn waitid(
+        &self,
+        which: u32,
+        id: u32,
+        info: *mut rt11_ffi_linux::native::wait::Siginfo,
+        options: u32,
+    ) -> Result<(), Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall5(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::WAITID as usize,
+                    which as usize,
+                    id as usize,
+                    info as usize,
+                    options as usize,
+                    0,
+                )
+            }
+        ).map(|_| ())
+    }
+
+    /// Write to File-descriptor
+    ///
+    /// `fn sys_write(fd: u32, buf: *const u8, count: usize) -> isize`
+    ///
+    /// Write up to `len` bytes from the buffer at `buf` to `fd`, and return
+    /// the number of bytes written. Short writes are not an error, and the
+    /// caller must retry with the remainder. Writing to a pipe without
+    /// readers returns `EPIPE` (and raises `SIGPIPE`, unless ignored).
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `buf` must be valid for reads of `len` bytes.
+    pub unsafe fn write(&self, fd: u32, buf: *const u8, len: usize) -> Result<usize, Errno> {
+        result_from_retval(
+            unsafe {
+                <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                    &self.ffi,
+                    rt11_ffi_linux::native::nr::WRITE as usize,
+                    fd as usize,
+                    buf as usize,
+                    len,
+                )
+            }
+        )
+    }
+
+    /// Write Entire Buffer
+    ///
+    /// Write all of `buf` to `fd`, calling `write()` as often as required.
+    /// Interrupted writes (i.e., `EINTR`) are retried, since no data was
+    /// transferred by them. Any other error is returned as is. A file that
+    /// accepts no data at all (i.e., `write()` returns 0 for a non-empty
+    /// buffer) fails with `EIO`, rather than looping forever.
+    ///
+    /// On error, an unknown prefix of `buf` was written. Non-blocking
+    /// file-descriptors return `EAGAIN` once they are full, so this is
+    /// meant for blocking file-descriptors.
+    ///
+    /// Note that `EINTR` must only be retried for system calls that did not
+    /// take effect. Never use this pattern for `close()` (see its
+    /// documentation).
+    pub fn write_all(&self, fd: u32, buf: &[u8]) -> Result<(), Errno> {
+        let mut len = 0;
+
+        while len < buf.len() {
+            let rest = &buf[len..];
+            match unsafe { self.write(fd, rest.as_ptr(), rest.len()) } {
+                Ok(0) => return Err(rt11_ffi_linux::native::errno::EIO),
+                Ok(n) => len += n,
+                Err(rt11_ffi_linux::native::errno::EINTR) => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    // Reap the child `pid` via `wait4()`, and return its status word.
+    pub(crate) fn reap(sc: &Syscall, pid: u32) -> i32 {
+        let mut status: i32 = 0;
+
+        assert_eq!(sc.wait4(pid as i32, &mut status, 0), Ok(pid));
+        status
+    }
+
+    // Reap the child `pid`, verify it exited rather than being killed by a
+    // signal, and return its exit code.
+    pub(crate) fn reap_exit(sc: &Syscall, pid: u32) -> u32 {
+        let status = reap(sc, pid);
+
+        assert!(crate::wait::wifexited(status));
+        crate::wait::wexitstatus(status) as u32
+    }
+
+    // Verify `result_from_retval()`. Check that error codes are correctly
+    // detected as such.
+    #[test]
+    fn retval_check() {
+        let success_values = [
+            0, 1, 2, 3,
+            254, 255, 256, 257,
+            65534, 65535, 65536, 65537,
+            core::usize::MAX / 2,
+            core::usize::MAX / 2 + 1,
+            core::usize::MAX - 4097,
+            core::usize::MAX - 4096,
+        ];
+
+        for v in &success_values {
+            let r = result_from_retval(*v);
+            assert_eq!(r, Ok(*v));
+        }
+
+        let error_values = [
+            (4096, core::usize::MAX - 4095),
+            (4095, core::usize::MAX - 4094),
+            (4094, core::usize::MAX - 4093),
+            (4093, core::usize::MAX - 4092),
+            (4, core::usize::MAX - 3),
+            (3, core::usize::MAX - 2),
+            (2, core::usize::MAX - 1),
+            (1, core::usize::MAX),
+        ];
+
+        for (c, v) in &error_values {
+            let r = result_from_retval(*v);
+            assert_eq!(r, Err(*c));
+        }
+    }
+
+    // Verify `split64()` returns the low half first, and that both halves
+    // are recombined to the original value. The placement of the halves is
+    // verified by the tests of the wrappers using it, when run on the
+    // respective 32-bit architecture (e.g., `truncate_check()` passes a
+    // length that is only rejected if the high half arrives intact).
+    #[test]
+    fn split64_check() {
+        assert_eq!(split64(0), (0, 0));
+        assert_eq!(split64(0x1234_5678), (0x1234_5678, 0));
+        assert_eq!(split64(0x1_0000_0000), (0, 1));
+        assert_eq!(split64(u64::MAX), (0xffff_ffff, 0xffff_ffff));
+
+        let v = 0x0123_4567_89ab_cdef_u64;
+        let (lo, hi) = split64(v);
+        assert_eq!(((hi as u64) << 32) | lo as u64, v);
+    }
+
+    // Verify the remaining time until a deadline is computed with borrow
+    // of nanoseconds, and clamped to 0 once the deadline passed.
+    #[test]
+    fn timespec_until_check() {
+        let ts = |tv_sec, tv_nsec| rt11_ffi_linux::native::time::Timespec { tv_sec, tv_nsec };
+
+        assert_eq!(timespec_until(&ts(5, 100), &ts(3, 50)), ts(2, 50));
+        assert_eq!(timespec_until(&ts(5, 100), &ts(3, 200)), ts(1, 999_999_900));
+        assert_eq!(timespec_until(&ts(5, 100), &ts(5, 100)), ts(0, 0));
+        assert_eq!(timespec_until(&ts(5, 100), &ts(5, 101)), ts(0, 0));
+        assert_eq!(timespec_until(&ts(5, 100), &ts(9, 0)), ts(0, 0));
+        assert_eq!(
+            timespec_until(&ts(i64::MAX, 0), &ts(i64::MIN, 1)),
+            ts(i64::MAX - 1, 999_999_999),
+        );
+    }
+
+    // Verify that `Syscall` instances can be created without context.
+    #[test]
+    fn syscall_creation() {
+        let _: Syscall = Syscall::new();
+    }
+
+    // Abort forked children, with `SIGABRT` in its default state, and
+    // blocked and ignored, and verify both are terminated by it. Core dumps
+    // are disabled in the children. Also verify the task IDs used to raise
+    // the signal.
+    #[test]
+    fn abort_check() {
+        let sc = Syscall::new();
+
+        assert_eq!(sc.getpid(), std::process::id());
+        assert_ne!(sc.gettid(), 0);
+
+        for ignore in [false, true] {
+            match unsafe { sc.fork() }.unwrap() {
+                ForkResult::Child => {
+                    let sig = rt11_ffi_linux::native::signal::SIGABRT;
+                    let mask = 1u64 << (sig - 1);
+                    let rlimit = rt11_ffi_linux::native::resource::RLIMIT_CORE;
+                    let v = rt11_ffi_linux::native::resource::Rlimit64 { rlim_cur: 0, rlim_max: 0 };
+
+                    if sc.gettid() != sc.getpid() {
+                        sc.exit_group(1);
+                    }
+                    if sc.prlimit64(0, rlimit, Some(&v), None).is_err() {
+                        sc.exit_group(2);
+                    }
+                    if ignore {
+                        let act = rt11_ffi_linux::native::signal::Sigaction {
+                            sa_handler: rt11_ffi_linux::native::signal::SIG_IGN,
+                            ..Default::default()
+                        };
+                        unsafe {
+                            let _ = sc.rt_sigaction(sig, &act, core::ptr::null_mut());
+                            let _ = sc.rt_sigprocmask(
+                                rt11_ffi_linux::native::signal::SIG_BLOCK,
+                                Some(&mask),
+                                None,
+                            );
+                        }
+                    }
+                    sc.abort();
+                },
+                ForkResult::Parent(pid) => {
+                    let status = reap(&sc, pid);
+                    assert!(crate::wait::wifsignaled(status));
+                    assert_eq!(
+                        crate::wait::wtermsig(status) as u32,
+                        rt11_ffi_linux::native::signal::SIGABRT,
+                    );
+                },
+            }
+        }
+    }
+
+    // Query the FS segment base and verify it matches the thread-pointer, which
+    // is self-referential on x86_64 (`%fs:0` stores its own address).
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn arch_fs_query() {
+        let sc = Syscall::new();
+        let fs = sc.arch_get_fs().unwrap();
+        let tp: usize;
+
+        unsafe {
+            core::arch::asm!(
+                "mov {}, qword ptr fs:0",
+                out(reg) tp,
+                options(nostack, readonly, preserves_flags),
+            );
+        }
+
+        assert_ne!(fs, 0);
+        assert_eq!(fs, tp);
+    }
+
+    // Change the working and root directory in a forked child, since both
+    // are shared by all threads of the test-suite.
+    #[test]
+    fn chdir_check() {
+        let sc = Syscall::new();
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => {
+                if unsafe { sc.chdir(b"/tmp\x00".as_ptr()) } != Ok(()) {
+                    sc.exit(1);
+                }
+                if unsafe { sc.chdir(b"/rt11-nonexistent\x00".as_ptr()) }
+                    != Err(rt11_ffi_linux::native::errno::ENOENT)
+                {
+                    sc.exit(2);
+                }
+
+                let fd = match unsafe { sc.open(b"/\x00".as_ptr(), 0, 0) } {
+                    Ok(v) => v,
+                    Err(_) => sc.exit(3),
+                };
+                if sc.fchdir(fd) != Ok(())
+                    || sc.fchdir(u32::MAX) != Err(rt11_ffi_linux::native::errno::EBADF)
+                {
+                    sc.exit(3);
+                }
+
+                match unsafe { sc.enter_chroot(b"/\x00".as_ptr()) } {
+                    Ok(()) | Err(rt11_ffi_linux::native::errno::EPERM) => sc.exit(0),
+                    Err(_) => sc.exit(4),
+                }
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 0),
+        }
+    }
+
+    // Query the resolution and value of the monotonic clock, verify it
+    // does not go backwards, and verify unknown clocks are rejected.
+    #[test]
+    fn clock_check() {
+        let sc = Syscall::new();
+        let mono = rt11_ffi_linux::native::time::CLOCK_MONOTONIC;
+
+        let res = sc.clock_getres(mono).unwrap();
+        assert_eq!(res.tv_sec, 0);
+        assert!(res.tv_nsec > 0 && res.tv_nsec < 1_000_000_000);
+
+        let tp = sc.clock_gettime(mono).unwrap();
+        assert!(tp.tv_nsec >= 0 && tp.tv_nsec < 1_000_000_000);
+
+        let t0 = sc.monotonic_nanos().unwrap();
+        let t1 = sc.monotonic_nanos().unwrap();
+        assert!(t0 <= t1);
+        assert!(t0 >= tp.tv_sec as u64 * 1_000_000_000);
+
+        assert_eq!(sc.clock_getres(u32::MAX >> 1), Err(rt11_ffi_linux::native::errno::EINVAL));
+        assert_eq!(sc.clock_gettime(u32::MAX >> 1), Err(rt11_ffi_linux::native::errno::EINVAL));
+    }
+
+    // Clone a child that shares the address space and runs on its own stack,
+    // and verify it ran before the caller resumed. Unsupported flags and
+    // misaligned stacks must be rejected.
+    #[test]
+    fn clone_entry_check() {
+        static SEEN: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
+        extern "C" fn entry(arg: usize) -> ! {
+            SEEN.store(arg, core::sync::atomic::Ordering::SeqCst);
+            Syscall::new().exit(0);
+        }
+
+        #[repr(C, align(16))]
+        struct Stack([u8; 16384]);
+
+        let sc = Syscall::new();
+        let mut stack = Stack([0; 16384]);
+        let top = unsafe { stack.0.as_mut_ptr().add(stack.0.len()) };
+        let flags = rt11_ffi_linux::native::sched::CLONE_VM
+            | rt11_ffi_linux::native::sched::CLONE_VFORK
+            | rt11_ffi_linux::native::signal::SIGCHLD;
+        let mut info = rt11_ffi_linux::native::wait::Siginfo::default();
+
+        let pid = unsafe { sc.clone_entry(flags, top, entry, 71) }.unwrap();
+        assert_eq!(SEEN.load(core::sync::atomic::Ordering::SeqCst), 71);
+
+        unsafe {
+            sc.waitid(
+                rt11_ffi_linux::native::wait::P_PID,
+                pid,
+                &mut info,
+                rt11_ffi_linux::native::wait::WEXITED,
+            ).unwrap();
+        }
+        assert_eq!(info.si_code, rt11_ffi_linux::native::wait::CLD_EXITED);
+        assert_eq!(info.si_status, 0);
+
+        assert_eq!(
+            unsafe { sc.clone_entry(flags, top.wrapping_sub(8), entry, 0) },
+            Err(rt11_ffi_linux::native::errno::EINVAL),
+        );
+        assert_eq!(
+            unsafe { sc.clone_entry(flags | rt11_ffi_linux::native::sched::CLONE_SETTLS, top, entry, 0) },
+            Err(rt11_ffi_linux::native::errno::EINVAL),
+        );
+    }
+
+    // Mark a pipe close-on-exec via `close_range()`, then close it. If the
+    // kernel lacks `close_range()` or `CLOSE_RANGE_CLOEXEC`, the check is
+    // skipped. Other tests might allocate file-descriptors concurrently, so
+    // each end is covered by its own range.
+    #[test]
+    fn close_range_check() {
+        let sc = Syscall::new();
+
+        let p = sc.pipe2(0).unwrap();
+
+        unsafe {
+            match sc.close_range(p[0], p[0], rt11_ffi_linux::native::fcntl::CLOSE_RANGE_CLOEXEC) {
+                Ok(()) => {},
+                Err(rt11_ffi_linux::native::errno::ENOSYS)
+                | Err(rt11_ffi_linux::native::errno::EINVAL) => {
+                    sc.close(p[0]).unwrap();
+                    sc.close(p[1]).unwrap();
+                    return;
+                },
+                Err(e) => panic!("unexpected error: {}", e),
+            }
+            sc.close_range(p[1], p[1], rt11_ffi_linux::native::fcntl::CLOSE_RANGE_CLOEXEC).unwrap();
+
+            for fd in p {
+                assert_eq!(
+                    sc.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFD, 0),
+                    Ok(rt11_ffi_linux::native::fcntl::FD_CLOEXEC as usize),
+                );
+            }
+
+            for fd in p {
+                sc.close_range(fd, fd, 0).unwrap();
+                assert_eq!(
+                    sc.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFD, 0),
+                    Err(rt11_ffi_linux::native::errno::EBADF),
+                );
+            }
+
+            assert_eq!(sc.close_range(1, 0, 0), Err(rt11_ffi_linux::native::errno::EINVAL));
+        }
+    }
+
+    // Daemonize a child, and verify the resulting daemon runs in a new
+    // session without being its leader.
+    #[test]
+    fn daemonize_check() {
+        let sc = Syscall::new();
+        let mut ids = [0u32; 3];
+
+        let p = sc.pipe2(rt11_ffi_linux::native::fcntl::O_CLOEXEC).unwrap();
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => {
+                if unsafe { sc.daemonize() }.is_err() {
+                    sc.exit_group(2);
+                }
+
+                ids = match (sc.getsid(0), sc.getpgid(0)) {
+                    (Ok(sid), Ok(pgid)) => [sid, pgid, sc.getpid()],
+                    _ => sc.exit_group(3),
+                };
+                let _ = unsafe { sc.write(p[1], ids.as_ptr() as *const u8, 12) };
+                sc.exit_group(0);
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 0),
+        }
+
+        unsafe {
+            assert_eq!(sc.read(p[0], ids.as_mut_ptr() as *mut u8, 12), Ok(12));
+            sc.close(p[1]).unwrap();
+            sc.close(p[0]).unwrap();
+        }
+        assert_ne!(ids[0], sc.getsid(0).unwrap());
+        assert_eq!(ids[0], ids[1]);
+        assert_ne!(ids[0], ids[2]);
+    }
+
+    // Duplicate a pipe onto a new number, and verify the close-on-exec flag
+    // follows `flags`. Duplicating onto itself must be rejected.
+    #[test]
+    fn dup3_check() {
+        let sc = Syscall::new();
+
+        let p = sc.pipe2(rt11_ffi_linux::native::fcntl::O_CLOEXEC).unwrap();
+
+        unsafe {
+            let fd = sc.dup3(p[1], 1000, 0).unwrap();
+            assert_eq!(fd, 1000);
+            assert_eq!(sc.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFD, 0), Ok(0));
+
+            sc.dup3(p[0], fd, rt11_ffi_linux::native::fcntl::O_CLOEXEC).unwrap();
+            assert_eq!(
+                sc.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFD, 0),
+                Ok(rt11_ffi_linux::native::fcntl::FD_CLOEXEC as usize),
+            );
+
+            assert_eq!(sc.dup3(fd, fd, 0), Err(rt11_ffi_linux::native::errno::EINVAL));
+
+            sc.close(fd).unwrap();
+            sc.close(p[1]).unwrap();
+            sc.close(p[0]).unwrap();
+        }
+    }
+
+    // Register the read-end of a pipe with an epoll instance, and verify it
+    // reports readiness with the registered data once written to. If
+    // `epoll_pwait2()` is not available, the wait is skipped.
+    #[test]
+    fn epoll_check() {
+        let sc = Syscall::new();
+        let mut ev = rt11_ffi_linux::native::epoll::EpollEvent {
+            events: rt11_ffi_linux::native::epoll::EPOLLIN,
+            data: 0x7171,
+        };
+        let mut out = [rt11_ffi_linux::native::epoll::EpollEvent::default(); 4];
+        let zero = rt11_ffi_linux::native::time::Timespec::default();
+
+        let epfd = sc.epoll_create1(rt11_ffi_linux::native::epoll::EPOLL_CLOEXEC).unwrap();
+
+        let p = sc.pipe2(0).unwrap();
+
+        unsafe {
+            sc.epoll_ctl(epfd, rt11_ffi_linux::native::epoll::EPOLL_CTL_ADD, p[0], &mut ev).unwrap();
+            assert_eq!(
+                sc.epoll_ctl(epfd, rt11_ffi_linux::native::epoll::EPOLL_CTL_ADD, p[0], &mut ev),
+                Err(rt11_ffi_linux::native::errno::EEXIST),
+            );
+
+            match sc.epoll_pwait2(epfd, out.as_mut_ptr(), 4, &zero, core::ptr::null()) {
+                Err(rt11_ffi_linux::native::errno::ENOSYS) => {},
+                r => {
+                    assert_eq!(r, Ok(0));
+
+                    assert_eq!(sc.write(p[1], b"x".as_ptr(), 1), Ok(1));
+
+                    let r = sc.epoll_pwait2(epfd, out.as_mut_ptr(), 4, &zero, core::ptr::null());
+                    assert_eq!(r, Ok(1));
+                    let (events, data) = (out[0].events, out[0].data);
+                    assert_eq!(events, rt11_ffi_linux::native::epoll::EPOLLIN);
+                    assert_eq!(data, 0x7171);
+                },
+            }
+
+            sc.epoll_ctl(epfd, rt11_ffi_linux::native::epoll::EPOLL_CTL_DEL, p[0], core::ptr::null_mut()).unwrap();
+            assert_eq!(
+                sc.epoll_ctl(epfd, 0xffff, p[0], &mut ev),
+                Err(rt11_ffi_linux::native::errno::EINVAL),
+            );
+
+            sc.close(p[1]).unwrap();
+            sc.close(p[0]).unwrap();
+            sc.close(epfd).unwrap();
+        }
+    }
+
+    // Execute `/bin/true` in a child via argument vectors built with
+    // `ExecArgs`, and verify failures are returned to the caller.
+    #[test]
+    fn execve_check() {
+        let sc = Syscall::new();
+        let path = b"/bin/true\0";
+        let mut argv = crate::exec::ExecArgs::<2>::new();
+        let mut envp = crate::exec::ExecArgs::<1>::new();
+
+        argv.push(path).unwrap();
+        argv.push(b"foo\0").unwrap();
+        envp.push(b"RT11=1\0").unwrap();
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => {
+                let _ = unsafe { sc.execve(path.as_ptr(), argv.as_ptr(), envp.as_ptr()) };
+                sc.exit(127);
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 0),
+        }
+
+        assert_eq!(
+            unsafe { sc.execve("/rt11-nonexistent\x00".as_ptr(), argv.as_ptr(), envp.as_ptr()) },
+            Err(rt11_ffi_linux::native::errno::ENOENT),
+        );
+    }
+
+    // Check the accessibility of existing and non-existent files.
+    #[test]
+    fn faccessat_check() {
+        let sc = Syscall::new();
+        let cwd = rt11_ffi_linux::native::fcntl::AT_FDCWD;
+
+        unsafe {
+            sc.faccessat(cwd, b"/proc/self/exe\x00".as_ptr(), rt11_ffi_linux::native::fcntl::F_OK).unwrap();
+            sc.faccessat(cwd, b"/proc/self/exe\x00".as_ptr(), rt11_ffi_linux::native::fcntl::R_OK).unwrap();
+            assert_eq!(
+                sc.faccessat(cwd, b"/proc/self/rt11-nonexistent\x00".as_ptr(), rt11_ffi_linux::native::fcntl::F_OK),
+                Err(rt11_ffi_linux::native::errno::ENOENT),
+            );
+        }
+    }
+
+    // Advise and read ahead the test executable, and verify pipes are
+    // rejected.
+    #[test]
+    fn fadvise_check() {
+        let sc = Syscall::new();
+
+        let fd = unsafe { sc.open(b"/proc/self/exe\x00".as_ptr(), 0, 0) }.unwrap();
+
+        sc.fadvise(fd, 0, 0, rt11_ffi_linux::native::fcntl::POSIX_FADV_SEQUENTIAL).unwrap();
+        sc.fadvise(fd, 0, 4096, rt11_ffi_linux::native::fcntl::POSIX_FADV_WILLNEED).unwrap();
+        sc.readahead(fd, 0, 4096).unwrap();
+        assert_eq!(
+            sc.fadvise(fd, 0, 0, 0xffff),
+            Err(rt11_ffi_linux::native::errno::EINVAL),
+        );
+
+        let p = sc.pipe2(0).unwrap();
+        assert_eq!(
+            sc.fadvise(p[0], 0, 0, rt11_ffi_linux::native::fcntl::POSIX_FADV_NORMAL),
+            Err(rt11_ffi_linux::native::errno::ESPIPE),
+        );
+
+        unsafe {
+            sc.close(p[1]).unwrap();
+            sc.close(p[0]).unwrap();
+            sc.close(fd).unwrap();
+        }
+    }
+
+    // Verify that `FD_CLOEXEC` can be set and queried via `fcntl()`, and
+    // that the access mode is reported via `get_flags()`.
+    #[test]
+    fn fcntl_cloexec() {
+        let sc = Syscall::new();
+
+        let fd = unsafe { sc.memfd_create("foobar\x00".as_ptr(), 0) }.unwrap();
+        assert!(fd > 2);
+
+        let f = unsafe { sc.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFD, 0) };
+        assert_eq!(f, Ok(0));
+        sc.set_cloexec(fd).unwrap();
+        let f = unsafe { sc.fcntl(fd, rt11_ffi_linux::native::fcntl::F_GETFD, 0) };
+        assert_eq!(f, Ok(rt11_ffi_linux::native::fcntl::FD_CLOEXEC as usize));
+
+        assert_eq!(sc.get_flags(fd).unwrap() & 0o3, 0o2); // O_RDWR
+
+        unsafe { sc.close(fd).unwrap() };
+    }
+
+    // Fork the current task and verify the child can exit with a custom
+    // exit code, which is then collected by the parent.
+    #[test]
+    fn fork_check() {
+        let sc = Syscall::new();
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => sc.exit(71),
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 71),
+        }
+    }
+
+    // Verify that file synchronization succeeds on a valid file-descriptor
+    // and reports `EBADF` otherwise.
+    #[test]
+    fn fsync_check() {
+        let sc = Syscall::new();
+
+        let fd = unsafe { sc.memfd_create("foobar\x00".as_ptr(), 0) }.unwrap();
+        assert!(fd > 2);
+
+        assert_eq!(sc.fsync(fd), Ok(()));
+        assert_eq!(sc.fdatasync(fd), Ok(()));
+
+        unsafe { sc.close(fd).unwrap() };
+
+        assert_eq!(sc.fsync(fd), Err(rt11_ffi_linux::native::errno::EBADF));
+        assert_eq!(sc.fdatasync(fd), Err(rt11_ffi_linux::native::errno::EBADF));
+    }
+
+    // Read the entries of `/proc/self` and verify the fixed entries are
+    // reported with their types. Reading from a non-directory must fail.
+    #[test]
+    fn getdents_check() {
+        let sc = Syscall::new();
+        let mut buf = [0u64; 512];
+        let mut found = 0;
+
+        let fd = unsafe {
+            sc.open(
+                b"/proc/self\0".as_ptr(),
+                rt11_ffi_linux::native::fcntl::O_RDONLY | rt11_ffi_linux::native::fcntl::O_CLOEXEC,
+                0,
+            ).unwrap()
+        };
+
+        loop {
+            let n = unsafe {
+                sc.getdents64(fd, buf.as_mut_ptr() as *mut u8, core::mem::size_of_val(&buf)).unwrap()
+            };
+            if n == 0 {
+                break;
+            }
+
+            let data = unsafe { core::slice::from_raw_parts(buf.as_ptr() as *const u8, n) };
+            let mut off = 0;
+            while off < n {
+                let ent = unsafe {
+                    (data.as_ptr().add(off) as *const rt11_ffi_linux::native::dirent::Dirent64).read()
+                };
+                let rec = &data[off..off + ent.d_reclen as usize];
+                let name = &rec[rt11_ffi_linux::native::dirent::DIRENT64_NAME_OFFSET..];
+                let name = &name[..name.iter().position(|v| *v == 0).unwrap()];
+
+                if name == b"fd" {
+                    assert_eq!(ent.d_type, rt11_ffi_linux::native::dirent::DT_DIR);
+                    found += 1;
+                } else if name == b"exe" {
+                    assert_eq!(ent.d_type, rt11_ffi_linux::native::dirent::DT_LNK);
+                    found += 1;
+                }
+
+                off += ent.d_reclen as usize;
+            }
+        }
+        assert_eq!(found, 2);
+
+        unsafe {
+            sc.close(fd).unwrap();
+
+            let fd = sc.open(
+                b"/proc/self/exe\0".as_ptr(),
+                rt11_ffi_linux::native::fcntl::O_RDONLY | rt11_ffi_linux::native::fcntl::O_CLOEXEC,
+                0,
+            ).unwrap();
+            assert_eq!(
+                sc.getdents64(fd, buf.as_mut_ptr() as *mut u8, core::mem::size_of_val(&buf)),
+                Err(rt11_ffi_linux::native::errno::ENOTDIR),
+            );
+            sc.close(fd).unwrap();
+        }
+    }
+
+    // Query the resource usage of the process, its children, and the
+    // calling thread, and verify invalid targets are rejected.
+    #[test]
+    fn getrusage_check() {
+        let sc = Syscall::new();
+
+        let ru = sc.getrusage(rt11_ffi_linux::native::resource::RUSAGE_SELF).unwrap();
+        assert!(ru.ru_maxrss > 0);
+        assert!(ru.ru_utime.tv_usec >= 0 && ru.ru_utime.tv_usec < 1_000_000);
+        assert!(ru.ru_stime.tv_usec >= 0 && ru.ru_stime.tv_usec < 1_000_000);
+        assert_eq!(ru.ru_nswap, 0);
+
+        sc.getrusage(rt11_ffi_linux::native::resource::RUSAGE_CHILDREN).unwrap();
+        sc.getrusage(rt11_ffi_linux::native::resource::RUSAGE_THREAD).unwrap();
+        assert_eq!(sc.getrusage(7), Err(rt11_ffi_linux::native::errno::EINVAL));
+    }
+
+    // Query the identity of the calling thread, and verify it matches the
+    // credentials reported via `/proc`, and that the group list is sized
+    // correctly.
+    #[test]
+    fn identity_query() {
+        let sc = Syscall::new();
+        let mut buf = [0u8; 4096];
+        let mut groups = [0u32; 256];
+
+        let n = sc.read_proc(b"/proc/thread-self/status\0", &mut buf).unwrap();
+        let field = |key: &[u8]| -> std::vec::Vec<u32> {
+            buf[..n]
+                .split(|v| *v == b'\n')
+                .find_map(|v| v.strip_prefix(key))
+                .unwrap()
+                .split(|v| v.is_ascii_whitespace())
+                .filter(|v| !v.is_empty())
+                .map(|v| core::str::from_utf8(v).unwrap().parse().unwrap())
+                .collect()
+        };
+
+        let uid = field(b"Uid:");
+        let gid = field(b"Gid:");
+        assert_eq!(sc.getuid(), uid[0]);
+        assert_eq!(sc.geteuid(), uid[1]);
+        assert_eq!(sc.getgid(), gid[0]);
+        assert_eq!(sc.getegid(), gid[1]);
+
+        let n = sc.getgroups(&mut []).unwrap();
+        assert_eq!(sc.getgroups(&mut groups), Ok(n));
+        assert_eq!(&groups[..n], &field(b"Groups:")[..]);
+        if n > 0 {
+            assert_eq!(sc.getgroups(&mut groups[..n - 1]), Err(rt11_ffi_linux::native::errno::EINVAL));
+        }
+    }
+
+    // Watch a temporary directory via inotify and verify creation of a
+    // sub-directory is reported as `IN_CREATE` event with its name.
+    #[test]
+    fn inotify_check() {
+        let sc = Syscall::new();
+
+        let ifd = sc.inotify_init1(
+            rt11_ffi_linux::native::inotify::IN_CLOEXEC
+            | rt11_ffi_linux::native::inotify::IN_NONBLOCK,
+        ).unwrap();
+        let wd = unsafe {
+            sc.inotify_add_watch(
+                ifd,
+                "/tmp\x00".as_ptr(),
+                rt11_ffi_linux::native::inotify::IN_CREATE,
+            ).unwrap()
+        };
+
+        let name = std::format!("rt11-inotify-{}", std::process::id());
+        let path = std::format!("/tmp/{}\x00", name);
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &sc.ffi,
+                rt11_ffi_linux::native::nr::MKDIRAT as usize,
+                rt11_ffi_linux::native::fcntl::AT_FDCWD as isize as usize,
+                path.as_ptr() as usize,
+                0o700,
+            )
+        };
+        assert_eq!(r, 0);
+        let r = unsafe {
+            <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                &sc.ffi,
+                rt11_ffi_linux::native::nr::UNLINKAT as usize,
+                rt11_ffi_linux::native::fcntl::AT_FDCWD as isize as usize,
+                path.as_ptr() as usize,
+                0x200, // AT_REMOVEDIR
+            )
+        };
+        assert_eq!(r, 0);
+
+        let mut buf = [0u8; 256];
+        let r = unsafe { sc.read(ifd, buf.as_mut_ptr(), buf.len()) }.unwrap();
+
+        // Other tasks might create entries in the directory concurrently, so
+        // search for the expected event.
+        let e = crate::inotify::EventIter::new(&buf[..r])
+            .find(|e| e.name == name.as_bytes())
+            .unwrap();
+        assert_eq!(e.wd, wd as i32);
+        assert_eq!(
+            e.mask,
+            rt11_ffi_linux::native::inotify::IN_CREATE
+            | rt11_ffi_linux::native::inotify::IN_ISDIR,
+        );
+
+        sc.inotify_rm_watch(ifd, wd).unwrap();
+
+        unsafe { sc.close(ifd).unwrap() };
+    }
+
+    // Set up an I/O ring, enter it without any submissions, and verify
+    // unknown register operations are rejected. If `io_uring` is disabled on
+    // the system, this is skipped.
+    #[test]
+    fn io_uring_check() {
+        let sc = Syscall::new();
+        let mut params = rt11_ffi_linux::native::io_uring::IoUringParams::default();
+
+        let fd = match unsafe { sc.io_uring_setup(4, &mut params) } {
+            Ok(v) => v,
+            Err(rt11_ffi_linux::native::errno::ENOSYS)
+            | Err(rt11_ffi_linux::native::errno::EPERM) => return,
+            Err(e) => panic!("io_uring_setup failed: {}", e),
+        };
+        assert_eq!(params.sq_entries, 4);
+        assert!(params.cq_entries >= 4);
+        assert_ne!(params.sq_off.ring_entries, 0);
+        assert_ne!(params.cq_off.cqes, 0);
+
+        assert_eq!(unsafe { sc.io_uring_enter(fd, 0, 0, 0, core::ptr::null(), 0) }, Ok(0));
+        assert_eq!(
+            unsafe { sc.io_uring_register(fd, 0xffff, core::ptr::null(), 0) },
+            Err(rt11_ffi_linux::native::errno::EINVAL),
+        );
+
+        unsafe { sc.close(fd).unwrap() };
+    }
+
+    // Verify non-terminals are rejected by `isatty()` and terminal
+    // requests. If a pseudo-terminal can be opened, verify it is detected
+    // and its window size can be queried.
+    #[test]
+    fn ioctl_check() {
+        let sc = Syscall::new();
+        let mut ws = rt11_ffi_linux::native::termios::Winsize::default();
+
+        let fd = unsafe { sc.memfd_create(b"rt11-ioctl\x00".as_ptr(), 0) }.unwrap();
+        assert!(!sc.isatty(fd));
+        assert!(!sc.isatty(u32::MAX));
+        assert_eq!(
+            unsafe {
+                sc.ioctl(
+                    fd,
+                    rt11_ffi_linux::native::termios::TIOCGWINSZ as usize,
+                    &mut ws as *mut _ as usize,
+                )
+            },
+            Err(rt11_ffi_linux::native::errno::ENOTTY),
+        );
+        unsafe { sc.close(fd).unwrap() };
+
+        // O_RDWR | O_NOCTTY
+        if let Ok(fd) = unsafe { sc.open(b"/dev/ptmx\x00".as_ptr(), 0o2 | 0o400, 0) } {
+            assert!(sc.isatty(fd));
+            unsafe {
+                sc.ioctl(
+                    fd,
+                    rt11_ffi_linux::native::termios::TIOCGWINSZ as usize,
+                    &mut ws as *mut _ as usize,
+                ).unwrap();
+                sc.close(fd).unwrap();
+            }
+        }
+    }
+
+    // Verify the existence checks of `kill()` and `tgkill()` on the own
+    // process and its main thread, as well as on a non-existent process.
+    #[test]
+    fn kill_check() {
+        let sc = Syscall::new();
+        let pid = std::process::id() as i32;
+
+        sc.kill(pid, 0).unwrap();
+        sc.tgkill(pid, pid, 0).unwrap();
+
+        assert_eq!(sc.kill(i32::MAX, 0), Err(rt11_ffi_linux::native::errno::ESRCH));
+        assert_eq!(sc.tgkill(i32::MAX, pid, 0), Err(rt11_ffi_linux::native::errno::ESRCH));
+    }
+
+    // Advise a written anonymous mapping as cold and freeable, and verify
+    // the contents survive the former. Unaligned ranges must be rejected.
+    #[test]
+    fn madvise_check() {
+        let sc = Syscall::new();
+        let prot = rt11_ffi_linux::native::mman::PROT_READ | rt11_ffi_linux::native::mman::PROT_WRITE;
+        let flags = rt11_ffi_linux::native::mman::MAP_PRIVATE | rt11_ffi_linux::native::mman::MAP_ANONYMOUS;
+
+        unsafe {
+            let addr = sc.mmap(0, 65536, prot, flags, -1, 0).unwrap();
+            (addr as *mut u8).write_volatile(0x71);
+
+            match sc.madvise(addr, 65536, rt11_ffi_linux::native::mman::MADV_COLD) {
+                Ok(()) => assert_eq!((addr as *const u8).read_volatile(), 0x71),
+                // `MADV_COLD` was added in linux-5.4.
+                Err(e) => assert_eq!(e, rt11_ffi_linux::native::errno::EINVAL),
+            }
+            sc.madvise(addr, 65536, rt11_ffi_linux::native::mman::MADV_FREE).unwrap();
+
+            assert_eq!(
+                sc.madvise(addr + 1, 1, rt11_ffi_linux::native::mman::MADV_NORMAL),
+                Err(rt11_ffi_linux::native::errno::EINVAL),
+            );
+
+            sc.munmap(addr, 65536).unwrap();
+        }
+    }
+
+    // Create a secret memory file, size and map it, and verify the mapping
+    // is usable. Kernels without secret memory are tolerated.
+    #[test]
+    fn memfd_secret_check() {
+        let sc = Syscall::new();
+        let prot = rt11_ffi_linux::native::mman::PROT_READ | rt11_ffi_linux::native::mman::PROT_WRITE;
+
+        let fd = match sc.memfd_secret(rt11_ffi_linux::native::fcntl::O_CLOEXEC) {
+            Ok(v) => v,
+            Err(e) => {
+                // Secret memory is not available on this kernel.
+                assert_eq!(e, rt11_ffi_linux::native::errno::ENOSYS);
+                return;
+            },
+        };
+        assert_eq!(sc.memfd_secret(!0), Err(rt11_ffi_linux::native::errno::EINVAL));
+
+        sc.ftruncate(fd, 65536).unwrap();
+        unsafe {
+            let addr = sc.mmap(0, 65536, prot, rt11_ffi_linux::native::mman::MAP_SHARED, fd as i32, 0).unwrap();
+            (addr as *mut u8).write_volatile(0x71);
+            assert_eq!((addr as *const u8).read_volatile(), 0x71);
+
+            sc.munmap(addr, 65536).unwrap();
+            sc.close(fd).unwrap();
+        }
+    }
+
+    // Touch the first page of an anonymous mapping and verify it is reported
+    // as resident. Unaligned addresses must be rejected.
+    #[test]
+    fn mincore_check() {
+        let sc = Syscall::new();
+        let prot = rt11_ffi_linux::native::mman::PROT_READ | rt11_ffi_linux::native::mman::PROT_WRITE;
+        let flags = rt11_ffi_linux::native::mman::MAP_PRIVATE | rt11_ffi_linux::native::mman::MAP_ANONYMOUS;
+        // Sufficient for any page size of at least 4KiB.
+        let mut vec = [0u8; 16];
+
+        unsafe {
+            let addr = sc.mmap(0, 65536, prot, flags, -1, 0).unwrap();
+            (addr as *mut u8).write_volatile(0x71);
+
+            sc.mincore(addr, 65536, vec.as_mut_ptr()).unwrap();
+            assert_eq!(vec[0] & 1, 1);
+
+            assert_eq!(
+                sc.mincore(addr + 1, 1, vec.as_mut_ptr()),
+                Err(rt11_ffi_linux::native::errno::EINVAL),
+            );
+
+            sc.munmap(addr, 65536).unwrap();
+        }
+    }
+
+    // Map anonymous memory, write to it, and unmap it again. Unaligned
+    // offsets must be rejected.
+    #[test]
+    fn mmap_check() {
+        let sc = Syscall::new();
+        let prot = rt11_ffi_linux::native::mman::PROT_READ | rt11_ffi_linux::native::mman::PROT_WRITE;
+        let flags = rt11_ffi_linux::native::mman::MAP_PRIVATE | rt11_ffi_linux::native::mman::MAP_ANONYMOUS;
+
+        unsafe {
+            let addr = sc.mmap(0, 8192, prot, flags, -1, 0).unwrap();
+            let v = core::slice::from_raw_parts_mut(addr as *mut u8, 8192);
+            assert!(v.iter().all(|v| *v == 0));
+            v[8191] = 0x71;
+            assert_eq!(v[8191], 0x71);
+            sc.munmap(addr, 8192).unwrap();
+
+            assert_eq!(
+                sc.mmap(0, 4096, prot, flags, -1, 1),
+                Err(rt11_ffi_linux::native::errno::EINVAL),
+            );
+
+            // Map the second page of a file both directly and as part of a
+            // larger mapping. Mixing up byte and page offsets would map
+            // different parts of the file. File offsets must be aligned to
+            // the page size, so query it from the auxiliary vector.
+            let mut auxv = [0u8; 1024];
+            let n = sc.read_proc(b"/proc/self/auxv\0", &mut auxv).unwrap();
+            let pagesz = auxv[..n]
+                .chunks_exact(2 * core::mem::size_of::<usize>())
+                .map(|v| v.split_at(core::mem::size_of::<usize>()))
+                .map(|(k, v)| (
+                    usize::from_ne_bytes(k.try_into().unwrap()),
+                    usize::from_ne_bytes(v.try_into().unwrap()),
+                ))
+                .find(|(k, _)| *k == rt11_ffi_linux::native::auxvec::AT_PAGESZ as usize)
+                .unwrap()
+                .1;
+
+            let fd = sc.open(
+                b"/proc/self/exe\0".as_ptr(),
+                rt11_ffi_linux::native::fcntl::O_RDONLY | rt11_ffi_linux::native::fcntl::O_CLOEXEC,
+                0,
+            ).unwrap();
+            let prot = rt11_ffi_linux::native::mman::PROT_READ;
+            let flags = rt11_ffi_linux::native::mman::MAP_PRIVATE;
+            let all = sc.mmap(0, 2 * pagesz, prot, flags, fd as i32, 0).unwrap();
+            let page = sc.mmap(0, pagesz, prot, flags, fd as i32, pagesz as u64).unwrap();
+            assert_eq!(
+                core::slice::from_raw_parts((all + pagesz) as *const u8, pagesz),
+                core::slice::from_raw_parts(page as *const u8, pagesz),
+            );
+            sc.munmap(page, pagesz).unwrap();
+            sc.munmap(all, 2 * pagesz).unwrap();
+            sc.close(fd).unwrap();
+        }
+    }
+
+    // Mount and unmount a file system in a new mount namespace of a forked
+    // child.
+    #[test]
+    fn mount_check() {
+        let sc = Syscall::new();
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => unsafe {
+                match sc.unshare(rt11_ffi_linux::native::sched::CLONE_NEWNS) {
+                    Ok(()) => {},
+                    Err(rt11_ffi_linux::native::errno::EPERM) => sc.exit(0),
+                    Err(_) => sc.exit(1),
+                }
+
+                // Prevent propagation to the mount namespace of the parent.
+                match sc.mount(
+                    core::ptr::null(),
+                    b"/\x00".as_ptr(),
+                    core::ptr::null(),
+                    rt11_ffi_linux::native::mount::MS_REC
+                    | rt11_ffi_linux::native::mount::MS_PRIVATE,
+                    core::ptr::null(),
+                ) {
+                    Ok(()) => {},
+                    Err(rt11_ffi_linux::native::errno::EPERM) => sc.exit(0),
+                    Err(_) => sc.exit(2),
+                }
+
+                if sc.mount(
+                    b"rt11\x00".as_ptr(),
+                    b"/tmp\x00".as_ptr(),
+                    b"tmpfs\x00".as_ptr(),
+                    rt11_ffi_linux::native::mount::MS_NOSUID
+                    | rt11_ffi_linux::native::mount::MS_NODEV
+                    | rt11_ffi_linux::native::mount::MS_NOEXEC,
+                    b"mode=0700\x00".as_ptr(),
+                ) != Ok(()) {
+                    sc.exit(3);
+                }
+                if sc.umount2(b"/tmp\x00".as_ptr(), rt11_ffi_linux::native::mount::MNT_DETACH)
+                    != Ok(())
+                {
+                    sc.exit(4);
+                }
+                if sc.umount2(b"/rt11-nonexistent\x00".as_ptr(), 0)
+                    != Err(rt11_ffi_linux::native::errno::ENOENT)
+                {
+                    sc.exit(5);
+                }
+
+                sc.exit(0);
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 0),
+        }
+    }
+
+    // Create and join namespaces in a forked child, since namespaces of the
+    // test-suite must not be modified.
+    #[test]
+    fn namespace_check() {
+        let sc = Syscall::new();
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => {
+                match unsafe { sc.unshare(rt11_ffi_linux::native::sched::CLONE_NEWUTS) } {
+                    Ok(()) | Err(rt11_ffi_linux::native::errno::EPERM) => {},
+                    Err(_) => sc.exit(1),
+                }
+                if unsafe { sc.setns(u32::MAX, rt11_ffi_linux::native::sched::CLONE_NEWUTS) }
+                    != Err(rt11_ffi_linux::native::errno::EBADF)
+                {
+                    sc.exit(2);
+                }
+                sc.exit(0);
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 0),
+        }
+    }
+
+    // Open a file via `open()` and `openat()`, both absolute and relative
+    // to a directory file-descriptor, and verify non-existent files are
+    // rejected.
+    #[test]
+    fn open_check() {
+        let sc = Syscall::new();
+
+        unsafe {
+            let fd = sc.open(b"/proc/self/exe\x00".as_ptr(), 0, 0).unwrap();
+            sc.close(fd).unwrap();
+
+            let dir = sc.open(b"/proc/self\x00".as_ptr(), 0, 0).unwrap();
+            let fd = sc.openat(dir as i32, b"exe\x00".as_ptr(), 0, 0).unwrap();
+            sc.close(fd).unwrap();
+            assert_eq!(
+                sc.openat(dir as i32, b"rt11-nonexistent\x00".as_ptr(), 0, 0),
+                Err(rt11_ffi_linux::native::errno::ENOENT),
+            );
+            sc.close(dir).unwrap();
+
+            assert_eq!(
+                sc.open(b"/proc/self/rt11-nonexistent\x00".as_ptr(), 0, 0),
+                Err(rt11_ffi_linux::native::errno::ENOENT),
+            );
+        }
+    }
+
+    // Set the parent-death signal of the calling thread and verify it can be
+    // queried. Invalid signals must be rejected.
+    #[test]
+    fn pdeathsig_check() {
+        let sc = Syscall::new();
+        let mut sig: u32 = 0;
+
+        sc.set_pdeathsig(rt11_ffi_linux::native::signal::SIGTERM).unwrap();
+        unsafe {
+            sc.prctl(
+                rt11_ffi_linux::native::prctl::PR_GET_PDEATHSIG,
+                &mut sig as *mut u32 as usize,
+                0,
+                0,
+                0,
+            ).unwrap();
+        }
+        assert_eq!(sig, rt11_ffi_linux::native::signal::SIGTERM);
+
+        assert_eq!(
+            sc.set_pdeathsig(rt11_ffi_linux::native::signal::SIGRTMAX + 1),
+            Err(rt11_ffi_linux::native::errno::EINVAL),
+        );
+
+        sc.set_pdeathsig(0).unwrap();
+    }
+
+    // Query the personality of the current task and verify that querying it
+    // does not change it.
+    #[test]
+    fn personality_query() {
+        let sc = Syscall::new();
+
+        let p0 = unsafe { sc.personality(0xffffffff) }.unwrap();
+        let p1 = unsafe { sc.personality(0xffffffff) }.unwrap();
+        assert_eq!(p0, p1);
+        assert_eq!(
+            p0 & rt11_ffi_linux::native::personality::PER_MASK,
+            rt11_ffi_linux::native::personality::PER_LINUX,
+        );
+    }
+
+    // Open a pidfd of the own process, signal it with the existence check,
+    // and duplicate a file-descriptor via it.
+    #[test]
+    fn pidfd_check() {
+        let sc = Syscall::new();
+        let pid = std::process::id() as i32;
+
+        let pidfd = sc.pidfd_open(pid, 0).unwrap();
+        unsafe { sc.pidfd_send_signal(pidfd, 0, core::ptr::null(), 0).unwrap() };
+
+        let dup = sc.pidfd_getfd(pidfd, pidfd, 0).unwrap();
+        assert_ne!(dup, pidfd);
+        assert_eq!(
+            unsafe { sc.fcntl(dup, rt11_ffi_linux::native::fcntl::F_GETFD, 0) }.unwrap(),
+            rt11_ffi_linux::native::fcntl::FD_CLOEXEC as usize,
+        );
+
+        assert_eq!(sc.pidfd_open(i32::MAX, 0), Err(rt11_ffi_linux::native::errno::ESRCH));
+
+        unsafe {
+            sc.close(dup).unwrap();
+            sc.close(pidfd).unwrap();
+        }
+    }
+
+    // Poll a pipe before and after writing to it, and once its write-end
+    // is closed.
+    #[test]
+    fn ppoll_check() {
+        let sc = Syscall::new();
+        let zero = rt11_ffi_linux::native::time::Timespec::default();
+
+        let p = sc.pipe2(rt11_ffi_linux::native::fcntl::O_CLOEXEC).unwrap();
+
+        let mut fds = [rt11_ffi_linux::native::poll::PollFd {
+            fd: p[0] as i32,
+            events: rt11_ffi_linux::native::poll::POLLIN,
+            revents: 0,
+        }];
+
+        unsafe {
+            assert_eq!(sc.ppoll(fds.as_mut_ptr(), 1, &zero, core::ptr::null()), Ok(0));
+            assert_eq!(fds[0].revents, 0);
+
+            assert_eq!(sc.write(p[1], b"foo".as_ptr(), 3), Ok(3));
+            assert_eq!(sc.ppoll(fds.as_mut_ptr(), 1, core::ptr::null(), core::ptr::null()), Ok(1));
+            assert_eq!(fds[0].revents, rt11_ffi_linux::native::poll::POLLIN);
+
+            sc.close(p[1]).unwrap();
+            assert_eq!(sc.ppoll(fds.as_mut_ptr(), 1, &zero, core::ptr::null()), Ok(1));
+            assert_ne!(fds[0].revents & rt11_ffi_linux::native::poll::POLLHUP, 0);
+
+            sc.close(p[0]).unwrap();
+        }
+    }
+
+    // Query the nice value of the calling thread, set it to the same value,
+    // and verify invalid targets are rejected.
+    #[test]
+    fn priority_check() {
+        let sc = Syscall::new();
+
+        let nice = sc.getpriority(rt11_ffi_linux::native::resource::PRIO_PROCESS, 0).unwrap();
+        assert!((-20..20).contains(&nice));
+
+        sc.setpriority(rt11_ffi_linux::native::resource::PRIO_PROCESS, 0, nice).unwrap();
+        assert_eq!(
+            sc.getpriority(rt11_ffi_linux::native::resource::PRIO_PROCESS, 0),
+            Ok(nice),
+        );
+
+        assert_eq!(sc.getpriority(0xffff, 0), Err(rt11_ffi_linux::native::errno::EINVAL));
+    }
+
+    // Drop privileges in a forked child and verify they cannot be regained.
+    // Without privileges, `EPERM` is expected instead.
+    #[test]
+    fn privilege_drop() {
+        let sc = Syscall::new();
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => {
+                match unsafe { sc.drop_privileges(65534, 65534, &[]) } {
+                    Ok(()) => {
+                        if sc.setresuid(0, 0, 0) != Err(rt11_ffi_linux::native::errno::EPERM) {
+                            sc.exit(1);
+                        }
+                        if sc.setgid(0) != Err(rt11_ffi_linux::native::errno::EPERM) {
+                            sc.exit(2);
+                        }
+                        sc.exit(0);
+                    },
+                    Err(rt11_ffi_linux::native::errno::EPERM) => sc.exit(0),
+                    Err(_) => sc.exit(3),
+                }
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 0),
+        }
+    }
+
+    // Query the stack limit and verify that writing it back unmodified
+    // succeeds.
+    #[test]
+    fn prlimit_query() {
+        let sc = Syscall::new();
+        let mut old = rt11_ffi_linux::native::resource::Rlimit64::default();
+        let mut cur = rt11_ffi_linux::native::resource::Rlimit64::default();
+
+        sc.prlimit64(
+            0,
+            rt11_ffi_linux::native::resource::RLIMIT_STACK,
+            None,
+            Some(&mut old),
+        ).unwrap();
+        assert!(old.rlim_cur <= old.rlim_max);
+
+        sc.prlimit64(
+            0,
+            rt11_ffi_linux::native::resource::RLIMIT_STACK,
+            Some(&old),
+            Some(&mut cur),
+        ).unwrap();
+        assert_eq!(old, cur);
+    }
+
+    // Advise a mapping of the own process via a pidfd, which might be denied
+    // without `CAP_SYS_NICE`, and verify reserved flags are rejected.
+    #[test]
+    fn process_madvise_check() {
+        let sc = Syscall::new();
+        let prot = rt11_ffi_linux::native::mman::PROT_READ | rt11_ffi_linux::native::mman::PROT_WRITE;
+        let flags = rt11_ffi_linux::native::mman::MAP_PRIVATE | rt11_ffi_linux::native::mman::MAP_ANONYMOUS;
+        let pidfd = sc.pidfd_open(std::process::id() as i32, 0).unwrap();
+
+        unsafe {
+            let addr = sc.mmap(0, 65536, prot, flags, -1, 0).unwrap();
+            (addr as *mut u8).write_volatile(0x71);
+            let iov = [rt11_ffi_linux::native::uio::Iovec { iov_base: addr, iov_len: 65536 }];
+
+            match sc.process_madvise(
+                pidfd,
+                iov.as_ptr(),
+                iov.len(),
+                rt11_ffi_linux::native::mman::MADV_COLD,
+                0,
+            ) {
+                Ok(n) => assert_eq!(n, 65536),
+                Err(e) => assert!(
+                    e == rt11_ffi_linux::native::errno::EPERM
+                        || e == rt11_ffi_linux::native::errno::ENOSYS,
+                ),
+            }
+            assert_eq!((addr as *const u8).read_volatile(), 0x71);
+
+            let r = sc.process_madvise(
+                pidfd,
+                iov.as_ptr(),
+                iov.len(),
+                rt11_ffi_linux::native::mman::MADV_COLD,
+                1,
+            );
+            assert!(r.is_err());
+
+            sc.munmap(addr, 65536).unwrap();
+            sc.close(pidfd).unwrap();
+        }
+    }
+
+    // Write to a pipe and read the data back, including a short read and
+    // the end-of-file once the write-end is closed.
+    #[test]
+    fn read_write_check() {
+        let sc = Syscall::new();
+        let mut buf = [0u8; 8];
+
+        let p = sc.pipe2(rt11_ffi_linux::native::fcntl::O_CLOEXEC).unwrap();
+
+        unsafe {
+            assert_eq!(sc.write(p[1], b"foobar".as_ptr(), 6), Ok(6));
+            assert_eq!(sc.read(p[0], buf.as_mut_ptr(), 4), Ok(4));
+            assert_eq!(&buf[..4], b"foob");
+            assert_eq!(sc.read(p[0], buf.as_mut_ptr(), 8), Ok(2));
+            assert_eq!(&buf[..2], b"ar");
+
+            sc.close(p[1]).unwrap();
+            assert_eq!(sc.read(p[0], buf.as_mut_ptr(), 8), Ok(0));
+            assert_eq!(
+                sc.write(p[0], buf.as_ptr(), 8),
+                Err(rt11_ffi_linux::native::errno::EBADF),
+            );
+            sc.close(p[0]).unwrap();
+        }
+    }
+
+    // Transfer a buffer larger than the pipe capacity between two threads,
+    // which requires multiple partial transfers, and verify a premature
+    // end-of-file is reported as such.
+    #[test]
+    fn read_exact_check() {
+        let sc = Syscall::new();
+
+        let p = sc.pipe2(rt11_ffi_linux::native::fcntl::O_CLOEXEC).unwrap();
+
+        let data: std::vec::Vec<u8> = (0..(1 << 20)).map(|v: u32| v as u8).collect();
+        let tx = data.clone();
+        let writer = std::thread::spawn(move || {
+            let sc = Syscall::new();
+            sc.write_all(p[1], &tx).unwrap();
+            sc.write_all(p[1], b"foo").unwrap();
+            sc.write_all(p[1], &[]).unwrap();
+            unsafe { sc.close(p[1]).unwrap() };
+        });
+
+        let mut buf = std::vec![0u8; data.len()];
+        sc.read_exact(p[0], &mut buf).unwrap();
+        assert!(buf == data);
+
+        let mut buf = [0u8; 8];
+        assert_eq!(sc.read_exact(p[0], &mut buf), Err(UNEXPECTED_EOF));
+        assert_eq!(&buf[..3], b"foo");
+        assert_eq!(sc.read_exact(p[0], &mut []), Ok(()));
+
+        writer.join().unwrap();
+        assert_eq!(sc.write_all(p[0], b"foo"), Err(rt11_ffi_linux::native::errno::EBADF));
+        unsafe { sc.close(p[0]).unwrap() };
+    }
+
+    // Read a `/proc` file of known content, both fully and into a buffer
+    // that is too small, and verify invalid paths are rejected.
+    #[test]
+    fn read_proc_check() {
+        let sc = Syscall::new();
+        let mut buf = [0u8; 4096];
+        let pid = std::format!("{} (", std::process::id());
+
+        let n = sc.read_proc(b"/proc/self/stat\0", &mut buf).unwrap();
+        assert!(n > 0 && n < buf.len());
+        assert!(buf[..n].starts_with(pid.as_bytes()));
+
+        let n = sc.read_proc(b"/proc/self/status\0", &mut buf[..8]).unwrap();
+        assert_eq!(n, 8);
+        assert_eq!(&buf[..6], b"Name:\t");
+
+        assert_eq!(
+            sc.read_proc(b"/proc/self/stat", &mut buf),
+            Err(rt11_ffi_linux::native::errno::EINVAL),
+        );
+        assert_eq!(
+            sc.read_proc(b"/proc/self/nonexistent\0", &mut buf),
+            Err(rt11_ffi_linux::native::errno::ENOENT),
+        );
+        assert_eq!(sc.read_proc(b"/proc/self/stat\0", &mut []), Ok(0));
+    }
+
+    // Interrupt a timed call twice, and verify it is retried with a
+    // decreasing timeout. Expired deadlines must pass a timeout of 0.
+    #[test]
+    fn retry_timed_check() {
+        let sc = Syscall::new();
+        let mut timeouts = std::vec::Vec::new();
+
+        let mono = rt11_ffi_linux::native::time::CLOCK_MONOTONIC;
+        let mut deadline = sc.clock_gettime(mono).unwrap();
+        deadline.tv_sec += 60;
+
+        let r = sc.retry_timed(deadline, |t| {
+            timeouts.push(*t);
+            if timeouts.len() < 3 {
+                Err(rt11_ffi_linux::native::errno::EINTR)
+            } else {
+                Ok(71)
+            }
+        });
+        assert_eq!(r, Ok(71));
+        assert_eq!(timeouts.len(), 3);
+        assert!(timeouts[0].tv_sec <= 60);
+        assert!(timeouts[0] >= timeouts[1] && timeouts[1] >= timeouts[2]);
+        assert!(timeouts[2].tv_sec >= 59);
+
+        let r: Result<(), Errno> = sc.retry_timed(Default::default(), |t| {
+            assert_eq!(*t, Default::default());
+            Err(rt11_ffi_linux::native::errno::ETIMEDOUT)
+        });
+        assert_eq!(r, Err(rt11_ffi_linux::native::errno::ETIMEDOUT));
+    }
+
+    // Query the default action of a signal, and block and unblock it on the
+    // calling thread. `SIGKILL` must not be modifiable.
+    #[test]
+    fn rt_sigaction_mask_check() {
+        let sc = Syscall::new();
+        let bit = 1u64 << (rt11_ffi_linux::native::signal::SIGWINCH - 1);
+        let mut act = rt11_ffi_linux::native::signal::Sigaction::default();
+        let mut old = 0u64;
+        let mut cur = 0u64;
+
+        unsafe {
+            sc.rt_sigaction(
+                rt11_ffi_linux::native::signal::SIGWINCH,
+                core::ptr::null(),
+                &mut act,
+            ).unwrap();
+            assert_eq!(act.sa_handler, rt11_ffi_linux::native::signal::SIG_DFL);
+
+            assert_eq!(
+                sc.rt_sigaction(
+                    rt11_ffi_linux::native::signal::SIGKILL,
+                    &rt11_ffi_linux::native::signal::Sigaction::default(),
+                    core::ptr::null_mut(),
+                ),
+                Err(rt11_ffi_linux::native::errno::EINVAL),
+            );
+
+            sc.rt_sigprocmask(rt11_ffi_linux::native::signal::SIG_BLOCK, Some(&bit), Some(&mut old)).unwrap();
+            sc.rt_sigprocmask(rt11_ffi_linux::native::signal::SIG_BLOCK, None, Some(&mut cur)).unwrap();
+            assert_eq!(cur & bit, bit);
+
+            sc.rt_sigprocmask(rt11_ffi_linux::native::signal::SIG_SETMASK, Some(&old), None).unwrap();
+            sc.rt_sigprocmask(rt11_ffi_linux::native::signal::SIG_BLOCK, None, Some(&mut cur)).unwrap();
+            assert_eq!(cur, old);
+        }
+    }
+
+    // Query the CPU affinity, and verify it round-trips through
+    // `sched_setaffinity()`. An empty set must be rejected.
+    #[test]
+    fn sched_affinity_check() {
+        let sc = Syscall::new();
+        let mut set = crate::sched::CpuSet::new();
+        let mut cur = crate::sched::CpuSet::new();
+
+        let n = sc.sched_getaffinity(0, &mut set).unwrap();
+        assert!(n > 0 && n <= core::mem::size_of::<crate::sched::CpuSet>());
+        assert!(set.count() > 0);
+
+        sc.sched_setaffinity(0, &set).unwrap();
+        sc.sched_getaffinity(0, &mut cur).unwrap();
+        assert_eq!(cur, set);
+
+        assert_eq!(
+            sc.sched_setaffinity(0, &crate::sched::CpuSet::new()),
+            Err(rt11_ffi_linux::native::errno::EINVAL),
+        );
+    }
+
+    // Query the session and process group of the calling process, and
+    // create a new session in a child.
+    #[test]
+    fn session_check() {
+        let sc = Syscall::new();
+
+        assert!(sc.getsid(0).unwrap() > 0);
+        assert!(sc.getpgid(0).unwrap() > 0);
+        assert_eq!(sc.getsid(i32::MAX), Err(rt11_ffi_linux::native::errno::ESRCH));
+        assert_eq!(sc.getpgid(i32::MAX), Err(rt11_ffi_linux::native::errno::ESRCH));
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => {
+                let Ok(sid) = sc.setsid() else {
+                    sc.exit(1);
+                };
+                if sc.getsid(0) != Ok(sid) || sc.getpgid(0) != Ok(sid) {
+                    sc.exit(2);
+                }
+                if sc.setsid() != Err(rt11_ffi_linux::native::errno::EPERM) {
+                    sc.exit(3);
+                }
+                if sc.setpgid(0, 0) != Err(rt11_ffi_linux::native::errno::EPERM) {
+                    sc.exit(4);
+                }
+                sc.exit(0);
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 0),
+        }
+    }
+
+    // Flush the file system of a test-owned file in a forked child and
+    // exit, and verify the parent collects the exit code. This covers the
+    // steps of `shutdown()`, without flushing all file systems of the host
+    // via `sync()`.
+    #[test]
+    fn shutdown_check() {
+        let sc = Syscall::new();
+
+        let fd = unsafe { sc.memfd_create("foobar\x00".as_ptr(), 0) }.unwrap();
+        assert!(fd > 2);
+
+        match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => {
+                let r = sc.syncfs(fd);
+                sc.exit_group(if r.is_ok() { 72 } else { 1 });
+            },
+            ForkResult::Parent(pid) => assert_eq!(reap_exit(&sc, pid), 72),
+        }
+
+        unsafe { sc.close(fd).unwrap() };
+        assert_eq!(sc.syncfs(fd), Err(rt11_ffi_linux::native::errno::EBADF));
+    }
+
+    // Install an alternate signal stack on the calling thread, query it
+    // back, and disable it again. Tiny stacks must be rejected. The
+    // original stack of the thread is restored afterwards.
+    #[test]
+    fn sigaltstack_check() {
+        use rt11_ffi_linux::native::signal;
+
+        let sc = Syscall::new();
+        let mut stack = [0u8; 65536];
+        let ss = signal::Stack {
+            ss_sp: stack.as_mut_ptr() as usize,
+            ss_flags: 0,
+            ss_size: stack.len(),
+        };
+        let mut orig = signal::Stack::default();
+        let mut old = signal::Stack::default();
+
+        unsafe {
+            sc.sigaltstack(None, Some(&mut orig)).unwrap();
+            sc.sigaltstack(Some(&ss), None).unwrap();
+            sc.sigaltstack(None, Some(&mut old)).unwrap();
+            assert_eq!(old, ss);
+
+            let off = signal::Stack { ss_flags: signal::SS_DISABLE as i32, ..Default::default() };
+            sc.sigaltstack(Some(&off), Some(&mut old)).unwrap();
+            assert_eq!(old, ss);
+            sc.sigaltstack(None, Some(&mut old)).unwrap();
+            assert_eq!(old.ss_flags, signal::SS_DISABLE as i32);
+
+            assert_eq!(
+                sc.sigaltstack(Some(&signal::Stack { ss_size: 16, ..ss }), None),
+                Err(rt11_ffi_linux::native::errno::ENOMEM),
+            );
+
+            sc.sigaltstack(Some(&orig), None).unwrap();
+        }
+    }
+
+    // Create a UNIX stream socket, query and set options, and verify that
+    // connecting to an unbound abstract address is refused.
+    #[test]
+    fn socket_check() {
+        let sc = Syscall::new();
+
+        let fd = sc.socket(
+            rt11_ffi_linux::native::socket::AF_UNIX,
+            rt11_ffi_linux::native::socket::SOCK_STREAM
+            | rt11_ffi_linux::native::socket::SOCK_CLOEXEC,
+            0,
+        ).unwrap();
+
+        unsafe {
+            let mut v: i32 = 0;
+            let mut len = core::mem::size_of::<i32>() as u32;
+            sc.getsockopt(
+                fd,
+                rt11_ffi_linux::native::socket::SOL_SOCKET,
+                rt11_ffi_linux::native::socket::SO_TYPE,
+                &mut v as *mut i32 as *mut u8,
+                &mut len,
+            ).unwrap();
+            assert_eq!(v as u32, rt11_ffi_linux::native::socket::SOCK_STREAM);
+            assert_eq!(len, 4);
+
+            // The kernel doubles the requested buffer size.
+            let v: i32 = 8192;
+            sc.setsockopt(
+                fd,
+                rt11_ffi_linux::native::socket::SOL_SOCKET,
+                rt11_ffi_linux::native::socket::SO_SNDBUF,
+                &v as *const i32 as *const u8,
+                4,
+            ).unwrap();
+            let mut v: i32 = 0;
+            sc.getsockopt(
+                fd,
+                rt11_ffi_linux::native::socket::SOL_SOCKET,
+                rt11_ffi_linux::native::socket::SO_SNDBUF,
+                &mut v as *mut i32 as *mut u8,
+                &mut len,
+            ).unwrap();
+            assert!(v >= 8192);
+
+            assert_eq!(
+                sc.getsockopt(
+                    fd,
+                    rt11_ffi_linux::native::socket::SOL_SOCKET,
+                    u32::MAX >> 1,
+                    &mut v as *mut i32 as *mut u8,
+                    &mut len,
+                ),
+                Err(rt11_ffi_linux::native::errno::ENOPROTOOPT),
+            );
+
+            let name = std::format!("rt11-socket-{}", std::process::id());
+            let mut addr = rt11_ffi_linux::native::socket::SockaddrUn::default();
+            addr.sun_path[1..1 + name.len()].copy_from_slice(name.as_bytes());
+            assert_eq!(
+                sc.connect(
+                    fd,
+                    &addr as *const _ as *const u8,
+                    (2 + 1 + name.len()) as u32,
+                ),
+                Err(rt11_ffi_linux::native::errno::ECONNREFUSED),
+            );
+
+            sc.close(fd).unwrap();
+        }
+
+        assert_eq!(
+            sc.socket(u32::MAX >> 1, rt11_ffi_linux::native::socket::SOCK_STREAM, 0),
+            Err(rt11_ffi_linux::native::errno::EAFNOSUPPORT),
+        );
+    }
+
+    // Query the system information and verify it reports at least the
+    // memory and the task running this test.
+    #[test]
+    fn sysinfo_check() {
+        let sc = Syscall::new();
+
+        let info = sc.sysinfo().unwrap();
+        assert!(info.uptime >= 0);
+        assert!(info.mem_unit >= 1);
+        assert!(info.totalram > 0);
+        assert!(info.freeram <= info.totalram);
+        assert!(info.freeswap <= info.totalswap);
+        assert!(info.procs >= 1);
+    }
+
+    // Grow and shrink a memfd via `ftruncate()` and `truncate()`, and verify
+    // its size via `lseek(SEEK_END)`.
+    #[test]
+    fn truncate_check() {
+        let sc = Syscall::new();
+
+        let fd = unsafe {
+            sc.memfd_create("foobar\x00".as_ptr(), rt11_ffi_linux::native::mman::MFD_CLOEXEC)
+        }.unwrap();
+        assert!(fd > 2);
+
+        let size = || sc.lseek(fd, 0, rt11_ffi_linux::native::fcntl::SEEK_END);
+
+        assert_eq!(size(), Ok(0));
+        sc.ftruncate(fd, 8193).unwrap();
+        assert_eq!(size(), Ok(8193));
+        sc.ftruncate(fd, 10).unwrap();
+        assert_eq!(size(), Ok(10));
+        assert_eq!(sc.ftruncate(fd, u64::MAX), Err(rt11_ffi_linux::native::errno::EINVAL));
+
+        let path = std::format!("/proc/self/fd/{}\x00", fd);
+        unsafe { sc.truncate(path.as_ptr(), 4096).unwrap() };
+        assert_eq!(size(), Ok(4096));
+
+        unsafe { sc.close(fd).unwrap() };
+    }
+
+    // Reap a child via `wait4()`, and verify the status word as well as
+    // that the child cannot be reaped twice.
+    #[test]
+    fn wait4_check() {
+        let sc = Syscall::new();
+        let mut status: i32 = 0;
+
+        let pid = match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => sc.exit(71),
+            ForkResult::Parent(pid) => pid,
+        };
+
+        assert_eq!(sc.wait4(pid as i32, &mut status, 0), Ok(pid));
+        assert!(crate::wait::wifexited(status));
+        assert_eq!(crate::wait::wexitstatus(status), 71);
+
+        assert_eq!(
+            sc.wait4(pid as i32, &mut status, rt11_ffi_linux::native::wait::WNOHANG),
+            Err(rt11_ffi_linux::native::errno::ECHILD),
+        );
+    }
+
+    // Reap children via their task ID and via a pidfd, and verify the
+    // reported exit information.
+    #[test]
+    fn waitid_check() {
+        let sc = Syscall::new();
+        let spawn = || match unsafe { sc.fork() }.unwrap() {
+            ForkResult::Child => sc.exit(71),
+            ForkResult::Parent(pid) => pid,
+        };
+
+        let pid = spawn();
+        let mut info = rt11_ffi_linux::native::wait::Siginfo::default();
+        unsafe {
+            sc.waitid(
+                rt11_ffi_linux::native::wait::P_PID,
+                pid,
+                &mut info,
+                rt11_ffi_linux::native::wait::WEXITED,
+            ).unwrap();
+        }
+        assert_eq!(info.si_signo as u32, rt11_ffi_linux::native::signal::SIGCHLD);
+        assert_eq!(info.si_code, rt11_ffi_linux::native::wait::CLD_EXITED);
+        assert_eq!(info.si_pid, pid as i32);
+        assert_eq!(info.si_status, 71);
+
+        assert_eq!(
+            unsafe {
+                sc.waitid(
+                    rt11_ffi_linux::native::wait::P_PID,
+                    pid,
+                    &mut info,
+                    rt11_ffi_linux::native::wait::WEXITED,
+                )
+            },
+            Err(rt11_ffi_linux::native::errno::ECHILD),
+        );
+
+        let pid = spawn();
+        let pidfd = sc.pidfd_open(pid as i32, 0).unwrap();
+        let mut info = rt11_ffi_linux::native::wait::Siginfo::default();
+        unsafe {
+            sc.waitid(
+                rt11_ffi_linux::native::wait::P_PIDFD,
+                pidfd,
+                &mut info,
+                rt11_ffi_linux::native::wait::WEXITED,
+            ).unwrap();
+            sc.close(pidfd).unwrap();
+        }
+        assert_eq!(info.si_pid, pid as i32);
+        assert_eq!(info.si_status, 71);
+    }
+
+    // Set, query, and list extended attributes of a temporary file via its
+    // path and its file-descriptor. File systems without support for the
+    // `user.` namespace skip the test.
+    #[test]
+    fn xattr_check() {
+        // Unlink and close the temporary file, even if an assertion fails.
+        struct Unlink<'a>(&'a Syscall, &'a str, u32);
+
+        impl Drop for Unlink<'_> {
+            fn drop(&mut self) {
+                unsafe {
+                    let _ = <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+                        &self.0.ffi,
+                        rt11_ffi_linux::native::nr::UNLINKAT as usize,
+                        rt11_ffi_linux::native::fcntl::AT_FDCWD as isize as usize,
+                        self.1.as_ptr() as usize,
+                        0,
+                    );
+                    let _ = self.0.close(self.2);
+                }
+            }
+        }
+
+        let sc = Syscall::new();
+        let path = std::format!("/tmp/rt11-xattr-{}\x00", std::process::id());
+        let name = "user.rt11\x00".as_ptr();
+        let mut buf = [0u8; 64];
+
+        let fd = unsafe {
+            sc.open(
+                path.as_ptr(),
+                rt11_ffi_linux::native::fcntl::O_RDWR
+                | rt11_ffi_linux::native::fcntl::O_CREAT
+                | rt11_ffi_linux::native::fcntl::O_EXCL
+                | rt11_ffi_linux::native::fcntl::O_CLOEXEC,
+                0o600,
+            ).unwrap()
+        };
+        let _unlink = Unlink(&sc, &path, fd);
+
+        unsafe {
+            match sc.fsetxattr(fd, name, b"foo", 0) {
+                Err(rt11_ffi_linux::native::errno::EOPNOTSUPP) => (),
+                r => {
+                    r.unwrap();
+
+                    assert_eq!(sc.getxattr(path.as_ptr(), name, &mut []), Ok(3));
+                    assert_eq!(sc.getxattr(path.as_ptr(), name, &mut buf), Ok(3));
+                    assert_eq!(&buf[..3], b"foo");
+                    assert_eq!(
+                        sc.getxattr(path.as_ptr(), name, &mut buf[..2]),
+                        Err(rt11_ffi_linux::native::errno::ERANGE),
+                    );
+                    assert_eq!(
+                        sc.getxattr(path.as_ptr(), "user.none\x00".as_ptr(), &mut buf),
+                        Err(rt11_ffi_linux::native::errno::ENODATA),
+                    );
+
+                    sc.setxattr(
+                        path.as_ptr(),
+                        name,
+                        b"foobar",
+                        rt11_ffi_linux::native::xattr::XATTR_REPLACE,
+                    ).unwrap();
+                    assert_eq!(
+                        sc.setxattr(
+                            path.as_ptr(),
+                            name,
+                            b"",
+                            rt11_ffi_linux::native::xattr::XATTR_CREATE,
+                        ),
+                        Err(rt11_ffi_linux::native::errno::EEXIST),
+                    );
+                    assert_eq!(sc.fgetxattr(fd, name, &mut buf), Ok(6));
+                    assert_eq!(&buf[..6], b"foobar");
+                    sc.lsetxattr(path.as_ptr(), name, b"baz", 0).unwrap();
+                    assert_eq!(sc.lgetxattr(path.as_ptr(), name, &mut buf), Ok(3));
+                    assert_eq!(&buf[..3], b"baz");
+
+                    // Other attributes (e.g., security labels) might be
+                    // present, so search the list.
+                    let n = sc.listxattr(path.as_ptr(), &mut []).unwrap();
+                    assert!(n >= 10);
+                    let n = sc.listxattr(path.as_ptr(), &mut buf).unwrap();
+                    assert!(buf[..n].split(|v| *v == 0).any(|v| v == b"user.rt11"));
+                    assert_eq!(sc.llistxattr(path.as_ptr(), &mut buf), Ok(n));
+                    assert_eq!(sc.flistxattr(fd, &mut buf), Ok(n));
+                    assert_eq!(
+                        sc.flistxattr(fd, &mut buf[..n - 1]),
+                        Err(rt11_ffi_linux::native::errno::ERANGE),
+                    );
+                },
+            }
+        }
     }
 }
@@ -15,6 +15,8 @@
 //! this module provides an abstraction used to represent the current execution
 //! context, called `This`.
 
+use crate::syscall::Errno;
+
 /// Current Execution Context
 ///
 /// This type is used to encapsulate the implicit kernel context for the
@@ -30,6 +32,10 @@
 ///
 /// Only a single instance of this type can exist for each task. It is the
 /// responsibility of the creator of the task to create the initial instance.
+///
+/// The most common system calls are forwarded as methods of this type (e.g.,
+/// `this.read()`), so call sites name the context they operate on. Any other
+/// system call is available via the `syscall` field.
 pub struct This {
     pub syscall: crate::syscall::Syscall,
     _marker_nonsend: core::marker::PhantomData<*mut ()>,
@@ -49,6 +55,101 @@ impl This {
             _marker_nonsend: core::default::Default::default(),
         }
     }
+
+    /// Close File-descriptor
+    ///
+    /// Forward to `Syscall::close()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `Syscall::close()`.
+    pub unsafe fn close(&self, fd: u32) -> Result<usize, Errno> {
+        unsafe { self.syscall.close(fd) }
+    }
+
+    /// Exit Thread Group
+    ///
+    /// Forward to `Syscall::exit_group()`.
+    pub fn exit_group(&self, code: u32) -> ! {
+        self.syscall.exit_group(code)
+    }
+
+    /// Map Memory
+    ///
+    /// Forward to `Syscall::mmap()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `Syscall::mmap()`.
+    pub unsafe fn mmap(
+        &self,
+        addr: usize,
+        len: usize,
+        prot: u32,
+        flags: u32,
+        fd: i32,
+        offset: u64,
+    ) -> Result<usize, Errno> {
+        unsafe { self.syscall.mmap(addr, len, prot, flags, fd, offset) }
+    }
+
+    /// Open File Relative to Directory
+    ///
+    /// Forward to `Syscall::openat()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `Syscall::openat()`.
+    pub unsafe fn openat(
+        &self,
+        dirfd: i32,
+        path: *const u8,
+        flags: u32,
+        mode: u32,
+    ) -> Result<u32, Errno> {
+        unsafe { self.syscall.openat(dirfd, path, flags, mode) }
+    }
+
+    /// Read from File-descriptor
+    ///
+    /// Forward to `Syscall::read()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `Syscall::read()`.
+    pub unsafe fn read(&self, fd: u32, buf: *mut u8, len: usize) -> Result<usize, Errno> {
+        unsafe { self.syscall.read(fd, buf, len) }
+    }
+
+    /// Read Exact Amount
+    ///
+    /// Forward to `Syscall::read_exact()`.
+    pub fn read_exact(&self, fd: u32, buf: &mut [u8]) -> Result<(), Errno> {
+        self.syscall.read_exact(fd, buf)
+    }
+
+    /// Write to File-descriptor
+    ///
+    /// Forward to `Syscall::write()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// See `Syscall::write()`.
+    pub unsafe fn write(&self, fd: u32, buf: *const u8, len: usize) -> Result<usize, Errno> {
+        unsafe { self.syscall.write(fd, buf, len) }
+    }
+
+    /// Write Entire Buffer
+    ///
+    /// Forward to `Syscall::write_all()`.
+    pub fn write_all(&self, fd: u32, buf: &[u8]) -> Result<(), Errno> {
+        self.syscall.write_all(fd, buf)
+    }
 }
 
 #[cfg(test)]
@@ -74,4 +175,25 @@ mod test {
             );
         }
     }
+
+    // Verify the forwarding methods of `This` by writing through a pipe and
+    // reading the data back.
+    #[test]
+    fn this_forward() {
+        let this: This = unsafe { This::new() };
+        let mut buf = [0u8; 4];
+
+        let p = this.syscall.pipe2(rt11_ffi_linux::native::fcntl::O_CLOEXEC).unwrap();
+
+        unsafe {
+            assert_eq!(this.write(p[1], b"foo".as_ptr(), 3), Ok(3));
+            assert_eq!(this.read(p[0], buf.as_mut_ptr(), 4), Ok(3));
+            assert_eq!(&buf[..3], b"foo");
+            this.write_all(p[1], b"bar").unwrap();
+            this.read_exact(p[0], &mut buf[..3]).unwrap();
+            assert_eq!(&buf[..3], b"bar");
+            this.close(p[1]).unwrap();
+            this.close(p[0]).unwrap();
+        }
+    }
 }
@@ -0,0 +1,111 @@
+//! Deadlines
+//!
+//! Blocking system calls take their timeouts in different forms: most take
+//! a relative timeout (e.g., `ppoll(2)` or `futex(2)` with `FUTEX_WAIT`),
+//! while others take an absolute time of a given clock (e.g.,
+//! `clock_nanosleep(2)` with `TIMER_ABSTIME`). Callers that have to honor
+//! an overall timeout across several calls (or across restarts after
+//! `EINTR`) must track it as absolute time, and derive the form required by
+//! each call from it. `Deadline` provides exactly that, based on
+//! `CLOCK_MONOTONIC`, which is not affected by changes of the wall-clock.
+
+use crate::syscall::{self, Errno, Syscall};
+use rt11_ffi_linux::native::time;
+
+/// Absolute Deadline
+///
+/// This is a point in time of `CLOCK_MONOTONIC`. It is usually created
+/// relative to the current time via `after()`, and then converted into the
+/// timeout of each blocking call via `remaining()` (for relative timeouts)
+/// or `as_timespec()` (for absolute timeouts of `CLOCK_MONOTONIC`, or for
+/// `Syscall::retry_timed()`).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Deadline {
+    at: time::Timespec,
+}
+
+impl Deadline {
+    /// Create Deadline at Absolute Time
+    ///
+    /// Create a deadline at the time `at` of `CLOCK_MONOTONIC`, which must
+    /// be normalized (i.e., `tv_nsec` in the range 0 to 999,999,999).
+    pub fn at(at: time::Timespec) -> Self {
+        Self { at }
+    }
+
+    /// Create Deadline Relative to Now
+    ///
+    /// Create a deadline `duration` after the current time of
+    /// `CLOCK_MONOTONIC`. `duration` must be normalized and non-negative.
+    /// The deadline saturates at the end of representable time, rather than
+    /// wrapping around. Errors of `clock_gettime()` are returned as is.
+    pub fn after(sc: &Syscall, duration: &time::Timespec) -> Result<Self, Errno> {
+        let now = sc.clock_gettime(time::CLOCK_MONOTONIC)?;
+        let mut sec = now.tv_sec.saturating_add(duration.tv_sec);
+        let mut nsec = now.tv_nsec + duration.tv_nsec;
+
+        if nsec >= 1_000_000_000 {
+            nsec -= 1_000_000_000;
+            sec = sec.saturating_add(1);
+        }
+
+        Ok(Self::at(time::Timespec { tv_sec: sec, tv_nsec: nsec }))
+    }
+
+    /// Access Absolute Time
+    ///
+    /// Return the deadline as absolute time of `CLOCK_MONOTONIC`.
+    pub fn as_timespec(&self) -> time::Timespec {
+        self.at
+    }
+
+    /// Query Remaining Time
+    ///
+    /// Return the time remaining until the deadline, or `None` if it has
+    /// passed. This reads `CLOCK_MONOTONIC` on every call, so it must be
+    /// called right before each blocking call (including restarts after
+    /// `EINTR`), rather than once up front.
+    ///
+    /// Reading `CLOCK_MONOTONIC` cannot fail on any supported kernel. If it
+    /// does anyway, the deadline is considered passed, so callers never
+    /// block indefinitely.
+    pub fn remaining(&self, sc: &Syscall) -> Option<time::Timespec> {
+        let now = sc.clock_gettime(time::CLOCK_MONOTONIC).ok()?;
+        let v = syscall::timespec_until(&self.at, &now);
+
+        if v == time::Timespec::default() {
+            None
+        } else {
+            Some(v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Create deadlines relative to now and in the past, and verify the
+    // remaining time, as well as saturation at the end of time.
+    #[test]
+    fn deadline_check() {
+        let sc = Syscall::new();
+        let ts = |tv_sec, tv_nsec| time::Timespec { tv_sec, tv_nsec };
+
+        let now = sc.clock_gettime(time::CLOCK_MONOTONIC).unwrap();
+        let d = Deadline::after(&sc, &ts(60, 999_999_999)).unwrap();
+        assert!(d.as_timespec() > now);
+        let v = d.remaining(&sc).unwrap();
+        assert!(v <= ts(60, 999_999_999));
+        assert!(v >= ts(59, 0));
+
+        assert_eq!(Deadline::after(&sc, &ts(0, 0)).unwrap().remaining(&sc), None);
+        assert_eq!(Deadline::at(ts(0, 0)).remaining(&sc), None);
+        assert_eq!(Deadline::at(now).remaining(&sc), None);
+        assert!(Deadline::at(now) < d);
+
+        let d = Deadline::after(&sc, &ts(i64::MAX, 999_999_999)).unwrap();
+        assert_eq!(d.as_timespec().tv_sec, i64::MAX);
+        assert!(d.remaining(&sc).is_some());
+    }
+}
@@ -0,0 +1,193 @@
+//! System Call Tracing
+//!
+//! This module provides a wrapper around any implementation of the
+//! `rt11_ffi_linux::common::Syscall` trait, which reports every system call
+//! to a callback. It is meant for debugging (e.g., to build a minimal
+//! `strace` for the loader), without modifying any call sites.
+
+/// Trace Callback
+///
+/// This is invoked after each system call with the system call number, its
+/// arguments, and its raw return value. Unused trailing arguments are
+/// reported as 0. Errors are reported in their raw encoding (i.e., `-E*`
+/// cast to `usize`).
+///
+/// This is a plain function pointer, so it works without allocation.
+/// Callbacks that need state must keep it in statics.
+pub type TraceFn = fn(nr: usize, args: &[usize; 6], result: usize);
+
+/// Tracing System Call Invocation
+///
+/// This implements `rt11_ffi_linux::common::Syscall` by delegating every
+/// invocation to the wrapped implementation, and reporting it to the trace
+/// callback afterwards. The callback runs in the context of the caller, so
+/// it must not invoke system calls through the same tracer, or it recurses.
+///
+/// Note that system calls that do not return (e.g., `exit(2)`, or a
+/// successful `execve(2)`) are never reported. A system call that returns
+/// twice (e.g., `clone(2)`) is reported in both tasks.
+pub struct TracingSyscall<S> {
+    inner: S,
+    trace: TraceFn,
+}
+
+impl<S> TracingSyscall<S> {
+    /// Create Tracer
+    ///
+    /// Wrap `inner`, reporting all its system calls to `trace`.
+    pub fn new(inner: S, trace: TraceFn) -> Self {
+        Self { inner, trace }
+    }
+
+    /// Access Wrapped Implementation
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap Tracer
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: rt11_ffi_linux::common::Syscall> rt11_ffi_linux::common::Syscall for TracingSyscall<S> {
+    unsafe fn syscall0(
+        &self,
+        nr: usize,
+    ) -> usize {
+        let r = unsafe { self.inner.syscall0(nr) };
+        (self.trace)(nr, &[0, 0, 0, 0, 0, 0], r);
+        r
+    }
+
+    unsafe fn syscall1(
+        &self,
+        nr: usize,
+        arg0: usize,
+    ) -> usize {
+        let r = unsafe { self.inner.syscall1(nr, arg0) };
+        (self.trace)(nr, &[arg0, 0, 0, 0, 0, 0], r);
+        r
+    }
+
+    unsafe fn syscall2(
+        &self,
+        nr: usize,
+        arg0: usize,
+        arg1: usize,
+    ) -> usize {
+        let r = unsafe { self.inner.syscall2(nr, arg0, arg1) };
+        (self.trace)(nr, &[arg0, arg1, 0, 0, 0, 0], r);
+        r
+    }
+
+    unsafe fn syscall3(
+        &self,
+        nr: usize,
+        arg0: usize,
+        arg1: usize,
+        arg2: usize,
+    ) -> usize {
+        let r = unsafe { self.inner.syscall3(nr, arg0, arg1, arg2) };
+        (self.trace)(nr, &[arg0, arg1, arg2, 0, 0, 0], r);
+        r
+    }
+
+    unsafe fn syscall4(
+        &self,
+        nr: usize,
+        arg0: usize,
+        arg1: usize,
+        arg2: usize,
+        arg3: usize,
+    ) -> usize {
+        let r = unsafe { self.inner.syscall4(nr, arg0, arg1, arg2, arg3) };
+        (self.trace)(nr, &[arg0, arg1, arg2, arg3, 0, 0], r);
+        r
+    }
+
+    unsafe fn syscall5(
+        &self,
+        nr: usize,
+        arg0: usize,
+        arg1: usize,
+        arg2: usize,
+        arg3: usize,
+        arg4: usize,
+    ) -> usize {
+        let r = unsafe { self.inner.syscall5(nr, arg0, arg1, arg2, arg3, arg4) };
+        (self.trace)(nr, &[arg0, arg1, arg2, arg3, arg4, 0], r);
+        r
+    }
+
+    unsafe fn syscall6(
+        &self,
+        nr: usize,
+        arg0: usize,
+        arg1: usize,
+        arg2: usize,
+        arg3: usize,
+        arg4: usize,
+        arg5: usize,
+    ) -> usize {
+        let r = unsafe { self.inner.syscall6(nr, arg0, arg1, arg2, arg3, arg4, arg5) };
+        (self.trace)(nr, &[arg0, arg1, arg2, arg3, arg4, arg5], r);
+        r
+    }
+}
+
+impl<S: rt11_ffi_linux::common::SyscallAbi> rt11_ffi_linux::common::SyscallAbi for TracingSyscall<S> {
+    type Abi = S::Abi;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use rt11_ffi_linux::common::Syscall;
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    static LAST: [AtomicUsize; 4] = [
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+    ];
+
+    fn record(nr: usize, args: &[usize; 6], result: usize) {
+        COUNT.fetch_add(1, Ordering::SeqCst);
+        LAST[0].store(nr, Ordering::SeqCst);
+        LAST[1].store(args[0], Ordering::SeqCst);
+        LAST[2].store(args[5], Ordering::SeqCst);
+        LAST[3].store(result, Ordering::SeqCst);
+    }
+
+    fn last() -> [usize; 4] {
+        core::array::from_fn(|i| LAST[i].load(Ordering::SeqCst))
+    }
+
+    // Trace successful and failing system calls, and verify each is
+    // reported exactly once with its arguments and result.
+    #[test]
+    fn trace_calls() {
+        let sc = TracingSyscall::new(rt11_ffi_linux::native::syscall::Syscall {}, record);
+        let getpid = rt11_ffi_linux::native::nr::GETPID as usize;
+        let close = rt11_ffi_linux::native::nr::CLOSE as usize;
+
+        let pid = unsafe { sc.syscall0(getpid) };
+        assert_eq!(pid, std::process::id() as usize);
+        assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+        assert_eq!(last(), [getpid, 0, 0, pid]);
+
+        let ebadf = rt11_ffi_linux::native::errno::EBADF;
+        let r = unsafe { sc.syscall_checked(close, &[u32::MAX as usize]) };
+        assert_eq!(r, Err(ebadf));
+        assert_eq!(COUNT.load(Ordering::SeqCst), 2);
+        assert_eq!(last(), [close, u32::MAX as usize, 0, (ebadf as usize).wrapping_neg()]);
+
+        let r = unsafe { sc.syscall6(getpid, 1, 2, 3, 4, 5, 6) };
+        assert_eq!(r, pid);
+        assert_eq!(last(), [getpid, 1, 6, pid]);
+        assert_eq!(COUNT.load(Ordering::SeqCst), 3);
+    }
+}
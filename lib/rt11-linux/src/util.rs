@@ -0,0 +1,68 @@
+//! Generic Utilities
+//!
+//! This module contains small helpers that are shared across the different
+//! kernel interfaces, but are not tied to any of them. They are completely
+//! decoupled from the rest of the crate.
+
+/// Align Value Downwards
+///
+/// Return the largest multiple of `align` that is less than or equal to `v`.
+/// `align` must be a power of two. This cannot overflow.
+pub fn align_down(v: usize, align: usize) -> usize {
+    debug_assert!(align.is_power_of_two());
+    v & !(align - 1)
+}
+
+/// Align Value Upwards
+///
+/// Return the smallest multiple of `align` that is greater than or equal to
+/// `v`. `align` must be a power of two. If the result is not representable
+/// in a `usize`, `None` is returned.
+///
+/// The overflow check is relevant whenever `v` is derived from untrusted
+/// input (e.g., segment sizes of an ELF program header), since a value close
+/// to `usize::MAX` would otherwise silently wrap around to 0.
+pub fn align_up(v: usize, align: usize) -> Option<usize> {
+    debug_assert!(align.is_power_of_two());
+    v.checked_add(align - 1).map(|v| align_down(v, align))
+}
+
+/// Check Value Alignment
+///
+/// Return whether `v` is a multiple of `align`. `align` must be a power of
+/// two.
+pub fn is_aligned(v: usize, align: usize) -> bool {
+    debug_assert!(align.is_power_of_two());
+    v & (align - 1) == 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the alignment helpers, including their behavior at the edges
+    // of the address space.
+    #[test]
+    fn alignment() {
+        assert_eq!(align_down(0, 4096), 0);
+        assert_eq!(align_down(1, 4096), 0);
+        assert_eq!(align_down(4096, 4096), 4096);
+        assert_eq!(align_down(8191, 4096), 4096);
+        assert_eq!(align_down(usize::MAX, 4096), usize::MAX - 4095);
+        assert_eq!(align_down(7, 1), 7);
+
+        assert_eq!(align_up(0, 4096), Some(0));
+        assert_eq!(align_up(1, 4096), Some(4096));
+        assert_eq!(align_up(4096, 4096), Some(4096));
+        assert_eq!(align_up(4097, 4096), Some(8192));
+        assert_eq!(align_up(usize::MAX - 4095, 4096), Some(usize::MAX - 4095));
+        assert_eq!(align_up(usize::MAX - 4094, 4096), None);
+        assert_eq!(align_up(usize::MAX, 1), Some(usize::MAX));
+        assert_eq!(align_up(usize::MAX, 2), None);
+
+        assert!(is_aligned(0, 4096));
+        assert!(is_aligned(8192, 4096));
+        assert!(!is_aligned(8191, 4096));
+        assert!(is_aligned(7, 1));
+    }
+}
@@ -0,0 +1,111 @@
+//! Wait Status Decoding
+//!
+//! The status word reported by `wait4(2)` and friends packs the exit code,
+//! the terminating signal, and stop information of a child task into a
+//! single integer. This module provides helpers to decode it, mirroring the
+//! `W*()` macros of the C library.
+//!
+//! The lower 7 bits contain the terminating signal, or 0 if the task exited
+//! normally, or 0x7f if the task is stopped. Bit 7 is set if a core dump was
+//! produced. The next 8 bits contain the exit code or stop signal.
+
+/// Check for Normal Exit
+///
+/// Return whether the task exited normally.
+pub fn wifexited(status: i32) -> bool {
+    status & 0x7f == 0
+}
+
+/// Query Exit Code
+///
+/// Return the exit code of a task that exited normally.
+pub fn wexitstatus(status: i32) -> u8 {
+    ((status >> 8) & 0xff) as u8
+}
+
+/// Check for Signal Termination
+///
+/// Return whether the task was terminated by a signal.
+pub fn wifsignaled(status: i32) -> bool {
+    status & 0x7f != 0 && status & 0x7f != 0x7f
+}
+
+/// Query Terminating Signal
+///
+/// Return the signal that terminated a task.
+pub fn wtermsig(status: i32) -> u8 {
+    (status & 0x7f) as u8
+}
+
+/// Check for Stop
+///
+/// Return whether the task is stopped.
+pub fn wifstopped(status: i32) -> bool {
+    status & 0xff == 0x7f
+}
+
+/// Query Stop Signal
+///
+/// Return the signal that stopped a task.
+pub fn wstopsig(status: i32) -> u8 {
+    wexitstatus(status)
+}
+
+/// Check for Continuation
+///
+/// Return whether the task was resumed by `SIGCONT`.
+pub fn wifcontinued(status: i32) -> bool {
+    status == 0xffff
+}
+
+/// Check for Core Dump
+///
+/// Return whether a task that was terminated by a signal dumped core.
+pub fn wcoredump(status: i32) -> bool {
+    status & 0x80 != 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Decode known status words and verify the bit layout.
+    #[test]
+    fn status_decoding() {
+        // Exited with code 71.
+        let s = 71 << 8;
+        assert!(wifexited(s));
+        assert_eq!(wexitstatus(s), 71);
+        assert!(!wifsignaled(s));
+        assert!(!wifstopped(s));
+        assert!(!wifcontinued(s));
+
+        // Killed by `SIGKILL` (9).
+        let s = 9;
+        assert!(!wifexited(s));
+        assert!(wifsignaled(s));
+        assert_eq!(wtermsig(s), 9);
+        assert!(!wcoredump(s));
+        assert!(!wifstopped(s));
+
+        // Killed by `SIGSEGV` (11) with core dump.
+        let s = 0x80 | 11;
+        assert!(wifsignaled(s));
+        assert_eq!(wtermsig(s), 11);
+        assert!(wcoredump(s));
+
+        // Stopped by `SIGSTOP` (19).
+        let s = (19 << 8) | 0x7f;
+        assert!(!wifexited(s));
+        assert!(!wifsignaled(s));
+        assert!(wifstopped(s));
+        assert_eq!(wstopsig(s), 19);
+
+        // Continued.
+        let s = 0xffff;
+        assert!(!wifexited(s));
+        assert!(!wifsignaled(s));
+        assert!(!wifstopped(s));
+        assert!(wifcontinued(s));
+    }
+}
@@ -73,14 +73,55 @@ extern "C" fn __aeabi_unwind_cpp_pr2(
     return 9;
 }
 
+/// Rust Personality Routine
+///
+/// Similar to the ARM EHABI personality routines (see
+/// `__aeabi_unwind_cpp_pr0()`), recent releases of the precompiled `core`
+/// crate retain references to `rust_eh_personality()` in their unwind tables,
+/// even when linked into `panic=abort` applications. It is never called,
+/// since we never unwind, but the linker requires the symbol to be present.
+///
+/// We simply return `_URC_FATAL_PHASE1_ERROR` (`3`), should it ever be
+/// called.
+#[no_mangle]
+extern "C" fn rust_eh_personality() -> i32 {
+    3
+}
+
 pub extern "C" fn main() -> ! {
     let this = unsafe { rt11_linux::this::This::new() };
 
     this.syscall.exit(71);
 }
 
-pub extern "C" fn loader_main(_sp: *const core::ffi::c_void) -> usize {
-    main as usize
+pub extern "C" fn loader_main(sp: *const core::ffi::c_void) -> usize {
+    let this = unsafe { rt11_linux::this::This::new() };
+    // The loader is not relocated, yet, so parse the auxiliary vector in
+    // place rather than copying it around.
+    let mut auxv = core::mem::MaybeUninit::<runtime11::loader::auxv::Auxv>::uninit();
+    let auxv = unsafe {
+        runtime11::loader::auxv::Auxv::init_from_stack(auxv.as_mut_ptr(), sp as *const usize);
+        auxv.assume_init_ref()
+    };
+
+    // Locate the own image. In direct mode, it is described by the
+    // auxiliary vector, otherwise it is read from the ELF header at
+    // `AT_BASE`. This only fails if the kernel passed unexpected data.
+    if unsafe { auxv.loader() }.is_err() {
+        this.exit_group(127);
+    }
+
+    match auxv.mode() {
+        // Started directly, so there is no application to hand off to.
+        runtime11::loader::auxv::Mode::Direct => main as *const () as usize,
+        // Started as interpreter, so hand off to the entry of the executable
+        // as mapped by the kernel. No relocations are applied to it, so this
+        // only supports static executables, so far.
+        runtime11::loader::auxv::Mode::Interpreter => match auxv.executable() {
+            Ok(v) => v.entry,
+            Err(_) => this.exit_group(127),
+        },
+    }
 }
 
 core::arch::global_asm!(
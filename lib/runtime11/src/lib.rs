@@ -3,3 +3,8 @@
 //! XXX
 
 #![no_std]
+
+#[cfg(test)]
+extern crate std;
+
+pub mod loader;
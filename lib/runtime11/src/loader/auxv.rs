@@ -0,0 +1,414 @@
+//! Auxiliary Vector
+//!
+//! This module implements parsing of the auxiliary vector passed by the
+//! kernel on the initial stack of a program, and derives the startup mode of
+//! the loader from it.
+//!
+//! The loader can be started in two distinct modes:
+//!
+//!  * **Direct**: The kernel mapped the executable and jumped to its entry
+//!    directly, since it has no `PT_INTERP` segment. The loader acts as the
+//!    startup code of the executable. `AT_BASE` is 0, and `AT_PHDR`,
+//!    `AT_PHNUM`, and `AT_ENTRY` describe the running object itself.
+//!
+//!  * **Interpreter**: The kernel mapped the executable, and the loader as
+//!    its interpreter (as named by `PT_INTERP`), and then jumped to the entry
+//!    of the loader. `AT_BASE` is the address the loader was mapped at, while
+//!    `AT_PHDR`, `AT_PHNUM`, and `AT_ENTRY` still describe the executable.
+//!    The loader has to discover its own image via its ELF header at
+//!    `AT_BASE`.
+//!
+//! Hence, `AT_BASE` disambiguates both modes.
+//!
+//! Note that the program headers in the auxiliary vector always belong to
+//! the main program, never to the interpreter. The interpreter locates its
+//! own program headers via its ELF header, and derives its load bias from
+//! its `PT_PHDR` entry (which records the link-time address of the program
+//! header table), rather than re-reading its file. The same computation
+//! yields the load bias of the main program from `AT_PHDR`.
+
+use crate::loader::LoadError;
+use rt11_ffi_elf::{elf, elfn};
+use rt11_ffi_linux::native::auxvec;
+
+/// Startup Mode
+///
+/// This describes how the kernel started the loader. See the module
+/// documentation for details.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Mode {
+    /// The loader runs as startup code of the executable.
+    Direct,
+    /// The loader runs as interpreter of the executable.
+    Interpreter,
+}
+
+/// Image Description
+///
+/// This describes the program headers and entry-point of a mapped ELF
+/// object. All addresses are absolute runtime addresses.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Image {
+    pub phdr: *const elfn::Phdr,
+    pub phnum: usize,
+    pub entry: usize,
+}
+
+impl Image {
+    /// Compute Load Bias
+    ///
+    /// Return the difference between the runtime and link-time addresses of
+    /// the image, derived from the runtime address of its program header
+    /// table and the link-time address recorded in its `PT_PHDR` entry.
+    /// `None` is returned if the image has no `PT_PHDR` entry, in which case
+    /// the bias must be derived by other means (e.g., static executables
+    /// are never relocated).
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `phdr` must point to `phnum` valid program headers.
+    pub unsafe fn bias(&self) -> Option<usize> {
+        let phdrs = unsafe { core::slice::from_raw_parts(self.phdr, self.phnum) };
+
+        phdrs
+            .iter()
+            .find(|v| v.p_type == elf::Phdr::<(), ()>::PT_PHDR)
+            .map(|v| (self.phdr as usize).wrapping_sub(v.p_vaddr as usize))
+    }
+}
+
+/// Locate Main Program Headers
+///
+/// Return the address and number of the program headers of the main
+/// program, as passed by the kernel via `AT_PHDR` and `AT_PHNUM` in the
+/// auxiliary vector on the initial stack at `sp`. In direct mode, these are
+/// the program headers of the running object. In interpreter mode, they
+/// belong to the executable, and the interpreter must locate its own via
+/// `Auxv::loader()` instead.
+///
+/// `None` is returned if the entries are missing, or if they do not
+/// describe a table of native program headers.
+///
+/// Safety
+/// ------
+///
+/// `sp` must point to an initial program stack as set up by the kernel.
+pub unsafe fn own_phdrs_from_auxv(sp: *const usize) -> Option<(*const elfn::Phdr, u16)> {
+    let auxv = unsafe { Auxv::from_stack(sp) };
+
+    if auxv.phdr == 0 || (auxv.phent != 0 && auxv.phent != core::mem::size_of::<elfn::Phdr>()) {
+        return None;
+    }
+
+    Some((auxv.phdr as *const elfn::Phdr, u16::try_from(auxv.phnum).ok()?))
+}
+
+/// Auxiliary Vector
+///
+/// This carries the entries of the auxiliary vector relevant to the loader.
+/// Entries that were not provided by the kernel are 0.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Auxv {
+    pub base: usize,
+    pub entry: usize,
+    pub execfn: usize,
+    pub pagesz: usize,
+    pub phdr: usize,
+    pub phent: usize,
+    pub phnum: usize,
+    pub random: usize,
+    pub secure: usize,
+    pub sysinfo_ehdr: usize,
+}
+
+impl Auxv {
+    /// Parse Auxiliary Vector
+    ///
+    /// Parse the auxiliary vector at `auxv` up to its terminating `AT_NULL`
+    /// entry. Unknown entries are ignored.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `auxv` must point to a valid auxiliary vector, terminated by
+    /// `AT_NULL`.
+    pub unsafe fn from_ptr(auxv: *const usize) -> Self {
+        let mut v = core::mem::MaybeUninit::<Self>::uninit();
+
+        unsafe {
+            Self::init_from_ptr(v.as_mut_ptr(), auxv);
+            v.assume_init()
+        }
+    }
+
+    /// Parse Auxiliary Vector in Place
+    ///
+    /// Parse the auxiliary vector at `auxv` like `Auxv::from_ptr()`, but
+    /// store the result in `this`. The fields are initialized one at a
+    /// time, so the compiler never emits calls to `memcpy()` or `memset()`
+    /// for the entire structure, not even in unoptimized builds. This is
+    /// required to parse the auxiliary vector before the loader relocated
+    /// itself, and since the loader does not link any C library.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `this` must be valid for writes of `Auxv`. `auxv` must point to a
+    /// valid auxiliary vector, terminated by `AT_NULL`.
+    pub unsafe fn init_from_ptr(this: *mut Self, auxv: *const usize) {
+        let mut p = auxv;
+
+        unsafe {
+            core::ptr::addr_of_mut!((*this).base).write(0);
+            core::ptr::addr_of_mut!((*this).entry).write(0);
+            core::ptr::addr_of_mut!((*this).execfn).write(0);
+            core::ptr::addr_of_mut!((*this).pagesz).write(0);
+            core::ptr::addr_of_mut!((*this).phdr).write(0);
+            core::ptr::addr_of_mut!((*this).phent).write(0);
+            core::ptr::addr_of_mut!((*this).phnum).write(0);
+            core::ptr::addr_of_mut!((*this).random).write(0);
+            core::ptr::addr_of_mut!((*this).secure).write(0);
+            core::ptr::addr_of_mut!((*this).sysinfo_ehdr).write(0);
+        }
+
+        loop {
+            let (key, val) = unsafe { (*p, *p.add(1)) };
+
+            // Only the entries relevant to the loader are retained.
+            let field = unsafe {
+                match key as u32 {
+                    auxvec::AT_NULL => break,
+                    auxvec::AT_BASE => core::ptr::addr_of_mut!((*this).base),
+                    auxvec::AT_ENTRY => core::ptr::addr_of_mut!((*this).entry),
+                    auxvec::AT_EXECFN => core::ptr::addr_of_mut!((*this).execfn),
+                    auxvec::AT_PAGESZ => core::ptr::addr_of_mut!((*this).pagesz),
+                    auxvec::AT_PHDR => core::ptr::addr_of_mut!((*this).phdr),
+                    auxvec::AT_PHENT => core::ptr::addr_of_mut!((*this).phent),
+                    auxvec::AT_PHNUM => core::ptr::addr_of_mut!((*this).phnum),
+                    auxvec::AT_RANDOM => core::ptr::addr_of_mut!((*this).random),
+                    auxvec::AT_SECURE => core::ptr::addr_of_mut!((*this).secure),
+                    auxvec::AT_SYSINFO_EHDR => core::ptr::addr_of_mut!((*this).sysinfo_ehdr),
+                    _ => core::ptr::null_mut(),
+                }
+            };
+
+            if !field.is_null() {
+                unsafe { field.write(val) };
+            }
+
+            p = unsafe { p.add(2) };
+        }
+    }
+
+    /// Parse Auxiliary Vector from Initial Stack
+    ///
+    /// Locate the auxiliary vector on the initial stack of a program and
+    /// parse it. `sp` is the stack-pointer as passed by the kernel to the
+    /// entry-point. It points to `argc`, followed by the NULL-terminated
+    /// argument and environment arrays, and then the auxiliary vector.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `sp` must point to an initial program stack as set up by the kernel.
+    pub unsafe fn from_stack(sp: *const usize) -> Self {
+        let mut v = core::mem::MaybeUninit::<Self>::uninit();
+
+        unsafe {
+            Self::init_from_stack(v.as_mut_ptr(), sp);
+            v.assume_init()
+        }
+    }
+
+    /// Parse Auxiliary Vector from Initial Stack in Place
+    ///
+    /// Locate the auxiliary vector on the initial stack like
+    /// `Auxv::from_stack()`, and parse it in place via
+    /// `Auxv::init_from_ptr()`.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `this` must be valid for writes of `Auxv`. `sp` must point to an
+    /// initial program stack as set up by the kernel.
+    pub unsafe fn init_from_stack(this: *mut Self, sp: *const usize) {
+        unsafe {
+            let argc = *sp;
+            let mut p = sp.add(1 + argc + 1);
+
+            while *p != 0 {
+                p = p.add(1);
+            }
+
+            Self::init_from_ptr(this, p.add(1));
+        }
+    }
+
+    /// Return the startup mode of the loader.
+    pub fn mode(&self) -> Mode {
+        if self.base == 0 {
+            Mode::Direct
+        } else {
+            Mode::Interpreter
+        }
+    }
+
+    /// Locate Executable
+    ///
+    /// Return the image description of the executable, as mapped by the
+    /// kernel. This is valid in both startup modes, and does not require
+    /// reading the ELF header of the executable.
+    pub fn executable(&self) -> Result<Image, LoadError> {
+        if self.phdr == 0 {
+            return Err(LoadError::MissingAuxv(auxvec::AT_PHDR));
+        }
+        if self.entry == 0 {
+            return Err(LoadError::MissingAuxv(auxvec::AT_ENTRY));
+        }
+        if self.phent != 0 && self.phent != core::mem::size_of::<elfn::Phdr>() {
+            return Err(LoadError::InvalidHeader);
+        }
+
+        Ok(Image {
+            phdr: self.phdr as *const elfn::Phdr,
+            phnum: self.phnum,
+            entry: self.entry,
+        })
+    }
+
+    /// Locate Loader
+    ///
+    /// Return the image description of the object running the loader. In
+    /// direct mode, this is the executable itself and it is described by
+    /// the auxiliary vector. In interpreter mode, the ELF header of the
+    /// loader at `AT_BASE` is read. If the loader has a `PT_PHDR` entry, the
+    /// load bias derived from it must match `AT_BASE`, otherwise the headers
+    /// are rejected.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// In interpreter mode, `AT_BASE` must point to the mapped ELF header of
+    /// the loader, and its program headers must be mapped at `e_phoff`
+    /// relative to it.
+    pub unsafe fn loader(&self) -> Result<Image, LoadError> {
+        if self.mode() == Mode::Direct {
+            return self.executable();
+        }
+
+        let ehdr = unsafe { &*(self.base as *const elfn::Ehdr) };
+
+        if ehdr.e_ident.i_magic != [
+            elf::Ident::ELFMAG0,
+            elf::Ident::ELFMAG1,
+            elf::Ident::ELFMAG2,
+            elf::Ident::ELFMAG3,
+        ] || ehdr.e_phentsize as usize != core::mem::size_of::<elfn::Phdr>() {
+            return Err(LoadError::InvalidHeader);
+        }
+
+        let image = Image {
+            phdr: self.base.wrapping_add(ehdr.e_phoff as usize) as *const elfn::Phdr,
+            phnum: ehdr.e_phnum as usize,
+            entry: self.base.wrapping_add(ehdr.e_entry as usize),
+        };
+
+        match unsafe { image.bias() } {
+            Some(bias) if bias != self.base => Err(LoadError::InvalidHeader),
+            _ => Ok(image),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Parse a synthetic initial stack of a directly started executable and
+    // verify the image is taken from the auxiliary vector.
+    #[test]
+    fn auxv_direct() {
+        let phdrs = [elfn::Phdr::default(); 3];
+        let stack: [usize; 16] = [
+            1, 0x1000, 0,           // argc, argv
+            0x2000, 0x3000, 0,      // envp
+            auxvec::AT_PHDR as usize, phdrs.as_ptr() as usize,
+            auxvec::AT_PHNUM as usize, 3,
+            auxvec::AT_ENTRY as usize, 0x4000,
+            auxvec::AT_BASE as usize, 0,
+            auxvec::AT_NULL as usize, 0,
+        ];
+
+        let auxv = unsafe { Auxv::from_stack(stack.as_ptr()) };
+        assert_eq!(auxv.mode(), Mode::Direct);
+
+        let exe = auxv.executable().unwrap();
+        assert_eq!(exe.phdr, phdrs.as_ptr());
+        assert_eq!(exe.phnum, 3);
+        assert_eq!(exe.entry, 0x4000);
+        assert_eq!(unsafe { auxv.loader() }, Ok(exe));
+        assert_eq!(unsafe { exe.bias() }, None);
+
+        let own = unsafe { own_phdrs_from_auxv(stack.as_ptr()) };
+        assert_eq!(own, Some((phdrs.as_ptr(), 3)));
+
+        let auxv = Auxv { phdr: 0, ..auxv };
+        assert_eq!(auxv.executable(), Err(LoadError::MissingAuxv(auxvec::AT_PHDR)));
+    }
+
+    // Parse a synthetic auxiliary vector of a loader started as interpreter
+    // and verify its own image is read from its ELF header, and its load
+    // bias is derived from its `PT_PHDR` entry.
+    #[test]
+    fn auxv_interpreter() {
+        #[repr(C)]
+        struct Object {
+            ehdr: elfn::Ehdr,
+            phdrs: [elfn::Phdr; 7],
+        }
+
+        let phoff = core::mem::size_of::<elfn::Ehdr>();
+        let mut obj = Object {
+            ehdr: elfn::Ehdr::default(),
+            phdrs: [elfn::Phdr::default(); 7],
+        };
+        obj.ehdr.e_ident.i_magic = [
+            elf::Ident::ELFMAG0,
+            elf::Ident::ELFMAG1,
+            elf::Ident::ELFMAG2,
+            elf::Ident::ELFMAG3,
+        ];
+        obj.ehdr.e_phoff = phoff as _;
+        obj.ehdr.e_phnum = 7;
+        obj.ehdr.e_phentsize = core::mem::size_of::<elfn::Phdr>() as u16;
+        obj.ehdr.e_entry = 0x100;
+        obj.phdrs[0].p_type = elf::Phdr::<(), ()>::PT_PHDR;
+        obj.phdrs[0].p_vaddr = phoff as _;
+        let base = &mut obj as *mut Object as usize;
+
+        let vec: [usize; 8] = [
+            auxvec::AT_BASE as usize, base,
+            auxvec::AT_PHDR as usize, 0x5000,
+            auxvec::AT_ENTRY as usize, 0x6000,
+            auxvec::AT_NULL as usize, 0,
+        ];
+
+        let auxv = unsafe { Auxv::from_ptr(vec.as_ptr()) };
+        assert_eq!(auxv.mode(), Mode::Interpreter);
+        assert_eq!(auxv.executable().unwrap().entry, 0x6000);
+
+        let ld = unsafe { auxv.loader() }.unwrap();
+        assert_eq!(ld.phdr as usize, base + phoff);
+        assert_eq!(ld.phnum, 7);
+        assert_eq!(ld.entry, base + 0x100);
+        assert_eq!(unsafe { ld.bias() }, Some(base));
+
+        unsafe { (*(base as *mut Object)).phdrs[0].p_vaddr += 0x1000 };
+        assert_eq!(unsafe { auxv.loader() }, Err(LoadError::InvalidHeader));
+
+        let bad = elfn::Ehdr::default();
+        let auxv = Auxv { base: &bad as *const elfn::Ehdr as usize, ..auxv };
+        assert_eq!(unsafe { auxv.loader() }, Err(LoadError::InvalidHeader));
+    }
+}
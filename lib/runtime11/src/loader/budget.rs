@@ -0,0 +1,150 @@
+//! Address-space Budget
+//!
+//! The kernel rejects mappings that would exceed the address-space limit
+//! (i.e., `RLIMIT_AS`) of a process with `ENOMEM`, which is
+//! indistinguishable from actual memory exhaustion and usually surfaces
+//! midway through loading an object. This module provides a guard the
+//! loader can consult up front, to fail early with a descriptive error.
+//!
+//! The guard is best-effort only: the usage of the address-space is read
+//! from `/proc`, which might be unavailable, and which is outdated as soon
+//! as any thread modifies its mappings.
+
+use crate::loader::{LoadError, Loader};
+use rt11_ffi_linux::native::{errno, resource};
+use rt11_linux::syscall::Errno;
+
+// Parse the `VmSize:` line of `/proc/self/status` and return its value in
+// bytes. The kernel reports it in KiB with a trailing ` kB`.
+fn parse_vm_size(status: &[u8]) -> Option<u64> {
+    let line = status
+        .split(|v| *v == b'\n')
+        .find_map(|v| v.strip_prefix(b"VmSize:"))?;
+    let line = &line[line.iter().position(|v| *v != b' ' && *v != b'\t')?..];
+    let n = line.iter().position(|v| !v.is_ascii_digit()).unwrap_or(line.len());
+
+    if n == 0 || &line[n..] != b" kB" {
+        return None;
+    }
+
+    line[..n]
+        .iter()
+        .try_fold(0u64, |acc, v| acc.checked_mul(10)?.checked_add((v - b'0') as u64))?
+        .checked_mul(1024)
+}
+
+impl<'this> Loader<'this> {
+    // Read the address-space size of the calling process from
+    // `/proc/self/status`. This file is used rather than
+    // `/proc/self/statm`, since the latter reports pages, and the loader
+    // context does not know the page size.
+    fn vm_size(&self) -> Result<u64, Errno> {
+        let mut buf = [0u8; 4096];
+
+        let n = self.this.syscall.read_proc(b"/proc/self/status\0", &mut buf)?;
+        parse_vm_size(&buf[..n]).ok_or(errno::EINVAL)
+    }
+
+    /// Query Address-space Budget
+    ///
+    /// Return the soft address-space limit (i.e., `RLIMIT_AS`) of the
+    /// calling process in bytes, as read via `prlimit64()`. An unlimited
+    /// address-space yields `RLIM64_INFINITY` (i.e., `u64::MAX`).
+    pub fn mmap_budget(&self) -> Result<u64, Errno> {
+        let mut v = resource::Rlimit64::default();
+
+        self.this.syscall.prlimit64(0, resource::RLIMIT_AS, None, Some(&mut v))?;
+        Ok(v.rlim_cur)
+    }
+
+    /// Check Address-space Budget
+    ///
+    /// Check whether `want` more bytes can be mapped without exceeding the
+    /// address-space limit of the calling process. If the limit would be
+    /// exceeded, `LoadError::AddressSpaceLimit` is returned.
+    ///
+    /// This is a best-effort check, meant to turn an opaque `ENOMEM` of a
+    /// later `mmap()` into a descriptive error. If the limit cannot be
+    /// queried, the check passes. If the current usage cannot be read from
+    /// `/proc`, only `want` is compared against the limit. Hence, passing
+    /// the check does not guarantee that the mappings succeed.
+    pub fn check_mmap_fits(&self, want: usize) -> Result<(), LoadError> {
+        let Ok(limit) = self.mmap_budget() else {
+            return Ok(());
+        };
+        if limit == resource::RLIM64_INFINITY {
+            return Ok(());
+        }
+
+        let used = self.vm_size().unwrap_or(0);
+        match used.checked_add(want as u64) {
+            Some(v) if v <= limit => Ok(()),
+            _ => Err(LoadError::AddressSpaceLimit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the address-space size is parsed from status files, and
+    // malformed lines are rejected.
+    #[test]
+    fn vm_size_parse() {
+        assert_eq!(parse_vm_size(b"Name:\tfoo\nVmSize:\t  1024 kB\nVmRSS:\t4 kB\n"), Some(1 << 20));
+        assert_eq!(parse_vm_size(b"VmSize:\t0 kB"), Some(0));
+        assert_eq!(parse_vm_size(b"VmPeak:\t1024 kB\n"), None);
+        assert_eq!(parse_vm_size(b"VmSize:\t1024 MB\n"), None);
+        assert_eq!(parse_vm_size(b"VmSize:\t kB\n"), None);
+        assert_eq!(parse_vm_size(b"VmSize:\t99999999999999999999 kB\n"), None);
+    }
+
+    // Verify the budget check passes with the inherited limit, and rejects
+    // oversized requests once the limit is lowered in a child.
+    #[test]
+    fn mmap_budget_check() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+
+        let used = loader.vm_size().unwrap();
+        assert!(used > 0);
+        let limit = loader.mmap_budget().unwrap();
+        if limit == resource::RLIM64_INFINITY {
+            loader.check_mmap_fits(usize::MAX).unwrap();
+        }
+
+        match unsafe { this.syscall.fork() }.unwrap() {
+            rt11_linux::syscall::ForkResult::Child => {
+                let used = match loader.vm_size() {
+                    Ok(v) => v,
+                    Err(_) => this.exit_group(1),
+                };
+                let v = resource::Rlimit64 {
+                    rlim_cur: used + (64 << 20),
+                    rlim_max: limit,
+                };
+                if this.syscall.prlimit64(0, resource::RLIMIT_AS, Some(&v), None).is_err() {
+                    this.exit_group(2);
+                }
+                if loader.mmap_budget() != Ok(v.rlim_cur) {
+                    this.exit_group(3);
+                }
+                if loader.check_mmap_fits(4096).is_err() {
+                    this.exit_group(4);
+                }
+                if loader.check_mmap_fits(usize::MAX) != Err(LoadError::AddressSpaceLimit) {
+                    this.exit_group(5);
+                }
+                this.exit_group(0);
+            },
+            rt11_linux::syscall::ForkResult::Parent(pid) => {
+                let pidfd = this.syscall.pidfd_open(pid as i32, 0).unwrap();
+                let status = loader.pidfd_wait(pidfd).unwrap();
+                assert!(rt11_linux::wait::wifexited(status));
+                assert_eq!(rt11_linux::wait::wexitstatus(status), 0);
+                unsafe { this.close(pidfd).unwrap() };
+            },
+        }
+    }
+}
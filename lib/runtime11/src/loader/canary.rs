@@ -0,0 +1,130 @@
+//! Stack Protector
+//!
+//! Code compiled with stack protection (e.g., `-fstack-protector-strong`)
+//! places a guard value (the "canary") between local buffers and the return
+//! address, and verifies it before returning. The guard must be initialized
+//! from entropy before any protected code runs, and it must be stored where
+//! the compiler expects it. The kernel provides 16 random bytes for this
+//! purpose via `AT_RANDOM`.
+//!
+//! The location of the guard is defined by the ABI of each architecture:
+//!
+//!  * **x86-64**: In the thread control block, at `%fs:0x28`.
+//!
+//!  * **x86**: In the thread control block, at `%gs:0x14`.
+//!
+//!  * **ARM, AArch64, RISC-V**: In the global `__stack_chk_guard` variable
+//!    of the C library. This is not thread-local, so the caller must resolve
+//!    the symbol in the loaded objects and write the guard there.
+
+use crate::loader::{auxv, LoadError};
+use rt11_ffi_linux::native::auxvec;
+
+/// Stack Guard Offset in Thread Control Block
+///
+/// This is the offset of the stack guard relative to the thread pointer, on
+/// architectures that keep it in the thread control block. It is `None` on
+/// architectures that use a global variable instead.
+#[cfg(target_arch = "x86_64")]
+pub const TCB_STACK_GUARD_OFFSET: Option<usize> = Some(0x28);
+#[cfg(target_arch = "x86")]
+pub const TCB_STACK_GUARD_OFFSET: Option<usize> = Some(0x14);
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+pub const TCB_STACK_GUARD_OFFSET: Option<usize> = None;
+
+/// Derive Stack Guard
+///
+/// Derive the stack guard from the random bytes `random` (i.e., the data at
+/// `AT_RANDOM`). The first word of the data is used, and its first byte in
+/// memory is cleared. Hence, the guard terminates string operations that
+/// overflow into it, so they cannot leak or reproduce the remaining bytes.
+/// This matches the convention of glibc.
+pub fn stack_guard(random: &[u8; 16]) -> usize {
+    let mut v = [0u8; core::mem::size_of::<usize>()];
+
+    v.copy_from_slice(&random[..core::mem::size_of::<usize>()]);
+    v[0] = 0;
+    usize::from_ne_bytes(v)
+}
+
+/// Derive Stack Guard from Auxiliary Vector
+///
+/// Read the random bytes at `AT_RANDOM` and derive the stack guard from
+/// them via `stack_guard()`. If the entry is missing,
+/// `LoadError::MissingAuxv` is returned.
+///
+/// Safety
+/// ------
+///
+/// `AT_RANDOM` must point to 16 readable bytes, as provided by the kernel.
+pub unsafe fn stack_guard_from_auxv(auxv: &auxv::Auxv) -> Result<usize, LoadError> {
+    if auxv.random == 0 {
+        return Err(LoadError::MissingAuxv(auxvec::AT_RANDOM));
+    }
+
+    let random = unsafe { (auxv.random as *const [u8; 16]).read_unaligned() };
+    Ok(stack_guard(&random))
+}
+
+/// Install Stack Guard in Thread Control Block
+///
+/// Write `guard` into the thread control block at the thread pointer `tp`,
+/// at `TCB_STACK_GUARD_OFFSET`. This must happen before the thread runs any
+/// code compiled with stack protection. Returns whether the guard was
+/// written. On architectures that do not keep the guard in the thread
+/// control block, nothing is written and `false` is returned.
+///
+/// Safety
+/// ------
+///
+/// `tp` must point to a thread control block that is valid for writes at
+/// the guard offset. If it is the control block of the running thread,
+/// no function with stack protection may be active, since it would fail
+/// its check on return.
+pub unsafe fn write_stack_guard(tp: usize, guard: usize) -> bool {
+    match TCB_STACK_GUARD_OFFSET {
+        Some(off) => {
+            unsafe { ((tp + off) as *mut usize).write_unaligned(guard) };
+            true
+        },
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Derive a guard from synthetic random data, both directly and via the
+    // auxiliary vector, and verify it is written to the expected slot.
+    #[test]
+    fn stack_guard_setup() {
+        let random: [u8; 16] = core::array::from_fn(|i| 0x71 + i as u8);
+        let guard = stack_guard(&random);
+
+        let mut expected = [0u8; core::mem::size_of::<usize>()];
+        expected.copy_from_slice(&random[..core::mem::size_of::<usize>()]);
+        expected[0] = 0;
+        assert_eq!(guard.to_ne_bytes(), expected);
+
+        let a = auxv::Auxv { random: random.as_ptr() as usize, ..Default::default() };
+        assert_eq!(unsafe { stack_guard_from_auxv(&a) }, Ok(guard));
+        assert_eq!(
+            unsafe { stack_guard_from_auxv(&Default::default()) },
+            Err(LoadError::MissingAuxv(auxvec::AT_RANDOM)),
+        );
+
+        let mut tcb = [0usize; 16];
+        let written = unsafe { write_stack_guard(tcb.as_mut_ptr() as usize, guard) };
+        match TCB_STACK_GUARD_OFFSET {
+            Some(off) => {
+                assert!(written);
+                assert_eq!(tcb[off / core::mem::size_of::<usize>()], guard);
+            },
+            None => {
+                assert!(!written);
+                assert!(tcb.iter().all(|v| *v == 0));
+            },
+        }
+    }
+}
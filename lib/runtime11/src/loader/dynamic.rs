@@ -0,0 +1,316 @@
+//! Dynamic Section
+//!
+//! The dynamic section (i.e., `PT_DYNAMIC`) of an object is an array of
+//! `elfn::Dyn` entries, terminated by `DT_NULL`. Depending on its tag, the
+//! value of an entry (i.e., `d_val`) is interpreted differently:
+//!
+//!  * **Addresses**: Virtual addresses of the object, which must be offset
+//!    by the load bias to get a runtime address. These are `DT_PLTGOT`,
+//!    `DT_HASH`, `DT_STRTAB`, `DT_SYMTAB`, `DT_RELA`, `DT_INIT`, `DT_FINI`,
+//!    `DT_REL`, `DT_JMPREL`, `DT_INIT_ARRAY`, `DT_FINI_ARRAY`,
+//!    `DT_PREINIT_ARRAY`, `DT_SYMTAB_SHNDX`, `DT_RELR`, all tags in the
+//!    range `DT_ADDRRNGLO` to `DT_ADDRRNGHI` (e.g., `DT_GNU_HASH`), and the
+//!    symbol versioning tables `DT_VERSYM`, `DT_VERDEF`, and `DT_VERNEED`.
+//!
+//!  * **Sizes**: Sizes in bytes of a table or of its entries, like
+//!    `DT_STRSZ`, `DT_SYMENT`, `DT_RELASZ`, `DT_RELAENT`, `DT_PLTRELSZ`, or
+//!    `DT_INIT_ARRAYSZ`.
+//!
+//!  * **Counts**: Numbers of entries, like `DT_RELACOUNT`, `DT_RELCOUNT`,
+//!    `DT_VERDEFNUM`, and `DT_VERNEEDNUM`.
+//!
+//!  * **String Offsets**: Offsets into the string table (i.e., `DT_STRTAB`),
+//!    like `DT_NEEDED`, `DT_SONAME`, `DT_RPATH`, and `DT_RUNPATH`.
+//!
+//!  * **Other Values**: Flags (e.g., `DT_FLAGS` and `DT_FLAGS_1`), the type
+//!    of the PLT relocations (i.e., `DT_PLTREL`), and `DT_DEBUG`, which is
+//!    filled in at runtime by the loader rather than by the linker.
+//!
+//! Interpreting a size or offset as address (or vice versa) points the
+//! loader at arbitrary memory. `dyn_ptr()` resolves addresses, and rejects
+//! all tags that do not carry one.
+//!
+//! The flags of `DT_FLAGS` and `DT_FLAGS_1` (and the legacy tags they
+//! replace, like `DT_TEXTREL` and `DT_BIND_NOW`) are decoded via
+//! `parse_flags()`.
+
+use rt11_ffi_elf::{elf, elfn};
+
+/// Check for Address Tag
+///
+/// Return whether the value of a dynamic entry with the tag `tag` (i.e.,
+/// `DT_*`) is a virtual address of the object. See the module documentation
+/// for the list of address tags.
+///
+/// Tags from `DT_ENCODING` up to `DT_LOOS` follow the encoding rule of the
+/// ELF specification: even tags carry addresses, odd tags carry values.
+/// Unknown tags outside of that range are never considered addresses.
+pub fn is_address_tag(tag: u32) -> bool {
+    type Dyn = elf::Dyn<(), ()>;
+
+    match tag {
+        Dyn::DT_PLTGOT
+        | Dyn::DT_HASH
+        | Dyn::DT_STRTAB
+        | Dyn::DT_SYMTAB
+        | Dyn::DT_RELA
+        | Dyn::DT_INIT
+        | Dyn::DT_FINI
+        | Dyn::DT_REL
+        | Dyn::DT_JMPREL
+        | Dyn::DT_INIT_ARRAY
+        | Dyn::DT_FINI_ARRAY
+        | Dyn::DT_VERSYM
+        | Dyn::DT_VERDEF
+        | Dyn::DT_VERNEED => true,
+        Dyn::DT_ENCODING..Dyn::DT_LOOS => tag % 2 == 0,
+        Dyn::DT_ADDRRNGLO..=Dyn::DT_ADDRRNGHI => true,
+        _ => false,
+    }
+}
+
+/// Resolve Dynamic Address
+///
+/// Find the first entry with the tag `tag` (i.e., `DT_*`) in the dynamic
+/// section `dynamic`, and return its value offset by the load bias `bias`
+/// as runtime address. The search stops at the terminating `DT_NULL`
+/// entry, if any.
+///
+/// `bias` must be the load bias of the object as returned by
+/// `LoaderMode::bias()`. It is 0 for fixed objects (i.e., `ET_EXEC`), whose
+/// addresses are absolute, and the distance to the load base for
+/// relocatable objects (i.e., `ET_DYN`). Hence, both are resolved correctly
+/// by adding the bias.
+///
+/// `None` is returned if `tag` does not carry an address (see
+/// `is_address_tag()`), if there is no such entry, or if the address
+/// overflows. The dynamic section is untrusted input, so the returned
+/// address is not validated beyond that, and must be checked against the
+/// mapped segments of the object before it is dereferenced.
+pub fn dyn_ptr(dynamic: &[elfn::Dyn], tag: u32, bias: usize) -> Option<*const u8> {
+    if !is_address_tag(tag) {
+        return None;
+    }
+
+    let entry = dynamic
+        .iter()
+        .take_while(|v| v.d_tag != elf::Dyn::<(), ()>::DT_NULL as elfn::Size)
+        .find(|v| v.d_tag == tag as elfn::Size)?;
+
+    bias.checked_add(entry.d_val as usize).map(|v| v as *const u8)
+}
+
+/// Dynamic Flags
+///
+/// This is the decoded form of the flags of an object, as announced via
+/// `DT_FLAGS` and `DT_FLAGS_1`, as well as the legacy tags that predate
+/// them. Flags not relevant to the loader are not decoded. See
+/// `parse_flags()`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DynFlags {
+    /// All symbols must be bound before control is passed to the object
+    /// (`DF_BIND_NOW`, `DF_1_NOW`, or `DT_BIND_NOW`). The loader never binds
+    /// lazily, so this is always honored, but it tells whether the object
+    /// relies on it (e.g., for full RELRO).
+    pub bind_now: bool,
+    /// Relocations target non-writable segments (`DF_TEXTREL` or
+    /// `DT_TEXTREL`), usually code of objects that were not compiled as
+    /// position-independent code.
+    ///
+    /// Such relocations can only be applied by temporarily making the
+    /// affected code pages writable, and restoring their protection
+    /// afterwards. While the pages are writable, the object violates W^X,
+    /// and any bug in the relocation processing can be used to inject code.
+    /// Hence, this is security relevant, and systems enforcing W^X strictly
+    /// reject it. See the `textrel` module for the relocation sequence.
+    pub textrel: bool,
+    /// Symbol lookup starts at the object itself rather than at the
+    /// executable (`DF_SYMBOLIC` or `DT_SYMBOLIC`).
+    pub symbolic: bool,
+    /// The object uses the static TLS model, and cannot be loaded after
+    /// startup (`DF_STATIC_TLS`).
+    pub static_tls: bool,
+    /// The object uses `$ORIGIN` in its search paths (`DF_ORIGIN` or
+    /// `DF_1_ORIGIN`).
+    pub origin: bool,
+    /// The object must not be unloaded (`DF_1_NODELETE`).
+    pub nodelete: bool,
+    /// The object must not be loaded at runtime via `dlopen()`
+    /// (`DF_1_NOOPEN`).
+    pub noopen: bool,
+    /// The object is a position-independent executable (`DF_1_PIE`), rather
+    /// than a shared library, even though both use `ET_DYN`.
+    pub pie: bool,
+}
+
+/// Parse Dynamic Flags
+///
+/// Decode the flags of the dynamic section `dynamic` (i.e., `DT_FLAGS`,
+/// `DT_FLAGS_1`, and the legacy tags `DT_SYMBOLIC`, `DT_TEXTREL`, and
+/// `DT_BIND_NOW`). The search stops at the terminating `DT_NULL` entry, if
+/// any. If a flag tag appears multiple times, the flags of all entries are
+/// combined. Objects without any flags yield `DynFlags::default()`.
+pub fn parse_flags(dynamic: &[elfn::Dyn]) -> DynFlags {
+    type Dyn = elf::Dyn<(), ()>;
+
+    let mut flags = 0;
+    let mut flags_1 = 0;
+    let mut r = DynFlags::default();
+
+    for entry in dynamic {
+        // Compare tags as native words, so tags beyond 32 bits never alias
+        // the tags of interest.
+        let is = |tag: u32| entry.d_tag == tag as elfn::Size;
+        let val = entry.d_val as u32;
+
+        if is(Dyn::DT_NULL) {
+            break;
+        } else if is(Dyn::DT_FLAGS) {
+            flags |= val;
+        } else if is(Dyn::DT_FLAGS_1) {
+            flags_1 |= val;
+        } else if is(Dyn::DT_SYMBOLIC) {
+            r.symbolic = true;
+        } else if is(Dyn::DT_TEXTREL) {
+            r.textrel = true;
+        } else if is(Dyn::DT_BIND_NOW) {
+            r.bind_now = true;
+        }
+    }
+
+    r.bind_now |= flags & Dyn::DF_BIND_NOW != 0 || flags_1 & Dyn::DF_1_NOW != 0;
+    r.textrel |= flags & Dyn::DF_TEXTREL != 0;
+    r.symbolic |= flags & Dyn::DF_SYMBOLIC != 0;
+    r.static_tls = flags & Dyn::DF_STATIC_TLS != 0;
+    r.origin = flags & Dyn::DF_ORIGIN != 0 || flags_1 & Dyn::DF_1_ORIGIN != 0;
+    r.nodelete = flags_1 & Dyn::DF_1_NODELETE != 0;
+    r.noopen = flags_1 & Dyn::DF_1_NOOPEN != 0;
+    r.pie = flags_1 & Dyn::DF_1_PIE != 0;
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify address tags are told apart from sizes, counts, and offsets,
+    // including the encoding rule of the generic range.
+    #[test]
+    fn address_tags() {
+        type Dyn = elf::Dyn<(), ()>;
+
+        for tag in [
+            Dyn::DT_STRTAB,
+            Dyn::DT_SYMTAB,
+            Dyn::DT_RELA,
+            Dyn::DT_PREINIT_ARRAY,
+            Dyn::DT_SYMTAB_SHNDX,
+            Dyn::DT_RELR,
+            Dyn::DT_GNU_HASH,
+            Dyn::DT_VERSYM,
+            Dyn::DT_VERNEED,
+        ] {
+            assert!(is_address_tag(tag), "tag {:#x}", tag);
+        }
+
+        for tag in [
+            Dyn::DT_NULL,
+            Dyn::DT_NEEDED,
+            Dyn::DT_STRSZ,
+            Dyn::DT_RELASZ,
+            Dyn::DT_SONAME,
+            Dyn::DT_DEBUG,
+            Dyn::DT_PREINIT_ARRAYSZ,
+            Dyn::DT_RELRSZ,
+            Dyn::DT_RELACOUNT,
+            Dyn::DT_RELCOUNT,
+            Dyn::DT_FLAGS_1,
+            Dyn::DT_VERNEEDNUM,
+        ] {
+            assert!(!is_address_tag(tag), "tag {:#x}", tag);
+        }
+    }
+
+    // Resolve addresses of fixed and relocatable objects, and verify
+    // non-address tags, entries past `DT_NULL`, and overflows yield nothing.
+    #[test]
+    fn dyn_ptr_lookup() {
+        let entry = |tag: u32, val: usize| elfn::Dyn {
+            d_tag: tag as elfn::Size,
+            d_val: val as elfn::Size,
+            ..Default::default()
+        };
+        let dynamic = [
+            entry(elf::Dyn::<(), ()>::DT_STRSZ, 0x200),
+            entry(elf::Dyn::<(), ()>::DT_STRTAB, 0x1000),
+            entry(elf::Dyn::<(), ()>::DT_SYMTAB, usize::MAX),
+            entry(elf::Dyn::<(), ()>::DT_NULL, 0),
+            entry(elf::Dyn::<(), ()>::DT_RELA, 0x3000),
+        ];
+
+        assert_eq!(
+            dyn_ptr(&dynamic, elf::Dyn::<(), ()>::DT_STRTAB, 0x7000_0000),
+            Some(0x7000_1000 as *const u8),
+        );
+        assert_eq!(
+            dyn_ptr(&dynamic, elf::Dyn::<(), ()>::DT_STRTAB, 0),
+            Some(0x1000 as *const u8),
+        );
+        assert_eq!(dyn_ptr(&dynamic, elf::Dyn::<(), ()>::DT_STRSZ, 0), None);
+        assert_eq!(dyn_ptr(&dynamic, elf::Dyn::<(), ()>::DT_RELA, 0), None);
+        assert_eq!(dyn_ptr(&dynamic, elf::Dyn::<(), ()>::DT_SYMTAB, 0x1000), None);
+        assert_eq!(dyn_ptr(&dynamic, elf::Dyn::<(), ()>::DT_HASH, 0), None);
+    }
+
+    // Decode flags of both flag tags and the legacy tags, and verify entries
+    // past `DT_NULL` and tags that only match in their low 32 bits are
+    // ignored.
+    #[test]
+    fn flags_decoding() {
+        type Dyn = elf::Dyn<(), ()>;
+
+        let entry = |tag: u32, val: u32| elfn::Dyn {
+            d_tag: tag as elfn::Size,
+            d_val: val as elfn::Size,
+            ..Default::default()
+        };
+
+        assert_eq!(parse_flags(&[]), DynFlags::default());
+
+        let flags = parse_flags(&[
+            entry(Dyn::DT_FLAGS, Dyn::DF_TEXTREL | Dyn::DF_STATIC_TLS),
+            entry(Dyn::DT_FLAGS_1, Dyn::DF_1_NOW | Dyn::DF_1_PIE),
+            entry(Dyn::DT_FLAGS_1, Dyn::DF_1_NODELETE),
+            entry(Dyn::DT_NULL, 0),
+            entry(Dyn::DT_FLAGS, Dyn::DF_SYMBOLIC),
+        ]);
+        assert_eq!(
+            flags,
+            DynFlags {
+                bind_now: true,
+                textrel: true,
+                static_tls: true,
+                nodelete: true,
+                pie: true,
+                ..Default::default()
+            },
+        );
+
+        let flags = parse_flags(&[
+            entry(Dyn::DT_TEXTREL, 0),
+            entry(Dyn::DT_BIND_NOW, 0),
+            entry(Dyn::DT_SYMBOLIC, 0),
+        ]);
+        assert!(flags.textrel && flags.bind_now && flags.symbolic);
+        assert!(!flags.pie && !flags.origin);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let flags = parse_flags(&[elfn::Dyn {
+                d_tag: (1 << 32) | Dyn::DT_TEXTREL as elfn::Size,
+                ..Default::default()
+            }]);
+            assert_eq!(flags, DynFlags::default());
+        }
+    }
+}
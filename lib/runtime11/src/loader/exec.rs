@@ -0,0 +1,388 @@
+//! Execution Hand-over
+//!
+//! A launcher that replaces itself via `execve(2)` passes all its open
+//! file-descriptors to the new program, unless they are marked
+//! close-on-exec. Leaked file-descriptors can grant the new program access
+//! it was not meant to have, and keep pipes and sockets open beyond their
+//! intended lifetime. This module provides the steps to prevent this.
+//!
+//! Launchers that spawn the program as child instead must collect its exit
+//! status. This module provides a race-free way to do so via a pidfd.
+
+use crate::loader::Loader;
+use rt11_ffi_linux::native::{dirent, errno, fcntl, poll, wait};
+use rt11_linux::syscall::Errno;
+
+// Parse the name of an entry of `/proc/self/fd` as file-descriptor number.
+// Any other entry (e.g., `.` and `..`) yields `None`.
+fn parse_fd(name: &[u8]) -> Option<u32> {
+    if name.is_empty() {
+        return None;
+    }
+
+    name.iter().try_fold(0u32, |acc, v| {
+        if !v.is_ascii_digit() {
+            return None;
+        }
+        acc.checked_mul(10)?.checked_add((v - b'0') as u32)
+    })
+}
+
+// Encode the exit information reported by `waitid()` as status word of
+// `wait4()`.
+fn status_from_info(info: &wait::Siginfo) -> i32 {
+    match info.si_code {
+        wait::CLD_EXITED => (info.si_status & 0xff) << 8,
+        wait::CLD_DUMPED => (info.si_status & 0x7f) | 0x80,
+        _ => info.si_status & 0x7f,
+    }
+}
+
+impl<'this> Loader<'this> {
+    // Mark all file-descriptors above `fd` close-on-exec by walking the
+    // entries of the directory `dir`, which must refer to `/proc/self/fd`.
+    // `dir` itself is skipped.
+    fn cloexec_dir(&self, dir: u32, fd: u32) -> Result<(), Errno> {
+        let mut buf = [0u64; 256];
+        let size = core::mem::size_of_val(&buf);
+
+        loop {
+            let n = unsafe { self.this.syscall.getdents64(dir, buf.as_mut_ptr() as *mut u8, size)? };
+            if n == 0 {
+                return Ok(());
+            }
+
+            let data = unsafe { core::slice::from_raw_parts(buf.as_ptr() as *const u8, n) };
+            let mut off = 0;
+            while off < n {
+                let ent = unsafe {
+                    (data.as_ptr().add(off) as *const dirent::Dirent64).read_unaligned()
+                };
+                let rec = &data[off..off + ent.d_reclen as usize];
+                let name = &rec[dirent::DIRENT64_NAME_OFFSET..];
+                let name = &name[..name.iter().position(|v| *v == 0).unwrap_or(name.len())];
+                off += rec.len();
+
+                let Some(v) = parse_fd(name) else {
+                    continue;
+                };
+                if v <= fd || v == dir {
+                    continue;
+                }
+
+                // The file-descriptor might have been closed by another
+                // thread since the directory was read.
+                match self.this.syscall.set_cloexec(v) {
+                    Ok(()) | Err(errno::EBADF) => {},
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+    }
+
+    // Mark all file-descriptors above `fd` close-on-exec via the entries of
+    // `/proc/self/fd`. This is the fallback for kernels without support
+    // for `CLOSE_RANGE_CLOEXEC`.
+    fn cloexec_all_above_procfs(&self, fd: u32) -> Result<(), Errno> {
+        let dir = unsafe {
+            self.this.openat(
+                fcntl::AT_FDCWD,
+                c"/proc/self/fd".as_ptr().cast(),
+                fcntl::O_RDONLY | fcntl::O_CLOEXEC,
+                0,
+            )?
+        };
+
+        let r = self.cloexec_dir(dir, fd);
+
+        // The directory was opened above and is not shared.
+        unsafe {
+            let _ = self.this.close(dir);
+        }
+
+        r
+    }
+
+    /// Mark File-descriptors Close-on-exec
+    ///
+    /// Mark all file-descriptors greater than `fd` close-on-exec, so they
+    /// are not inherited by the program started via the next `execve()`.
+    /// The file-descriptors remain usable until then. A launcher usually
+    /// passes 2, to retain only the standard streams.
+    ///
+    /// This uses `close_range(CLOSE_RANGE_CLOEXEC)`, which marks the entire
+    /// range with a single system call. On kernels without it (i.e.,
+    /// `ENOSYS` before linux-5.9, and `EINVAL` before linux-5.11, which
+    /// lack the flag), this falls back to reading `/proc/self/fd` via
+    /// `getdents64()` and marking each listed file-descriptor individually.
+    /// The fallback skips the file-descriptor it uses to read the
+    /// directory, which is opened with `O_CLOEXEC` and closed afterwards.
+    ///
+    /// The fallback is considerably more expensive: it costs two `fcntl()`
+    /// calls per open file-descriptor, on top of the directory reads.
+    /// Furthermore, it requires `/proc` to be mounted, and fails otherwise
+    /// (usually with `ENOENT`). It is also racy in multi-threaded
+    /// programs, since file-descriptors opened by other threads while the
+    /// directory is read might be missed. Launchers should call this right
+    /// before `execve()`, once no other threads are running.
+    pub fn cloexec_all_above(&self, fd: u32) -> Result<(), Errno> {
+        let Some(first) = fd.checked_add(1) else {
+            return Ok(());
+        };
+
+        let r = unsafe {
+            self.this.syscall.close_range(first, u32::MAX, fcntl::CLOSE_RANGE_CLOEXEC)
+        };
+        match r {
+            Err(errno::ENOSYS) | Err(errno::EINVAL) => self.cloexec_all_above_procfs(fd),
+            r => r,
+        }
+    }
+
+    /// Wait for Child via pidfd
+    ///
+    /// Wait for the child referred to by `pidfd` to exit, reap it, and
+    /// return its status word (as reported by `wait4()`, see
+    /// `rt11_linux::wait` to decode it). Unlike waiting for the task ID,
+    /// this cannot reap an unrelated child that reused the ID.
+    ///
+    /// This first polls `pidfd` for `POLLIN`, which signals the exit of the
+    /// child, and then reaps it via `waitid(P_PIDFD, WEXITED)`. Both steps
+    /// are retried if interrupted by a signal. Hence, the wait can be
+    /// integrated in an event loop that polls `pidfd` itself, and this only
+    /// blocks until the exit is reported.
+    ///
+    /// `waitid()` with `P_PIDFD` requires linux-5.4 or newer, and returns
+    /// `EINVAL` on older kernels. `ECHILD` is returned if the process is
+    /// not a child of the caller, or was reaped already.
+    pub fn pidfd_wait(&self, pidfd: u32) -> Result<i32, Errno> {
+        let mut fds = [poll::PollFd { fd: pidfd as i32, events: poll::POLLIN, revents: 0 }];
+        let mut info = wait::Siginfo::default();
+
+        loop {
+            let r = unsafe {
+                self.this.syscall.ppoll(fds.as_mut_ptr(), 1, core::ptr::null(), core::ptr::null())
+            };
+            match r {
+                Ok(_) => break,
+                Err(errno::EINTR) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        if fds[0].revents & poll::POLLNVAL != 0 {
+            return Err(errno::EBADF);
+        }
+
+        loop {
+            let r = unsafe {
+                self.this.syscall.waitid(wait::P_PIDFD, pidfd, &mut info, wait::WEXITED)
+            };
+            match r {
+                Ok(()) => break,
+                Err(errno::EINTR) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(status_from_info(&info))
+    }
+
+    /// Peek at Child Status
+    ///
+    /// Check whether the child `pid` exited, without reaping it. If it is
+    /// still running, `None` is returned. Otherwise, its status word is
+    /// returned (as reported by `wait4()`, see `rt11_linux::wait` to decode
+    /// it). This never blocks.
+    ///
+    /// This uses `waitid(P_PID, WEXITED | WNOWAIT | WNOHANG)`. Due to
+    /// `WNOWAIT`, the child stays a zombie and remains waitable, so its
+    /// status can be peeked at repeatedly, and its task ID is not recycled.
+    /// The caller must still reap it via `wait4()` or `waitid()` (e.g., via
+    /// `pidfd_wait()`), or it lingers until the caller exits. `ECHILD` is
+    /// returned if `pid` is not a child of the caller, or was reaped
+    /// already.
+    pub fn peek_child_status(&self, pid: u32) -> Result<Option<i32>, Errno> {
+        let mut info = wait::Siginfo::default();
+
+        unsafe {
+            self.this.syscall.waitid(
+                wait::P_PID,
+                pid,
+                &mut info,
+                wait::WEXITED | wait::WNOWAIT | wait::WNOHANG,
+            )?;
+        }
+
+        // With `WNOHANG`, the kernel leaves `si_pid` 0 if the child did not
+        // exit yet.
+        if info.si_pid == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(status_from_info(&info)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rt11_ffi_linux::native::signal;
+
+    // Kill and reap the child behind a pidfd when dropped, so a failed
+    // assertion does not leave a spinning child behind. Both steps fail
+    // harmlessly if the child was already reaped.
+    struct Reaper<'a>(&'a rt11_linux::this::This, u32);
+
+    impl Drop for Reaper<'_> {
+        fn drop(&mut self) {
+            let mut info = wait::Siginfo::default();
+
+            unsafe {
+                let _ = self.0.syscall.pidfd_send_signal(
+                    self.1,
+                    signal::SIGKILL,
+                    core::ptr::null(),
+                    0,
+                );
+                let _ = self.0.syscall.waitid(wait::P_PIDFD, self.1, &mut info, wait::WEXITED);
+                let _ = self.0.close(self.1);
+            }
+        }
+    }
+
+    // Verify directory entry names are parsed as file-descriptor numbers.
+    #[test]
+    fn fd_parse() {
+        assert_eq!(parse_fd(b"0"), Some(0));
+        assert_eq!(parse_fd(b"71"), Some(71));
+        assert_eq!(parse_fd(b"4294967295"), Some(u32::MAX));
+        assert_eq!(parse_fd(b"4294967296"), None);
+        assert_eq!(parse_fd(b"."), None);
+        assert_eq!(parse_fd(b".."), None);
+        assert_eq!(parse_fd(b""), None);
+    }
+
+    // Open file-descriptors without `O_CLOEXEC`, and verify both the
+    // regular path and the fallback mark only those above the limit. This
+    // runs in a forked child, since the sweep hits the file-descriptors of
+    // all other tests of the process as well. The child reports the first
+    // failed check as exit code.
+    #[test]
+    fn cloexec_above() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let open = || unsafe {
+            this.syscall.open(b"/proc/self/exe\0".as_ptr(), fcntl::O_RDONLY, 0)
+        };
+        let flags = |fd: u32| unsafe { this.syscall.fcntl(fd, fcntl::F_GETFD, 0) };
+
+        let check = || -> Result<(), u32> {
+            let mut fds = [
+                open().map_err(|_| 1u32)?,
+                open().map_err(|_| 1u32)?,
+                open().map_err(|_| 1u32)?,
+            ];
+            fds.sort();
+            let [a, b, c] = fds;
+
+            loader.cloexec_all_above_procfs(b).map_err(|_| 2u32)?;
+            if flags(a) != Ok(0) || flags(b) != Ok(0) {
+                return Err(3);
+            }
+            if flags(c) != Ok(fcntl::FD_CLOEXEC as usize) {
+                return Err(4);
+            }
+
+            loader.cloexec_all_above(a).map_err(|_| 5u32)?;
+            if flags(a) != Ok(0) || flags(b) != Ok(fcntl::FD_CLOEXEC as usize) {
+                return Err(6);
+            }
+
+            loader.cloexec_all_above(u32::MAX).map_err(|_| 7u32)?;
+
+            Ok(())
+        };
+
+        match unsafe { this.syscall.fork() }.unwrap() {
+            rt11_linux::syscall::ForkResult::Child => {
+                this.exit_group(check().err().unwrap_or(0));
+            },
+            rt11_linux::syscall::ForkResult::Parent(pid) => {
+                assert_eq!(crate::loader::test::reap_exit(&this.syscall, pid), 0);
+            },
+        }
+    }
+
+    // Spawn children that exit and that are killed, and verify their
+    // status is collected via their pidfd exactly once.
+    #[test]
+    fn pidfd_exit() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let spawn = |code: Option<u32>| {
+            match unsafe { this.syscall.fork() }.unwrap() {
+                rt11_linux::syscall::ForkResult::Child => match code {
+                    Some(v) => this.exit_group(v),
+                    None => loop {
+                        core::hint::spin_loop();
+                    },
+                },
+                rt11_linux::syscall::ForkResult::Parent(pid) => {
+                    this.syscall.pidfd_open(pid as i32, 0).unwrap()
+                },
+            }
+        };
+
+        let pidfd = spawn(Some(71));
+        let status = loader.pidfd_wait(pidfd).unwrap();
+        assert!(rt11_linux::wait::wifexited(status));
+        assert_eq!(rt11_linux::wait::wexitstatus(status), 71);
+        assert_eq!(loader.pidfd_wait(pidfd), Err(errno::ECHILD));
+        unsafe { this.close(pidfd).unwrap() };
+
+        let pidfd = spawn(None);
+        let _reaper = Reaper(&this, pidfd);
+        unsafe {
+            this.syscall.pidfd_send_signal(pidfd, signal::SIGKILL, core::ptr::null(), 0).unwrap();
+        }
+        let status = loader.pidfd_wait(pidfd).unwrap();
+        assert!(rt11_linux::wait::wifsignaled(status));
+        assert_eq!(rt11_linux::wait::wtermsig(status) as u32, signal::SIGKILL);
+    }
+
+    // Peek at the status of a child before and after it exits, and verify
+    // it stays waitable until it is reaped.
+    #[test]
+    fn child_peek() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+
+        let pid = match unsafe { this.syscall.fork() }.unwrap() {
+            rt11_linux::syscall::ForkResult::Child => loop {
+                core::hint::spin_loop();
+            },
+            rt11_linux::syscall::ForkResult::Parent(pid) => pid,
+        };
+        let pidfd = this.syscall.pidfd_open(pid as i32, 0).unwrap();
+        let _reaper = Reaper(&this, pidfd);
+
+        assert_eq!(loader.peek_child_status(pid), Ok(None));
+
+        unsafe {
+            this.syscall.pidfd_send_signal(pidfd, signal::SIGKILL, core::ptr::null(), 0).unwrap();
+        }
+        let mut fds = [poll::PollFd { fd: pidfd as i32, events: poll::POLLIN, revents: 0 }];
+        unsafe {
+            this.syscall.ppoll(fds.as_mut_ptr(), 1, core::ptr::null(), core::ptr::null()).unwrap();
+        }
+
+        let status = loader.peek_child_status(pid).unwrap().unwrap();
+        assert!(rt11_linux::wait::wifsignaled(status));
+        assert_eq!(rt11_linux::wait::wtermsig(status) as u32, signal::SIGKILL);
+        assert_eq!(loader.peek_child_status(pid), Ok(Some(status)));
+
+        assert_eq!(loader.pidfd_wait(pidfd), Ok(status));
+        assert_eq!(loader.peek_child_status(pid), Err(errno::ECHILD));
+    }
+}
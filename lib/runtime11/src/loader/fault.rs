@@ -0,0 +1,227 @@
+//! Fault Reporting
+//!
+//! Bugs in the loader (or malformed objects it did not reject) usually
+//! surface as segmentation faults while relocating, which the parent only
+//! sees as an opaque `SIGSEGV`. This module installs handlers for `SIGSEGV`
+//! and `SIGBUS` that report the faulting address on standard error before
+//! terminating the process:
+//!
+//! ```text
+//! fault at 0x7f3a2c001010
+//! ```
+//!
+//! The handlers are installed with `SA_ONSTACK`, and run on an alternate
+//! signal stack (see `Syscall::sigaltstack()`), so faults caused by stack
+//! overflows are reported as well. They are async-signal-safe: the report is
+//! formatted into a buffer on the stack via `fmt::BufWriter`, written via the
+//! raw `write(2)` system call, and the process is terminated via
+//! `exit_group(2)` with `128 + signal` as exit-code. Nothing is allocated,
+//! and no lock is taken.
+//!
+//! Only the start of `siginfo_t` is needed, which is common to all supported
+//! architectures: `si_signo`, `si_errno`, and `si_code` as 32-bit integers,
+//! followed by a union aligned to the native word size. For the fault
+//! signals, the union starts with `si_addr`, the faulting address. Hence,
+//! `si_addr` is at offset 12 on 32-bit architectures, and 16 on 64-bit
+//! architectures.
+
+use crate::loader::fmt::BufWriter;
+use crate::loader::Loader;
+use core::fmt::Write;
+use rt11_ffi_linux::native::{mman, signal};
+use rt11_linux::mapping::Mapping;
+use rt11_linux::syscall::Errno;
+
+// Size of the alternate signal stack, if one must be allocated. This is
+// well beyond `MINSIGSTKSZ` of all supported architectures, including
+// those with large vector register files in the signal frame.
+const STACK_SIZE: usize = 65536;
+
+// Signal-return trampoline. The handlers never return, but x86-64 refuses
+// to deliver signals to handlers without `SA_RESTORER`.
+core::arch::global_asm!(
+    rt11_linux::sigreturn!("text.rt11_loader_fault_restorer", "rt11_loader_fault_restorer"),
+);
+extern "C" {
+    fn rt11_loader_fault_restorer() -> !;
+}
+
+// Subset of `siginfo_t` for fault signals (see the module documentation).
+// `repr(C)` inserts the padding before `si_addr` on 64-bit architectures.
+#[repr(C)]
+struct FaultInfo {
+    si_signo: i32,
+    si_errno: i32,
+    si_code: i32,
+    si_addr: usize,
+}
+
+// Report the faulting address of `info` and terminate the process. This
+// runs on the alternate signal stack, and must be async-signal-safe. The
+// faulting task already owns its execution context, so the system calls
+// are invoked via the raw dispatcher. The report is shorter than
+// `PIPE_BUF`, so a single write suffices for pipes, and any error is
+// ignored since the process terminates either way.
+extern "C" fn report(sig: u32, info: *const FaultInfo, _ctx: *mut u8) -> ! {
+    let ffi = rt11_ffi_linux::native::syscall::Syscall {};
+    let mut buf = [0u8; 32];
+    let mut w = BufWriter::new(&mut buf);
+
+    // The kernel passes a valid `siginfo_t` to handlers with `SA_SIGINFO`.
+    let addr = unsafe { (*info).si_addr };
+    let _ = writeln!(w, "fault at {:#x}", addr);
+
+    unsafe {
+        <_ as rt11_ffi_linux::common::Syscall>::syscall3(
+            &ffi,
+            rt11_ffi_linux::native::nr::WRITE as usize,
+            2,
+            w.as_bytes().as_ptr() as usize,
+            w.as_bytes().len(),
+        );
+        <_ as rt11_ffi_linux::common::Syscall>::syscall1(
+            &ffi,
+            rt11_ffi_linux::native::nr::EXIT_GROUP as usize,
+            128 + sig as usize,
+        );
+    }
+
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+impl<'this> Loader<'this> {
+    /// Install Fault Reporter
+    ///
+    /// Install handlers for `SIGSEGV` and `SIGBUS`, which report the
+    /// faulting address on standard error and terminate the process with
+    /// `128 + signal` as exit-code. See the module documentation for
+    /// details.
+    ///
+    /// The handlers run on the alternate signal stack of the faulting
+    /// thread. If the calling thread has none, a new one is mapped and
+    /// leaked, otherwise the existing one is used. Other threads must
+    /// provide their own alternate stack, since it is a property of the
+    /// thread. Faults of threads without one are reported on their regular
+    /// stack.
+    ///
+    /// The handlers are reset to the default action on delivery (i.e.,
+    /// `SA_RESETHAND`), so a fault in the handler itself terminates the
+    /// process via the default action, rather than recursing. Any handlers
+    /// installed previously are replaced.
+    ///
+    /// Errors of the kernel are returned as is, and the handlers might be
+    /// installed partially in that case.
+    pub fn install_fault_reporter(&self) -> Result<(), Errno> {
+        let mut ss = signal::Stack::default();
+
+        // Querying the stack has no side-effects.
+        unsafe { self.this.syscall.sigaltstack(None, Some(&mut ss))? };
+
+        if ss.ss_flags & signal::SS_DISABLE as i32 != 0 {
+            let prot = mman::PROT_READ | mman::PROT_WRITE;
+            let stack = Mapping::anonymous(&self.this.syscall, STACK_SIZE, prot)?;
+
+            ss = signal::Stack {
+                ss_sp: stack.as_ptr() as usize,
+                ss_flags: 0,
+                ss_size: stack.len(),
+            };
+
+            // The stack is leaked below, so it remains mapped as long as
+            // it is installed.
+            unsafe { self.this.syscall.sigaltstack(Some(&ss), None)? };
+            let _ = stack.leak();
+        }
+
+        let mut flags = signal::SA_SIGINFO | signal::SA_ONSTACK | signal::SA_RESETHAND;
+        if cfg!(not(target_arch = "riscv64")) {
+            flags |= signal::SA_RESTORER;
+        }
+
+        let act = signal::Sigaction {
+            sa_handler: report as *const () as usize,
+            sa_flags: flags as usize,
+            #[cfg(not(target_arch = "riscv64"))]
+            sa_restorer: rt11_loader_fault_restorer as *const () as usize,
+            ..Default::default()
+        };
+
+        for sig in [signal::SIGSEGV, signal::SIGBUS] {
+            // The action has the native layout, and the handler is
+            // async-signal-safe.
+            unsafe {
+                self.this.syscall.rt_sigaction(sig, &act, core::ptr::null_mut())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rt11_ffi_linux::native::fcntl;
+
+    // Verify the subset of `siginfo_t` matches the kernel layout.
+    #[test]
+    fn typeinfo() {
+        let v = FaultInfo { si_signo: 0, si_errno: 0, si_code: 0, si_addr: 0 };
+        let base = &v as *const _ as usize;
+        let pad = core::mem::size_of::<usize>() - 4;
+        assert_eq!(&v.si_addr as *const _ as usize - base, 12 + pad);
+    }
+
+    // Fault in a child with the reporter installed, and verify it reports
+    // the faulting address and exits with the expected code.
+    #[test]
+    fn fault_report() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let mut buf = [0u8; 64];
+
+        let p = this.syscall.pipe2(fcntl::O_CLOEXEC).unwrap();
+
+        match unsafe { this.syscall.fork() }.unwrap() {
+            rt11_linux::syscall::ForkResult::Child => {
+                if unsafe { this.syscall.dup3(p[1], 2, 0) }.is_err() {
+                    this.exit_group(1);
+                }
+                if loader.install_fault_reporter().is_err() {
+                    this.exit_group(2);
+                }
+                unsafe { core::ptr::read_volatile(0x71 as *const u8) };
+                this.exit_group(3);
+            },
+            rt11_linux::syscall::ForkResult::Parent(pid) => {
+                let pidfd = this.syscall.pidfd_open(pid as i32, 0).unwrap();
+                unsafe { this.close(p[1]).unwrap() };
+
+                let mut len = 0;
+                loop {
+                    match unsafe { this.read(p[0], buf[len..].as_mut_ptr(), buf.len() - len) }
+                        .unwrap()
+                    {
+                        0 => break,
+                        v => len += v,
+                    }
+                }
+                assert_eq!(&buf[..len], b"fault at 0x71\n");
+
+                let status = loader.pidfd_wait(pidfd).unwrap();
+                assert!(rt11_linux::wait::wifexited(status));
+                assert_eq!(
+                    rt11_linux::wait::wexitstatus(status),
+                    128 + signal::SIGSEGV as u8,
+                );
+
+                unsafe {
+                    this.close(pidfd).unwrap();
+                    this.close(p[0]).unwrap();
+                }
+            },
+        }
+    }
+}
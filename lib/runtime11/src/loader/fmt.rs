@@ -0,0 +1,134 @@
+//! Diagnostic Formatting
+//!
+//! This module provides allocation-free helpers to render diagnostics of
+//! the loader (e.g., while bringing it up on a new architecture). All
+//! helpers write to any `core::fmt::Write`, and `BufWriter` provides one
+//! backed by a caller provided buffer.
+
+/// Fixed-buffer Writer
+///
+/// A writer that formats into a caller provided byte buffer. It never
+/// allocates, and fails with `core::fmt::Error` once the buffer is full.
+/// The data written so far is retained in that case, but a string that did
+/// not fit is written partially (possibly splitting a character).
+#[derive(Debug)]
+pub struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> BufWriter<'a> {
+    /// Create Writer
+    ///
+    /// Create a new writer that formats into `buf`, starting at its
+    /// beginning.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Access Written Data
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Reset Writer
+    ///
+    /// Discard all written data, making the entire buffer available again.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<'a> core::fmt::Write for BufWriter<'a> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let rem = &mut self.buf[self.len..];
+        let n = s.len().min(rem.len());
+
+        rem[..n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+
+        if n < s.len() {
+            Err(core::fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Write Hex Dump
+///
+/// Write `bytes` to `w` as classic hex dump, with 16 bytes per line. Each
+/// line starts with the address of its first byte, relative to `addr`,
+/// followed by the bytes in hexadecimal, and their ASCII representation
+/// (with non-printable bytes shown as `.`):
+///
+/// ```text
+/// 00001000: 7f 45 4c 46 02 01 01 00 00 00 00 00 00 00 00 00  |.ELF............|
+/// ```
+///
+/// Addresses are printed with at least 8 digits. The last line is padded,
+/// so the ASCII columns align. Nothing is written for empty input.
+pub fn hexdump(w: &mut impl core::fmt::Write, addr: usize, bytes: &[u8]) -> core::fmt::Result {
+    for (i, line) in bytes.chunks(16).enumerate() {
+        write!(w, "{:08x}:", addr.wrapping_add(i * 16))?;
+
+        for v in line {
+            write!(w, " {:02x}", v)?;
+        }
+        for _ in line.len()..16 {
+            w.write_str("   ")?;
+        }
+
+        w.write_str("  |")?;
+        for v in line {
+            let c = if v.is_ascii_graphic() || *v == b' ' { *v as char } else { '.' };
+            w.write_char(c)?;
+        }
+        w.write_str("|\n")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::fmt::Write;
+
+    // Verify the fixed-buffer writer retains data and reports overflows.
+    #[test]
+    fn buf_writer() {
+        let mut buf = [0u8; 8];
+        let mut w = BufWriter::new(&mut buf);
+
+        write!(w, "{}-{:x}", 7, 0xf00d).unwrap();
+        assert_eq!(w.as_bytes(), b"7-f00d");
+        assert!(w.write_str("foo").is_err());
+        assert_eq!(w.as_bytes(), b"7-f00dfo");
+
+        w.clear();
+        assert_eq!(w.as_bytes(), b"");
+    }
+
+    // Dump a partial second line and compare against the expected output.
+    #[test]
+    fn hexdump_lines() {
+        let mut buf = [0u8; 256];
+        let mut w = BufWriter::new(&mut buf);
+        let data = b"\x7fELF\x02\x01\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00rt11 ~\xff";
+
+        hexdump(&mut w, 0x1000, data).unwrap();
+        assert_eq!(
+            core::str::from_utf8(w.as_bytes()).unwrap(),
+            "00001000: 7f 45 4c 46 02 01 01 00 00 00 00 00 00 00 00 00  |.ELF............|\n\
+             00001010: 72 74 31 31 20 7e ff                             |rt11 ~.|\n",
+        );
+
+        w.clear();
+        hexdump(&mut w, 0, b"").unwrap();
+        assert_eq!(w.as_bytes(), b"");
+
+        let mut small = [0u8; 16];
+        assert!(hexdump(&mut BufWriter::new(&mut small), 0, data).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+//! Memory Layout
+//!
+//! This module implements the loader steps that compute the memory layout of
+//! an ELF object before it is mapped.
+
+use crate::loader::LoadError;
+use rt11_ffi_elf::{elf, elfn};
+use rt11_ffi_linux::native::{errno, mman};
+use rt11_linux::syscall::{Errno, Syscall};
+use rt11_linux::util;
+
+/// Placement Mode
+///
+/// This describes how the virtual addresses of an object (i.e., `p_vaddr`)
+/// relate to its runtime addresses, as dictated by its ELF type. See
+/// `loader_mode()` for details.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LoaderMode {
+    /// Virtual addresses are absolute (i.e., `ET_EXEC`). The object must
+    /// be mapped exactly at them, and the load bias is 0.
+    Fixed,
+    /// Virtual addresses are relative (i.e., `ET_DYN`, as used by shared
+    /// libraries and position-independent executables). The object can be
+    /// mapped anywhere, and the load bias is the distance to the chosen
+    /// base.
+    Relocatable,
+}
+
+impl LoaderMode {
+    /// Compute Reservation Arguments
+    ///
+    /// Return the address hint and the additional `MAP_*` flags to reserve
+    /// the load span `span` (see `load_span()`) with `mmap()`.
+    ///
+    /// Fixed objects must be reserved exactly at the start of their span.
+    /// This uses `MAP_FIXED_NOREPLACE` rather than `MAP_FIXED`, so a
+    /// conflict with an existing mapping (e.g., the loader itself) fails
+    /// with `EEXIST` instead of silently replacing it (see `bias()` for
+    /// older kernels). Relocatable objects are placed by the kernel. In both
+    /// cases, the segments are then mapped into the reservation with
+    /// `MAP_FIXED`.
+    pub fn reservation(&self, span: (usize, usize)) -> (usize, u32) {
+        match self {
+            Self::Fixed => (span.0, mman::MAP_FIXED_NOREPLACE),
+            Self::Relocatable => (0, 0),
+        }
+    }
+
+    /// Compute Load Bias
+    ///
+    /// Return the load bias of an object with the load span `span`, which
+    /// was reserved at `base`. The load bias is added to all virtual
+    /// addresses of the object to get their runtime address.
+    ///
+    /// For fixed objects, this is 0. Kernels before linux-4.17 do not know
+    /// `MAP_FIXED_NOREPLACE`, and treat the address as a mere hint. Hence,
+    /// if `base` does not match the start of the span, the reservation is
+    /// unmapped and `EEXIST` is returned (as newer kernels do right away).
+    /// For relocatable objects, this is the distance from the start of the
+    /// span to `base`, using wrapping arithmetic, since the start of the
+    /// span is usually 0.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `base` must be the address of a reservation of `span`, as returned by
+    /// `mmap()` for the arguments of `reservation()`. It is unmapped if the
+    /// check fails, so the caller must not use it afterwards.
+    pub unsafe fn bias(
+        &self,
+        syscall: &Syscall,
+        span: (usize, usize),
+        base: usize,
+    ) -> Result<usize, Errno> {
+        match self {
+            Self::Fixed => {
+                if base != span.0 {
+                    // The reservation is owned by the caller, and unused.
+                    let _ = unsafe { syscall.munmap(base, span.1 - span.0) };
+                    return Err(errno::EEXIST);
+                }
+                Ok(0)
+            },
+            Self::Relocatable => Ok(base.wrapping_sub(span.0)),
+        }
+    }
+}
+
+/// Select Placement Mode
+///
+/// Return the placement mode of an object with the ELF header `ehdr`, based
+/// on its type (i.e., `e_type`). `ET_EXEC` objects use absolute virtual
+/// addresses, and `ET_DYN` objects use addresses relative to their load
+/// bias. Any other type (e.g., `ET_REL` objects, which must be linked
+/// first, or `ET_CORE` dumps) cannot be loaded, and
+/// `LoadError::UnsupportedType` is returned.
+///
+/// Getting this wrong is fatal: mapping a fixed object at a different
+/// address breaks every absolute reference in its code and data, while
+/// mapping a relocatable object at its raw virtual addresses (usually
+/// starting at 0) fails, or collides with other mappings. Neither can be
+/// fixed up by relocations.
+pub fn loader_mode(ehdr: &elfn::Ehdr) -> Result<LoaderMode, LoadError> {
+    match ehdr.e_type {
+        elf::Ehdr::<(), ()>::ET_EXEC => Ok(LoaderMode::Fixed),
+        elf::Ehdr::<(), ()>::ET_DYN => Ok(LoaderMode::Relocatable),
+        v => Err(LoadError::UnsupportedType(v)),
+    }
+}
+
+/// Compute Load Span
+///
+/// Return the page-aligned virtual address range covered by all `PT_LOAD`
+/// program headers of `phdrs`. The range is returned as the lowest virtual
+/// address (rounded down to `pagesz`) and the highest end address (i.e.,
+/// `p_vaddr + p_memsz`, rounded up to `pagesz`). If there are no `PT_LOAD`
+/// program headers, or the range is not representable, `None` is returned.
+///
+/// Segments of an object must keep their relative distances, but a
+/// position-independent object can be placed anywhere. Hence, a loader
+/// reserves the entire span with a single `PROT_NONE` mapping at an address
+/// picked by the kernel (and thus randomized), and then maps each segment
+/// into the reservation with `MAP_FIXED`. This avoids conflicts with other
+/// mappings, and keeps the gaps between segments inaccessible. The load bias
+/// is then the difference between the reservation and the start of the
+/// span.
+///
+/// The program headers are untrusted input, so overflows are checked
+/// rather than wrapped.
+pub fn load_span(phdrs: &[elfn::Phdr], pagesz: usize) -> Option<(usize, usize)> {
+    let mut span: Option<(usize, usize)> = None;
+
+    for phdr in phdrs {
+        if phdr.p_type != elf::Phdr::<(), ()>::PT_LOAD {
+            continue;
+        }
+
+        let start = phdr.p_vaddr as usize;
+        let end = start.checked_add(phdr.p_memsz as usize)?;
+        let start = util::align_down(start, pagesz);
+        let end = util::align_up(end, pagesz)?;
+
+        span = Some(match span {
+            None => (start, end),
+            Some((s, e)) => (core::cmp::min(s, start), core::cmp::max(e, end)),
+        });
+    }
+
+    span
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the span covers all loadable segments with page-aligned
+    // boundaries, ignores other segments, and rejects overflows.
+    #[test]
+    fn span_calculation() {
+        let load = |vaddr: usize, memsz: usize| elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_LOAD,
+            p_vaddr: vaddr as _,
+            p_memsz: memsz as _,
+            ..Default::default()
+        };
+        let other = elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_GNU_RELRO,
+            p_vaddr: 0x100000,
+            p_memsz: 0x1000,
+            ..Default::default()
+        };
+
+        assert_eq!(load_span(&[], 0x1000), None);
+        assert_eq!(load_span(&[other], 0x1000), None);
+        assert_eq!(
+            load_span(&[load(0x0, 0x1234), other, load(0x3e10, 0x2001)], 0x1000),
+            Some((0x0, 0x6000)),
+        );
+        assert_eq!(
+            load_span(&[load(0x5010, 0x10), load(0x2fff, 0x1)], 0x1000),
+            Some((0x2000, 0x6000)),
+        );
+        assert_eq!(load_span(&[load(usize::MAX - 0x10, 0x20)], 0x1000), None);
+        assert_eq!(load_span(&[load(usize::MAX - 0x10, 0x1)], 0x1000), None);
+    }
+
+    // Verify the placement mode is derived from the ELF type, and that
+    // reservation and load bias match the mode.
+    #[test]
+    fn mode_selection() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let sc = &this.syscall;
+        let ehdr = |e_type: u16| elfn::Ehdr { e_type, ..Default::default() };
+
+        let fixed = loader_mode(&ehdr(elf::Ehdr::<(), ()>::ET_EXEC)).unwrap();
+        assert_eq!(fixed, LoaderMode::Fixed);
+        assert_eq!(fixed.reservation((0x400000, 0x410000)), (0x400000, mman::MAP_FIXED_NOREPLACE));
+        assert_eq!(unsafe { fixed.bias(sc, (0x400000, 0x410000), 0x400000) }, Ok(0));
+
+        // A reservation at the wrong address is released.
+        let base = unsafe {
+            sc.mmap(0, 0x1000, mman::PROT_NONE, mman::MAP_PRIVATE | mman::MAP_ANONYMOUS, -1, 0)
+        }.unwrap();
+        assert_eq!(
+            unsafe { fixed.bias(sc, (base + 0x10000, base + 0x11000), base) },
+            Err(errno::EEXIST),
+        );
+
+        let reloc = loader_mode(&ehdr(elf::Ehdr::<(), ()>::ET_DYN)).unwrap();
+        assert_eq!(reloc, LoaderMode::Relocatable);
+        assert_eq!(reloc.reservation((0x0, 0x10000)), (0, 0));
+        assert_eq!(unsafe { reloc.bias(sc, (0x0, 0x10000), 0x7f0000) }, Ok(0x7f0000));
+        assert_eq!(unsafe { reloc.bias(sc, (0x1000, 0x10000), 0x7f0000) }, Ok(0x7ef000));
+
+        for v in [
+            elf::Ehdr::<(), ()>::ET_NONE,
+            elf::Ehdr::<(), ()>::ET_REL,
+            elf::Ehdr::<(), ()>::ET_CORE,
+            elf::Ehdr::<(), ()>::ET_LOOS,
+        ] {
+            assert_eq!(loader_mode(&ehdr(v)), Err(LoadError::UnsupportedType(v)));
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! ELF Loader
+//!
+//! This module implements the individual steps required to load an ELF
+//! object into memory and prepare it for execution. The steps are kept
+//! independent of each other, so they can be used by the dynamic loader of
+//! this runtime as well as by external tools that want to load or inspect
+//! ELF objects.
+//!
+//! Unless noted otherwise, all interfaces operate on the native ELF format
+//! (see `rt11_ffi_elf::elfn`).
+
+pub mod auxv;
+pub mod budget;
+pub mod canary;
+pub mod dynamic;
+pub mod exec;
+pub mod fault;
+pub mod fmt;
+pub mod layout;
+pub mod note;
+pub mod object;
+pub mod reloc;
+pub mod relro;
+pub mod search;
+pub mod seccomp;
+pub mod segment;
+pub mod spawn;
+pub mod stack;
+pub mod symbol;
+pub mod textrel;
+pub mod unwind;
+
+/// Loader Errors
+///
+/// This enumerates all errors that can be raised by the individual loader
+/// steps. Errors of the kernel are not included, but reported as `Errno`
+/// by the respective interfaces. Only steps that combine kernel operations
+/// with the processing of an object wrap them (see `LoadError::Protection`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LoadError {
+    /// A mapping would exceed the address-space limit (i.e., `RLIMIT_AS`).
+    AddressSpaceLimit,
+    /// An ELF header is malformed or does not match the native format.
+    InvalidHeader,
+    /// A relocation references a symbol beyond the symbol table.
+    InvalidSymbol(u32),
+    /// A symbol name is not a valid reference into the string table.
+    InvalidSymbolName(u32),
+    /// A required entry of the auxiliary vector (i.e., `AT_*`) is missing.
+    MissingAuxv(u32),
+    /// The protection of a segment could not be changed, failing with the
+    /// given error of the kernel.
+    Protection(rt11_linux::syscall::Errno),
+    /// An object has more loadable segments than supported.
+    TooManySegments,
+    /// A relocation type is not supported by the loader.
+    UnsupportedRelocation(u32),
+    /// An object type (i.e., `ET_*`) cannot be loaded.
+    UnsupportedType(u16),
+    /// A non-weak symbol could not be resolved.
+    UnresolvedSymbol(u32),
+}
+
+impl core::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AddressSpaceLimit => f.write_str("address-space limit exceeded"),
+            Self::InvalidHeader => f.write_str("invalid ELF header"),
+            Self::InvalidSymbol(v) => write!(f, "invalid symbol index {}", v),
+            Self::InvalidSymbolName(v) => write!(f, "invalid name of symbol {}", v),
+            Self::MissingAuxv(v) => write!(f, "missing auxiliary vector entry {}", v),
+            Self::Protection(v) => write!(f, "cannot change segment protection (errno {})", v),
+            Self::TooManySegments => f.write_str("too many loadable segments"),
+            Self::UnsupportedRelocation(v) => write!(f, "unsupported relocation type {}", v),
+            Self::UnsupportedType(v) => write!(f, "unsupported object type {}", v),
+            Self::UnresolvedSymbol(v) => write!(f, "unresolved symbol {}", v),
+        }
+    }
+}
+
+/// Errors of the loader implement `core::error::Error`, so tools with access
+/// to `std` can propagate them as `Box<dyn Error>`. Note that this trait is
+/// only available in `core` since Rust 1.81.
+impl core::error::Error for LoadError {}
+
+/// Loader Context
+///
+/// This type carries the context required by loader steps that interact
+/// with the kernel. It borrows the execution context of the calling task,
+/// and thus is bound to it.
+pub struct Loader<'this> {
+    this: &'this rt11_linux::this::This,
+}
+
+impl<'this> Loader<'this> {
+    /// Create Loader Context
+    ///
+    /// Create a new loader context operating on the execution context
+    /// `this`.
+    pub fn new(this: &'this rt11_linux::this::This) -> Self {
+        Self { this }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    // Reap the child `pid` via `wait4()`, verify it exited rather than being
+    // killed by a signal, and return its exit code.
+    pub(crate) fn reap_exit(sc: &rt11_linux::syscall::Syscall, pid: u32) -> u32 {
+        let mut status: i32 = 0;
+
+        assert_eq!(sc.wait4(pid as i32, &mut status, 0), Ok(pid));
+        assert!(rt11_linux::wait::wifexited(status));
+        rt11_linux::wait::wexitstatus(status) as u32
+    }
+
+    // Verify errors render as short messages, and convert into boxed
+    // standard errors.
+    #[test]
+    fn load_error_display() {
+        assert_eq!(std::format!("{}", LoadError::InvalidHeader), "invalid ELF header");
+        assert_eq!(
+            std::format!("{}", LoadError::UnsupportedRelocation(42)),
+            "unsupported relocation type 42",
+        );
+
+        let f = || -> Result<(), std::boxed::Box<dyn std::error::Error>> {
+            Err(LoadError::MissingAuxv(6))?
+        };
+        assert_eq!(
+            std::format!("{}", f().unwrap_err()),
+            "missing auxiliary vector entry 6",
+        );
+    }
+}
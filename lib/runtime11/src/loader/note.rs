@@ -0,0 +1,107 @@
+//! Notes
+//!
+//! Objects carry auxiliary information as notes (e.g., their build-ID via
+//! `NT_GNU_BUILD_ID`, or the features they were built for via
+//! `NT_GNU_PROPERTY_TYPE_0`). Tools usually locate notes via section
+//! headers, but those are not loaded and might be stripped. At runtime, the
+//! loader locates notes via the `PT_NOTE` program headers instead, which
+//! cover all allocated note sections. Linkers emit one `PT_NOTE` segment
+//! per alignment of the contained notes, so an object usually has one with
+//! 4-byte alignment, and 64-bit objects with program properties another
+//! one with 8-byte alignment.
+
+use rt11_ffi_elf::{elf, elfn, parse};
+
+/// Enumerate Notes of a Loaded Object
+///
+/// Return an iterator over the notes of all `PT_NOTE` segments of an object
+/// with the program headers `phdrs`, which was loaded with the load bias
+/// `bias` (see `unwind::eh_frame_hdr()`). Each note is yielded as tuple of
+/// its type, its name without terminating NUL, and its descriptor (see
+/// `parse::NoteIter`), in the order of the program headers.
+///
+/// Segments whose range overflows are skipped. If a segment contains
+/// malformed notes, the iteration of that segment stops at the first one,
+/// but the following segments are still iterated. Segments with an invalid
+/// alignment are parsed with the default alignment of 4 bytes.
+///
+/// Safety
+/// ------
+///
+/// All `PT_NOTE` segments of `phdrs` must be mapped readable at `bias`, and
+/// must not be modified or unmapped while the iterator or any note yielded
+/// by it is used.
+pub unsafe fn notes_from_phdrs<'a>(
+    phdrs: &'a [elfn::Phdr],
+    bias: usize,
+) -> impl Iterator<Item = (u32, &'a [u8], &'a [u8])> + 'a {
+    phdrs
+        .iter()
+        .filter(|v| v.p_type == elf::Phdr::<(), ()>::PT_NOTE)
+        .filter_map(move |v| {
+            let addr = bias.checked_add(v.p_vaddr as usize)?;
+            let len = usize::try_from(v.p_filesz).ok()?;
+            addr.checked_add(len)?;
+
+            let align = usize::try_from(v.p_align).ok().filter(|v| v.is_power_of_two());
+
+            // The caller guarantees the segment is mapped as described.
+            let data = unsafe { core::slice::from_raw_parts(addr as *const u8, len) };
+            Some(parse::NoteIter::new(data, align.unwrap_or(4)))
+        })
+        .flat_map(|v| v.map_while(Result::ok))
+        .map(|v| (v.n_type, v.name, v.desc))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Place notes in a buffer described by three `PT_NOTE` headers, one of
+    // them with 8-byte alignment and one malformed, and verify all valid
+    // notes are found.
+    #[test]
+    fn phdr_notes() {
+        let mut buf = [0u64; 16];
+        let words: [u32; 20] = [
+            // 4-byte aligned build-ID note at offset 0.
+            4, 4, elf::Nhdr::NT_GNU_BUILD_ID, u32::from_ne_bytes(*b"GNU\0"), 0x71717171,
+            // 8-byte aligned property note at offset 24.
+            4, 8, elf::Nhdr::NT_GNU_PROPERTY_TYPE_0, u32::from_ne_bytes(*b"GNU\0"), 1, 2,
+            // Malformed note at offset 64, followed by garbage.
+            0, 0xff, 7, 0, 0, 0, 0, 0, 0,
+        ];
+        unsafe {
+            let p = buf.as_mut_ptr() as *mut u32;
+            p.copy_from_nonoverlapping(words.as_ptr(), 5);
+            p.add(6).copy_from_nonoverlapping(words[5..].as_ptr(), 6);
+            p.add(16).copy_from_nonoverlapping(words[11..].as_ptr(), 9);
+        }
+
+        let note = |vaddr: usize, filesz: usize, align: usize| elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_NOTE,
+            p_vaddr: vaddr as _,
+            p_filesz: filesz as _,
+            p_align: align as _,
+            ..Default::default()
+        };
+        let phdrs = [
+            note(0, 20, 4),
+            elfn::Phdr::default(),
+            note(24, 24, 8),
+            note(64, 36, 3),
+            note(usize::MAX, 16, 4),
+        ];
+        let bias = buf.as_ptr() as usize;
+
+        let notes: std::vec::Vec<_> = unsafe { notes_from_phdrs(&phdrs, bias) }.collect();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0], (elf::Nhdr::NT_GNU_BUILD_ID, &b"GNU"[..], &[0x71u8; 4][..]));
+        assert_eq!(notes[1].0, elf::Nhdr::NT_GNU_PROPERTY_TYPE_0);
+        assert_eq!(notes[1].1, b"GNU");
+        assert_eq!(notes[1].2.len(), 8);
+        assert_eq!(notes[1].2[..4], 1u32.to_ne_bytes());
+
+        assert_eq!(unsafe { notes_from_phdrs(&[], bias) }.count(), 0);
+    }
+}
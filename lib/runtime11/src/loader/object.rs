@@ -0,0 +1,193 @@
+//! Object Validation
+//!
+//! The loader usually accesses ELF objects via raw pointers into their
+//! mappings (see `auxv`), trusting the kernel or the segment mapper. This
+//! module provides the counterpart for untrusted input: a validation entry
+//! point that operates solely on a byte slice, and bounds-checks every
+//! structure it hands out against it. It never reads outside of the slice,
+//! and never panics on malformed input, which makes it a suitable target
+//! for fuzzing.
+
+use crate::loader::layout::{self, LoaderMode};
+use crate::loader::LoadError;
+use rt11_ffi_elf::{elf, elfn};
+
+/// Validated Object
+///
+/// This is a view of an ELF object whose ELF header and program header
+/// table were validated by `parse_and_validate()`. All references borrow
+/// from the validated data.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParsedElf<'a> {
+    /// The ELF header of the object.
+    pub ehdr: &'a elfn::Ehdr,
+    /// The program header table of the object.
+    pub phdrs: &'a [elfn::Phdr],
+    /// The placement mode of the object, as dictated by its type.
+    pub mode: LoaderMode,
+}
+
+// Native ELF data encoding (i.e., `ELFDATA*`).
+#[cfg(target_endian = "little")]
+const ELFDATA_NATIVE: u8 = elf::Ident::ELFDATA2LSB;
+#[cfg(target_endian = "big")]
+const ELFDATA_NATIVE: u8 = elf::Ident::ELFDATA2MSB;
+
+// Check that `ehdr` describes an object for the native machine.
+fn check_ident(ehdr: &elfn::Ehdr) -> Result<(), LoadError> {
+    let ident = &ehdr.e_ident;
+    let magic = [
+        elf::Ident::ELFMAG0,
+        elf::Ident::ELFMAG1,
+        elf::Ident::ELFMAG2,
+        elf::Ident::ELFMAG3,
+    ];
+    let arch = rt11_ffi_linux::arch::Arch::native();
+
+    if ident.i_magic != magic
+        || ident.i_class != arch.elf_class()
+        || ident.i_data != ELFDATA_NATIVE
+        || ident.i_version != elf::Ehdr::<(), ()>::EV_CURRENT
+        || ehdr.e_version != elf::Ehdr::<(), ()>::EV_CURRENT as u32
+        || ehdr.e_machine != arch.em()
+        || (ehdr.e_ehsize as usize) < core::mem::size_of::<elfn::Ehdr>()
+    {
+        return Err(LoadError::InvalidHeader);
+    }
+
+    Ok(())
+}
+
+/// Parse and Validate Object
+///
+/// Interpret the start of `data` as ELF header of an object, validate it,
+/// and locate its program header table. The header must match the native
+/// machine (i.e., magic, class, data encoding, version, and machine), and
+/// the object must be loadable (see `layout::loader_mode()`). The program
+/// header table must use native entries (i.e., `e_phentsize`), and must lie
+/// entirely within `data`.
+///
+/// The returned view borrows the header and the table from `data`, rather
+/// than copying them. Hence, `data` must be suitably aligned for
+/// `elfn::Ehdr` (e.g., a file read into a `u64` buffer, or a mapping), and
+/// `e_phoff` must be aligned for `elfn::Phdr`. Misaligned input is rejected
+/// with `LoadError::InvalidHeader`, as is any other malformed input. Object
+/// types that cannot be loaded yield `LoadError::UnsupportedType`.
+///
+/// Only the headers are validated. The contents of the program headers
+/// (e.g., segment ranges) are untrusted, and must be checked by the steps
+/// interpreting them.
+pub fn parse_and_validate(data: &[u8]) -> Result<ParsedElf<'_>, LoadError> {
+    if data.len() < core::mem::size_of::<elfn::Ehdr>()
+        || data.as_ptr() as usize % core::mem::align_of::<elfn::Ehdr>() != 0
+    {
+        return Err(LoadError::InvalidHeader);
+    }
+
+    // The size and alignment were checked above, and any bit-pattern is a
+    // valid header.
+    let ehdr = unsafe { &*(data.as_ptr() as *const elfn::Ehdr) };
+
+    check_ident(ehdr)?;
+    let mode = layout::loader_mode(ehdr)?;
+
+    if ehdr.e_phentsize as usize != core::mem::size_of::<elfn::Phdr>() {
+        return Err(LoadError::InvalidHeader);
+    }
+
+    let (off, size) = ehdr.phdr_table_range().ok_or(LoadError::InvalidHeader)?;
+    let off = usize::try_from(off).map_err(|_| LoadError::InvalidHeader)?;
+    let table = off
+        .checked_add(size)
+        .and_then(|end| data.get(off..end))
+        .ok_or(LoadError::InvalidHeader)?;
+    if table.as_ptr() as usize % core::mem::align_of::<elfn::Phdr>() != 0 {
+        return Err(LoadError::InvalidHeader);
+    }
+
+    // The table lies within `data` and is aligned, and any bit-pattern is
+    // a valid program header.
+    let phdrs = unsafe {
+        core::slice::from_raw_parts(table.as_ptr() as *const elfn::Phdr, ehdr.e_phnum as usize)
+    };
+
+    Ok(ParsedElf { ehdr, phdrs, mode })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rt11_ffi_elf::build;
+
+    // Assemble a valid object with two program headers into an aligned
+    // buffer, and return the buffer. The header is synthesized via
+    // `EhdrBuilder`, so this also verifies that it produces headers the
+    // loader accepts.
+    fn object() -> [u64; 64] {
+        let mut buf = [0u64; 64];
+        let ehdr = build::EhdrBuilder::new(elf::Ehdr::<(), ()>::ET_DYN)
+            .phdrs(core::mem::size_of::<elfn::Ehdr>() as _, 2)
+            .build();
+        let phdr = elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_LOAD,
+            p_memsz: 0x1000,
+            ..Default::default()
+        };
+
+        unsafe {
+            let p = buf.as_mut_ptr() as *mut u8;
+            (p as *mut elfn::Ehdr).write(ehdr);
+            let p = p.add(core::mem::size_of::<elfn::Ehdr>()) as *mut elfn::Phdr;
+            p.write(phdr);
+            p.add(1).write(elfn::Phdr { p_type: elf::Phdr::<(), ()>::PT_NOTE, ..phdr });
+        }
+
+        buf
+    }
+
+    fn bytes(buf: &[u64]) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(buf.as_ptr() as *const u8, buf.len() * 8) }
+    }
+
+    fn ehdr_mut(buf: &mut [u64]) -> &mut elfn::Ehdr {
+        unsafe { &mut *(buf.as_mut_ptr() as *mut elfn::Ehdr) }
+    }
+
+    // Validate a well-formed object, and verify malformed headers,
+    // truncated input, and misaligned input are rejected.
+    #[test]
+    fn object_validation() {
+        let buf = object();
+        let v = parse_and_validate(bytes(&buf)).unwrap();
+        assert_eq!(v.mode, LoaderMode::Relocatable);
+        assert_eq!(v.phdrs.len(), 2);
+        assert_eq!(v.phdrs[0].p_type, elf::Phdr::<(), ()>::PT_LOAD);
+        assert_eq!(v.phdrs[1].p_type, elf::Phdr::<(), ()>::PT_NOTE);
+
+        let end = core::mem::size_of::<elfn::Ehdr>() + 2 * core::mem::size_of::<elfn::Phdr>();
+        assert!(parse_and_validate(&bytes(&buf)[..end]).is_ok());
+        for n in 0..end {
+            assert_eq!(parse_and_validate(&bytes(&buf)[..n]), Err(LoadError::InvalidHeader));
+        }
+        assert_eq!(parse_and_validate(&bytes(&buf)[1..]), Err(LoadError::InvalidHeader));
+
+        let check = |f: fn(&mut elfn::Ehdr)| {
+            let mut buf = object();
+            f(ehdr_mut(&mut buf));
+            parse_and_validate(bytes(&buf)).map(|_| ())
+        };
+        assert_eq!(check(|v| v.e_ident.i_magic[0] = 0), Err(LoadError::InvalidHeader));
+        assert_eq!(check(|v| v.e_ident.i_class ^= 3), Err(LoadError::InvalidHeader));
+        assert_eq!(check(|v| v.e_ident.i_data ^= 3), Err(LoadError::InvalidHeader));
+        assert_eq!(check(|v| v.e_machine = 0), Err(LoadError::InvalidHeader));
+        assert_eq!(check(|v| v.e_phentsize += 1), Err(LoadError::InvalidHeader));
+        assert_eq!(check(|v| v.e_phnum = u16::MAX), Err(LoadError::InvalidHeader));
+        assert_eq!(check(|v| v.e_phoff = !0), Err(LoadError::InvalidHeader));
+        assert_eq!(check(|v| v.e_phoff += 1), Err(LoadError::InvalidHeader));
+        assert_eq!(
+            check(|v| v.e_type = elf::Ehdr::<(), ()>::ET_REL),
+            Err(LoadError::UnsupportedType(elf::Ehdr::<(), ()>::ET_REL)),
+        );
+        assert_eq!(check(|v| v.e_phnum = 0), Ok(()));
+    }
+}
@@ -0,0 +1,520 @@
+//! Relocation Processing
+//!
+//! Relocations patch an ELF object after it was mapped into memory. Relative
+//! relocations only depend on the load address of the object itself, but
+//! symbol relocations (e.g., `R_*_GLOB_DAT` and `R_*_JUMP_SLOT`) require the
+//! symbols to be looked up in the dependency graph of the object. This module
+//! implements the latter.
+//!
+//! The policy of how symbols are looked up (i.e., the search order across
+//! objects, symbol versioning, and interposition rules) is not part of this
+//! module. Instead, the caller provides a `Resolver`, which binds symbols by
+//! name. This keeps the relocation core independent of how the dependency
+//! graph of an object is managed.
+
+use crate::loader::LoadError;
+use rt11_ffi_elf::{elf, elfn, parse};
+
+/// Symbol Lookup Scope
+///
+/// This describes which objects shall be searched when a symbol is bound.
+/// It is up to the `Resolver` to map this to the actual set of objects.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Scope {
+    /// Search all objects of the lookup scope, including the object that is
+    /// being relocated.
+    Global,
+    /// Search all objects of the lookup scope, but skip the object that is
+    /// being relocated. This is used for copy relocations, which copy the
+    /// initial value of a symbol from its definition into the object that
+    /// carries the relocation (usually the executable).
+    Foreign,
+}
+
+/// Symbol Resolver
+///
+/// A resolver binds symbols referenced by relocations to their absolute
+/// addresses. It is implemented for all closures with a matching signature,
+/// so the caller rarely has to implement it manually.
+pub trait Resolver {
+    /// Bind Symbol
+    ///
+    /// Look up the symbol with the name `name` in the given scope and return
+    /// its absolute address. If the relocated object uses symbol versioning,
+    /// `versym` carries the entry of its version-symbol table (`DT_VERSYM`)
+    /// for this symbol, otherwise it is `None`.
+    ///
+    /// If the symbol cannot be found, `None` must be returned. It is up to the
+    /// caller to decide whether this is fatal (it is not for weak symbols).
+    fn bind_symbol(
+        &mut self,
+        name: &[u8],
+        versym: Option<u16>,
+        scope: Scope,
+    ) -> Option<usize>;
+}
+
+impl<F> Resolver for F
+where
+    F: FnMut(&[u8], Option<u16>, Scope) -> Option<usize>,
+{
+    fn bind_symbol(
+        &mut self,
+        name: &[u8],
+        versym: Option<u16>,
+        scope: Scope,
+    ) -> Option<usize> {
+        self(name, versym, scope)
+    }
+}
+
+// Relocation Classes
+//
+// The relocation types are architecture dependent, but the loader only
+// distinguishes a small set of classes. Each architecture maps its types onto
+// these classes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Kind {
+    // No-op relocation.
+    None,
+    // Relative relocation, not handled by symbol relocation.
+    Relative,
+    // Store `S`.
+    Symbol,
+    // Store `S + A`.
+    SymbolAddend,
+    // Copy `st_size` bytes from `S`.
+    Copy,
+    // Anything not supported by the loader.
+    Unsupported,
+}
+
+#[cfg(target_arch = "aarch64")]
+fn kind(typ: u32) -> Kind {
+    match typ {
+        elfn::Rela::R_AARCH64_NONE => Kind::None,
+        elfn::Rela::R_AARCH64_RELATIVE => Kind::Relative,
+        elfn::Rela::R_AARCH64_IRELATIVE => Kind::Relative,
+        elfn::Rela::R_AARCH64_ABS64 => Kind::SymbolAddend,
+        elfn::Rela::R_AARCH64_GLOB_DAT => Kind::SymbolAddend,
+        elfn::Rela::R_AARCH64_JUMP_SLOT => Kind::SymbolAddend,
+        elfn::Rela::R_AARCH64_COPY => Kind::Copy,
+        _ => Kind::Unsupported,
+    }
+}
+
+#[cfg(target_arch = "arm")]
+fn kind(typ: u32) -> Kind {
+    match typ {
+        elfn::Rel::R_ARM_NONE => Kind::None,
+        elfn::Rel::R_ARM_RELATIVE => Kind::Relative,
+        elfn::Rel::R_ARM_IRELATIVE => Kind::Relative,
+        elfn::Rel::R_ARM_ABS32 => Kind::SymbolAddend,
+        elfn::Rel::R_ARM_GLOB_DAT => Kind::Symbol,
+        elfn::Rel::R_ARM_JUMP_SLOT => Kind::Symbol,
+        elfn::Rel::R_ARM_COPY => Kind::Copy,
+        _ => Kind::Unsupported,
+    }
+}
+
+#[cfg(target_arch = "riscv64")]
+fn kind(typ: u32) -> Kind {
+    match typ {
+        elfn::Rela::R_RISCV_NONE => Kind::None,
+        elfn::Rela::R_RISCV_RELATIVE => Kind::Relative,
+        elfn::Rela::R_RISCV_IRELATIVE => Kind::Relative,
+        elfn::Rela::R_RISCV_64 => Kind::SymbolAddend,
+        elfn::Rela::R_RISCV_JUMP_SLOT => Kind::Symbol,
+        elfn::Rela::R_RISCV_COPY => Kind::Copy,
+        _ => Kind::Unsupported,
+    }
+}
+
+#[cfg(target_arch = "x86")]
+fn kind(typ: u32) -> Kind {
+    match typ {
+        elfn::Rel::R_386_NONE => Kind::None,
+        elfn::Rel::R_386_RELATIVE => Kind::Relative,
+        elfn::Rel::R_386_IRELATIVE => Kind::Relative,
+        elfn::Rel::R_386_32 => Kind::SymbolAddend,
+        elfn::Rel::R_386_GLOB_DAT => Kind::Symbol,
+        elfn::Rel::R_386_JMP_SLOT => Kind::Symbol,
+        elfn::Rel::R_386_COPY => Kind::Copy,
+        _ => Kind::Unsupported,
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn kind(typ: u32) -> Kind {
+    match typ {
+        elfn::Rela::R_X86_64_NONE => Kind::None,
+        elfn::Rela::R_X86_64_RELATIVE => Kind::Relative,
+        elfn::Rela::R_X86_64_IRELATIVE => Kind::Relative,
+        elfn::Rela::R_X86_64_64 => Kind::SymbolAddend,
+        elfn::Rela::R_X86_64_GLOB_DAT => Kind::Symbol,
+        elfn::Rela::R_X86_64_JUMP_SLOT => Kind::Symbol,
+        elfn::Rela::R_X86_64_COPY => Kind::Copy,
+        _ => Kind::Unsupported,
+    }
+}
+
+/// Extract Symbol Index from Relocation Info
+///
+/// Return the symbol index encoded in the `r_info` field of a native
+/// relocation entry.
+#[cfg(target_pointer_width = "32")]
+pub fn r_sym(info: elfn::Size) -> u32 {
+    info >> 8
+}
+
+/// Extract Symbol Index from Relocation Info
+///
+/// Return the symbol index encoded in the `r_info` field of a native
+/// relocation entry.
+#[cfg(target_pointer_width = "64")]
+pub fn r_sym(info: elfn::Size) -> u32 {
+    (info >> 32) as u32
+}
+
+/// Extract Relocation Type from Relocation Info
+///
+/// Return the relocation type encoded in the `r_info` field of a native
+/// relocation entry.
+#[cfg(target_pointer_width = "32")]
+pub fn r_type(info: elfn::Size) -> u32 {
+    info & 0xff
+}
+
+/// Extract Relocation Type from Relocation Info
+///
+/// Return the relocation type encoded in the `r_info` field of a native
+/// relocation entry.
+#[cfg(target_pointer_width = "64")]
+pub fn r_type(info: elfn::Size) -> u32 {
+    info as u32
+}
+
+// Bind the symbol with index `idx` and return its address and size.
+fn bind<R: Resolver + ?Sized>(
+    base: usize,
+    idx: u32,
+    symtab: &[elfn::Sym],
+    strtab: &[u8],
+    versym: Option<&[u16]>,
+    scope: Scope,
+    resolver: &mut R,
+) -> Result<(usize, usize), LoadError> {
+    if idx == elf::Sym::<(), ()>::STN_UNDEF {
+        return Ok((0, 0));
+    }
+
+    let sym = symtab.get(idx as usize).ok_or(LoadError::InvalidSymbol(idx))?;
+    let binding = sym.st_info >> 4;
+
+    // Local symbols are never interposed, so bind them directly to the
+    // definition in the relocated object.
+    if binding == elf::Sym::<(), ()>::STB_LOCAL
+        && sym.st_shndx != elfn::Shdr::SHN_UNDEF
+    {
+        return Ok((base.wrapping_add(sym.st_value as usize), sym.st_size as usize));
+    }
+
+    let name = parse::strtab_get(strtab, sym.st_name as usize)
+        .ok_or(LoadError::InvalidSymbolName(idx))?;
+    let vs = versym.and_then(|v| v.get(idx as usize).copied());
+
+    match resolver.bind_symbol(name, vs, scope) {
+        Some(v) => Ok((v, sym.st_size as usize)),
+        None if binding == elf::Sym::<(), ()>::STB_WEAK => Ok((0, 0)),
+        None => Err(LoadError::UnresolvedSymbol(idx)),
+    }
+}
+
+/// Apply Symbol Relocations
+///
+/// Apply all symbol relocations of `relocs` to the object loaded at `base`
+/// (i.e., the load bias, which is added to all virtual addresses of the
+/// object). The relocations can be given as `Rel` or `Rela` entries (see
+/// `parse::RelocView`). For entries without explicit addend, the addend is
+/// read from the relocation target. For each relocation, the referenced
+/// symbol is looked up in `symtab` and its name in `strtab`. If `versym` is
+/// given, it is the version-symbol table of the object, indexed in parallel
+/// to `symtab`.
+///
+/// The symbols are bound via `resolver`, except for local symbols which are
+/// bound directly to their definition in the relocated object. Unresolved
+/// weak symbols are bound to 0, any other unresolved symbol fails the
+/// operation. Copy relocations are resolved with `Scope::Foreign` and copy
+/// `st_size` bytes from the definition. Copy relocations of unresolved weak
+/// symbols leave their target untouched.
+///
+/// Relative relocations (including `R_*_IRELATIVE`) are skipped, since they
+/// do not reference symbols. They must be applied separately.
+///
+/// If any relocation fails, an error is returned and the remaining
+/// relocations are not applied. Relocations processed before the failure
+/// remain applied.
+///
+/// Safety
+/// ------
+///
+/// The caller must guarantee that all relocation targets of `relocs`
+/// relative to `base` are mapped readable and writable, and that all copy
+/// relocations reference readable memory.
+pub unsafe fn relocate_symbols<T, R>(
+    base: usize,
+    relocs: &[T],
+    symtab: &[elfn::Sym],
+    strtab: &[u8],
+    versym: Option<&[u16]>,
+    resolver: &mut R,
+) -> Result<(), LoadError>
+where
+    T: Copy + Into<parse::RelocView<elfn::Size, elfn::Addend>>,
+    R: Resolver + ?Sized,
+{
+    for r in relocs {
+        let r: parse::RelocView<elfn::Size, elfn::Addend> = (*r).into();
+        let typ = r.typ;
+        let idx = r.sym;
+        let target = base.wrapping_add(r.offset as usize);
+
+        let scope = match kind(typ) {
+            Kind::None | Kind::Relative => continue,
+            Kind::Unsupported => return Err(LoadError::UnsupportedRelocation(typ)),
+            Kind::Copy => Scope::Foreign,
+            Kind::Symbol | Kind::SymbolAddend => Scope::Global,
+        };
+
+        let (value, size) = bind(base, idx, symtab, strtab, versym, scope, resolver)?;
+
+        match kind(typ) {
+            Kind::Symbol => unsafe {
+                core::ptr::write_unaligned(target as *mut usize, value);
+            },
+            Kind::SymbolAddend => unsafe {
+                let addend = match r.addend {
+                    Some(v) => v as usize,
+                    None => core::ptr::read_unaligned(target as *const usize),
+                };
+                core::ptr::write_unaligned(
+                    target as *mut usize,
+                    value.wrapping_add(addend),
+                );
+            },
+            // Unresolved weak symbols (and `STN_UNDEF`) are bound to 0, and
+            // have no definition to copy from. Leave the target untouched,
+            // like the static linker leaves it zero-initialized.
+            Kind::Copy if value == 0 => {},
+            Kind::Copy => unsafe {
+                core::ptr::copy_nonoverlapping(
+                    value as *const u8,
+                    target as *mut u8,
+                    size,
+                );
+            },
+            Kind::None | Kind::Relative | Kind::Unsupported => {},
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "aarch64")]
+    const R_ABS: u32 = elfn::Rela::R_AARCH64_ABS64;
+    #[cfg(target_arch = "aarch64")]
+    const R_COPY: u32 = elfn::Rela::R_AARCH64_COPY;
+    #[cfg(target_arch = "aarch64")]
+    const R_SLOT: u32 = elfn::Rela::R_AARCH64_JUMP_SLOT;
+    #[cfg(target_arch = "arm")]
+    const R_ABS: u32 = elfn::Rel::R_ARM_ABS32;
+    #[cfg(target_arch = "arm")]
+    const R_COPY: u32 = elfn::Rel::R_ARM_COPY;
+    #[cfg(target_arch = "arm")]
+    const R_SLOT: u32 = elfn::Rel::R_ARM_JUMP_SLOT;
+    #[cfg(target_arch = "riscv64")]
+    const R_ABS: u32 = elfn::Rela::R_RISCV_64;
+    #[cfg(target_arch = "riscv64")]
+    const R_COPY: u32 = elfn::Rela::R_RISCV_COPY;
+    #[cfg(target_arch = "riscv64")]
+    const R_SLOT: u32 = elfn::Rela::R_RISCV_JUMP_SLOT;
+    #[cfg(target_arch = "x86")]
+    const R_ABS: u32 = elfn::Rel::R_386_32;
+    #[cfg(target_arch = "x86")]
+    const R_COPY: u32 = elfn::Rel::R_386_COPY;
+    #[cfg(target_arch = "x86")]
+    const R_SLOT: u32 = elfn::Rel::R_386_JMP_SLOT;
+    #[cfg(target_arch = "x86_64")]
+    const R_ABS: u32 = elfn::Rela::R_X86_64_64;
+    #[cfg(target_arch = "x86_64")]
+    const R_COPY: u32 = elfn::Rela::R_X86_64_COPY;
+    #[cfg(target_arch = "x86_64")]
+    const R_SLOT: u32 = elfn::Rela::R_X86_64_JUMP_SLOT;
+
+    #[cfg(target_pointer_width = "32")]
+    fn r_info(sym: u32, typ: u32) -> elfn::Size {
+        (sym << 8) | typ
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    fn r_info(sym: u32, typ: u32) -> elfn::Size {
+        ((sym as u64) << 32) | typ as u64
+    }
+
+    fn rela(off: usize, sym: u32, typ: u32, addend: elfn::Addend) -> elfn::Rela {
+        elfn::Rela {
+            r_offset: (off * core::mem::size_of::<usize>()) as elfn::Size,
+            r_info: r_info(sym, typ),
+            r_addend: addend,
+            ..Default::default()
+        }
+    }
+
+    fn rel(off: usize, sym: u32, typ: u32) -> elfn::Rel {
+        elfn::Rel {
+            r_offset: (off * core::mem::size_of::<usize>()) as elfn::Size,
+            r_info: r_info(sym, typ),
+            ..Default::default()
+        }
+    }
+
+    fn sym(name: u32, info: u8, shndx: u16, value: usize) -> elfn::Sym {
+        elfn::Sym {
+            st_name: name,
+            st_info: info,
+            st_shndx: shndx,
+            st_value: value as elfn::Size,
+            ..Default::default()
+        }
+    }
+
+    // Verify symbol relocations are resolved via the resolver, local symbols
+    // are bound directly, and addends are applied.
+    #[test]
+    fn relocate_basic() {
+        let strtab = b"\0foo\0bar\0weak\0";
+        let symtab = [
+            elfn::Sym::default(),
+            sym(1, 0x10, 0, 0),
+            sym(5, 0x00, 1, 0x40),
+            sym(9, 0x20, 0, 0),
+        ];
+        let relocs = [
+            rela(0, 1, R_SLOT, 0),
+            rela(1, 1, R_ABS, 8),
+            rela(2, 2, R_SLOT, 0),
+            rela(3, 3, R_SLOT, 0),
+        ];
+        let mut image: [usize; 4] = [0xff; 4];
+        let base = image.as_mut_ptr() as usize;
+
+        let mut resolver = |name: &[u8], versym: Option<u16>, scope: Scope| {
+            assert_eq!(versym, None);
+            assert_eq!(scope, Scope::Global);
+            match name {
+                b"foo" => Some(0x1000),
+                _ => None,
+            }
+        };
+
+        unsafe {
+            relocate_symbols(base, &relocs, &symtab, strtab, None, &mut resolver)
+                .unwrap();
+        }
+
+        assert_eq!(image[0], 0x1000);
+        assert_eq!(image[1], 0x1008);
+        assert_eq!(image[2], base + 0x40);
+        assert_eq!(image[3], 0);
+    }
+
+    // Verify that relocations with implicit addend read the addend from
+    // the relocation target, while others overwrite it.
+    #[test]
+    fn relocate_implicit() {
+        let strtab = b"\0foo\0";
+        let symtab = [elfn::Sym::default(), sym(1, 0x10, 0, 0)];
+        let relocs = [rel(0, 1, R_SLOT), rel(1, 1, R_ABS)];
+        let mut image: [usize; 2] = [0x20, 0x10];
+        let base = image.as_mut_ptr() as usize;
+        let mut resolver = |_: &[u8], _: Option<u16>, _: Scope| Some(0x1000);
+
+        unsafe {
+            relocate_symbols(base, &relocs, &symtab, strtab, None, &mut resolver)
+                .unwrap();
+        }
+
+        assert_eq!(image[0], 0x1000);
+        assert_eq!(image[1], 0x1010);
+    }
+
+    // Verify that unresolved, non-weak symbols and invalid symbol indices
+    // are reported.
+    #[test]
+    fn relocate_errors() {
+        let strtab = b"\0foo\0";
+        let symtab = [elfn::Sym::default(), sym(1, 0x10, 0, 0)];
+        let mut image: [usize; 1] = [0; 1];
+        let base = image.as_mut_ptr() as usize;
+        let mut resolver = |_: &[u8], _: Option<u16>, _: Scope| None;
+
+        let r = unsafe {
+            relocate_symbols(
+                base, &[rela(0, 1, R_SLOT, 0)], &symtab, strtab, None, &mut resolver,
+            )
+        };
+        assert_eq!(r, Err(LoadError::UnresolvedSymbol(1)));
+
+        let r = unsafe {
+            relocate_symbols(
+                base, &[rela(0, 7, R_SLOT, 0)], &symtab, strtab, None, &mut resolver,
+            )
+        };
+        assert_eq!(r, Err(LoadError::InvalidSymbol(7)));
+    }
+
+    // Verify copy relocations copy `st_size` bytes from the definition in
+    // foreign scope, and that unresolved weak symbols leave the target
+    // untouched while unresolved strong symbols fail.
+    #[test]
+    fn relocate_copy() {
+        let strtab = b"\0foo\0weak\0";
+        let mut symtab = [
+            elfn::Sym::default(),
+            sym(1, 0x10, 0, 0),
+            sym(5, 0x20, 0, 0),
+        ];
+        symtab[1].st_size = 16;
+        symtab[2].st_size = 16;
+        let src: [u8; 16] = *b"0123456789abcdef";
+        let mut image = [0xffu8; 48];
+        let base = image.as_mut_ptr() as usize;
+        let relocs = [rela(0, 1, R_COPY, 0), rela(4, 2, R_COPY, 0)];
+
+        let mut resolver = |name: &[u8], _: Option<u16>, scope: Scope| {
+            assert_eq!(scope, Scope::Foreign);
+            match name {
+                b"foo" => Some(src.as_ptr() as usize),
+                _ => None,
+            }
+        };
+
+        unsafe {
+            relocate_symbols(base, &relocs, &symtab, strtab, None, &mut resolver)
+                .unwrap();
+        }
+
+        assert_eq!(image[..16], src);
+        assert!(image[16..].iter().all(|v| *v == 0xff));
+
+        symtab[2].st_info = 0x10;
+        let r = unsafe {
+            relocate_symbols(base, &relocs, &symtab, strtab, None, &mut resolver)
+        };
+        assert_eq!(r, Err(LoadError::UnresolvedSymbol(2)));
+    }
+}
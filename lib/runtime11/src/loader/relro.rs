@@ -0,0 +1,119 @@
+//! Relocation Read-only Protection
+//!
+//! Data that is written only by relocations (e.g., the GOT, or `.data.rel.ro`
+//! of position-independent code) is grouped by the linker and described by a
+//! `PT_GNU_RELRO` program header. Once all relocations of an object were
+//! applied, the loader makes this range read-only, so the relocated pointers
+//! cannot be overwritten at runtime.
+
+use crate::loader::Loader;
+use rt11_ffi_elf::{elf, elfn};
+use rt11_ffi_linux::native::mman;
+use rt11_linux::syscall::Errno;
+use rt11_linux::util;
+
+/// Compute RELRO Range
+///
+/// Return the page-aligned address range covered by the `PT_GNU_RELRO`
+/// program header `phdr` of an object loaded with the load bias `bias`. The
+/// range is returned as start address and length. If the range does not
+/// cover a full page, `None` is returned.
+///
+/// This follows the logic of glibc: The start is rounded down, since the
+/// linker places the RELRO range at the start of a writable `PT_LOAD`
+/// segment, and the bytes preceding it on the same page belong to that
+/// segment, but are not used by it. The end is rounded down as well,
+/// since the remainder of the last page is shared with writable data
+/// following the RELRO range. That remainder thus stays writable. Linkers
+/// usually pad the RELRO range to a page boundary to avoid this.
+pub fn relro_range(phdr: &elfn::Phdr, bias: usize, pagesz: usize) -> Option<(usize, usize)> {
+    let start = bias.checked_add(phdr.p_vaddr as usize)?;
+    let end = start.checked_add(phdr.p_memsz as usize)?;
+    let start = util::align_down(start, pagesz);
+    let end = util::align_down(end, pagesz);
+
+    if end > start {
+        Some((start, end - start))
+    } else {
+        None
+    }
+}
+
+impl<'this> Loader<'this> {
+    /// Apply RELRO Protection
+    ///
+    /// Make the ranges described by all `PT_GNU_RELRO` program headers of
+    /// `phdrs` read-only. `bias` is the load bias of the object (i.e., the
+    /// difference between its runtime addresses and the virtual addresses
+    /// of its program headers), and `pagesz` is the page size of the system
+    /// (i.e., `AT_PAGESZ`). See `relro_range()` for the handling of partial
+    /// pages.
+    ///
+    /// Objects without `PT_GNU_RELRO` are left untouched.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// This must be called only after all relocations of the object were
+    /// applied, including the relocations of indirect functions (i.e.,
+    /// `R_*_IRELATIVE`), and lazy binding must not be used for the object.
+    /// Any later write to the range faults. Furthermore, `phdrs` and `bias`
+    /// must describe an object that is mapped as described.
+    pub unsafe fn apply_relro(
+        &self,
+        phdrs: &[elfn::Phdr],
+        bias: usize,
+        pagesz: usize,
+    ) -> Result<(), Errno> {
+        for phdr in phdrs {
+            if phdr.p_type != elf::Phdr::<(), ()>::PT_GNU_RELRO {
+                continue;
+            }
+
+            if let Some((start, len)) = relro_range(phdr, bias, pagesz) {
+                unsafe {
+                    self.this.syscall.mprotect(start, len, mman::PROT_READ)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the page rounding of the RELRO range, and that objects without
+    // a RELRO range of at least one page are left untouched.
+    #[test]
+    fn relro_rounding() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let mut phdr = elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_GNU_RELRO,
+            p_vaddr: 0x1e10,
+            p_memsz: 0x21f0,
+            ..Default::default()
+        };
+
+        assert_eq!(relro_range(&phdr, 0x10000, 0x1000), Some((0x11000, 0x3000)));
+
+        phdr.p_memsz = 0x100;
+        assert_eq!(relro_range(&phdr, 0x10000, 0x1000), None);
+
+        phdr.p_vaddr = usize::MAX as _;
+        phdr.p_memsz = 0x2000;
+        assert_eq!(relro_range(&phdr, 0x10000, 0x1000), None);
+
+        phdr.p_vaddr = 0x1e10;
+        phdr.p_memsz = 0x100;
+        let load = elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_LOAD,
+            p_memsz: 0x10000,
+            ..Default::default()
+        };
+        assert_eq!(unsafe { loader.apply_relro(&[load, phdr], 0x10000, 0x1000) }, Ok(()));
+    }
+}
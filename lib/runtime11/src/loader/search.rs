@@ -0,0 +1,159 @@
+//! Library Search
+//!
+//! Dependencies of an object are usually named by their file name only
+//! (i.e., `DT_NEEDED`), and must be looked up in a list of search paths
+//! (e.g., `DT_RUNPATH`, `LD_LIBRARY_PATH`, and the system default paths).
+//! This module implements the lookup in the file system. Assembling the
+//! list of search paths is up to the caller.
+
+use crate::loader::Loader;
+use rt11_ffi_linux::native::{errno, fcntl};
+use rt11_linux::syscall::Errno;
+
+/// Maximum Path Length
+///
+/// This is the maximum length of a path passed to the kernel, including the
+/// terminating NUL (i.e., `PATH_MAX`).
+pub const PATH_MAX: usize = 4096;
+
+// Join `dir` and `name` into `buf`, separated by a slash and terminated by
+// NUL, and return the used part of `buf` (including the NUL). An empty
+// `dir` refers to the current working directory. If the result does not
+// fit, or either part contains NUL, `None` is returned.
+fn join<'a>(buf: &'a mut [u8], dir: &[u8], name: &[u8]) -> Option<&'a [u8]> {
+    if dir.contains(&0) || name.contains(&0) {
+        return None;
+    }
+
+    let sep: &[u8] = if dir.is_empty() || dir.ends_with(b"/") { b"" } else { b"/" };
+    let len = dir.len() + sep.len() + name.len() + 1;
+    let path = buf.get_mut(..len)?;
+
+    let (d, rest) = path.split_at_mut(dir.len());
+    let (s, rest) = rest.split_at_mut(sep.len());
+    let (n, z) = rest.split_at_mut(name.len());
+    d.copy_from_slice(dir);
+    s.copy_from_slice(sep);
+    n.copy_from_slice(name);
+    z[0] = 0;
+
+    Some(path)
+}
+
+impl<'this> Loader<'this> {
+    // Probe the NUL-terminated `path` for execute access and open it.
+    fn probe(&self, path: &[u8]) -> Result<u32, Errno> {
+        debug_assert_eq!(path.last(), Some(&0));
+
+        unsafe {
+            self.this.syscall.faccessat(fcntl::AT_FDCWD, path.as_ptr(), fcntl::X_OK)?;
+            self.this.openat(
+                fcntl::AT_FDCWD,
+                path.as_ptr(),
+                fcntl::O_RDONLY | fcntl::O_CLOEXEC,
+                0,
+            )
+        }
+    }
+
+    /// Find Library
+    ///
+    /// Look up the library `name` in the directories `search_paths`, in
+    /// order, and return a file-descriptor (with `O_CLOEXEC`) of the first
+    /// match. An empty directory refers to the current working directory.
+    ///
+    /// If `name` contains a slash, it is used as path directly (relative to
+    /// the current working directory, unless it is absolute), and the search
+    /// paths are ignored. This matches the behavior of other dynamic
+    /// loaders.
+    ///
+    /// Each candidate is probed for execute access before it is opened,
+    /// since its segments are mapped executable. Note that the probe uses
+    /// the real rather than the effective user and group IDs.
+    /// Candidates that do not exist, are not accessible, or whose path is
+    /// too long (i.e., `ENOENT`, `ENOTDIR`, `EACCES`, `ENAMETOOLONG`) are
+    /// skipped. Symbolic links are followed, and candidates that cannot be
+    /// resolved due to too many levels of symbolic links (i.e., `ELOOP`)
+    /// are skipped as well. Any other error is returned right away. If no
+    /// candidate matches, `ENOENT` is returned.
+    ///
+    /// Note that a candidate is only checked for accessibility, not for
+    /// its type or content. A directory of the same name is opened just
+    /// fine, and must be rejected by the caller when reading its header.
+    pub fn find_library(&self, name: &[u8], search_paths: &[&[u8]]) -> Result<u32, Errno> {
+        let mut buf = [0u8; PATH_MAX];
+
+        if name.is_empty() || name.contains(&0) {
+            return Err(errno::EINVAL);
+        }
+
+        if name.contains(&b'/') {
+            let path = join(&mut buf, b"", name).ok_or(errno::ENAMETOOLONG)?;
+            return self.probe(path);
+        }
+
+        for dir in search_paths {
+            let Some(path) = join(&mut buf, dir, name) else {
+                continue;
+            };
+
+            match self.probe(path) {
+                Ok(fd) => return Ok(fd),
+                Err(
+                    errno::ENOENT
+                    | errno::ENOTDIR
+                    | errno::EACCES
+                    | errno::ENAMETOOLONG
+                    | errno::ELOOP
+                ) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(errno::ENOENT)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify path joining, including the handling of empty directories,
+    // trailing slashes, and paths that do not fit.
+    #[test]
+    fn path_join() {
+        let mut buf = [0u8; 16];
+
+        assert_eq!(join(&mut buf, b"/lib", b"libc.so"), Some(&b"/lib/libc.so\0"[..]));
+        assert_eq!(join(&mut buf, b"/lib/", b"libc.so"), Some(&b"/lib/libc.so\0"[..]));
+        assert_eq!(join(&mut buf, b"", b"libc.so"), Some(&b"libc.so\0"[..]));
+        assert_eq!(join(&mut buf, b"/usr/lib", b"libc.so"), None);
+        assert_eq!(join(&mut buf, b"/li\0b", b"libc.so"), None);
+    }
+
+    // Look up a file in a list of search paths that contains non-existent,
+    // overlong, and non-directory entries, and verify paths bypass the
+    // search.
+    #[test]
+    fn library_search() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let long = [b'x'; PATH_MAX];
+
+        let fd = loader
+            .find_library(b"exe", &[b"/rt11-nonexistent", &long, b"/proc/self/exe", b"/proc/self"])
+            .unwrap();
+        unsafe { this.close(fd).unwrap() };
+
+        let fd = loader.find_library(b"/proc/self/exe", &[]).unwrap();
+        unsafe { this.close(fd).unwrap() };
+
+        assert_eq!(loader.find_library(b"exe", &[]), Err(errno::ENOENT));
+        assert_eq!(loader.find_library(b"exe", &[b"/rt11-nonexistent"]), Err(errno::ENOENT));
+        assert_eq!(
+            loader.find_library(b"/proc/self/rt11-nonexistent", &[b"/proc/self"]),
+            Err(errno::ENOENT),
+        );
+        assert_eq!(loader.find_library(b"", &[b"/proc/self"]), Err(errno::EINVAL));
+    }
+}
@@ -0,0 +1,113 @@
+//! Strict Secure Computing
+//!
+//! The kernel can restrict the system calls available to a task via
+//! seccomp. Its strict mode is the most rigid form: only `read(2)`,
+//! `write(2)`, `exit(2)`, and `rt_sigreturn(2)` remain available, and any
+//! other system call kills the task with `SIGKILL`. This suits workloads
+//! that only compute on file-descriptors set up by the loader (e.g., pipes
+//! to their supervisor), and is meant as the very last setup step before
+//! handing off to such a workload.
+//!
+//! Note that `exit_group(2)` is not part of the allowed set, so a strict
+//! workload must terminate via `exit(2)` (e.g., `Syscall::exit()`). Any
+//! implicit system call (e.g., `mmap(2)` of an allocator, or
+//! `exit_group(2)` of a panic handler) is fatal, too. Calls served by the
+//! vDSO (e.g., `clock_gettime()`) usually do not enter the kernel, and thus
+//! may keep working, but the vDSO can fall back to a real system call at
+//! any time (e.g., for clocks it cannot read directly).
+
+use crate::loader::Loader;
+use rt11_ffi_linux::native::prctl;
+use rt11_linux::syscall::Errno;
+
+impl<'this> Loader<'this> {
+    /// Enter Strict Seccomp Mode
+    ///
+    /// Restrict the calling thread to `read()`, `write()`, `exit()`, and
+    /// `rt_sigreturn()` via `prctl(PR_SET_SECCOMP, SECCOMP_MODE_STRICT)`.
+    /// After this returns successfully, any other system call kills the
+    /// thread with `SIGKILL`, and the mode can never be left again. See the
+    /// module documentation for the implications.
+    ///
+    /// The mode is a property of the calling thread, and is inherited by
+    /// threads and processes it creates afterwards (which it cannot do
+    /// anymore, though). Other threads of the process are not affected, so
+    /// this must be called on a single-threaded process to confine it
+    /// entirely.
+    ///
+    /// Unlike the filter mode, the strict mode does not require
+    /// `PR_SET_NO_NEW_PRIVS` or `CAP_SYS_ADMIN`, since it can only reduce
+    /// the privileges of the task. Kernels without seccomp support return
+    /// `EINVAL`.
+    pub fn enter_seccomp_strict(&self) -> Result<(), Errno> {
+        // Entering strict mode has no memory-safety implications for the
+        // caller. It merely restricts the system calls it can use.
+        unsafe {
+            self.this.syscall.prctl(
+                prctl::PR_SET_SECCOMP,
+                prctl::SECCOMP_MODE_STRICT as usize,
+                0,
+                0,
+                0,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rt11_ffi_linux::native::{fcntl, signal};
+
+    // Confine forked children, and verify they can still write to a pipe
+    // and exit, but are killed by any other system call.
+    #[test]
+    fn seccomp_strict() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+
+        for allowed in [true, false] {
+            let mut buf = [0u8; 8];
+
+            let p = this.syscall.pipe2(fcntl::O_CLOEXEC).unwrap();
+
+            match unsafe { this.syscall.fork() }.unwrap() {
+                rt11_linux::syscall::ForkResult::Child => {
+                    if loader.enter_seccomp_strict().is_err() {
+                        this.exit_group(1);
+                    }
+                    if this.syscall.write_all(p[1], b"ok").is_err() {
+                        this.syscall.exit(2);
+                    }
+                    if !allowed {
+                        this.syscall.getpid();
+                    }
+                    this.syscall.exit(0);
+                },
+                rt11_linux::syscall::ForkResult::Parent(pid) => {
+                    let pidfd = this.syscall.pidfd_open(pid as i32, 0).unwrap();
+                    unsafe { this.close(p[1]).unwrap() };
+
+                    this.read_exact(p[0], &mut buf[..2]).unwrap();
+                    assert_eq!(&buf[..2], b"ok");
+
+                    let status = loader.pidfd_wait(pidfd).unwrap();
+                    if allowed {
+                        assert!(rt11_linux::wait::wifexited(status));
+                        assert_eq!(rt11_linux::wait::wexitstatus(status), 0);
+                    } else {
+                        assert!(rt11_linux::wait::wifsignaled(status));
+                        assert_eq!(rt11_linux::wait::wtermsig(status) as u32, signal::SIGKILL);
+                    }
+
+                    unsafe {
+                        this.close(pidfd).unwrap();
+                        this.close(p[0]).unwrap();
+                    }
+                },
+            }
+        }
+    }
+}
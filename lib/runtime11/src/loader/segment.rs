@@ -0,0 +1,575 @@
+//! Mapped Segments
+//!
+//! This module provides a typed view of the `PT_LOAD` segments of an object
+//! after they were mapped. Later loader steps (and diagnostics) operate on
+//! this view rather than re-deriving addresses and protections from the
+//! program headers. It also implements the mapping of the segments from the
+//! file of the object.
+
+use crate::loader::{LoadError, Loader};
+use rt11_ffi_elf::{elf, elfn};
+use rt11_ffi_linux::native::{errno, mman};
+use rt11_linux::syscall::Errno;
+use rt11_linux::util;
+
+/// Maximum Number of Segments
+///
+/// This is the maximum number of `PT_LOAD` program headers supported per
+/// object. Common linkers emit at most 4 (i.e., one per combination of
+/// protections, plus a separate read-only segment preceding the code), so
+/// this leaves ample room while keeping `Segments` small enough for the
+/// stack of the loader. Objects exceeding it are rejected.
+///
+/// The fixed capacity is a deliberate trade-off of `no_std`: the loader has
+/// no allocator while mapping objects, so the list must live on the stack,
+/// and its size must be known at compile-time. `count_loads()` allows
+/// rejecting objects up front, before any segment is mapped.
+pub const MAX_SEGMENTS: usize = 16;
+
+/// Mapped Segment
+///
+/// This describes a single `PT_LOAD` segment after it was mapped. The range
+/// is page-aligned and given as absolute runtime address and length.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Segment {
+    /// Page-aligned start address of the mapping.
+    pub base: usize,
+    /// Page-aligned length of the mapping.
+    pub len: usize,
+    /// Memory protection of the mapping (i.e., `PROT_*`).
+    pub prot: u32,
+    /// Whether the entry-point of the object lies in this segment.
+    pub entry: bool,
+}
+
+/// Segment List
+///
+/// This is a fixed-capacity list of mapped segments, in the order of their
+/// program headers. It can hold at most `MAX_SEGMENTS` entries.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Segments {
+    n: usize,
+    v: [Segment; MAX_SEGMENTS],
+}
+
+impl Segment {
+    /// Check Address Containment
+    ///
+    /// Return whether `addr` lies within the range of this segment.
+    pub fn contains(&self, addr: usize) -> bool {
+        addr >= self.base && addr - self.base < self.len
+    }
+}
+
+impl Segments {
+    /// Create Empty List
+    pub fn new() -> Self {
+        Self {
+            n: 0,
+            v: [Segment::default(); MAX_SEGMENTS],
+        }
+    }
+
+    /// Append Segment
+    ///
+    /// Append `segment` to the list. If the list is full,
+    /// `LoadError::TooManySegments` is returned.
+    pub fn push(&mut self, segment: Segment) -> Result<(), LoadError> {
+        let slot = self.v.get_mut(self.n).ok_or(LoadError::TooManySegments)?;
+        *slot = segment;
+        self.n += 1;
+        Ok(())
+    }
+
+    /// Access Segments
+    pub fn as_slice(&self) -> &[Segment] {
+        &self.v[..self.n]
+    }
+}
+
+impl Default for Segments {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convert Segment Flags to Protection
+///
+/// Return the memory protection (i.e., `PROT_*`) matching the segment
+/// flags `p_flags` (i.e., `PF_*`) of a program header.
+pub fn prot_from_flags(p_flags: u32) -> u32 {
+    let mut prot = mman::PROT_NONE;
+
+    if p_flags & elf::Phdr::<(), ()>::PF_R != 0 {
+        prot |= mman::PROT_READ;
+    }
+    if p_flags & elf::Phdr::<(), ()>::PF_W != 0 {
+        prot |= mman::PROT_WRITE;
+    }
+    if p_flags & elf::Phdr::<(), ()>::PF_X != 0 {
+        prot |= mman::PROT_EXEC;
+    }
+
+    prot
+}
+
+/// Count Loadable Segments
+///
+/// Return the number of `PT_LOAD` entries of the program headers `phdrs`.
+/// Objects with more than `MAX_SEGMENTS` loadable segments cannot be
+/// described by `Segments`, and are rejected by `segments()` with
+/// `LoadError::TooManySegments`.
+pub fn count_loads(phdrs: &[elfn::Phdr]) -> usize {
+    phdrs.iter().filter(|v| v.p_type == elf::Phdr::<(), ()>::PT_LOAD).count()
+}
+
+/// Describe Mapped Segments
+///
+/// Return the segments an object with the program headers `phdrs` occupies
+/// when mapped with the load bias `bias`. `entry` is the absolute runtime
+/// address of the entry-point, and `pagesz` is the page size of the system
+/// (i.e., `AT_PAGESZ`).
+///
+/// This is the view a segment mapper hands to the following loader steps,
+/// and it can equally be derived for objects mapped by the kernel (e.g.,
+/// from `AT_PHDR` and `AT_ENTRY`).
+///
+/// If any range is not representable, `LoadError::InvalidHeader` is
+/// returned. If there are more than `MAX_SEGMENTS` loadable segments (see
+/// `count_loads()`), `LoadError::TooManySegments` is returned before any
+/// range is inspected.
+pub fn segments(
+    phdrs: &[elfn::Phdr],
+    bias: usize,
+    entry: usize,
+    pagesz: usize,
+) -> Result<Segments, LoadError> {
+    let mut list = Segments::new();
+
+    if count_loads(phdrs) > MAX_SEGMENTS {
+        return Err(LoadError::TooManySegments);
+    }
+
+    for phdr in phdrs {
+        if phdr.p_type != elf::Phdr::<(), ()>::PT_LOAD {
+            continue;
+        }
+
+        let start = bias
+            .checked_add(phdr.p_vaddr as usize)
+            .ok_or(LoadError::InvalidHeader)?;
+        let end = start
+            .checked_add(phdr.p_memsz as usize)
+            .ok_or(LoadError::InvalidHeader)?;
+        let base = util::align_down(start, pagesz);
+        let end = util::align_up(end, pagesz).ok_or(LoadError::InvalidHeader)?;
+
+        list.push(Segment {
+            base,
+            len: end - base,
+            prot: prot_from_flags(phdr.p_flags),
+            entry: entry >= start && entry < end,
+        })?;
+    }
+
+    Ok(list)
+}
+
+/// File Mapping of a Segment
+///
+/// This describes how the file-backed part of a `PT_LOAD` segment is mapped.
+/// The mapping covers `len` bytes of the file starting at `offset`, placed
+/// at `addr`. Both `addr` and `offset` are page-aligned, and the first
+/// `skew` bytes of the mapping precede the segment.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FileMapping {
+    /// Page-aligned runtime address of the mapping.
+    pub addr: usize,
+    /// Length of the mapping, including the skew.
+    pub len: usize,
+    /// Page-aligned file offset of the mapping.
+    pub offset: u64,
+    /// Distance from the start of the mapping to the start of the segment.
+    pub skew: usize,
+}
+
+/// Compute File Mapping
+///
+/// Return the file mapping of the `PT_LOAD` program header `phdr` of an
+/// object loaded with the load bias `bias`, for the page size `pagesz`.
+///
+/// Segments need not start on a page boundary, but `mmap()` only maps
+/// whole pages. Hence, the in-page offset of the segment (the skew, i.e.,
+/// `p_vaddr & (pagesz - 1)`) is subtracted from both the address and the
+/// file offset, and added to the length. The bytes preceding the segment on
+/// its first page are mapped as well, but not used by it.
+///
+/// This is only correct if `p_offset` and `p_vaddr` have the same in-page
+/// offset. ELF guarantees `p_offset ≡ p_vaddr (mod p_align)` for loadable
+/// segments, and `p_align` is a multiple of the page size for any object
+/// linked for this system. Headers violating either condition (or with
+/// `p_filesz > p_memsz`, or any range that is not representable) are
+/// rejected with `LoadError::InvalidHeader`, since mapping them would
+/// silently shift the segment contents.
+pub fn file_mapping(
+    phdr: &elfn::Phdr,
+    bias: usize,
+    pagesz: usize,
+) -> Result<FileMapping, LoadError> {
+    let vaddr = phdr.p_vaddr as usize;
+    let offset = phdr.p_offset as usize;
+    let align = phdr.p_align as usize;
+
+    if phdr.p_filesz > phdr.p_memsz {
+        return Err(LoadError::InvalidHeader);
+    }
+    if align > 1 && offset % align != vaddr % align {
+        return Err(LoadError::InvalidHeader);
+    }
+    if (offset ^ vaddr) & (pagesz - 1) != 0 {
+        return Err(LoadError::InvalidHeader);
+    }
+
+    let skew = vaddr & (pagesz - 1);
+    let start = bias.checked_add(vaddr).ok_or(LoadError::InvalidHeader)?;
+    start.checked_add(phdr.p_memsz as usize).ok_or(LoadError::InvalidHeader)?;
+
+    Ok(FileMapping {
+        addr: start - skew,
+        len: (phdr.p_filesz as usize).checked_add(skew).ok_or(LoadError::InvalidHeader)?,
+        offset: (offset - skew) as u64,
+        skew,
+    })
+}
+
+impl<'this> Loader<'this> {
+    // Map a single `PT_LOAD` segment, whose file mapping was computed by
+    // `file_mapping()`. See `map_segments()` for details.
+    unsafe fn map_segment(
+        &self,
+        fd: u32,
+        phdr: &elfn::Phdr,
+        map: &FileMapping,
+        pagesz: usize,
+    ) -> Result<(), Errno> {
+        let prot = prot_from_flags(phdr.p_flags);
+        let file_end = map.addr + map.len;
+        let bss_end = map.addr + map.skew + phdr.p_memsz as usize;
+        let mem_end = util::align_up(bss_end, pagesz).ok_or(errno::ENOEXEC)?;
+
+        if phdr.p_filesz == 0 {
+            if mem_end > map.addr {
+                unsafe {
+                    self.this.mmap(
+                        map.addr,
+                        mem_end - map.addr,
+                        prot,
+                        mman::MAP_PRIVATE | mman::MAP_FIXED | mman::MAP_ANONYMOUS,
+                        -1,
+                        0,
+                    )?;
+                }
+            }
+            return Ok(());
+        }
+
+        unsafe {
+            self.this.mmap(
+                map.addr,
+                map.len,
+                prot,
+                mman::MAP_PRIVATE | mman::MAP_FIXED,
+                fd as i32,
+                map.offset,
+            )?;
+        }
+
+        if phdr.p_memsz == phdr.p_filesz {
+            return Ok(());
+        }
+
+        // The last file page extends beyond the file data of the segment,
+        // and shows whatever follows in the file. Clear it up to the end of
+        // the segment, since it is the start of the zero-initialized part.
+        // Read-only segments are made writable temporarily.
+        let zero_end = util::align_up(file_end, pagesz).ok_or(errno::ENOEXEC)?;
+        if zero_end > file_end {
+            let page = zero_end - pagesz;
+            let len = core::cmp::min(zero_end, bss_end) - file_end;
+
+            if prot & mman::PROT_WRITE == 0 {
+                unsafe { self.this.syscall.mprotect(page, pagesz, prot | mman::PROT_WRITE)? };
+            }
+            unsafe { core::ptr::write_bytes(file_end as *mut u8, 0, len) };
+            if prot & mman::PROT_WRITE == 0 {
+                unsafe { self.this.syscall.mprotect(page, pagesz, prot)? };
+            }
+        }
+
+        // Any remaining pages are not backed by the file at all.
+        if mem_end > zero_end {
+            unsafe {
+                self.this.mmap(
+                    zero_end,
+                    mem_end - zero_end,
+                    prot,
+                    mman::MAP_PRIVATE | mman::MAP_FIXED | mman::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Map Segments
+    ///
+    /// Map all `PT_LOAD` segments of `phdrs` from the file `fd` with the load
+    /// bias `bias`, for the page size `pagesz` (i.e., `AT_PAGESZ`). Each
+    /// segment is mapped privately with the protection of its flags. See
+    /// `file_mapping()` for the handling of segments that do not start on a
+    /// page boundary. On success, the mapped segments are returned as
+    /// described by `segments()`, with `entry` being the absolute runtime
+    /// address of the entry-point of the object.
+    ///
+    /// If `p_memsz` exceeds `p_filesz`, the excess is zero-initialized (i.e.,
+    /// `.bss`): the remainder of the last file page is cleared (up to the end
+    /// of the segment), and any further pages are mapped anonymously. This
+    /// requires `pagesz` to be the actual page size of the system, since
+    /// only the pages covering the file data are mapped from the file.
+    ///
+    /// All program headers are validated before anything is mapped. If any
+    /// of them is rejected by `segments()` or `file_mapping()`, `ENOEXEC` is
+    /// returned (as does the kernel for malformed executables). Errors of
+    /// the kernel are returned as is, and leave the segments mapped so far
+    /// in place.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// The segments are mapped with `MAP_FIXED`, replacing any existing
+    /// mapping in their range. The caller must have reserved the load span
+    /// of the object (see `layout::load_span()`) at `bias`.
+    pub unsafe fn map_segments(
+        &self,
+        fd: u32,
+        phdrs: &[elfn::Phdr],
+        bias: usize,
+        entry: usize,
+        pagesz: usize,
+    ) -> Result<Segments, Errno> {
+        let load = || phdrs.iter().filter(|v| v.p_type == elf::Phdr::<(), ()>::PT_LOAD);
+        let list = segments(phdrs, bias, entry, pagesz).map_err(|_| errno::ENOEXEC)?;
+
+        for phdr in load() {
+            file_mapping(phdr, bias, pagesz).map_err(|_| errno::ENOEXEC)?;
+        }
+
+        for phdr in load() {
+            let map = file_mapping(phdr, bias, pagesz).map_err(|_| errno::ENOEXEC)?;
+            unsafe { self.map_segment(fd, phdr, &map, pagesz)? };
+        }
+
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify the segment view of a typical object, including entry-point
+    // detection, protections, and the rejection of malformed headers. Too
+    // many segments must be rejected before any range is inspected.
+    #[test]
+    fn segment_view() {
+        let load = |vaddr: usize, memsz: usize, flags: u32| elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_LOAD,
+            p_flags: flags,
+            p_vaddr: vaddr as _,
+            p_memsz: memsz as _,
+            ..Default::default()
+        };
+        let r = elf::Phdr::<(), ()>::PF_R;
+        let w = elf::Phdr::<(), ()>::PF_W;
+        let x = elf::Phdr::<(), ()>::PF_X;
+        let phdrs = [
+            load(0x0, 0x800, r),
+            load(0x1000, 0x1234, r | x),
+            elfn::Phdr {
+                p_type: elf::Phdr::<(), ()>::PT_GNU_RELRO,
+                ..Default::default()
+            },
+            load(0x3e10, 0x2001, r | w),
+        ];
+
+        let list = segments(&phdrs, 0x10000, 0x11100, 0x1000).unwrap();
+        assert_eq!(
+            list.as_slice(),
+            &[
+                Segment { base: 0x10000, len: 0x1000, prot: mman::PROT_READ, entry: false },
+                Segment {
+                    base: 0x11000,
+                    len: 0x2000,
+                    prot: mman::PROT_READ | mman::PROT_EXEC,
+                    entry: true,
+                },
+                Segment {
+                    base: 0x13000,
+                    len: 0x3000,
+                    prot: mman::PROT_READ | mman::PROT_WRITE,
+                    entry: false,
+                },
+            ],
+        );
+        assert!(list.as_slice()[2].contains(0x15fff));
+        assert!(!list.as_slice()[2].contains(0x16000));
+
+        assert_eq!(segments(&[], 0, 0, 0x1000).unwrap().as_slice(), &[]);
+        assert_eq!(
+            segments(&[load(0x0, 0x10, r)], usize::MAX, 0, 0x1000),
+            Err(LoadError::InvalidHeader),
+        );
+        assert_eq!(
+            segments(&[load(0x0, 0x10, r); MAX_SEGMENTS + 1], 0, 0, 0x1000),
+            Err(LoadError::TooManySegments),
+        );
+        assert_eq!(
+            segments(&[load(0x0, 0x10, r); MAX_SEGMENTS], usize::MAX, 0, 0x1000),
+            Err(LoadError::InvalidHeader),
+        );
+        assert_eq!(
+            segments(&[load(0x0, 0x10, r); MAX_SEGMENTS + 1], usize::MAX, 0, 0x1000),
+            Err(LoadError::TooManySegments),
+        );
+
+        assert_eq!(count_loads(&phdrs), 3);
+        assert_eq!(count_loads(&phdrs[2..3]), 0);
+        assert_eq!(count_loads(&[]), 0);
+    }
+
+    // Verify file mappings of segments that do not start on a page
+    // boundary, and the rejection of incongruent offsets.
+    #[test]
+    fn mapping_skew() {
+        let phdr = elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_LOAD,
+            p_offset: 0x3e10,
+            p_vaddr: 0x13e10,
+            p_filesz: 0x300,
+            p_memsz: 0x2000,
+            p_align: 0x10000,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            file_mapping(&phdr, 0x100000, 0x1000),
+            Ok(FileMapping { addr: 0x113000, len: 0x1110, offset: 0x3000, skew: 0xe10 }),
+        );
+
+        let v = elfn::Phdr { p_offset: 0x3e14, ..phdr };
+        assert_eq!(file_mapping(&v, 0x100000, 0x1000), Err(LoadError::InvalidHeader));
+        let v = elfn::Phdr { p_align: 0x1000, p_offset: 0x2e10, ..phdr };
+        assert!(file_mapping(&v, 0x100000, 0x1000).is_ok());
+        let v = elfn::Phdr { p_offset: 0x4e10, ..phdr };
+        assert_eq!(file_mapping(&v, 0x100000, 0x1000), Err(LoadError::InvalidHeader));
+        let v = elfn::Phdr { p_filesz: 0x2001, ..phdr };
+        assert_eq!(file_mapping(&v, 0x100000, 0x1000), Err(LoadError::InvalidHeader));
+        assert_eq!(file_mapping(&phdr, usize::MAX, 0x1000), Err(LoadError::InvalidHeader));
+    }
+
+    // Map segments of the test binary into a reservation, and verify the
+    // returned segment list, their contents, and the zero-initialized tails.
+    // The segments are aligned to 64KiB, so this works with any page size
+    // of the system up to that.
+    #[test]
+    fn segment_mapping() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let mut auxv = [0usize; 128];
+        let fd = unsafe {
+            this.openat(
+                rt11_ffi_linux::native::fcntl::AT_FDCWD,
+                b"/proc/self/auxv\0".as_ptr(),
+                rt11_ffi_linux::native::fcntl::O_RDONLY | rt11_ffi_linux::native::fcntl::O_CLOEXEC,
+                0,
+            ).unwrap()
+        };
+        // Leave the last entry zero, so the vector is terminated.
+        let len = core::mem::size_of_val(&auxv) - 2 * core::mem::size_of::<usize>();
+        unsafe {
+            this.read(fd, auxv.as_mut_ptr() as *mut u8, len).unwrap();
+            this.close(fd).unwrap();
+        }
+        let pagesz = unsafe { crate::loader::auxv::Auxv::from_ptr(auxv.as_ptr()) }.pagesz;
+        assert!(pagesz.is_power_of_two() && pagesz <= 0x10000);
+
+        let fd = unsafe {
+            this.openat(
+                rt11_ffi_linux::native::fcntl::AT_FDCWD,
+                b"/proc/self/exe\0".as_ptr(),
+                rt11_ffi_linux::native::fcntl::O_RDONLY | rt11_ffi_linux::native::fcntl::O_CLOEXEC,
+                0,
+            ).unwrap()
+        };
+        let file = unsafe {
+            this.mmap(0, 0x30000, mman::PROT_READ, mman::MAP_PRIVATE, fd as i32, 0).unwrap()
+        };
+        let file = unsafe { core::slice::from_raw_parts(file as *const u8, 0x30000) };
+
+        let rsv = unsafe {
+            this.mmap(
+                0,
+                0x50000,
+                mman::PROT_NONE,
+                mman::MAP_PRIVATE | mman::MAP_ANONYMOUS,
+                -1,
+                0,
+            ).unwrap()
+        };
+        let bias = util::align_up(rsv, 0x10000).unwrap();
+        let at = |addr: usize, len: usize| unsafe {
+            core::slice::from_raw_parts((bias + addr) as *const u8, len)
+        };
+
+        let r = elf::Phdr::<(), ()>::PF_R;
+        let w = elf::Phdr::<(), ()>::PF_W;
+        let load = |offset: usize, vaddr: usize, filesz: usize, memsz: usize, flags: u32| {
+            elfn::Phdr {
+                p_type: elf::Phdr::<(), ()>::PT_LOAD,
+                p_flags: flags,
+                p_offset: offset as _,
+                p_vaddr: vaddr as _,
+                p_filesz: filesz as _,
+                p_memsz: memsz as _,
+                p_align: 0x10000,
+                ..Default::default()
+            }
+        };
+        let phdrs = [
+            load(0x100, 0x100, 0x200, 0x400, r),
+            load(0x2_1234, 0x1_1234, 0x100, 0x2_0000, r | w),
+        ];
+
+        let list = unsafe { loader.map_segments(fd, &phdrs, bias, bias + 0x100, pagesz).unwrap() };
+        assert_eq!(list, segments(&phdrs, bias, bias + 0x100, pagesz).unwrap());
+        assert!(list.as_slice()[0].entry);
+        assert_eq!(at(0x100, 0x200), &file[0x100..0x300]);
+        assert!(at(0x300, 0x100).iter().all(|v| *v == 0));
+        assert_eq!(at(0x1_1234, 0x100), &file[0x2_1234..0x2_1334]);
+        assert!(at(0x1_1334, 0x2_0000 - 0x100).iter().all(|v| *v == 0));
+
+        let bad = [load(0x2_1235, 0x1_1234, 0x100, 0x100, r)];
+        assert_eq!(
+            unsafe { loader.map_segments(fd, &bad, bias, bias, pagesz) },
+            Err(errno::ENOEXEC),
+        );
+
+        unsafe {
+            this.syscall.munmap(rsv, 0x50000).unwrap();
+            this.syscall.munmap(file.as_ptr() as usize, 0x30000).unwrap();
+            this.close(fd).unwrap();
+        }
+    }
+}
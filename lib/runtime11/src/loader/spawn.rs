@@ -0,0 +1,327 @@
+//! Process Spawning
+//!
+//! A launcher that starts a program as its child, rather than replacing
+//! itself, would usually `fork()` and then execute the program in the child.
+//! Copying the address space is wasted work, though, since it is discarded
+//! by `execve(2)` right away. This module provides a `posix_spawn()`-like
+//! launcher, which instead creates the child via
+//! `clone(CLONE_VM | CLONE_VFORK)`: the child borrows the address space of
+//! the caller, which is suspended until the child executes the program (or
+//! exits).
+//!
+//! Since the child shares all memory with the caller, it must only perform
+//! async-signal-safe operations: it must not allocate, take locks, or
+//! modify any state of the caller. This is enforced by running only the
+//! fixed code of this module in the child, on a stack of its own. It applies
+//! the requested file actions via plain system calls, and reports errors
+//! via a single atomic in the shared memory. Signals are blocked across the
+//! `clone()`, and the child resets all handled signals to their default
+//! action before restoring the signal mask, so no handler of the caller can
+//! ever run in the child.
+
+use crate::loader::Loader;
+use rt11_ffi_linux::native::{errno, fcntl, sched, signal, wait};
+use rt11_linux::syscall::Errno;
+
+/// File Action
+///
+/// This describes a modification of the file-descriptor table of a spawned
+/// child, applied before the program is executed. The table of the caller is
+/// never modified.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FileAction<'a> {
+    /// Duplicate `from` onto `to`, which is closed first if open. If both
+    /// are equal, `FD_CLOEXEC` is cleared on it instead, so it is inherited
+    /// by the program.
+    Dup2 { from: u32, to: u32 },
+    /// Close `fd`. Fails with `EBADF` if it is not open.
+    Close { fd: u32 },
+    /// Open `path` with `flags` (i.e., `O_*`) and place it at `fd`, which is
+    /// closed first if open. `path` must include its NUL terminator. Files
+    /// created via `O_CREAT` get the mode `0o666`, reduced by the umask.
+    Open { fd: u32, path: &'a [u8], flags: u32 },
+}
+
+// Size of the stack of the child. It only runs `Context::run()` and the
+// system call wrappers it invokes, so this is generous even for unoptimized
+// builds.
+const STACK_SIZE: usize = 16384;
+
+#[repr(C, align(16))]
+struct Stack([u8; STACK_SIZE]);
+
+// State shared between the caller and the child. The caller is suspended
+// while the child runs, and only reads `err` once the child executed the
+// program or exited.
+struct Context<'a> {
+    this: &'a rt11_linux::this::This,
+    path: *const u8,
+    argv: *const *const u8,
+    envp: *const *const u8,
+    actions: &'a [FileAction<'a>],
+    mask: u64,
+    err: core::sync::atomic::AtomicU32,
+}
+
+impl<'a> Context<'a> {
+    // Reset the action of every handled signal to its default, so no handler
+    // of the caller runs in the child once the mask is restored. Ignored
+    // signals stay ignored, as they would across `execve()`.
+    unsafe fn reset_signals(&self) {
+        let sc = &self.this.syscall;
+        let dfl = signal::Sigaction::default();
+
+        for sig in 1..=signal::SIGRTMAX {
+            if sig == signal::SIGKILL || sig == signal::SIGSTOP {
+                continue;
+            }
+
+            let mut old = signal::Sigaction::default();
+            let r = unsafe { sc.rt_sigaction(sig, core::ptr::null(), &mut old) };
+            if r.is_err() {
+                continue;
+            }
+
+            let handler = old.sa_handler;
+            if handler != signal::SIG_DFL && handler != signal::SIG_IGN {
+                let _ = unsafe { sc.rt_sigaction(sig, &dfl, core::ptr::null_mut()) };
+            }
+        }
+    }
+
+    // Apply a single file action.
+    unsafe fn apply(&self, action: &FileAction<'_>) -> Result<(), Errno> {
+        let sc = &self.this.syscall;
+
+        match *action {
+            FileAction::Dup2 { from, to } if from == to => {
+                unsafe { sc.fcntl(from, fcntl::F_SETFD, 0)? };
+            },
+            FileAction::Dup2 { from, to } => {
+                unsafe { sc.dup3(from, to, 0)? };
+            },
+            FileAction::Close { fd } => {
+                // Only `EBADF` reliably signals the file-descriptor was not
+                // closed. See `close()` for details.
+                if let Err(errno::EBADF) = unsafe { sc.close(fd) } {
+                    return Err(errno::EBADF);
+                }
+            },
+            FileAction::Open { fd, path, flags } => {
+                let v = unsafe { sc.openat(fcntl::AT_FDCWD, path.as_ptr(), flags, 0o666)? };
+                if v != fd {
+                    let r = unsafe { sc.dup3(v, fd, 0) };
+                    let _ = unsafe { sc.close(v) };
+                    r?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    // Prepare the child and execute the program. This only returns on
+    // failure.
+    unsafe fn run(&self) -> Errno {
+        let sc = &self.this.syscall;
+
+        unsafe {
+            self.reset_signals();
+            if let Err(e) = sc.rt_sigprocmask(signal::SIG_SETMASK, Some(&self.mask), None) {
+                return e;
+            }
+
+            for action in self.actions {
+                if let Err(e) = self.apply(action) {
+                    return e;
+                }
+            }
+
+            match sc.execve(self.path, self.argv, self.envp) {
+                Ok(v) => match v {},
+                Err(e) => e,
+            }
+        }
+    }
+}
+
+// Entry-point of the child, running on its own stack. `arg` is the address
+// of the shared context. On failure, the error is stored in the context
+// before the child exits.
+extern "C" fn child(arg: usize) -> ! {
+    let ctx = unsafe { &*(arg as *const Context<'_>) };
+    let e = unsafe { ctx.run() };
+
+    ctx.err.store(e as u32, core::sync::atomic::Ordering::SeqCst);
+    ctx.this.exit_group(127)
+}
+
+impl<'this> Loader<'this> {
+    /// Spawn Program
+    ///
+    /// Start the program at `path` in a new child process with the argument
+    /// vector `argv` and the environment `envp` (see `execve()`), after
+    /// applying `actions` in order to the file-descriptor table of the
+    /// child. The task ID of the child is returned once it executes the
+    /// program. The caller must collect its exit status (e.g., via
+    /// `pidfd_wait()`).
+    ///
+    /// The child is created via `clone(CLONE_VM | CLONE_VFORK)`, so the
+    /// address space is not copied, and the caller is suspended until the
+    /// child executes the program. See the module documentation for the
+    /// restrictions this imposes on the child, and how they are upheld.
+    ///
+    /// If a file action or `execve()` fails, the child exits with status 127
+    /// and is reaped, and its error is returned. Hence, on error, no child
+    /// is left behind. `EINVAL` is returned without creating a child if any
+    /// path of an `Open` action lacks its NUL terminator.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `path` must point to a valid NUL-terminated string, and `argv` and
+    /// `envp` must point to valid NULL-terminated arrays of such strings
+    /// (see `rt11_linux::exec::ExecArgs`).
+    pub unsafe fn spawn(
+        &self,
+        path: *const u8,
+        argv: *const *const u8,
+        envp: *const *const u8,
+        actions: &[FileAction<'_>],
+    ) -> Result<u32, Errno> {
+        let sc = &self.this.syscall;
+        let all = !0u64;
+        let mut mask = 0u64;
+        let mut stack = Stack([0; STACK_SIZE]);
+
+        for action in actions {
+            if let FileAction::Open { path, .. } = action {
+                if path.last() != Some(&0) {
+                    return Err(errno::EINVAL);
+                }
+            }
+        }
+
+        unsafe { sc.rt_sigprocmask(signal::SIG_SETMASK, Some(&all), Some(&mut mask))? };
+
+        let ctx = Context {
+            this: self.this,
+            path,
+            argv,
+            envp,
+            actions,
+            mask,
+            err: core::sync::atomic::AtomicU32::new(0),
+        };
+        let flags = sched::CLONE_VM | sched::CLONE_VFORK | signal::SIGCHLD;
+        let top = unsafe { stack.0.as_mut_ptr().add(STACK_SIZE) };
+
+        let r = unsafe { sc.clone_entry(flags, top, child, &ctx as *const Context<'_> as usize) };
+
+        // Restoring the previous mask cannot fail, since it was accepted
+        // by the kernel before.
+        let _ = unsafe { sc.rt_sigprocmask(signal::SIG_SETMASK, Some(&mask), None) };
+
+        let pid = r?;
+        let e = ctx.err.load(core::sync::atomic::Ordering::SeqCst);
+        if e == 0 {
+            return Ok(pid);
+        }
+
+        let mut info = wait::Siginfo::default();
+        while let Err(errno::EINTR) = unsafe { sc.waitid(wait::P_PID, pid, &mut info, wait::WEXITED) } {
+        }
+
+        Err(e as Errno)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Spawn `cat` with its input opened from a file and its output
+    // redirected into a pipe, and verify it copied the file.
+    #[test]
+    fn spawn_program() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let mut expect = [0u8; 512];
+        let mut buf = [0u8; 512];
+        let mut argv = rt11_linux::exec::ExecArgs::<2>::new();
+        let envp = rt11_linux::exec::ExecArgs::<0>::new();
+
+        let n = this.syscall.read_proc(b"/proc/version\0", &mut expect).unwrap();
+        argv.push(b"cat\0").unwrap();
+
+        let p = this.syscall.pipe2(fcntl::O_CLOEXEC).unwrap();
+
+        let actions = [
+            FileAction::Open { fd: 0, path: b"/proc/version\0", flags: fcntl::O_RDONLY },
+            FileAction::Dup2 { from: p[1], to: 1 },
+        ];
+        let pid = unsafe {
+            loader.spawn(b"/bin/cat\0".as_ptr(), argv.as_ptr(), envp.as_ptr(), &actions).unwrap()
+        };
+        let pidfd = this.syscall.pidfd_open(pid as i32, 0).unwrap();
+        unsafe { this.close(p[1]).unwrap() };
+
+        let mut len = 0;
+        loop {
+            match unsafe { this.read(p[0], buf[len..].as_mut_ptr(), buf.len() - len) }.unwrap() {
+                0 => break,
+                v => len += v,
+            }
+        }
+        assert_eq!(&buf[..len], &expect[..n]);
+
+        let status = loader.pidfd_wait(pidfd).unwrap();
+        assert!(rt11_linux::wait::wifexited(status));
+        assert_eq!(rt11_linux::wait::wexitstatus(status), 0);
+
+        unsafe {
+            this.close(pidfd).unwrap();
+            this.close(p[0]).unwrap();
+        }
+    }
+
+    // Verify failures of `execve()` and of file actions are reported to the
+    // caller, and invalid actions are rejected up front.
+    #[test]
+    fn spawn_errors() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let mut argv = rt11_linux::exec::ExecArgs::<1>::new();
+        let envp = rt11_linux::exec::ExecArgs::<0>::new();
+        argv.push(b"true\0").unwrap();
+
+        let spawn = |path: &[u8], actions: &[FileAction<'_>]| unsafe {
+            loader.spawn(path.as_ptr(), argv.as_ptr(), envp.as_ptr(), actions)
+        };
+
+        assert_eq!(spawn(b"/nonexistent\0", &[]), Err(errno::ENOENT));
+        assert_eq!(
+            spawn(b"/bin/true\0", &[FileAction::Close { fd: u32::MAX >> 1 }]),
+            Err(errno::EBADF),
+        );
+        assert_eq!(
+            spawn(
+                b"/bin/true\0",
+                &[FileAction::Open { fd: 71, path: b"/nonexistent\0", flags: fcntl::O_RDONLY }],
+            ),
+            Err(errno::ENOENT),
+        );
+        assert_eq!(
+            spawn(
+                b"/bin/true\0",
+                &[FileAction::Open { fd: 71, path: b"/dev/null", flags: fcntl::O_RDONLY }],
+            ),
+            Err(errno::EINVAL),
+        );
+
+        let pid = spawn(b"/bin/true\0", &[FileAction::Dup2 { from: 2, to: 2 }]).unwrap();
+        let pidfd = this.syscall.pidfd_open(pid as i32, 0).unwrap();
+        assert_eq!(loader.pidfd_wait(pidfd), Ok(0));
+        unsafe { this.close(pidfd).unwrap() };
+    }
+}
@@ -0,0 +1,112 @@
+//! Stack Setup
+//!
+//! This module implements the loader steps that prepare the stack of the
+//! loaded application.
+
+use crate::loader::Loader;
+use rt11_ffi_linux::native::resource;
+use rt11_linux::syscall::Errno;
+
+impl<'this> Loader<'this> {
+    /// Ensure Minimum Stack Limit
+    ///
+    /// Query the stack limit (`RLIMIT_STACK`) of the calling task and raise
+    /// its soft limit to `min_bytes`, if it is lower. The soft limit can only
+    /// be raised up to the hard limit. If the hard limit is lower than
+    /// `min_bytes`, the soft limit is raised to the hard limit and `EPERM`
+    /// is returned. An unlimited soft limit (`RLIM64_INFINITY`) is left
+    /// untouched.
+    ///
+    /// The stack limit of the main thread is consulted by the kernel
+    /// whenever the stack grows, but the limit is also used to size the
+    /// stacks of new threads and to lay out the address space of new
+    /// programs. Hence, this must be called before spawning threads, before
+    /// executing programs that rely on the limit, and before any deep
+    /// recursion.
+    pub fn ensure_stack_limit(&self, min_bytes: u64) -> Result<(), Errno> {
+        let mut limit = resource::Rlimit64::default();
+
+        self.this.syscall.prlimit64(0, resource::RLIMIT_STACK, None, Some(&mut limit))?;
+
+        if limit.rlim_cur == resource::RLIM64_INFINITY || limit.rlim_cur >= min_bytes {
+            return Ok(());
+        }
+
+        if limit.rlim_cur < limit.rlim_max {
+            limit.rlim_cur = core::cmp::min(min_bytes, limit.rlim_max);
+            self.this.syscall.prlimit64(0, resource::RLIMIT_STACK, Some(&limit), None)?;
+        }
+
+        if limit.rlim_cur < min_bytes {
+            Err(rt11_ffi_linux::native::errno::EPERM)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Lower the stack soft limit, then verify `ensure_stack_limit()` raises
+    // it as requested and refuses to go beyond the hard limit. This runs in
+    // a forked child, since the limit is shared by all tests of the process.
+    // The child reports the first failed check as exit code.
+    #[test]
+    fn stack_limit() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+
+        let get = || {
+            let mut v = resource::Rlimit64::default();
+            this.syscall.prlimit64(0, resource::RLIMIT_STACK, None, Some(&mut v)).map(|_| v)
+        };
+        let set = |v: &resource::Rlimit64| {
+            this.syscall.prlimit64(0, resource::RLIMIT_STACK, Some(v), None)
+        };
+
+        let check = || -> Result<(), u32> {
+            let orig = get().map_err(|_| 1u32)?;
+            let low = resource::Rlimit64 {
+                rlim_cur: 1 << 20,
+                rlim_max: orig.rlim_max,
+            };
+            set(&low).map_err(|_| 2u32)?;
+
+            // Limits that are already satisfied are left untouched.
+            loader.ensure_stack_limit(1 << 19).map_err(|_| 3u32)?;
+            if get() != Ok(low) {
+                return Err(4);
+            }
+
+            // Lower limits are raised to the requested size.
+            loader.ensure_stack_limit(1 << 21).map_err(|_| 5u32)?;
+            if get().map(|v| v.rlim_cur) != Ok(1 << 21) {
+                return Err(6);
+            }
+
+            // The hard limit cannot be exceeded.
+            if orig.rlim_max != resource::RLIM64_INFINITY {
+                let r = loader.ensure_stack_limit(orig.rlim_max + 1);
+                if r != Err(rt11_ffi_linux::native::errno::EPERM) {
+                    return Err(7);
+                }
+                if get().map(|v| v.rlim_cur) != Ok(orig.rlim_max) {
+                    return Err(8);
+                }
+            }
+
+            Ok(())
+        };
+
+        match unsafe { this.syscall.fork() }.unwrap() {
+            rt11_linux::syscall::ForkResult::Child => {
+                this.exit_group(check().err().unwrap_or(0));
+            },
+            rt11_linux::syscall::ForkResult::Parent(pid) => {
+                assert_eq!(crate::loader::test::reap_exit(&this.syscall, pid), 0);
+            },
+        }
+    }
+}
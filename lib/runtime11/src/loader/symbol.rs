@@ -0,0 +1,111 @@
+//! Address Symbolization
+//!
+//! This module maps addresses within a loaded object back to the symbols of
+//! its dynamic symbol table, similar to `dladdr(3)`. This is what panic
+//! handlers and profilers need to print readable locations.
+
+use rt11_ffi_elf::{elf, elfn, parse};
+
+/// Symbol Index
+///
+/// A symbol index borrows the dynamic symbol table and string table of a
+/// loaded object, and resolves runtime addresses to the nearest preceding
+/// function or data symbol.
+///
+/// The index performs no allocations. Instead, each lookup scans the entire
+/// symbol table, which is linear in the number of symbols. Lookups are
+/// meant for diagnostics, and are not suitable for hot paths.
+///
+/// Note that the dynamic symbol table only carries exported and imported
+/// symbols. Addresses in local functions thus resolve to the nearest
+/// preceding exported symbol.
+#[derive(Clone, Copy, Debug)]
+pub struct SymbolIndex<'a> {
+    symtab: &'a [elfn::Sym],
+    strtab: &'a [u8],
+    bias: usize,
+}
+
+impl<'a> SymbolIndex<'a> {
+    /// Create Symbol Index
+    ///
+    /// Create a new index over the symbol table `symtab` with names in the
+    /// string table `strtab` of an object loaded with the load bias `bias`.
+    pub fn new(symtab: &'a [elfn::Sym], strtab: &'a [u8], bias: usize) -> Self {
+        Self { symtab, strtab, bias }
+    }
+
+    /// Resolve Address
+    ///
+    /// Find the defined `STT_FUNC` or `STT_OBJECT` symbol with the highest
+    /// address that is less than or equal to `addr`, and return its name
+    /// and the offset of `addr` into it. If no such symbol exists, `None` is
+    /// returned. Symbols with invalid names are skipped.
+    ///
+    /// Symbol sizes are not considered, since they are often missing for
+    /// hand-written assembly. Hence, addresses beyond the end of the last
+    /// symbol of a segment still resolve to that symbol.
+    pub fn resolve(&self, addr: usize) -> Option<(&'a [u8], usize)> {
+        let mut best: Option<(&'a [u8], usize)> = None;
+
+        for sym in self.symtab {
+            let typ = sym.st_info & 0xf;
+            if typ != elf::Sym::<(), ()>::STT_FUNC && typ != elf::Sym::<(), ()>::STT_OBJECT {
+                continue;
+            }
+            if sym.st_shndx == elf::Shdr::<(), ()>::SHN_UNDEF {
+                continue;
+            }
+
+            let Some(value) = self.bias.checked_add(sym.st_value as usize) else {
+                continue;
+            };
+            if value > addr || best.is_some_and(|(_, v)| v >= value) {
+                continue;
+            }
+
+            if let Some(name) = parse::strtab_get(self.strtab, sym.st_name as usize) {
+                best = Some((name, value));
+            }
+        }
+
+        best.map(|(name, value)| (name, addr - value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify addresses resolve to the nearest preceding function or object,
+    // and that undefined and other symbols are ignored.
+    #[test]
+    fn symbol_resolve() {
+        let sym = |name: u32, typ: u8, shndx: u16, value: usize| elfn::Sym {
+            st_name: name,
+            st_info: (elf::Sym::<(), ()>::STB_GLOBAL << 4) | typ,
+            st_shndx: shndx,
+            st_value: value as _,
+            ..Default::default()
+        };
+        let func = elf::Sym::<(), ()>::STT_FUNC;
+        let object = elf::Sym::<(), ()>::STT_OBJECT;
+        let strtab = b"\0foo\0bar\0baz\0sec\0";
+        let symtab = [
+            elfn::Sym::default(),
+            sym(1, func, 7, 0x1000),
+            sym(5, object, 8, 0x3000),
+            sym(9, func, 0, 0x2000),
+            sym(13, elf::Sym::<(), ()>::STT_SECTION, 7, 0x2800),
+            sym(99, func, 7, 0x2f00),
+        ];
+        let index = SymbolIndex::new(&symtab, strtab, 0x10000);
+
+        assert_eq!(index.resolve(0x10fff), None);
+        assert_eq!(index.resolve(0x11000), Some((&b"foo"[..], 0)));
+        assert_eq!(index.resolve(0x12f80), Some((&b"foo"[..], 0x1f80)));
+        assert_eq!(index.resolve(0x13010), Some((&b"bar"[..], 0x10)));
+        assert_eq!(index.resolve(usize::MAX), Some((&b"bar"[..], usize::MAX - 0x13000)));
+        assert_eq!(SymbolIndex::new(&[], strtab, 0).resolve(0x1000), None);
+    }
+}
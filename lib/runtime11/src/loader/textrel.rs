@@ -0,0 +1,326 @@
+//! Text Relocations
+//!
+//! Objects that were not compiled as position-independent code (usually
+//! legacy objects) can carry relocations that target their code, rather than
+//! writable data. Such objects announce this via `DF_TEXTREL` (or the legacy
+//! `DT_TEXTREL`, see `dynamic::parse_flags()`). The affected segments are
+//! mapped without write access, so the loader has to make them writable
+//! temporarily, apply the relocations, and then restore their protection:
+//!
+//!  1. `text_targets()` selects the non-writable segments that contain
+//!     relocation targets.
+//!  2. `Loader::unprotect_text()` makes them readable and writable.
+//!  3. The relocations are applied as usual (e.g., via
+//!     `reloc::relocate_symbols()`).
+//!  4. `Loader::restore_text()` restores their original protection.
+//!
+//! `Loader::relocate_text()` runs this sequence for objects that announce
+//! text relocations, and applies the relocations directly otherwise.
+//!
+//! While the segments are writable, the object violates W^X. Any bug in the
+//! relocation processing (or any other thread of the process) can then
+//! inject code into the object. The segments are never mapped writable and
+//! executable at the same time, but the window cannot be avoided. Hence,
+//! systems enforcing W^X strictly (e.g., via SELinux `execmod`) reject the
+//! restoring `mprotect()`, and such objects cannot be loaded there.
+
+use crate::loader::dynamic::DynFlags;
+use crate::loader::segment::{Segment, Segments};
+use crate::loader::{LoadError, Loader};
+use rt11_ffi_elf::{elfn, parse};
+use rt11_ffi_linux::native::mman;
+use rt11_linux::cache;
+use rt11_linux::syscall::Errno;
+
+/// Select Text Relocation Targets
+///
+/// Return the segments of `segments` that are not writable, but contain
+/// the target of at least one relocation of `relocs`, applied to the object
+/// loaded at `base` (i.e., the load bias). The segments are returned in the
+/// order of `segments` with their original protection, as required by
+/// `Loader::restore_text()`.
+///
+/// Relocation targets are a native word, and a target that straddles two
+/// segments selects both. Targets outside of all segments are ignored, and
+/// must be rejected by the relocation processing.
+pub fn text_targets<T>(
+    segments: &[Segment],
+    base: usize,
+    relocs: &[T],
+) -> Result<Segments, LoadError>
+where
+    T: Copy + Into<parse::RelocView<elfn::Size, elfn::Addend>>,
+{
+    let mut r = Segments::new();
+
+    for segment in segments {
+        if segment.prot & mman::PROT_WRITE != 0 {
+            continue;
+        }
+
+        let hit = relocs.iter().any(|v| {
+            let v: parse::RelocView<elfn::Size, elfn::Addend> = (*v).into();
+            let start = base.wrapping_add(v.offset as usize);
+            let end = start.wrapping_add(core::mem::size_of::<usize>() - 1);
+            segment.contains(start) || segment.contains(end)
+        });
+
+        if hit {
+            r.push(*segment)?;
+        }
+    }
+
+    Ok(r)
+}
+
+impl<'this> Loader<'this> {
+    /// Make Text Writable
+    ///
+    /// Change the protection of all segments of `text` to be readable and
+    /// writable, so text relocations can be applied to them. Execute access
+    /// is revoked until `restore_text()` is called, so the segments are never
+    /// writable and executable at the same time. See the module
+    /// documentation for the security implications.
+    ///
+    /// If any segment cannot be changed, the error is returned as is, and
+    /// the segments changed so far remain writable.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `text` must describe mapped segments of an object (see
+    /// `text_targets()`), and no code of those segments must be executed
+    /// until `restore_text()` is called (including code of the loader
+    /// itself).
+    pub unsafe fn unprotect_text(&self, text: &Segments) -> Result<(), Errno> {
+        for segment in text.as_slice() {
+            unsafe {
+                self.this.syscall.mprotect(
+                    segment.base,
+                    segment.len,
+                    mman::PROT_READ | mman::PROT_WRITE,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Restore Text Protection
+    ///
+    /// Restore the original protection of all segments of `text`, after
+    /// `unprotect_text()` made them writable and all relocations were
+    /// applied.
+    ///
+    /// The instruction cache is synchronized for all segments that are
+    /// restored as executable (see `rt11_linux::cache::sync_icache()`), so
+    /// the relocated code is visible to instruction fetches.
+    ///
+    /// Errors of the kernel are returned as is. In particular, systems
+    /// enforcing W^X strictly reject making modified pages executable with
+    /// `EACCES`. The object must not be used in that case, since its code
+    /// is not executable.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `text` must be the same segment list passed to `unprotect_text()`.
+    /// Any later write to the segments faults.
+    pub unsafe fn restore_text(&self, text: &Segments) -> Result<(), Errno> {
+        for segment in text.as_slice() {
+            unsafe {
+                if segment.prot & mman::PROT_EXEC != 0 {
+                    cache::sync_icache(segment.base, segment.len);
+                }
+                self.this.syscall.mprotect(segment.base, segment.len, segment.prot)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply Relocations with Text Relocations
+    ///
+    /// Run `relocate` to apply the relocations `relocs` to the object loaded
+    /// at `base` (e.g., via `reloc::relocate_symbols()`). `flags` are the
+    /// dynamic flags of the object (see `dynamic::parse_flags()`), and
+    /// `segments` are its mapped segments.
+    ///
+    /// If the object announces text relocations (i.e., `DF_TEXTREL` or
+    /// `DT_TEXTREL`), the non-writable segments targeted by `relocs` are
+    /// made writable via `unprotect_text()` before `relocate` is run, and
+    /// their protection is restored via `restore_text()` afterwards. The
+    /// protection is restored even if `relocate` fails, in which case its
+    /// error is returned. Failures to change the protection are returned as
+    /// `LoadError::Protection`. Objects without text relocations are passed
+    /// to `relocate` directly, and any relocation targeting a non-writable
+    /// segment faults.
+    ///
+    /// Safety
+    /// ------
+    ///
+    /// `segments` must describe the mapped segments of the object at `base`,
+    /// and `relocate` must neither execute code of the object nor apply
+    /// relocations other than `relocs`. See `unprotect_text()` for details.
+    pub unsafe fn relocate_text<T, F>(
+        &self,
+        flags: &DynFlags,
+        segments: &[Segment],
+        base: usize,
+        relocs: &[T],
+        relocate: F,
+    ) -> Result<(), LoadError>
+    where
+        T: Copy + Into<parse::RelocView<elfn::Size, elfn::Addend>>,
+        F: FnOnce(&[T]) -> Result<(), LoadError>,
+    {
+        if !flags.textrel {
+            return relocate(relocs);
+        }
+
+        let text = text_targets(segments, base, relocs)?;
+
+        if let Err(e) = unsafe { self.unprotect_text(&text) } {
+            // Restore the segments that were made writable already. Those
+            // that were not changed are restored to their protection as is.
+            let _ = unsafe { self.restore_text(&text) };
+            return Err(LoadError::Protection(e));
+        }
+
+        let r = relocate(relocs);
+        let restored = unsafe { self.restore_text(&text) }.map_err(LoadError::Protection);
+
+        r.and(restored)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rela(offset: usize) -> elfn::Rela {
+        elfn::Rela {
+            r_offset: offset as elfn::Size,
+            ..Default::default()
+        }
+    }
+
+    // Select segments with relocation targets, skipping writable segments,
+    // and verify targets straddling two segments select both.
+    #[test]
+    fn text_selection() {
+        let segment = |base: usize, prot: u32| Segment {
+            base,
+            len: 0x1000,
+            prot,
+            entry: false,
+        };
+        let segments = [
+            segment(0x10000, mman::PROT_READ),
+            segment(0x11000, mman::PROT_READ | mman::PROT_EXEC),
+            segment(0x12000, mman::PROT_READ | mman::PROT_EXEC),
+            segment(0x13000, mman::PROT_READ | mman::PROT_WRITE),
+        ];
+
+        let text = text_targets(&segments, 0x10000, &[rela(0x1010), rela(0x3000)]).unwrap();
+        assert_eq!(text.as_slice(), &segments[1..2]);
+
+        let text = text_targets(&segments, 0x10000, &[rela(0x1fff)]).unwrap();
+        assert_eq!(text.as_slice(), &segments[1..3]);
+
+        let text = text_targets(&segments, 0x10000, &[rela(0x8000)]).unwrap();
+        assert_eq!(text.as_slice(), &[]);
+    }
+
+    // Apply a relocation to a read-only executable mapping, and verify the
+    // relocated value is retained once its protection is restored.
+    #[test]
+    fn text_relocation() {
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let prot = mman::PROT_READ | mman::PROT_EXEC;
+
+        let base = unsafe {
+            this.syscall
+                .mmap(0, 0x1000, prot, mman::MAP_PRIVATE | mman::MAP_ANONYMOUS, -1, 0)
+                .unwrap()
+        };
+        let segments = [Segment { base, len: 0x1000, prot, entry: false }];
+        let text = text_targets(&segments, base, &[rela(0x10)]).unwrap();
+        assert_eq!(text.as_slice(), &segments);
+
+        unsafe {
+            loader.unprotect_text(&text).unwrap();
+            core::ptr::write_unaligned((base + 0x10) as *mut usize, 0x71);
+            loader.restore_text(&text).unwrap();
+            assert_eq!(core::ptr::read_unaligned((base + 0x10) as *const usize), 0x71);
+            this.syscall.munmap(base, 0x1000).unwrap();
+        }
+    }
+
+    // Relocate a read-only executable mapping of an object announcing text
+    // relocations via `relocate_text()`, and verify the relocation is
+    // applied and errors of the relocation processing are propagated.
+    #[test]
+    fn text_relocation_path() {
+        use crate::loader::{dynamic, reloc};
+
+        #[cfg(target_arch = "aarch64")]
+        const R_ABS: u32 = elfn::Rela::R_AARCH64_ABS64;
+        #[cfg(target_arch = "arm")]
+        const R_ABS: u32 = elfn::Rel::R_ARM_ABS32;
+        #[cfg(target_arch = "riscv64")]
+        const R_ABS: u32 = elfn::Rela::R_RISCV_64;
+        #[cfg(target_arch = "x86")]
+        const R_ABS: u32 = elfn::Rel::R_386_32;
+        #[cfg(target_arch = "x86_64")]
+        const R_ABS: u32 = elfn::Rela::R_X86_64_64;
+
+        #[cfg(target_pointer_width = "32")]
+        let info = (1 << 8) | R_ABS;
+        #[cfg(target_pointer_width = "64")]
+        let info = (1 << 32) | R_ABS as u64;
+
+        let this = unsafe { rt11_linux::this::This::new() };
+        let loader = Loader::new(&this);
+        let prot = mman::PROT_READ | mman::PROT_EXEC;
+        let flags = dynamic::parse_flags(&[elfn::Dyn {
+            d_tag: rt11_ffi_elf::elf::Dyn::<(), ()>::DT_TEXTREL as elfn::Size,
+            ..Default::default()
+        }]);
+        let symtab = [
+            elfn::Sym::default(),
+            elfn::Sym { st_shndx: 1, st_value: 0x40, ..Default::default() },
+        ];
+        let relocs = [elfn::Rela {
+            r_offset: 0x10,
+            r_info: info,
+            r_addend: 8,
+            ..Default::default()
+        }];
+        let mut resolver = |_: &[u8], _: Option<u16>, _: reloc::Scope| None;
+
+        let base = unsafe {
+            this.syscall
+                .mmap(0, 0x1000, prot, mman::MAP_PRIVATE | mman::MAP_ANONYMOUS, -1, 0)
+                .unwrap()
+        };
+        let segments = [Segment { base, len: 0x1000, prot, entry: false }];
+
+        unsafe {
+            loader.relocate_text(&flags, &segments, base, &relocs, |v| {
+                reloc::relocate_symbols(base, v, &symtab, b"\0", None, &mut resolver)
+            }).unwrap();
+            assert_eq!(core::ptr::read_unaligned((base + 0x10) as *const usize), base + 0x48);
+
+            assert_eq!(
+                loader.relocate_text(&flags, &segments, base, &relocs, |_| {
+                    Err(LoadError::InvalidSymbol(1))
+                }),
+                Err(LoadError::InvalidSymbol(1)),
+            );
+
+            this.syscall.munmap(base, 0x1000).unwrap();
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Unwind Information
+//!
+//! Unwinders (e.g., to produce backtraces) locate the call-frame information
+//! of an object via its `.eh_frame_hdr` section, which the linker describes
+//! with a `PT_GNU_EH_FRAME` program header. This module provides the lookup
+//! of that section in a loaded object, which is the entry point a minimal
+//! unwinder registers for every object. The unwinder itself is not part of
+//! the loader.
+//!
+//! The `.eh_frame_hdr` section has the following format (see the LSB,
+//! "Exception Frames"), where encoded values use the pointer encodings of
+//! DWARF (i.e., `DW_EH_PE_*`):
+//!
+//!  * `version: u8`: Version of the format, currently 1.
+//!  * `eh_frame_ptr_enc: u8`: Encoding of `eh_frame_ptr`.
+//!  * `fde_count_enc: u8`: Encoding of `fde_count`, or `DW_EH_PE_omit`
+//!    (i.e., 0xff) if there is no search table.
+//!  * `table_enc: u8`: Encoding of the search table entries, or
+//!    `DW_EH_PE_omit` if there is no search table.
+//!  * `eh_frame_ptr`: Encoded address of the `.eh_frame` section.
+//!  * `fde_count`: Encoded number of entries in the search table.
+//!  * `table`: Binary search table of `fde_count` pairs of encoded values
+//!    `(initial_location, fde_address)`, sorted by `initial_location`.
+//!    Each pair maps the start address of a function to the address of the
+//!    frame description entry covering it.
+//!
+//! Linkers emit the search table with `table_enc` set to
+//! `DW_EH_PE_datarel | DW_EH_PE_sdata4` (i.e., 0x3b), in which case each
+//! value is a signed 32-bit offset relative to the start of `.eh_frame_hdr`.
+//! An unwinder looks up a program counter by searching for the last entry
+//! with an `initial_location` not above it, and then verifies the range of
+//! the frame description entry. Without a search table, an unwinder must
+//! scan `.eh_frame` linearly, starting at `eh_frame_ptr`.
+
+use rt11_ffi_elf::{elf, elfn};
+
+/// Locate Unwind Table Header
+///
+/// Return the runtime address of the `.eh_frame_hdr` section of an object
+/// with the program headers `phdrs`, which was loaded with the load bias
+/// `bias` (i.e., the difference between its runtime addresses and the
+/// virtual addresses of its program headers). The section is described by
+/// the `PT_GNU_EH_FRAME` program header, and lies within a loadable
+/// segment. See the module documentation for its format.
+///
+/// `None` is returned if the object has no `PT_GNU_EH_FRAME` program header
+/// (e.g., it was linked without `--eh-frame-hdr`), or if its address
+/// overflows. The returned address is not validated beyond that, and must
+/// only be dereferenced if the object is mapped as described.
+pub fn eh_frame_hdr(phdrs: &[elfn::Phdr], bias: usize) -> Option<*const u8> {
+    let phdr = phdrs
+        .iter()
+        .find(|v| v.p_type == elf::Phdr::<(), ()>::PT_GNU_EH_FRAME)?;
+
+    bias.checked_add(phdr.p_vaddr as usize).map(|v| v as *const u8)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Locate the header in a set of program headers, and verify objects
+    // without it, or with an overflowing address, yield nothing.
+    #[test]
+    fn eh_frame_hdr_lookup() {
+        let load = elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_LOAD,
+            p_memsz: 0x10000,
+            ..Default::default()
+        };
+        let mut eh = elfn::Phdr {
+            p_type: elf::Phdr::<(), ()>::PT_GNU_EH_FRAME,
+            p_vaddr: 0x2c40,
+            p_memsz: 0x1a4,
+            ..Default::default()
+        };
+
+        assert_eq!(eh_frame_hdr(&[load, eh], 0x10000), Some(0x12c40 as *const u8));
+        assert_eq!(eh_frame_hdr(&[eh, load], 0), Some(0x2c40 as *const u8));
+        assert_eq!(eh_frame_hdr(&[load], 0x10000), None);
+        assert_eq!(eh_frame_hdr(&[], 0x10000), None);
+
+        eh.p_vaddr = usize::MAX as _;
+        assert_eq!(eh_frame_hdr(&[load, eh], 0x10000), None);
+    }
+}
@@ -8,6 +8,11 @@ this project. It currently generates the following data:
      The linux kernel sources provide `include/uapi/asm-generic/unistd.h` with
      proposed system call numbers for new architectures. The system-call
      numbers are extracted by this tool and then provided as rust definitions.
+
+   * Typed Syscall Numbers
+     The same table is emitted a second time, wrapping each system call
+     number in `SyscallNr` tagged with the ABI of the architecture. These
+     definitions refer to the plain ones, and are placed next to them.
 """
 
 
@@ -111,6 +116,32 @@ def systbl(args):
     systbl_emit(systbl=systbl)
 
 
+def typed_emit(*, systbl):
+    """Emit Typed Syscall Table
+
+    Emit rust code as expected by the crate, which wraps the definitions of
+    the system call numbers (as emitted by `systbl_emit()`) as typed numbers
+    of the ABI of the architecture.
+    """
+
+    print("// This code is generated.")
+    print("use super::syscall::{nr, Abi};")
+    print("use crate::common::SyscallNr;")
+    print()
+    for entry in systbl:
+        name = entry[2].upper()
+        print(f"pub const {name}: SyscallNr<Abi> = nr(super::nr::{name});")
+
+
+def typed(args):
+    print("Fetch System Table...", file=sys.stderr)
+    data = systbl_fetch(args=args)
+    print("Parse System Table...", file=sys.stderr)
+    systbl = systbl_parse(args=args, data=data)
+    print("Emit Typed System Table...", file=sys.stderr)
+    typed_emit(systbl=systbl)
+
+
 def parse_args(argv):
     parser = argparse.ArgumentParser(
         add_help=True,
@@ -122,7 +153,7 @@ def parse_args(argv):
 
     parser.add_argument(
         "--generate",
-        choices=["systbl"],
+        choices=["systbl", "typed"],
         help="What to generate",
         required=True,
         type=str,
@@ -136,6 +167,8 @@ def run(argv):
 
     if args.generate == "systbl":
         systbl(args)
+    elif args.generate == "typed":
+        typed(args)
     else:
         raise RuntimeError("Nothing to do")
 